@@ -0,0 +1,635 @@
+//! Async Alert Multiplexing API
+//!
+//! This module allows a single task to wait on threshold alerts from several sensors at once,
+//! even when those sensors measure different quantities.
+//!
+//! Each sensor-type module providing a `*ThresholdWait` trait also provides an adapter
+//! (e.g. [`TemperatureAlert`](crate::temperature::TemperatureAlert)) which turns any implementor
+//! of that trait into an [`AlertSource`]. Sources are then grouped into an [`AlertMux`], whose
+//! [`wait_any`](AlertMux::wait_any) method resolves as soon as any of them fires. The sample
+//! reported with each alert is a [`MuxSample`], whose variant identifies the source which fired
+//! and holds a sample of that source's own type.
+//!
+//! Each source also assigns a [`Severity`] to its thresholds, which is reported with every
+//! [`AlertEvent`] so that consumers can route critical alerts to emergency handling without
+//! re-deriving levels themselves.
+//!
+//! Sources which latch the time of each crossing themselves report it in
+//! [`ThresholdEvent::timestamp_us`]. Other sources can be wrapped in [`Timestamped`], which
+//! records the time read from a [`Clock`] in the same poll in which the source reports the
+//! crossing, so that alerts from several sensors can be correlated even when the consumer
+//! handles them late. That is the time the crossing was observed rather than when it occurred:
+//! it is later by the latency of the waker and executor, and by however long the crossing was
+//! pending before the source was next waited on.
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal_async::alert::{AlertEvent, AlertMux, MuxSample};
+//! use embedded_sensors_hal_async::humidity::{RelativeHumidityAlert, RelativeHumidityThresholdWait};
+//! use embedded_sensors_hal_async::sensor::{Severity, ThresholdKind};
+//! use embedded_sensors_hal_async::temperature::{TemperatureAlert, TemperatureThresholdWait};
+//!
+//! async fn supervise(
+//!     temp_sensor: impl TemperatureThresholdWait,
+//!     humidity_sensor: impl RelativeHumidityThresholdWait,
+//! ) {
+//!     let mut temp =
+//!         TemperatureAlert::new(temp_sensor).with_severity(Severity::Warning, Severity::Critical);
+//!     let mut humidity = RelativeHumidityAlert::new(humidity_sensor);
+//!
+//!     if temp.set_thresholds(10.0, 70.0).await.is_err()
+//!         || humidity.set_thresholds(20.0, 80.0).await.is_err()
+//!     {
+//!         return;
+//!     }
+//!
+//!     let mut mux = AlertMux::new((temp, humidity));
+//!     loop {
+//!         match mux.wait_any().await {
+//!             Ok(AlertEvent { severity: Severity::Critical, .. }) => {
+//!                 // Too hot, begin emergency shutdown...
+//!             }
+//!             Ok(AlertEvent { event, .. }) if event.kind == ThresholdKind::High => {
+//!                 if let MuxSample::Source1(relative_humidity) = event.value {
+//!                     // Too humid...
+//!                     let _ = relative_humidity;
+//!                 }
+//!             }
+//!             Ok(_) => {
+//!                 // Some other threshold was crossed...
+//!             }
+//!             Err(_) => {
+//!                 // A sensor failed while waiting for its alert...
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use core::convert::Infallible;
+use core::future::{poll_fn, Future};
+use core::pin::pin;
+use core::task::Poll;
+
+use crate::clock::Clock;
+use crate::sensor::{Error, ErrorKind, Severity, ThresholdEvent, ThresholdKind};
+
+/// A source of threshold alerts which can be multiplexed by an [`AlertMux`].
+///
+/// Implementations must be cancel-safe: when another source fires first, the pending
+/// [`wait_for_alert`](AlertSource::wait_for_alert) future of every other source is dropped.
+pub trait AlertSource {
+    /// Sample type reported alongside each alert.
+    type Sample;
+
+    /// Wait for a threshold of this source to be crossed.
+    async fn wait_for_alert(&mut self) -> Result<ThresholdEvent<Self::Sample>, ErrorKind>;
+
+    /// Returns the severity assigned to crossings of the given threshold.
+    ///
+    /// Defaults to [`Severity::Warning`] for both thresholds.
+    fn severity(&self, _kind: ThresholdKind) -> Severity {
+        Severity::Warning
+    }
+}
+
+impl<T: AlertSource + ?Sized> AlertSource for &mut T {
+    type Sample = T::Sample;
+
+    #[inline]
+    async fn wait_for_alert(&mut self) -> Result<ThresholdEvent<Self::Sample>, ErrorKind> {
+        T::wait_for_alert(self).await
+    }
+
+    #[inline]
+    fn severity(&self, kind: ThresholdKind) -> Severity {
+        T::severity(self, kind)
+    }
+}
+
+/// Wraps an [`AlertSource`], timestamping each of its events with the time read from a [`Clock`]
+/// in the same poll in which the source resolves.
+///
+/// This is the time at which the crossing was observed, which may be later than the crossing
+/// itself. Events already timestamped by the source, such as those of a driver which latches the
+/// time of the crossing, keep their own timestamp.
+pub struct Timestamped<S, C> {
+    source: S,
+    clock: C,
+}
+
+impl<S: AlertSource, C: Clock> Timestamped<S, C> {
+    /// Wraps `source`, timestamping its events with `clock`.
+    pub fn new(source: S, clock: C) -> Self {
+        Self { source, clock }
+    }
+
+    /// Consumes the wrapper, returning the wrapped source and clock.
+    pub fn into_inner(self) -> (S, C) {
+        (self.source, self.clock)
+    }
+}
+
+impl<S: AlertSource, C: Clock> AlertSource for Timestamped<S, C> {
+    type Sample = S::Sample;
+
+    async fn wait_for_alert(&mut self) -> Result<ThresholdEvent<Self::Sample>, ErrorKind> {
+        let event = self.source.wait_for_alert().await?;
+        Ok(match event.timestamp_us {
+            Some(_) => event,
+            None => event.with_timestamp(self.clock.now_us()),
+        })
+    }
+
+    #[inline]
+    fn severity(&self, kind: ThresholdKind) -> Severity {
+        self.source.severity(kind)
+    }
+}
+
+/// An alert reported by an [`AlertMux`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AlertEvent<T> {
+    /// Index of the source which fired, in the order sources were given to the mux.
+    pub source: usize,
+    /// The threshold crossing reported by the source.
+    pub event: ThresholdEvent<T>,
+    /// Severity the source assigns to the crossed threshold.
+    pub severity: Severity,
+}
+
+/// Sample of an alert reported by an [`AlertMux`], tagged with the source which reported it.
+///
+/// Each variant holds a sample of the type reported by the source at the same index of the tuple
+/// given to the mux, so that sources measuring different quantities can be multiplexed. The
+/// variants of the sources a mux does not have hold [`Infallible`] and are never reported.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MuxSample<A, B, C = Infallible, D = Infallible> {
+    /// Sample reported by the first source.
+    Source0(A),
+    /// Sample reported by the second source.
+    Source1(B),
+    /// Sample reported by the third source.
+    Source2(C),
+    /// Sample reported by the fourth source.
+    Source3(D),
+}
+
+/// An error reported by one of the sources of an [`AlertMux`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AlertError {
+    /// Index of the source which failed, in the order sources were given to the mux.
+    pub source: usize,
+    /// Kind of error reported by the source.
+    pub kind: ErrorKind,
+}
+
+impl Error for AlertError {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+/// Waits on several [`AlertSource`]s at once.
+///
+/// Sources are held as a tuple of between two and four elements, which may be of different
+/// types and report different sample types. If several sources are ready at the same time, the
+/// first one reported is the one following the source which fired last, in tuple order, so that
+/// a source which fires continually cannot starve the others.
+pub struct AlertMux<S> {
+    sources: S,
+    next: usize,
+}
+
+impl<S> AlertMux<S> {
+    /// Creates a new multiplexer over a tuple of sources.
+    pub fn new(sources: S) -> Self {
+        Self { sources, next: 0 }
+    }
+
+    /// Returns a mutable reference to the tuple of sources.
+    pub fn sources_mut(&mut self) -> &mut S {
+        &mut self.sources
+    }
+
+    /// Consumes the multiplexer, returning the tuple of sources.
+    pub fn into_inner(self) -> S {
+        self.sources
+    }
+}
+
+macro_rules! impl_alert_mux {
+    ($($Source:ident => $idx:tt => $Variant:ident),+; $count:literal) => {
+        impl<$($Source: AlertSource),+> AlertMux<($($Source,)+)> {
+            /// Wait for any source to report a threshold crossing.
+            ///
+            /// Returns the index of the source which fired along with its event.
+            pub async fn wait_any(
+                &mut self,
+            ) -> Result<AlertEvent<MuxSample<$($Source::Sample),+>>, AlertError> {
+                paste::paste! {
+                    $(
+                        let [<severity_ $idx>] = (
+                            self.sources.$idx.severity(ThresholdKind::Low),
+                            self.sources.$idx.severity(ThresholdKind::High),
+                        );
+                        let mut [<future_ $idx>] = pin!(self.sources.$idx.wait_for_alert());
+                    )+
+                    let start = self.next;
+                    let next = &mut self.next;
+
+                    poll_fn(|cx| {
+                        for offset in 0..$count {
+                            let source = (start + offset) % $count;
+                            let result = match source {
+                                $(
+                                    $idx => match [<future_ $idx>].as_mut().poll(cx) {
+                                        Poll::Ready(result) => result.map(|event| AlertEvent {
+                                            source,
+                                            severity: match event.kind {
+                                                ThresholdKind::Low => [<severity_ $idx>].0,
+                                                ThresholdKind::High => [<severity_ $idx>].1,
+                                            },
+                                            event: ThresholdEvent {
+                                                value: MuxSample::$Variant(event.value),
+                                                kind: event.kind,
+                                                timestamp_us: event.timestamp_us,
+                                            },
+                                        }),
+                                        Poll::Pending => continue,
+                                    },
+                                )+
+                                _ => unreachable!(),
+                            };
+                            *next = (source + 1) % $count;
+                            return Poll::Ready(result.map_err(|kind| AlertError { source, kind }));
+                        }
+                        Poll::Pending
+                    })
+                    .await
+                }
+            }
+        }
+    };
+}
+
+impl_alert_mux!(A => 0 => Source0, B => 1 => Source1; 2);
+impl_alert_mux!(A => 0 => Source0, B => 1 => Source1, C => 2 => Source2; 3);
+impl_alert_mux!(A => 0 => Source0, B => 1 => Source1, C => 2 => Source2, D => 3 => Source3; 4);
+
+/// Generates an [`AlertSource`] adapter for the `*ThresholdWait` trait of the specified sensor type.
+macro_rules! decl_alert_source {
+    ($SensorName:ident, $SampleType:ty) => {
+        paste::paste! {
+            #[doc = concat!(" Adapts a [`", stringify!($SensorName), "ThresholdWait`] implementor into an [`AlertSource`](crate::alert::AlertSource).")]
+            pub struct [<$SensorName Alert>]<T> {
+                sensor: T,
+                severity_low: $crate::sensor::Severity,
+                severity_high: $crate::sensor::Severity,
+            }
+
+            impl<T: [<$SensorName ThresholdWait>]> [<$SensorName Alert>]<T> {
+                #[doc = concat!(" Creates a new ", stringify!($SensorName), " alert source.")]
+                pub fn new(sensor: T) -> Self {
+                    Self {
+                        sensor,
+                        severity_low: $crate::sensor::Severity::Warning,
+                        severity_high: $crate::sensor::Severity::Warning,
+                    }
+                }
+
+                /// Sets the severity reported for crossings of the lower and upper thresholds.
+                ///
+                /// Both default to [`Severity::Warning`]($crate::sensor::Severity::Warning).
+                pub fn with_severity(
+                    mut self,
+                    severity_low: $crate::sensor::Severity,
+                    severity_high: $crate::sensor::Severity,
+                ) -> Self {
+                    self.severity_low = severity_low;
+                    self.severity_high = severity_high;
+                    self
+                }
+
+                #[doc = concat!(" Set the lower and upper ", stringify!($SensorName), " thresholds of the underlying sensor.")]
+                pub async fn set_thresholds(
+                    &mut self,
+                    threshold_low: $SampleType,
+                    threshold_high: $SampleType,
+                ) -> Result<(), T::Error> {
+                    self.sensor.[<set_ $SensorName:snake _threshold_low>](threshold_low).await?;
+                    self.sensor.[<set_ $SensorName:snake _threshold_high>](threshold_high).await
+                }
+
+                /// Consumes the adapter, returning the underlying sensor.
+                pub fn into_inner(self) -> T {
+                    self.sensor
+                }
+            }
+
+            impl<T: [<$SensorName ThresholdWait>]> $crate::alert::AlertSource for [<$SensorName Alert>]<T> {
+                type Sample = $SampleType;
+
+                async fn wait_for_alert(
+                    &mut self,
+                ) -> Result<$crate::sensor::ThresholdEvent<$SampleType>, $crate::sensor::ErrorKind> {
+                    self.sensor
+                        .[<wait_for_ $SensorName:snake _threshold>]()
+                        .await
+                        .map_err(|e| $crate::sensor::Error::kind(&e))
+                }
+
+                fn severity(&self, kind: $crate::sensor::ThresholdKind) -> $crate::sensor::Severity {
+                    match kind {
+                        $crate::sensor::ThresholdKind::Low => self.severity_low,
+                        $crate::sensor::ThresholdKind::High => self.severity_high,
+                    }
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use decl_alert_source;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::temperature::{
+        DegreesCelsius, TemperatureAlert, TemperatureSensor, TemperatureThresholdSet,
+        TemperatureThresholdWait,
+    };
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_TEMP_HIGH: DegreesCelsius = 72.0;
+    const TEST_TEMP_LOW: DegreesCelsius = 4.0;
+    const TEST_THRESHOLD_LOW: DegreesCelsius = 5.0;
+    const TEST_THRESHOLD_HIGH: DegreesCelsius = 70.0;
+    const TEST_COUNT: u32 = 3;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Peripheral
+        }
+    }
+
+    // Mock source which either never fires or fires immediately with a fixed result.
+    struct MockSource {
+        result: Option<Result<ThresholdEvent<f32>, ErrorKind>>,
+    }
+
+    impl AlertSource for MockSource {
+        type Sample = f32;
+
+        async fn wait_for_alert(&mut self) -> Result<ThresholdEvent<f32>, ErrorKind> {
+            match self.result {
+                Some(result) => result,
+                None => core::future::pending().await,
+            }
+        }
+    }
+
+    // Same as `MockSource`, for a source reporting counts rather than temperatures.
+    struct MockCountSource {
+        result: Option<Result<ThresholdEvent<u32>, ErrorKind>>,
+    }
+
+    impl AlertSource for MockCountSource {
+        type Sample = u32;
+
+        async fn wait_for_alert(&mut self) -> Result<ThresholdEvent<u32>, ErrorKind> {
+            match self.result {
+                Some(result) => result,
+                None => core::future::pending().await,
+            }
+        }
+    }
+
+    struct MockTempSensor {
+        value: DegreesCelsius,
+        threshold_low: DegreesCelsius,
+        threshold_high: DegreesCelsius,
+        fail: bool,
+    }
+
+    impl crate::sensor::ErrorType for MockTempSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockTempSensor {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl TemperatureThresholdSet for MockTempSensor {
+        async fn set_temperature_threshold_low(
+            &mut self,
+            threshold: DegreesCelsius,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = threshold;
+            Ok(())
+        }
+
+        async fn set_temperature_threshold_high(
+            &mut self,
+            threshold: DegreesCelsius,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = threshold;
+            Ok(())
+        }
+    }
+
+    impl TemperatureThresholdWait for MockTempSensor {
+        async fn wait_for_temperature_threshold(
+            &mut self,
+        ) -> Result<ThresholdEvent<DegreesCelsius>, Self::Error> {
+            if self.fail {
+                return Err(MockError);
+            }
+            // Simulate reading the alert status register of the sensor.
+            let kind = if self.value > self.threshold_high {
+                ThresholdKind::High
+            } else {
+                ThresholdKind::Low
+            };
+            Ok(ThresholdEvent::new(self.value, kind))
+        }
+    }
+
+    fn mock_temp_sensor(value: DegreesCelsius, fail: bool) -> MockTempSensor {
+        MockTempSensor {
+            value,
+            threshold_low: 0.0,
+            threshold_high: 0.0,
+            fail,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_alert_mux_reports_ready_source() {
+        let pending = MockSource { result: None };
+        let ready = MockSource {
+            result: Some(Ok(ThresholdEvent::new(TEST_TEMP_HIGH, ThresholdKind::High))),
+        };
+        let mut mux = AlertMux::new((pending, ready));
+        let result = mux.wait_any().await;
+        assert!(result.is_ok());
+        let alert = result.unwrap();
+        assert_eq!(alert.source, 1);
+        assert_eq!(alert.event.kind, ThresholdKind::High);
+        assert_eq!(alert.severity, Severity::Warning);
+        match alert.event.value {
+            MuxSample::Source1(value) => assert_approx_eq!(value, TEST_TEMP_HIGH),
+            other => panic!("unexpected sample {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_alert_mux_mixed_sample_types() {
+        let temperature = MockSource {
+            result: Some(Ok(ThresholdEvent::new(TEST_TEMP_HIGH, ThresholdKind::High))),
+        };
+        let count = MockCountSource {
+            result: Some(Ok(ThresholdEvent::new(TEST_COUNT, ThresholdKind::Low))),
+        };
+        let mut mux = AlertMux::new((temperature, count));
+
+        let alert = mux.wait_any().await.unwrap();
+        assert_eq!(alert.source, 0);
+        assert!(matches!(alert.event.value, MuxSample::Source0(value) if value == TEST_TEMP_HIGH));
+
+        let alert = mux.wait_any().await.unwrap();
+        assert_eq!(alert.source, 1);
+        assert_eq!(alert.event.kind, ThresholdKind::Low);
+        assert_eq!(alert.event.value, MuxSample::Source1(TEST_COUNT));
+    }
+
+    #[tokio::test]
+    async fn test_alert_mux_rotates_ready_sources() {
+        let ready = || MockSource {
+            result: Some(Ok(ThresholdEvent::new(TEST_TEMP_HIGH, ThresholdKind::High))),
+        };
+        let mut mux = AlertMux::new((ready(), MockSource { result: None }, ready()));
+
+        // Sources which are always ready take turns rather than the first one starving the other.
+        let mut sources = [0; 4];
+        for source in sources.iter_mut() {
+            *source = mux.wait_any().await.unwrap().source;
+        }
+        assert_eq!(sources, [0, 2, 0, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_alert_mux_reports_source_error() {
+        let pending = MockSource { result: None };
+        let failed = MockSource {
+            result: Some(Err(ErrorKind::Peripheral)),
+        };
+        let mut mux = AlertMux::new((pending, MockSource { result: None }, failed));
+        let result = mux.wait_any().await;
+        assert_eq!(
+            result,
+            Err(AlertError {
+                source: 2,
+                kind: ErrorKind::Peripheral
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_timestamped_alert_source() {
+        let clock = ManualClock::new();
+        let ready = MockSource {
+            result: Some(Ok(ThresholdEvent::new(TEST_TEMP_LOW, ThresholdKind::Low))),
+        };
+        let mut mux = AlertMux::new((MockSource { result: None }, Timestamped::new(ready, &clock)));
+
+        clock.set_us(42_000);
+        let result = mux.wait_any().await;
+        assert!(result.is_ok());
+        let alert = result.unwrap();
+        assert_eq!(alert.source, 1);
+        assert_eq!(alert.event.timestamp_us, Some(42_000));
+
+        // Timestamps latched by the source itself are kept.
+        let latched = MockSource {
+            result: Some(Ok(
+                ThresholdEvent::new(TEST_TEMP_LOW, ThresholdKind::Low).with_timestamp(7_000)
+            )),
+        };
+        let mut latched = Timestamped::new(latched, &clock);
+        let event = latched.wait_for_alert().await.unwrap();
+        assert_eq!(event.timestamp_us, Some(7_000));
+
+        // Events of sources without a clock are not timestamped.
+        let mut untimed = TemperatureAlert::new(mock_temp_sensor(TEST_TEMP_HIGH, false));
+        let event = untimed.wait_for_alert().await.unwrap();
+        assert_eq!(event.timestamp_us, None);
+    }
+
+    #[tokio::test]
+    async fn test_temperature_alert_set_thresholds() {
+        let mut alert = TemperatureAlert::new(mock_temp_sensor(TEST_TEMP_HIGH, false));
+        let result = alert
+            .set_thresholds(TEST_THRESHOLD_LOW, TEST_THRESHOLD_HIGH)
+            .await;
+        assert!(result.is_ok());
+        let sensor = alert.into_inner();
+        assert_approx_eq!(sensor.threshold_low, TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high, TEST_THRESHOLD_HIGH);
+    }
+
+    #[tokio::test]
+    async fn test_temperature_alert_kind() {
+        let mut high = TemperatureAlert::new(mock_temp_sensor(TEST_TEMP_HIGH, false));
+        let mut low = TemperatureAlert::new(mock_temp_sensor(TEST_TEMP_LOW, false));
+        for alert in [&mut high, &mut low] {
+            alert
+                .set_thresholds(TEST_THRESHOLD_LOW, TEST_THRESHOLD_HIGH)
+                .await
+                .unwrap();
+        }
+
+        let event = high.wait_for_alert().await.unwrap();
+        assert_eq!(event.kind, ThresholdKind::High);
+        assert_approx_eq!(event.value, TEST_TEMP_HIGH);
+
+        let event = low.wait_for_alert().await.unwrap();
+        assert_eq!(event.kind, ThresholdKind::Low);
+        assert_approx_eq!(event.value, TEST_TEMP_LOW);
+    }
+
+    #[tokio::test]
+    async fn test_temperature_alert_error() {
+        let mut alert = TemperatureAlert::new(mock_temp_sensor(TEST_TEMP_HIGH, true));
+        let result = alert.wait_for_alert().await;
+        assert_eq!(result, Err(ErrorKind::Peripheral));
+    }
+
+    #[tokio::test]
+    async fn test_alert_mux_with_adapters() {
+        let mut pending = MockSource { result: None };
+        let mut alert = TemperatureAlert::new(mock_temp_sensor(TEST_TEMP_LOW, false))
+            .with_severity(Severity::Critical, Severity::Info);
+        alert
+            .set_thresholds(TEST_THRESHOLD_LOW, TEST_THRESHOLD_HIGH)
+            .await
+            .unwrap();
+        let mut mux = AlertMux::new((&mut pending, &mut alert));
+        let alert = mux.wait_any().await.unwrap();
+        assert_eq!(alert.source, 1);
+        assert_eq!(alert.event.kind, ThresholdKind::Low);
+        assert_eq!(alert.severity, Severity::Critical);
+    }
+}
@@ -0,0 +1,449 @@
+//! Async Altitude Sensor API
+//!
+//! This API provides generic methods for interfacing with altitude sensors specifically.
+//!
+//! Drivers for targets without a floating-point unit can implement the
+//! [`FixedPointAltitudeSensor`] trait, reporting samples in integer millimeters and taking the
+//! reference pressure in integer millipascals, along with the fixed-point threshold and
+//! calibration traits built upon it.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the AltitudeSensor and AltitudeThresholdWait traits for a barometric altitude sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::altitude::{
+//!     AltitudeHysteresis, AltitudeSensor, AltitudeThresholdSet, AltitudeThresholdWait, Meters, Pascals,
+//! };
+//!
+//! // A struct representing a barometric altitude sensor.
+//! pub struct MyAltitudeSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyAltitudeSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl AltitudeSensor for MyAltitudeSensor {
+//!     async fn altitude(&mut self) -> Result<Meters, Self::Error> {
+//!         // ...
+//!         Ok(120.0)
+//!     }
+//!
+//!     async fn set_sea_level_pressure(&mut self, pressure: Pascals) -> Result<(), Self::Error> {
+//!         // Store reference pressure used by altitude conversion...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl AltitudeThresholdSet for MyAltitudeSensor {
+//!     async fn set_altitude_threshold_low(
+//!         &mut self,
+//!         threshold: Meters
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_altitude_threshold_high(
+//!         &mut self,
+//!         threshold: Meters
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl AltitudeThresholdWait for MyAltitudeSensor {
+//!     async fn wait_for_altitude_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<Meters>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.altitude().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl AltitudeHysteresis for MyAltitudeSensor {
+//!     async fn set_altitude_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: Meters
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{
+    decl_calibration_trait, decl_configuration_trait, decl_fixed_point_calibration_trait,
+    decl_fixed_point_threshold_traits, decl_sample_read_trait, decl_threshold_traits,
+    impl_sensor_refinement, ErrorType, Sensor,
+};
+pub use embedded_sensors_hal::altitude::{
+    Altitude, AltitudeConfig, Meters, MilliMeters, MilliPascals, Pascals, ALTITUDE_RANGE,
+    STANDARD_SEA_LEVEL_PRESSURE, STANDARD_SEA_LEVEL_PRESSURE_MILLI,
+};
+
+/// Async Altitude Sensor methods.
+pub trait AltitudeSensor: Sensor<Altitude, Sample = Meters> {
+    /// Returns an altitude sample in meters above sea level.
+    async fn altitude(&mut self) -> Result<Meters, Self::Error>;
+
+    /// Set the sea-level reference pressure (in pascals) used to derive altitude.
+    async fn set_sea_level_pressure(&mut self, pressure: Pascals) -> Result<(), Self::Error>;
+}
+
+impl<T: AltitudeSensor + ?Sized> AltitudeSensor for &mut T {
+    #[inline]
+    async fn altitude(&mut self) -> Result<Meters, Self::Error> {
+        T::altitude(self).await
+    }
+
+    #[inline]
+    async fn set_sea_level_pressure(&mut self, pressure: Pascals) -> Result<(), Self::Error> {
+        T::set_sea_level_pressure(self, pressure).await
+    }
+}
+
+impl_sensor_refinement!(async, Altitude, AltitudeSensor, altitude, Meters);
+
+/// Async fixed-point Altitude Sensor methods, for targets without a floating-point unit.
+pub trait FixedPointAltitudeSensor: ErrorType {
+    /// Returns an altitude sample in millimeters above sea level.
+    async fn altitude_milli(&mut self) -> Result<MilliMeters, Self::Error>;
+
+    /// Set the sea-level reference pressure (in millipascals) used to derive altitude.
+    async fn set_sea_level_pressure_milli(
+        &mut self,
+        pressure: MilliPascals,
+    ) -> Result<(), Self::Error>;
+}
+
+impl<T: FixedPointAltitudeSensor + ?Sized> FixedPointAltitudeSensor for &mut T {
+    #[inline]
+    async fn altitude_milli(&mut self) -> Result<MilliMeters, Self::Error> {
+        T::altitude_milli(self).await
+    }
+
+    #[inline]
+    async fn set_sea_level_pressure_milli(
+        &mut self,
+        pressure: MilliPascals,
+    ) -> Result<(), Self::Error> {
+        T::set_sea_level_pressure_milli(self, pressure).await
+    }
+}
+
+// This macro generates the following async threshold traits:
+//
+// pub trait AltitudeThresholdSet: AltitudeSensor {
+//     async fn set_altitude_threshold_low(&mut self, threshold: Meters) -> Result<(), Self::Error>;
+//     async fn set_altitude_threshold_high(&mut self, threshold: Meters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait AltitudeHysteresis: AltitudeThresholdSet {
+//     async fn set_altitude_threshold_hysteresis(&mut self, hysteresis: Meters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait AltitudeThresholdControl: AltitudeThresholdSet {
+//     async fn disable_altitude_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_altitude_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait AltitudeThresholdWait: AltitudeThresholdSet {
+//     async fn wait_for_altitude_threshold(&mut self) -> Result<ThresholdEvent<Meters>, Self::Error>;
+// }
+decl_threshold_traits!(async, Altitude, AltitudeSensor, Meters, "meters");
+
+// This macro generates the `AltitudeAlert` adapter, which allows implementors of
+// `AltitudeThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(Altitude, Meters);
+
+// This macro generates the following async configuration trait:
+//
+// pub trait AltitudeConfigure: AltitudeSensor {
+//     async fn set_altitude_config(&mut self, config: AltitudeConfig) -> Result<(), Self::Error>;
+// }
+decl_configuration_trait!(async, Altitude, AltitudeSensor, AltitudeConfig);
+
+// This macro generates the following async calibration trait:
+//
+// pub trait AltitudeCalibrate: AltitudeSensor {
+//     async fn set_altitude_offset(&mut self, offset: Meters) -> Result<(), Self::Error>;
+//     async fn set_altitude_gain(&mut self, gain: f32) -> Result<(), Self::Error>;
+// }
+decl_calibration_trait!(async, Altitude, AltitudeSensor, Meters, "meters");
+
+// This macro generates the following async sample read trait:
+//
+// pub trait AltitudeSampleRead: AltitudeSensor {
+//     async fn altitude_sample(&mut self) -> Result<Sample<Meters>, Self::Error>;
+// }
+decl_sample_read_trait!(async, Altitude, AltitudeSensor, Meters);
+
+// This macro generates the following async fixed-point threshold traits:
+//
+// pub trait FixedPointAltitudeThresholdSet: FixedPointAltitudeSensor {
+//     async fn set_altitude_threshold_low_milli(&mut self, threshold: MilliMeters) -> Result<(), Self::Error>;
+//     async fn set_altitude_threshold_high_milli(&mut self, threshold: MilliMeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FixedPointAltitudeHysteresis: FixedPointAltitudeThresholdSet {
+//     async fn set_altitude_threshold_hysteresis_milli(&mut self, hysteresis: MilliMeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FixedPointAltitudeThresholdWait: FixedPointAltitudeThresholdSet {
+//     async fn wait_for_altitude_threshold_milli(&mut self) -> Result<ThresholdEvent<MilliMeters>, Self::Error>;
+// }
+decl_fixed_point_threshold_traits!(async, Altitude, MilliMeters, "millimeters");
+
+// This macro generates the following async fixed-point calibration trait:
+//
+// pub trait FixedPointAltitudeCalibrate: FixedPointAltitudeSensor {
+//     async fn set_altitude_offset_milli(&mut self, offset: MilliMeters) -> Result<(), Self::Error>;
+//     async fn set_altitude_gain_milli(&mut self, gain: u32) -> Result<(), Self::Error>;
+// }
+decl_fixed_point_calibration_trait!(async, Altitude, MilliMeters, "millimeters");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind, ThresholdEvent, ThresholdKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Meters = 350.0;
+    const TEST_THRESHOLD_LOW: Meters = 0.0;
+    const TEST_THRESHOLD_HIGH: Meters = 3_000.0;
+    const TEST_INITIAL_THRESHOLD: Meters = 0.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncAltitudeSensor {
+        value: Meters,
+        threshold_low: Meters,
+        threshold_high: Meters,
+        sea_level_pressure: Pascals,
+    }
+
+    impl MockAsyncAltitudeSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: TEST_INITIAL_THRESHOLD,
+                threshold_high: TEST_INITIAL_THRESHOLD,
+                sea_level_pressure: STANDARD_SEA_LEVEL_PRESSURE,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncAltitudeSensor {
+        type Error = MockError;
+    }
+
+    impl AltitudeSensor for MockAsyncAltitudeSensor {
+        async fn altitude(&mut self) -> Result<Meters, Self::Error> {
+            Ok(self.value)
+        }
+
+        async fn set_sea_level_pressure(&mut self, pressure: Pascals) -> Result<(), Self::Error> {
+            self.sea_level_pressure = pressure;
+            Ok(())
+        }
+    }
+
+    impl AltitudeThresholdSet for MockAsyncAltitudeSensor {
+        async fn set_altitude_threshold_low(
+            &mut self,
+            threshold: Meters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = threshold;
+            Ok(())
+        }
+
+        async fn set_altitude_threshold_high(
+            &mut self,
+            threshold: Meters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = threshold;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_altitude_sensor_trait() {
+        let mut sensor = MockAsyncAltitudeSensor::new();
+        let result = sensor.altitude().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_altitude_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncAltitudeSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.altitude().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_altitude_threshold_set_trait_mut_ref() {
+        let mut sensor = MockAsyncAltitudeSensor::new();
+
+        {
+            let mut_ref = &mut sensor;
+            let result_low = mut_ref.set_altitude_threshold_low(TEST_THRESHOLD_LOW).await;
+            assert!(result_low.is_ok());
+            let result_high = mut_ref
+                .set_altitude_threshold_high(TEST_THRESHOLD_HIGH)
+                .await;
+            assert!(result_high.is_ok());
+        }
+
+        assert_approx_eq!(sensor.threshold_low, TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high, TEST_THRESHOLD_HIGH);
+    }
+
+    #[tokio::test]
+    async fn test_async_altitude_set_sea_level_pressure_mut_ref() {
+        let mut sensor = MockAsyncAltitudeSensor::new();
+        let mut_ref = &mut sensor;
+        let pressure = 100_900.0;
+        let result = mut_ref.set_sea_level_pressure(pressure).await;
+        assert!(result.is_ok());
+        assert_approx_eq!(sensor.sea_level_pressure, pressure);
+    }
+
+    struct MockAsyncFixedPointAltitudeSensor {
+        sea_level_pressure: MilliPascals,
+        threshold_low: MilliMeters,
+        threshold_high: MilliMeters,
+        offset: MilliMeters,
+        gain: u32,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncFixedPointAltitudeSensor {
+        type Error = MockError;
+    }
+
+    impl FixedPointAltitudeSensor for MockAsyncFixedPointAltitudeSensor {
+        async fn altitude_milli(&mut self) -> Result<MilliMeters, Self::Error> {
+            Ok(350_000)
+        }
+
+        async fn set_sea_level_pressure_milli(
+            &mut self,
+            pressure: MilliPascals,
+        ) -> Result<(), Self::Error> {
+            self.sea_level_pressure = pressure;
+            Ok(())
+        }
+    }
+
+    impl FixedPointAltitudeThresholdSet for MockAsyncFixedPointAltitudeSensor {
+        async fn set_altitude_threshold_low_milli(
+            &mut self,
+            threshold: MilliMeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = threshold;
+            Ok(())
+        }
+
+        async fn set_altitude_threshold_high_milli(
+            &mut self,
+            threshold: MilliMeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = threshold;
+            Ok(())
+        }
+    }
+
+    impl FixedPointAltitudeThresholdWait for MockAsyncFixedPointAltitudeSensor {
+        async fn wait_for_altitude_threshold_milli(
+            &mut self,
+        ) -> Result<ThresholdEvent<MilliMeters>, Self::Error> {
+            Ok(ThresholdEvent::new(3_100_000, ThresholdKind::High))
+        }
+    }
+
+    impl FixedPointAltitudeCalibrate for MockAsyncFixedPointAltitudeSensor {
+        async fn set_altitude_offset_milli(
+            &mut self,
+            offset: MilliMeters,
+        ) -> Result<(), Self::Error> {
+            self.offset = offset;
+            Ok(())
+        }
+
+        async fn set_altitude_gain_milli(&mut self, gain: u32) -> Result<(), Self::Error> {
+            self.gain = gain;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_fixed_point_altitude_sensor_mut_ref() {
+        let mut sensor = MockAsyncFixedPointAltitudeSensor {
+            sea_level_pressure: STANDARD_SEA_LEVEL_PRESSURE_MILLI,
+            threshold_low: 0,
+            threshold_high: 0,
+            offset: 0,
+            gain: 1_000,
+        };
+
+        {
+            let mut_ref = &mut sensor;
+            assert_eq!(mut_ref.altitude_milli().await.unwrap(), 350_000);
+            assert!(mut_ref
+                .set_sea_level_pressure_milli(100_900_000)
+                .await
+                .is_ok());
+            assert!(mut_ref
+                .set_altitude_threshold_low_milli(-500_000)
+                .await
+                .is_ok());
+            assert!(mut_ref
+                .set_altitude_threshold_high_milli(3_000_000)
+                .await
+                .is_ok());
+            assert!(mut_ref.set_altitude_offset_milli(-1_250).await.is_ok());
+            assert!(mut_ref.set_altitude_gain_milli(998).await.is_ok());
+            let event = mut_ref.wait_for_altitude_threshold_milli().await.unwrap();
+            assert_eq!(event.value, 3_100_000);
+            assert_eq!(event.kind, ThresholdKind::High);
+        }
+
+        assert_eq!(sensor.sea_level_pressure, 100_900_000);
+        assert_eq!(sensor.threshold_low, -500_000);
+        assert_eq!(sensor.threshold_high, 3_000_000);
+        assert_eq!(sensor.offset, -1_250);
+        assert_eq!(sensor.gain, 998);
+    }
+}
@@ -0,0 +1,163 @@
+//! Async Angle Sensor API
+//!
+//! This API provides generic methods for interfacing with angle (rotary position) sensors
+//! specifically, such as AS5600-class magnetic encoders, so that motor control code can use any
+//! of them as a position source.
+//!
+//! Sensors which also count full turns (either in hardware or in their driver) can additionally
+//! implement the [`MultiTurnAngleSensor`] trait.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the AngleSensor and MultiTurnAngleSensor traits
+//! for a magnetic encoder.
+//!
+//! ```
+//! use embedded_sensors_hal_async::angle::{AngleSensor, Degrees, MultiTurnAngleSensor};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a magnetic encoder.
+//! pub struct MyEncoder {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyEncoder {
+//!     type Error = Error;
+//! }
+//!
+//! impl AngleSensor for MyEncoder {
+//!     async fn angle(&mut self) -> Result<Degrees, Self::Error> {
+//!         // Read 12-bit angle register of sensor and scale it to degrees...
+//!         let raw: u16 = 1024;
+//!         Ok(f32::from(raw) * 360.0 / 4096.0)
+//!     }
+//! }
+//!
+//! impl MultiTurnAngleSensor for MyEncoder {
+//!     async fn turns(&mut self) -> Result<i32, Self::Error> {
+//!         // Read turn counter of sensor...
+//!         Ok(-2)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::angle::{
+    angle_delta, Angle, Degrees, RotaryPosition, Turns, ANGLE_RANGE,
+};
+
+/// Async Angle Sensor methods.
+pub trait AngleSensor: Sensor<Angle, Sample = Degrees> {
+    /// Returns an absolute angle sample in degrees, in `[0, 360)`.
+    async fn angle(&mut self) -> Result<Degrees, Self::Error>;
+}
+
+impl<T: AngleSensor + ?Sized> AngleSensor for &mut T {
+    #[inline]
+    async fn angle(&mut self) -> Result<Degrees, Self::Error> {
+        T::angle(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Angle, AngleSensor, angle, Degrees);
+
+/// Async Multi-Turn Angle Sensor methods.
+pub trait MultiTurnAngleSensor: AngleSensor + Sensor<Turns, Sample = i32> {
+    /// Returns the number of full turns from the zero position, negative in the reverse
+    /// direction.
+    async fn turns(&mut self) -> Result<i32, Self::Error>;
+
+    /// Returns the rotary position, made up of the turn count and absolute angle.
+    ///
+    /// The default implementation reads the turn count, then the angle. Sensors which can latch
+    /// both at once should override it to avoid tearing across a turn boundary.
+    async fn position(&mut self) -> Result<RotaryPosition, Self::Error> {
+        let turns = self.turns().await?;
+        let angle = self.angle().await?;
+        Ok(RotaryPosition { turns, angle })
+    }
+}
+
+impl<T: MultiTurnAngleSensor + ?Sized> MultiTurnAngleSensor for &mut T {
+    #[inline]
+    async fn turns(&mut self) -> Result<i32, Self::Error> {
+        T::turns(self).await
+    }
+
+    #[inline]
+    async fn position(&mut self) -> Result<RotaryPosition, Self::Error> {
+        T::position(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Turns, MultiTurnAngleSensor, turns, i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_ANGLE: Degrees = 90.0;
+    const TEST_TURNS: i32 = -2;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncEncoder;
+
+    impl crate::sensor::ErrorType for MockAsyncEncoder {
+        type Error = MockError;
+    }
+
+    impl AngleSensor for MockAsyncEncoder {
+        async fn angle(&mut self) -> Result<Degrees, Self::Error> {
+            Ok(TEST_ANGLE)
+        }
+    }
+
+    impl MultiTurnAngleSensor for MockAsyncEncoder {
+        async fn turns(&mut self) -> Result<i32, Self::Error> {
+            Ok(TEST_TURNS)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_angle_sensor_trait() {
+        let mut sensor = MockAsyncEncoder;
+        let result = sensor.angle().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_ANGLE);
+    }
+
+    #[tokio::test]
+    async fn test_async_multi_turn_angle_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncEncoder;
+        let mut_ref = &mut sensor;
+        let result = mut_ref.position().await;
+        assert!(result.is_ok());
+        let position = result.unwrap();
+        assert_eq!(position.turns, TEST_TURNS);
+        assert_approx_eq!(position.total_degrees(), -630.0);
+    }
+}
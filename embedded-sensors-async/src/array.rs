@@ -0,0 +1,216 @@
+//! Async Sensor Array API
+//!
+//! This module provides a [`SensorArray`] of `N` sensors of the same type (e.g. the thermistors
+//! of a battery pack), which are sampled together into an [`ArrayReading`] with aggregate
+//! minimum and maximum, and checked against per-sensor thresholds.
+//!
+//! [`SensorArray::read_all`] fails as soon as any sensor does, whereas
+//! [`SensorArray::read_each`] carries on past failed sensors so that telemetry keeps flowing
+//! when one sensor on the bus dies.
+//!
+//! Sensors are given to the array as [`SampleSource`]s, so that arrays of any quantity share the
+//! same implementation.
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal_async::array::{ArrayError, SensorArray};
+//! use embedded_sensors_hal_async::source::TemperatureSource;
+//! use embedded_sensors_hal_async::temperature::TemperatureSensor;
+//!
+//! async fn coldest_cell<S: TemperatureSensor>(cells: [S; 4]) -> Result<Option<f32>, ArrayError> {
+//!     let mut array = SensorArray::new(cells.map(TemperatureSource));
+//!     let reading = array.read_all().await?;
+//!     Ok(reading.min().map(|(_, temperature)| temperature))
+//! }
+//! ```
+
+use crate::sensor::{ErrorKind, ThresholdEvent};
+use crate::source::SampleSource;
+pub use embedded_sensors_hal::array::{ArrayError, ArrayReading, ArrayThresholds, PartialReading};
+
+/// An array of `N` sensors of the same type, sampled together.
+pub struct SensorArray<S: SampleSource, const N: usize> {
+    sources: [S; N],
+    thresholds: ArrayThresholds<S::Sample, N>,
+}
+
+impl<S: SampleSource, const N: usize> SensorArray<S, N>
+where
+    S::Sample: Copy + Default + PartialOrd,
+{
+    /// Creates a new array without thresholds.
+    pub fn new(sources: [S; N]) -> Self {
+        Self {
+            sources,
+            thresholds: ArrayThresholds::new(),
+        }
+    }
+
+    /// Set the lower and upper thresholds of the sensor at `index`.
+    ///
+    /// Fails with [`ErrorKind::InvalidInput`] if there is no sensor at `index`.
+    pub fn set_thresholds(
+        &mut self,
+        index: usize,
+        low: S::Sample,
+        high: S::Sample,
+    ) -> Result<(), ArrayError> {
+        self.thresholds.set(index, low, high)
+    }
+
+    /// Remove the thresholds of the sensor at `index`, if any.
+    pub fn clear_thresholds(&mut self, index: usize) {
+        self.thresholds.clear(index)
+    }
+
+    /// Returns the thresholds of every sensor.
+    pub fn thresholds(&self) -> &ArrayThresholds<S::Sample, N> {
+        &self.thresholds
+    }
+
+    /// Samples every sensor, sequentially and in order.
+    ///
+    /// Sampling stops at the first sensor which fails, whose index is reported in the error.
+    pub async fn read_all(&mut self) -> Result<ArrayReading<S::Sample, N>, ArrayError> {
+        let mut samples = [S::Sample::default(); N];
+        for (index, (sample, source)) in samples.iter_mut().zip(&mut self.sources).enumerate() {
+            *sample = source
+                .sample()
+                .await
+                .map_err(|kind| ArrayError { index, kind })?;
+        }
+
+        Ok(ArrayReading { samples })
+    }
+
+    /// Samples every sensor, sequentially and in order, carrying on past sensors which fail.
+    pub async fn read_each(&mut self) -> PartialReading<S::Sample, N> {
+        let mut samples = [Err(ErrorKind::Other); N];
+        for (sample, source) in samples.iter_mut().zip(&mut self.sources) {
+            *sample = source.sample().await;
+        }
+
+        PartialReading { samples }
+    }
+
+    /// Returns the index and threshold event of every sample of `reading` beyond the thresholds
+    /// of its sensor, in order.
+    pub fn crossings<'a>(
+        &'a self,
+        reading: &'a ArrayReading<S::Sample, N>,
+    ) -> impl Iterator<Item = (usize, ThresholdEvent<S::Sample>)> + 'a {
+        self.thresholds.crossings(reading)
+    }
+
+    /// Returns the index and threshold event of every successful sample of `reading` beyond the
+    /// thresholds of its sensor, in order.
+    pub fn partial_crossings<'a>(
+        &'a self,
+        reading: &'a PartialReading<S::Sample, N>,
+    ) -> impl Iterator<Item = (usize, ThresholdEvent<S::Sample>)> + 'a {
+        self.thresholds.partial_crossings(reading)
+    }
+
+    /// Returns a mutable reference to the sensor at `index`.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut S> {
+        self.sources.get_mut(index)
+    }
+
+    /// Consumes the array, returning the sensors.
+    pub fn into_inner(self) -> [S; N] {
+        self.sources
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ThresholdKind};
+    use crate::source::TemperatureSource;
+    use crate::temperature::{DegreesCelsius, TemperatureSensor};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_TEMPS: [DegreesCelsius; 3] = [31.0, 47.5, 28.0];
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Peripheral
+        }
+    }
+
+    struct MockAsyncTempSensor {
+        value: Option<DegreesCelsius>,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncTempSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockAsyncTempSensor {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            self.value.ok_or(MockError)
+        }
+    }
+
+    fn mock_array() -> SensorArray<TemperatureSource<MockAsyncTempSensor>, 3> {
+        SensorArray::new(
+            TEST_TEMPS.map(|value| TemperatureSource(MockAsyncTempSensor { value: Some(value) })),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_async_sensor_array_read_all() {
+        let mut array = mock_array();
+        assert!(array.set_thresholds(1, 0.0, 45.0).is_ok());
+
+        let result = array.read_all().await;
+        assert!(result.is_ok());
+        let reading = result.unwrap();
+        assert_eq!(reading.samples, TEST_TEMPS);
+        assert_approx_eq!(reading.max().unwrap().1, 47.5);
+
+        let mut crossings = array.crossings(&reading);
+        assert_eq!(
+            crossings.next(),
+            Some((1, ThresholdEvent::new(47.5, ThresholdKind::High)))
+        );
+        assert_eq!(crossings.next(), None);
+    }
+
+    #[tokio::test]
+    async fn test_async_sensor_array_error() {
+        let mut array = mock_array();
+        array.get_mut(1).unwrap().0.value = None;
+        assert_eq!(
+            array.read_all().await,
+            Err(ArrayError {
+                index: 1,
+                kind: ErrorKind::Peripheral
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_async_sensor_array_read_each() {
+        let mut array = mock_array();
+        array.get_mut(0).unwrap().0.value = None;
+        assert!(array.set_thresholds(1, 0.0, 45.0).is_ok());
+
+        let reading = array.read_each().await;
+        assert!(!reading.is_complete());
+        assert_eq!(reading.samples[0], Err(ErrorKind::Peripheral));
+        assert_eq!(reading.min(), Some((2, 28.0)));
+
+        let mut crossings = array.partial_crossings(&reading);
+        assert_eq!(
+            crossings.next(),
+            Some((1, ThresholdEvent::new(47.5, ThresholdKind::High)))
+        );
+        assert_eq!(crossings.next(), None);
+    }
+}
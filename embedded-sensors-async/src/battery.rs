@@ -0,0 +1,339 @@
+//! Async Battery Fuel Gauge API
+//!
+//! This API provides generic methods for interfacing with battery fuel gauges (e.g. MAX17048 or
+//! BQ27xxx) specifically, so that battery services can read the state of charge independently
+//! of the gauge vendor.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the StateOfChargeSensor and StateOfChargeThresholdWait traits for a fuel gauge.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::battery::{
+//!     Percentage, StateOfChargeHysteresis, StateOfChargeSensor, StateOfChargeThresholdSet, StateOfChargeThresholdWait,
+//! };
+//!
+//! // A struct representing a fuel gauge.
+//! pub struct MyFuelGauge {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyFuelGauge {
+//!     type Error = Error;
+//! }
+//!
+//! impl StateOfChargeSensor for MyFuelGauge {
+//!     async fn state_of_charge(&mut self) -> Result<Percentage, Self::Error> {
+//!         // Read state of charge register of gauge (1/256 % per bit)...
+//!         Ok(87.5)
+//!     }
+//! }
+//!
+//! impl StateOfChargeThresholdSet for MyFuelGauge {
+//!     async fn set_state_of_charge_threshold_low(
+//!         &mut self,
+//!         threshold: Percentage
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_state_of_charge_threshold_high(
+//!         &mut self,
+//!         threshold: Percentage
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl StateOfChargeThresholdWait for MyFuelGauge {
+//!     async fn wait_for_state_of_charge_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<Percentage>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.state_of_charge().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl StateOfChargeHysteresis for MyFuelGauge {
+//!     async fn set_state_of_charge_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: Percentage
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::battery::{
+    CycleCount, FullChargeCapacity, MilliampHours, Percentage, StateOfCharge, StateOfHealth,
+    STATE_OF_CHARGE_RANGE,
+};
+
+/// Async State of Charge Sensor methods.
+pub trait StateOfChargeSensor: Sensor<StateOfCharge, Sample = Percentage> {
+    /// Returns a state of charge sample as a percentage of the full capacity of the battery.
+    async fn state_of_charge(&mut self) -> Result<Percentage, Self::Error>;
+}
+
+impl<T: StateOfChargeSensor + ?Sized> StateOfChargeSensor for &mut T {
+    #[inline]
+    async fn state_of_charge(&mut self) -> Result<Percentage, Self::Error> {
+        T::state_of_charge(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    StateOfCharge,
+    StateOfChargeSensor,
+    state_of_charge,
+    Percentage
+);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait StateOfChargeThresholdSet: StateOfChargeSensor {
+//     async fn set_state_of_charge_threshold_low(&mut self, threshold: Percentage) -> Result<(), Self::Error>;
+//     async fn set_state_of_charge_threshold_high(&mut self, threshold: Percentage) -> Result<(), Self::Error>;
+// }
+//
+// pub trait StateOfChargeHysteresis: StateOfChargeThresholdSet {
+//     async fn set_state_of_charge_threshold_hysteresis(&mut self, hysteresis: Percentage) -> Result<(), Self::Error>;
+// }
+//
+// pub trait StateOfChargeThresholdControl: StateOfChargeThresholdSet {
+//     async fn disable_state_of_charge_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_state_of_charge_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait StateOfChargeThresholdWait: StateOfChargeThresholdSet {
+//     async fn wait_for_state_of_charge_threshold(&mut self) -> Result<ThresholdEvent<Percentage>, Self::Error>;
+// }
+decl_threshold_traits!(
+    async,
+    StateOfCharge,
+    StateOfChargeSensor,
+    Percentage,
+    "percent"
+);
+
+// This macro generates the `StateOfChargeAlert` adapter, which allows implementors of
+// `StateOfChargeThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(StateOfCharge, Percentage);
+
+/// Async State of Health Sensor methods.
+pub trait StateOfHealthSensor: Sensor<StateOfHealth, Sample = Percentage> {
+    /// Returns a state of health sample as a percentage, i.e. the full charge capacity of the
+    /// battery relative to its design capacity.
+    async fn state_of_health(&mut self) -> Result<Percentage, Self::Error>;
+}
+
+impl<T: StateOfHealthSensor + ?Sized> StateOfHealthSensor for &mut T {
+    #[inline]
+    async fn state_of_health(&mut self) -> Result<Percentage, Self::Error> {
+        T::state_of_health(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    StateOfHealth,
+    StateOfHealthSensor,
+    state_of_health,
+    Percentage
+);
+
+/// Async Full Charge Capacity Sensor methods.
+pub trait FullChargeCapacitySensor: Sensor<FullChargeCapacity, Sample = MilliampHours> {
+    /// Returns the capacity of the battery when fully charged, as learned by the gauge, in
+    /// milliamp-hours.
+    async fn full_charge_capacity(&mut self) -> Result<MilliampHours, Self::Error>;
+}
+
+impl<T: FullChargeCapacitySensor + ?Sized> FullChargeCapacitySensor for &mut T {
+    #[inline]
+    async fn full_charge_capacity(&mut self) -> Result<MilliampHours, Self::Error> {
+        T::full_charge_capacity(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    FullChargeCapacity,
+    FullChargeCapacitySensor,
+    full_charge_capacity,
+    MilliampHours
+);
+
+/// Async Cycle Count Sensor methods.
+pub trait CycleCountSensor: Sensor<CycleCount, Sample = u32> {
+    /// Returns the number of full charge-discharge cycles the battery has gone through.
+    async fn cycle_count(&mut self) -> Result<u32, Self::Error>;
+}
+
+impl<T: CycleCountSensor + ?Sized> CycleCountSensor for &mut T {
+    #[inline]
+    async fn cycle_count(&mut self) -> Result<u32, Self::Error> {
+        T::cycle_count(self).await
+    }
+}
+
+impl_sensor_refinement!(async, CycleCount, CycleCountSensor, cycle_count, u32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Percentage = 87.5;
+    const TEST_THRESHOLD_LOW: Percentage = 10.0;
+    const TEST_THRESHOLD_HIGH: Percentage = 95.0;
+    const TEST_INITIAL_THRESHOLD: Percentage = 0.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncFuelGauge {
+        value: Percentage,
+        threshold_low: Percentage,
+        threshold_high: Percentage,
+    }
+
+    impl MockAsyncFuelGauge {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: TEST_INITIAL_THRESHOLD,
+                threshold_high: TEST_INITIAL_THRESHOLD,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncFuelGauge {
+        type Error = MockError;
+    }
+
+    impl StateOfChargeSensor for MockAsyncFuelGauge {
+        async fn state_of_charge(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl StateOfChargeThresholdSet for MockAsyncFuelGauge {
+        async fn set_state_of_charge_threshold_low(
+            &mut self,
+            threshold: Percentage,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = threshold;
+            Ok(())
+        }
+
+        async fn set_state_of_charge_threshold_high(
+            &mut self,
+            threshold: Percentage,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = threshold;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_battery_sensor_trait() {
+        let mut sensor = MockAsyncFuelGauge::new();
+        let result = sensor.state_of_charge().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_battery_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncFuelGauge::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.state_of_charge().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_battery_threshold_set_trait_mut_ref() {
+        let mut sensor = MockAsyncFuelGauge::new();
+
+        {
+            let mut_ref = &mut sensor;
+            let result_low = mut_ref
+                .set_state_of_charge_threshold_low(TEST_THRESHOLD_LOW)
+                .await;
+            assert!(result_low.is_ok());
+            let result_high = mut_ref
+                .set_state_of_charge_threshold_high(TEST_THRESHOLD_HIGH)
+                .await;
+            assert!(result_high.is_ok());
+        }
+
+        assert_approx_eq!(sensor.threshold_low, TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high, TEST_THRESHOLD_HIGH);
+    }
+
+    struct MockAsyncSmartGauge;
+
+    impl crate::sensor::ErrorType for MockAsyncSmartGauge {
+        type Error = MockError;
+    }
+
+    impl StateOfHealthSensor for MockAsyncSmartGauge {
+        async fn state_of_health(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(92.0)
+        }
+    }
+
+    impl FullChargeCapacitySensor for MockAsyncSmartGauge {
+        async fn full_charge_capacity(&mut self) -> Result<MilliampHours, Self::Error> {
+            Ok(4_600)
+        }
+    }
+
+    impl CycleCountSensor for MockAsyncSmartGauge {
+        async fn cycle_count(&mut self) -> Result<u32, Self::Error> {
+            Ok(312)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_battery_health_traits_mut_ref() {
+        let mut sensor = MockAsyncSmartGauge;
+        let mut_ref = &mut sensor;
+        assert_approx_eq!(mut_ref.state_of_health().await.unwrap(), 92.0);
+        assert_eq!(mut_ref.full_charge_capacity().await.unwrap(), 4_600);
+        assert_eq!(mut_ref.cycle_count().await.unwrap(), 312);
+    }
+}
@@ -0,0 +1,163 @@
+//! Async Biopotential Sensor API
+//!
+//! This API provides generic methods for interfacing with biopotential analog front-ends (AFE)
+//! specifically, such as the ADS1292 or MAX30003, as used to acquire electrocardiogram (ECG) or
+//! electromyogram (EMG) signals.
+//!
+//! These front-ends produce continuous, high-rate data, so samples are read into a buffer rather
+//! than one at a time. Samples of multi-channel front-ends are interleaved, i.e. the buffer holds
+//! one sample per channel for each conversion, in channel order.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the BiopotentialSensor trait for an ECG
+//! front-end.
+//!
+//! ```
+//! use embedded_sensors_hal_async::biopotential::{BiopotentialSensor, LeadOffStatus, Microvolts};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a single channel ECG front-end.
+//! pub struct MyEcgFrontEnd {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyEcgFrontEnd {
+//!     type Error = Error;
+//! }
+//!
+//! impl BiopotentialSensor for MyEcgFrontEnd {
+//!     fn channel_count(&self) -> usize {
+//!         1
+//!     }
+//!
+//!     async fn read_samples(&mut self, buf: &mut [Microvolts]) -> Result<usize, Self::Error> {
+//!         // Drain FIFO of front-end into buffer and scale samples by gain of channel...
+//!         Ok(0)
+//!     }
+//!
+//!     async fn lead_off(&mut self) -> Result<LeadOffStatus, Self::Error> {
+//!         // Read lead-off status register of front-end...
+//!         Ok(LeadOffStatus::NONE)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+pub use embedded_sensors_hal::biopotential::{LeadOffStatus, Microvolts};
+
+/// Async Biopotential Sensor methods.
+pub trait BiopotentialSensor: ErrorType {
+    /// Returns the number of channels whose samples are interleaved in the buffer.
+    fn channel_count(&self) -> usize;
+
+    /// Reads the available samples into `buf`, in microvolts, returning the number of samples
+    /// read.
+    ///
+    /// Only whole conversions are read, so the number of samples is a multiple of the
+    /// [channel count](Self::channel_count).
+    async fn read_samples(&mut self, buf: &mut [Microvolts]) -> Result<usize, Self::Error>;
+
+    /// Returns the electrodes currently detected as disconnected.
+    async fn lead_off(&mut self) -> Result<LeadOffStatus, Self::Error>;
+}
+
+impl<T: BiopotentialSensor + ?Sized> BiopotentialSensor for &mut T {
+    #[inline]
+    fn channel_count(&self) -> usize {
+        T::channel_count(self)
+    }
+
+    #[inline]
+    async fn read_samples(&mut self, buf: &mut [Microvolts]) -> Result<usize, Self::Error> {
+        T::read_samples(self, buf).await
+    }
+
+    #[inline]
+    async fn lead_off(&mut self) -> Result<LeadOffStatus, Self::Error> {
+        T::lead_off(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_FIFO: [Microvolts; 6] = [120.0, -35.5, 840.25, 12.0, -410.0, 3.5];
+    const TEST_LEAD_OFF: LeadOffStatus = LeadOffStatus::electrode(1);
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    // Two channel front-end with three conversions in its FIFO
+    struct MockAsyncEcgFrontEnd {
+        read: usize,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncEcgFrontEnd {
+        type Error = MockError;
+    }
+
+    impl BiopotentialSensor for MockAsyncEcgFrontEnd {
+        fn channel_count(&self) -> usize {
+            2
+        }
+
+        async fn read_samples(&mut self, buf: &mut [Microvolts]) -> Result<usize, Self::Error> {
+            let available = &TEST_FIFO[self.read..];
+            let len = available.len().min(buf.len() / 2 * 2);
+            buf[..len].copy_from_slice(&available[..len]);
+            self.read += len;
+            Ok(len)
+        }
+
+        async fn lead_off(&mut self) -> Result<LeadOffStatus, Self::Error> {
+            Ok(TEST_LEAD_OFF)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_biopotential_sensor_trait() {
+        let mut sensor = MockAsyncEcgFrontEnd { read: 0 };
+        let mut buf = [0.0; 8];
+        let result = sensor.read_samples(&mut buf).await;
+        assert_eq!(result.unwrap(), TEST_FIFO.len());
+        for (sample, expected) in buf.iter().zip(TEST_FIFO.iter()) {
+            assert_approx_eq!(*sample, *expected);
+        }
+        assert!(sensor.lead_off().await.unwrap().contains(TEST_LEAD_OFF));
+    }
+
+    #[tokio::test]
+    async fn test_async_biopotential_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncEcgFrontEnd { read: 0 };
+        let mut_ref = &mut sensor;
+        let mut buf = [0.0; 5];
+        assert_eq!(mut_ref.read_samples(&mut buf).await.unwrap(), 4);
+        assert_eq!(mut_ref.read_samples(&mut buf).await.unwrap(), 2);
+        assert_eq!(mut_ref.read_samples(&mut buf).await.unwrap(), 0);
+        assert_eq!(mut_ref.channel_count(), 2);
+    }
+}
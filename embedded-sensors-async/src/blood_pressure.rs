@@ -0,0 +1,132 @@
+//! Async Blood Pressure Monitor API
+//!
+//! This API provides generic methods for interfacing with blood pressure monitors specifically,
+//! such as oscillometric cuff modules.
+//!
+//! A measurement involves inflating and deflating the cuff and typically takes tens of seconds,
+//! so rather than reading the latest sample, the [`BloodPressureMonitor`] trait explicitly
+//! performs a measurement and returns the systolic and diastolic pressures along with the pulse
+//! rate measured during it.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the BloodPressureMonitor trait for a cuff module.
+//!
+//! ```
+//! use embedded_sensors_hal_async::blood_pressure::{
+//!     BloodPressureMeasurement, BloodPressureMonitor,
+//! };
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a blood pressure cuff module.
+//! pub struct MyCuffModule {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyCuffModule {
+//!     type Error = Error;
+//! }
+//!
+//! impl BloodPressureMonitor for MyCuffModule {
+//!     async fn measure(&mut self) -> Result<BloodPressureMeasurement, Self::Error> {
+//!         // Start pump, await oscillometric measurement to complete and deflate cuff...
+//!         Ok(BloodPressureMeasurement {
+//!             systolic: 118.0,
+//!             diastolic: 76.0,
+//!             pulse: 64.0,
+//!         })
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+pub use embedded_sensors_hal::blood_pressure::{
+    BeatsPerMinute, BloodPressureMeasurement, MillimetersOfMercury,
+};
+
+/// Async Blood Pressure Monitor methods.
+pub trait BloodPressureMonitor: ErrorType {
+    /// Performs a measurement and returns the blood pressure and pulse rate measured.
+    ///
+    /// The measurement typically takes tens of seconds to complete. Implementations must deflate
+    /// the cuff before returning, including when the measurement fails.
+    async fn measure(&mut self) -> Result<BloodPressureMeasurement, Self::Error>;
+}
+
+impl<T: BloodPressureMonitor + ?Sized> BloodPressureMonitor for &mut T {
+    #[inline]
+    async fn measure(&mut self) -> Result<BloodPressureMeasurement, Self::Error> {
+        T::measure(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_MEASUREMENT: BloodPressureMeasurement = BloodPressureMeasurement {
+        systolic: 120.0,
+        diastolic: 81.0,
+        pulse: 64.0,
+    };
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncCuffMonitor {
+        inflated: bool,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncCuffMonitor {
+        type Error = MockError;
+    }
+
+    impl BloodPressureMonitor for MockAsyncCuffMonitor {
+        async fn measure(&mut self) -> Result<BloodPressureMeasurement, Self::Error> {
+            self.inflated = true;
+            // ...
+            self.inflated = false;
+            Ok(TEST_MEASUREMENT)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_blood_pressure_monitor_trait() {
+        let mut sensor = MockAsyncCuffMonitor { inflated: false };
+        let result = sensor.measure().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_MEASUREMENT);
+        assert!(!sensor.inflated);
+    }
+
+    #[tokio::test]
+    async fn test_async_blood_pressure_monitor_trait_mut_ref() {
+        let mut sensor = MockAsyncCuffMonitor { inflated: false };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.measure().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap().mean_arterial_pressure(), 94.0);
+    }
+}
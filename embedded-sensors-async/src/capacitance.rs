@@ -0,0 +1,259 @@
+//! Async Capacitance Sensor API
+//!
+//! This API provides generic methods for interfacing with capacitance-to-digital converters (CDC)
+//! specifically, such as the FDC1004 or AD7745, as used for liquid level sensing and capacitive
+//! proximity detection.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the CapacitanceSensor and CapacitanceThresholdWait
+//! traits for a capacitance-to-digital converter.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::capacitance::{
+//!     CapacitanceHysteresis, CapacitanceSensor, CapacitanceThresholdSet, CapacitanceThresholdWait,
+//!     Picofarads,
+//! };
+//!
+//! // A struct representing a capacitance-to-digital converter.
+//! pub struct MyCdc {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyCdc {
+//!     type Error = Error;
+//! }
+//!
+//! impl CapacitanceSensor for MyCdc {
+//!     async fn capacitance(&mut self) -> Result<Picofarads, Self::Error> {
+//!         // Read measurement registers of converter (24-bit, 2^-19 pF per bit)...
+//!         Ok(4.7)
+//!     }
+//! }
+//!
+//! impl CapacitanceThresholdSet for MyCdc {
+//!     async fn set_capacitance_threshold_low(
+//!         &mut self,
+//!         threshold: Picofarads
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_capacitance_threshold_high(
+//!         &mut self,
+//!         threshold: Picofarads
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl CapacitanceThresholdWait for MyCdc {
+//!     async fn wait_for_capacitance_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<Picofarads>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.capacitance().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl CapacitanceHysteresis for MyCdc {
+//!     async fn set_capacitance_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: Picofarads
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::capacitance::{Capacitance, Picofarads, CAPACITANCE_RANGE};
+
+/// Async Capacitance Sensor methods.
+pub trait CapacitanceSensor: Sensor<Capacitance, Sample = Picofarads> {
+    /// Returns a capacitance sample in picofarads.
+    async fn capacitance(&mut self) -> Result<Picofarads, Self::Error>;
+}
+
+impl<T: CapacitanceSensor + ?Sized> CapacitanceSensor for &mut T {
+    #[inline]
+    async fn capacitance(&mut self) -> Result<Picofarads, Self::Error> {
+        T::capacitance(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    Capacitance,
+    CapacitanceSensor,
+    capacitance,
+    Picofarads
+);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait CapacitanceThresholdSet: CapacitanceSensor {
+//     async fn set_capacitance_threshold_low(&mut self, threshold: Picofarads) -> Result<(), Self::Error>;
+//     async fn set_capacitance_threshold_high(&mut self, threshold: Picofarads) -> Result<(), Self::Error>;
+// }
+//
+// pub trait CapacitanceHysteresis: CapacitanceThresholdSet {
+//     async fn set_capacitance_threshold_hysteresis(&mut self, hysteresis: Picofarads) -> Result<(), Self::Error>;
+// }
+//
+// pub trait CapacitanceThresholdControl: CapacitanceThresholdSet {
+//     async fn disable_capacitance_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_capacitance_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait CapacitanceThresholdWait: CapacitanceThresholdSet {
+//     async fn wait_for_capacitance_threshold(&mut self) -> Result<ThresholdEvent<Picofarads>, Self::Error>;
+// }
+decl_threshold_traits!(
+    async,
+    Capacitance,
+    CapacitanceSensor,
+    Picofarads,
+    "picofarads"
+);
+
+// This macro generates the `CapacitanceAlert` adapter, which allows implementors of
+// `CapacitanceThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(Capacitance, Picofarads);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Picofarads = 4.7;
+    const TEST_THRESHOLD_LOW: Picofarads = 1.0;
+    const TEST_THRESHOLD_HIGH: Picofarads = 12.0;
+    const TEST_HYSTERESIS: Picofarads = 0.2;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncCdc {
+        value: Picofarads,
+        threshold_low: Option<Picofarads>,
+        threshold_high: Option<Picofarads>,
+        hysteresis: Option<Picofarads>,
+    }
+
+    impl MockAsyncCdc {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncCdc {
+        type Error = MockError;
+    }
+
+    impl CapacitanceSensor for MockAsyncCdc {
+        async fn capacitance(&mut self) -> Result<Picofarads, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl CapacitanceThresholdSet for MockAsyncCdc {
+        async fn set_capacitance_threshold_low(
+            &mut self,
+            threshold: Picofarads,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        async fn set_capacitance_threshold_high(
+            &mut self,
+            threshold: Picofarads,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl CapacitanceHysteresis for MockAsyncCdc {
+        async fn set_capacitance_threshold_hysteresis(
+            &mut self,
+            hysteresis: Picofarads,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_capacitance_sensor_trait() {
+        let mut sensor = MockAsyncCdc::new();
+        let result = sensor.capacitance().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_capacitance_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncCdc::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.capacitance().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_capacitance_threshold_set_mut_ref() {
+        let mut sensor = MockAsyncCdc::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_capacitance_threshold_low(TEST_THRESHOLD_LOW)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_capacitance_threshold_high(TEST_THRESHOLD_HIGH)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_capacitance_threshold_hysteresis(TEST_HYSTERESIS)
+            .await
+            .is_ok());
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), TEST_HYSTERESIS);
+    }
+}
@@ -0,0 +1,191 @@
+//! Async Color Sensor API
+//!
+//! This API provides generic methods for interfacing with color (RGBC) sensors specifically.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the ColorSensor and ColorIlluminance traits
+//! for a color sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::color::{ColorIlluminance, ColorSensor, Lux, Rgbc};
+//!
+//! // A struct representing a color sensor.
+//! pub struct MyColorSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyColorSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl ColorSensor for MyColorSensor {
+//!     async fn color(&mut self) -> Result<Rgbc, Self::Error> {
+//!         // Await end of integration cycle, then read channel data registers of sensor...
+//!         Ok(Rgbc {
+//!             red: 100,
+//!             green: 200,
+//!             blue: 50,
+//!             clear: 400,
+//!         })
+//!     }
+//! }
+//!
+//! impl ColorIlluminance for MyColorSensor {
+//!     async fn illuminance(&mut self) -> Result<Lux, Self::Error> {
+//!         // Derive illuminance from channel data using part-specific coefficients...
+//!         Ok(250.0)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{decl_configuration_trait, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::color::{
+    Color, ColorConfig, ColorTemperature, Illuminance, Kelvin, Lux, Rgbc,
+    CORRELATED_COLOR_TEMPERATURE_RANGE, ILLUMINANCE_RANGE,
+};
+
+/// Async Color Sensor methods.
+pub trait ColorSensor: Sensor<Color, Sample = Rgbc> {
+    /// Returns a sample of the red, green, blue and clear channels.
+    async fn color(&mut self) -> Result<Rgbc, Self::Error>;
+}
+
+impl<T: ColorSensor + ?Sized> ColorSensor for &mut T {
+    #[inline]
+    async fn color(&mut self) -> Result<Rgbc, Self::Error> {
+        T::color(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Color, ColorSensor, color, Rgbc);
+
+/// Async Correlated Color Temperature methods for color sensors able to derive it.
+pub trait CorrelatedColorTemperature:
+    ColorSensor + Sensor<ColorTemperature, Sample = Kelvin>
+{
+    /// Returns the correlated color temperature (CCT) in kelvin.
+    async fn correlated_color_temperature(&mut self) -> Result<Kelvin, Self::Error>;
+}
+
+impl<T: CorrelatedColorTemperature + ?Sized> CorrelatedColorTemperature for &mut T {
+    #[inline]
+    async fn correlated_color_temperature(&mut self) -> Result<Kelvin, Self::Error> {
+        T::correlated_color_temperature(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    ColorTemperature,
+    CorrelatedColorTemperature,
+    correlated_color_temperature,
+    Kelvin
+);
+
+/// Async Illuminance methods for color sensors able to derive it.
+pub trait ColorIlluminance: ColorSensor + Sensor<Illuminance, Sample = Lux> {
+    /// Returns the illuminance in lux.
+    async fn illuminance(&mut self) -> Result<Lux, Self::Error>;
+}
+
+impl<T: ColorIlluminance + ?Sized> ColorIlluminance for &mut T {
+    #[inline]
+    async fn illuminance(&mut self) -> Result<Lux, Self::Error> {
+        T::illuminance(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Illuminance, ColorIlluminance, illuminance, Lux);
+
+// This macro generates the following async configuration trait:
+//
+// pub trait ColorConfigure: ColorSensor {
+//     async fn set_color_config(&mut self, config: ColorConfig) -> Result<(), Self::Error>;
+// }
+decl_configuration_trait!(async, Color, ColorSensor, ColorConfig);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_COLOR: Rgbc = Rgbc {
+        red: 120,
+        green: 240,
+        blue: 60,
+        clear: 480,
+    };
+    const TEST_CCT: Kelvin = 5000.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncColorSensor {
+        value: Rgbc,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncColorSensor {
+        type Error = MockError;
+    }
+
+    impl ColorSensor for MockAsyncColorSensor {
+        async fn color(&mut self) -> Result<Rgbc, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl CorrelatedColorTemperature for MockAsyncColorSensor {
+        async fn correlated_color_temperature(&mut self) -> Result<Kelvin, Self::Error> {
+            Ok(TEST_CCT)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_color_sensor_trait() {
+        let mut sensor = MockAsyncColorSensor { value: TEST_COLOR };
+        let result = sensor.color().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_COLOR);
+    }
+
+    #[tokio::test]
+    async fn test_async_color_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncColorSensor { value: TEST_COLOR };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.color().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_COLOR);
+    }
+
+    #[tokio::test]
+    async fn test_async_correlated_color_temperature_mut_ref() {
+        let mut sensor = MockAsyncColorSensor { value: TEST_COLOR };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.correlated_color_temperature().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_CCT);
+    }
+}
@@ -0,0 +1,265 @@
+//! Async Combustible Gas Sensor API
+//!
+//! This API provides generic methods for interfacing with combustible gas sensors specifically,
+//! such as catalytic bead and MEMS pellistor sensors used in industrial safety devices.
+//!
+//! Concentrations are reported as a percentage of the lower explosive limit (LEL) of the gas the
+//! sensor is calibrated for, which is how gas detector alarm levels are specified (e.g. 10 % and 20
+//! % LEL). Alarm levels are configured with the lower explosive limit threshold traits.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the CombustibleGasSensor and
+//! LowerExplosiveLimitThresholdWait traits for a pellistor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::combustible_gas::{
+//!     CombustibleGasSensor, LowerExplosiveLimitHysteresis, LowerExplosiveLimitThresholdSet,
+//!     LowerExplosiveLimitThresholdWait, PercentLel,
+//! };
+//!
+//! // A struct representing a pellistor.
+//! pub struct MyPellistor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyPellistor {
+//!     type Error = Error;
+//! }
+//!
+//! impl CombustibleGasSensor for MyPellistor {
+//!     async fn lower_explosive_limit(&mut self) -> Result<PercentLel, Self::Error> {
+//!         // Read bridge voltage and scale by span calibration...
+//!         Ok(4.5)
+//!     }
+//! }
+//!
+//! impl LowerExplosiveLimitThresholdSet for MyPellistor {
+//!     async fn set_lower_explosive_limit_threshold_low(
+//!         &mut self,
+//!         threshold: PercentLel
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_lower_explosive_limit_threshold_high(
+//!         &mut self,
+//!         threshold: PercentLel
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl LowerExplosiveLimitThresholdWait for MyPellistor {
+//!     async fn wait_for_lower_explosive_limit_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<PercentLel>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.lower_explosive_limit().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl LowerExplosiveLimitHysteresis for MyPellistor {
+//!     async fn set_lower_explosive_limit_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: PercentLel
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::combustible_gas::{
+    CombustibleGas, PercentLel, LOWER_EXPLOSIVE_LIMIT_RANGE,
+};
+
+/// Async Combustible Gas Sensor methods.
+pub trait CombustibleGasSensor: Sensor<CombustibleGas, Sample = PercentLel> {
+    /// Returns a combustible gas concentration sample as a percentage of the lower explosive
+    /// limit.
+    async fn lower_explosive_limit(&mut self) -> Result<PercentLel, Self::Error>;
+}
+
+impl<T: CombustibleGasSensor + ?Sized> CombustibleGasSensor for &mut T {
+    #[inline]
+    async fn lower_explosive_limit(&mut self) -> Result<PercentLel, Self::Error> {
+        T::lower_explosive_limit(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    CombustibleGas,
+    CombustibleGasSensor,
+    lower_explosive_limit,
+    PercentLel
+);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait LowerExplosiveLimitThresholdSet: CombustibleGasSensor {
+//     async fn set_lower_explosive_limit_threshold_low(&mut self, threshold: PercentLel) -> Result<(), Self::Error>;
+//     async fn set_lower_explosive_limit_threshold_high(&mut self, threshold: PercentLel) -> Result<(), Self::Error>;
+// }
+//
+// pub trait LowerExplosiveLimitHysteresis: LowerExplosiveLimitThresholdSet {
+//     async fn set_lower_explosive_limit_threshold_hysteresis(&mut self, hysteresis: PercentLel) -> Result<(), Self::Error>;
+// }
+//
+// pub trait LowerExplosiveLimitThresholdControl: LowerExplosiveLimitThresholdSet {
+//     async fn disable_lower_explosive_limit_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_lower_explosive_limit_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait LowerExplosiveLimitThresholdWait: LowerExplosiveLimitThresholdSet {
+//     async fn wait_for_lower_explosive_limit_threshold(&mut self) -> Result<ThresholdEvent<PercentLel>, Self::Error>;
+// }
+decl_threshold_traits!(
+    async,
+    LowerExplosiveLimit,
+    CombustibleGasSensor,
+    PercentLel,
+    "percent of the lower explosive limit"
+);
+
+// This macro generates the `LowerExplosiveLimitAlert` adapter, which allows implementors of
+// `LowerExplosiveLimitThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(LowerExplosiveLimit, PercentLel);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: PercentLel = 4.5;
+    const TEST_THRESHOLD_LOW: PercentLel = 10.0;
+    const TEST_THRESHOLD_HIGH: PercentLel = 20.0;
+    const TEST_HYSTERESIS: PercentLel = 1.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncPellistor {
+        value: PercentLel,
+        threshold_low: Option<PercentLel>,
+        threshold_high: Option<PercentLel>,
+        hysteresis: Option<PercentLel>,
+    }
+
+    impl MockAsyncPellistor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncPellistor {
+        type Error = MockError;
+    }
+
+    impl CombustibleGasSensor for MockAsyncPellistor {
+        async fn lower_explosive_limit(&mut self) -> Result<PercentLel, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl LowerExplosiveLimitThresholdSet for MockAsyncPellistor {
+        async fn set_lower_explosive_limit_threshold_low(
+            &mut self,
+            threshold: PercentLel,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        async fn set_lower_explosive_limit_threshold_high(
+            &mut self,
+            threshold: PercentLel,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl LowerExplosiveLimitHysteresis for MockAsyncPellistor {
+        async fn set_lower_explosive_limit_threshold_hysteresis(
+            &mut self,
+            hysteresis: PercentLel,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_combustible_gas_sensor_trait() {
+        let mut sensor = MockAsyncPellistor::new();
+        let result = sensor.lower_explosive_limit().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_combustible_gas_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncPellistor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.lower_explosive_limit().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_combustible_gas_threshold_set_mut_ref() {
+        let mut sensor = MockAsyncPellistor::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_lower_explosive_limit_threshold_low(TEST_THRESHOLD_LOW)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_lower_explosive_limit_threshold_high(TEST_THRESHOLD_HIGH)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_lower_explosive_limit_threshold_hysteresis(TEST_HYSTERESIS)
+            .await
+            .is_ok());
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), TEST_HYSTERESIS);
+    }
+}
@@ -0,0 +1,220 @@
+//! Async Environmental Compensation API
+//!
+//! This API provides generic methods for feeding ambient conditions into sensors which compensate
+//! their samples internally, such as gas sensors (e.g. SGP40 or SCD4x) whose accuracy depends on
+//! the temperature, humidity or pressure of the air they sample.
+//!
+//! As the compensation inputs are plain samples, the output of one sensor can be wired into the
+//! compensation of another regardless of the drivers involved.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the TemperatureCompensation and
+//! HumidityCompensation traits for a gas sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::compensation::{
+//!     DegreesCelsius, HumidityCompensation, Percentage, TemperatureCompensation,
+//! };
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a gas sensor with humidity compensation.
+//! pub struct MyGasSensor {
+//!     temperature: DegreesCelsius,
+//!     humidity: Percentage,
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyGasSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl TemperatureCompensation for MyGasSensor {
+//!     async fn set_compensation_temperature(
+//!         &mut self,
+//!         temperature: DegreesCelsius,
+//!     ) -> Result<(), Self::Error> {
+//!         // Store temperature, sent along with the next measurement command of sensor...
+//!         self.temperature = temperature;
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl HumidityCompensation for MyGasSensor {
+//!     async fn set_compensation_humidity(&mut self, humidity: Percentage) -> Result<(), Self::Error> {
+//!         // Store humidity, sent along with the next measurement command of sensor...
+//!         self.humidity = humidity;
+//!         Ok(())
+//!     }
+//! }
+//! ```
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal_async::compensation::{HumidityCompensation, TemperatureCompensation};
+//! use embedded_sensors_hal_async::humidity::RelativeHumiditySensor;
+//! use embedded_sensors_hal_async::sensor::{Error, ErrorKind};
+//! use embedded_sensors_hal_async::temperature::TemperatureSensor;
+//!
+//! // Feed the ambient conditions measured by a humidity sensor into a gas sensor.
+//! async fn compensate<S, G>(ambient: &mut S, gas: &mut G) -> Result<(), ErrorKind>
+//! where
+//!     S: TemperatureSensor + RelativeHumiditySensor,
+//!     G: TemperatureCompensation + HumidityCompensation,
+//! {
+//!     let temperature = ambient.temperature().await.map_err(|e| e.kind())?;
+//!     let humidity = ambient.relative_humidity().await.map_err(|e| e.kind())?;
+//!     gas.set_compensation_temperature(temperature).await.map_err(|e| e.kind())?;
+//!     gas.set_compensation_humidity(humidity).await.map_err(|e| e.kind())
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+pub use embedded_sensors_hal::compensation::{DegreesCelsius, Pascals, Percentage};
+
+/// Async Temperature Compensation methods.
+pub trait TemperatureCompensation: ErrorType {
+    /// Set the ambient temperature the sensor compensates its samples for (in degrees Celsius).
+    ///
+    /// The value is used for every subsequent sample, until it is set again.
+    async fn set_compensation_temperature(
+        &mut self,
+        temperature: DegreesCelsius,
+    ) -> Result<(), Self::Error>;
+}
+
+impl<T: TemperatureCompensation + ?Sized> TemperatureCompensation for &mut T {
+    #[inline]
+    async fn set_compensation_temperature(
+        &mut self,
+        temperature: DegreesCelsius,
+    ) -> Result<(), Self::Error> {
+        T::set_compensation_temperature(self, temperature).await
+    }
+}
+
+/// Async Humidity Compensation methods.
+pub trait HumidityCompensation: ErrorType {
+    /// Set the ambient relative humidity the sensor compensates its samples for (in percent).
+    ///
+    /// The value is used for every subsequent sample, until it is set again.
+    async fn set_compensation_humidity(&mut self, humidity: Percentage) -> Result<(), Self::Error>;
+}
+
+impl<T: HumidityCompensation + ?Sized> HumidityCompensation for &mut T {
+    #[inline]
+    async fn set_compensation_humidity(&mut self, humidity: Percentage) -> Result<(), Self::Error> {
+        T::set_compensation_humidity(self, humidity).await
+    }
+}
+
+/// Async Pressure Compensation methods.
+pub trait PressureCompensation: ErrorType {
+    /// Set the ambient pressure the sensor compensates its samples for (in pascals).
+    ///
+    /// The value is used for every subsequent sample, until it is set again.
+    async fn set_compensation_pressure(&mut self, pressure: Pascals) -> Result<(), Self::Error>;
+}
+
+impl<T: PressureCompensation + ?Sized> PressureCompensation for &mut T {
+    #[inline]
+    async fn set_compensation_pressure(&mut self, pressure: Pascals) -> Result<(), Self::Error> {
+        T::set_compensation_pressure(self, pressure).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_TEMP: DegreesCelsius = 21.5;
+    const TEST_HUMIDITY: Percentage = 48.0;
+    const TEST_PRESSURE: Pascals = 98_650.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    #[derive(Default)]
+    struct MockAsyncGasSensor {
+        temperature: DegreesCelsius,
+        humidity: Percentage,
+        pressure: Pascals,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncGasSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureCompensation for MockAsyncGasSensor {
+        async fn set_compensation_temperature(
+            &mut self,
+            temperature: DegreesCelsius,
+        ) -> Result<(), Self::Error> {
+            self.temperature = temperature;
+            Ok(())
+        }
+    }
+
+    impl HumidityCompensation for MockAsyncGasSensor {
+        async fn set_compensation_humidity(
+            &mut self,
+            humidity: Percentage,
+        ) -> Result<(), Self::Error> {
+            self.humidity = humidity;
+            Ok(())
+        }
+    }
+
+    impl PressureCompensation for MockAsyncGasSensor {
+        async fn set_compensation_pressure(
+            &mut self,
+            pressure: Pascals,
+        ) -> Result<(), Self::Error> {
+            self.pressure = pressure;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_compensation_traits_mut_ref() {
+        let mut sensor = MockAsyncGasSensor::default();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_compensation_temperature(TEST_TEMP)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_compensation_humidity(TEST_HUMIDITY)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_compensation_pressure(TEST_PRESSURE)
+            .await
+            .is_ok());
+        assert_approx_eq!(sensor.temperature, TEST_TEMP);
+        assert_approx_eq!(sensor.humidity, TEST_HUMIDITY);
+        assert_approx_eq!(sensor.pressure, TEST_PRESSURE);
+    }
+}
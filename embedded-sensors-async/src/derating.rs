@@ -0,0 +1,114 @@
+//! Async Thermal Derating API
+//!
+//! This module provides a [`ThermalDerating`] component which maps the samples of a temperature
+//! sensor through a [`DeratingCurve`] into a limit, such as the maximum charge current of a
+//! battery charger or the minimum duty cycle of a fan.
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal_async::derating::{
+//!     Breakpoint, DeratingCurve, DeratingError, ThermalDerating,
+//! };
+//! use embedded_sensors_hal_async::temperature::TemperatureSensor;
+//!
+//! // Minimum fan duty cycle in percent, ramping up from 40 °C to 70 °C.
+//! const FAN_DUTY: DeratingCurve<2> =
+//!     DeratingCurve::new([Breakpoint::new(40.0, 20.0), Breakpoint::new(70.0, 100.0)]);
+//!
+//! async fn fan_duty<S: TemperatureSensor>(cpu: S) -> Result<f32, DeratingError<S::Error>> {
+//!     let mut derating = ThermalDerating::new(cpu, FAN_DUTY);
+//!     derating.limit().await
+//! }
+//! ```
+
+use crate::sensor::checked_sample;
+use crate::temperature::TemperatureSensor;
+pub use embedded_sensors_hal::derating::{Breakpoint, DeratingCurve, DeratingError};
+
+/// Derives a limit from the samples of a temperature sensor through a [`DeratingCurve`].
+pub struct ThermalDerating<S, const N: usize> {
+    sensor: S,
+    curve: DeratingCurve<N>,
+}
+
+impl<S: TemperatureSensor, const N: usize> ThermalDerating<S, N> {
+    /// Creates a new derating component.
+    pub fn new(sensor: S, curve: DeratingCurve<N>) -> Self {
+        Self { sensor, curve }
+    }
+
+    /// Returns the curve used to derive limits.
+    pub fn curve(&self) -> &DeratingCurve<N> {
+        &self.curve
+    }
+
+    /// Samples the temperature sensor, returning the limit at the current temperature.
+    pub async fn limit(&mut self) -> Result<f32, DeratingError<S::Error>> {
+        let temperature = self
+            .sensor
+            .temperature()
+            .await
+            .map_err(DeratingError::Sensor)?;
+        self.curve
+            .evaluate(temperature)
+            .and_then(|limit| checked_sample(limit).ok())
+            .ok_or(DeratingError::InvalidSample)
+    }
+
+    /// Consumes the component, returning the temperature sensor.
+    pub fn into_inner(self) -> S {
+        self.sensor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use crate::temperature::DegreesCelsius;
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_CURVE: DeratingCurve<2> =
+        DeratingCurve::new([Breakpoint::new(40.0, 20.0), Breakpoint::new(70.0, 100.0)]);
+
+    #[derive(Debug, PartialEq)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Peripheral
+        }
+    }
+
+    struct MockAsyncTempSensor {
+        value: Option<DegreesCelsius>,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncTempSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockAsyncTempSensor {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            self.value.ok_or(MockError)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_thermal_derating() {
+        let sensor = MockAsyncTempSensor { value: Some(55.0) };
+        let mut derating = ThermalDerating::new(sensor, TEST_CURVE);
+        let result = derating.limit().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), 60.0);
+
+        let sensor = MockAsyncTempSensor { value: None };
+        let mut derating = ThermalDerating::new(sensor, TEST_CURVE);
+        assert_eq!(
+            derating.limit().await,
+            Err(DeratingError::Sensor(MockError))
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! Async Dew Point Sensor API
+//!
+//! This API provides generic methods for interfacing with sensors reporting the dew point, the
+//! temperature at which the water vapor in the air would start to condense.
+//!
+//! Sensors measuring both temperature and relative humidity do not need to implement the
+//! [`DewPointSensor`] trait themselves: with the `libm` feature enabled, the `DerivedDewPoint`
+//! adapter derives the dew point of any such sensor using the Magnus formula.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the DewPointSensor trait for a sensor computing
+//! the dew point itself.
+//!
+//! ```
+//! use embedded_sensors_hal_async::dew_point::{DegreesCelsius, DewPointSensor};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a dew point transmitter.
+//! pub struct MyDewPointSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyDewPointSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl DewPointSensor for MyDewPointSensor {
+//!     async fn dew_point(&mut self) -> Result<DegreesCelsius, Self::Error> {
+//!         // Read dew point register of sensor...
+//!         Ok(9.3)
+//!     }
+//! }
+//! ```
+
+#[cfg(feature = "libm")]
+use crate::humidity::{Percentage, RelativeHumiditySensor};
+#[cfg(feature = "libm")]
+use crate::sensor::checked_sample;
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+#[cfg(feature = "libm")]
+use crate::temperature::TemperatureSensor;
+#[cfg(feature = "libm")]
+pub use embedded_sensors_hal::dew_point::{magnus_dew_point, DerivedDewPointError};
+pub use embedded_sensors_hal::dew_point::{DegreesCelsius, DewPoint};
+
+/// Async Dew Point Sensor methods.
+pub trait DewPointSensor: Sensor<DewPoint, Sample = DegreesCelsius> {
+    /// Returns a dew point sample in degrees Celsius.
+    async fn dew_point(&mut self) -> Result<DegreesCelsius, Self::Error>;
+}
+
+impl<T: DewPointSensor + ?Sized> DewPointSensor for &mut T {
+    #[inline]
+    async fn dew_point(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        T::dew_point(self).await
+    }
+}
+
+impl_sensor_refinement!(async, DewPoint, DewPointSensor, dew_point, DegreesCelsius);
+
+/// Derives the dew point of a sensor measuring both temperature and relative humidity.
+///
+/// The wrapped sensor's temperature and relative humidity remain available through the adapter.
+#[cfg(feature = "libm")]
+pub struct DerivedDewPoint<S>(pub S);
+
+#[cfg(feature = "libm")]
+impl<S: ErrorType> ErrorType for DerivedDewPoint<S> {
+    type Error = DerivedDewPointError<S::Error>;
+}
+
+#[cfg(feature = "libm")]
+impl<S: TemperatureSensor + RelativeHumiditySensor> DewPointSensor for DerivedDewPoint<S> {
+    async fn dew_point(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        let temperature = self
+            .0
+            .temperature()
+            .await
+            .map_err(DerivedDewPointError::Sensor)?;
+        let relative_humidity = self
+            .0
+            .relative_humidity()
+            .await
+            .map_err(DerivedDewPointError::Sensor)?;
+        checked_sample(magnus_dew_point(temperature, relative_humidity))
+            .map_err(|_| DerivedDewPointError::InvalidSample)
+    }
+}
+
+#[cfg(feature = "libm")]
+impl<S: TemperatureSensor> TemperatureSensor for DerivedDewPoint<S> {
+    #[inline]
+    async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        self.0
+            .temperature()
+            .await
+            .map_err(DerivedDewPointError::Sensor)
+    }
+}
+
+#[cfg(feature = "libm")]
+impl<S: RelativeHumiditySensor> RelativeHumiditySensor for DerivedDewPoint<S> {
+    #[inline]
+    async fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+        self.0
+            .relative_humidity()
+            .await
+            .map_err(DerivedDewPointError::Sensor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_DEW_POINT: DegreesCelsius = 9.3;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncComboSensor;
+
+    impl crate::sensor::ErrorType for MockAsyncComboSensor {
+        type Error = MockError;
+    }
+
+    impl DewPointSensor for MockAsyncComboSensor {
+        async fn dew_point(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(TEST_DEW_POINT)
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    impl TemperatureSensor for MockAsyncComboSensor {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(20.0)
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    impl RelativeHumiditySensor for MockAsyncComboSensor {
+        async fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(50.0)
+        }
+    }
+
+    // Reports bone-dry air, which has no dew point.
+    #[cfg(feature = "libm")]
+    struct MockAsyncDrySensor;
+
+    #[cfg(feature = "libm")]
+    impl crate::sensor::ErrorType for MockAsyncDrySensor {
+        type Error = MockError;
+    }
+
+    #[cfg(feature = "libm")]
+    impl TemperatureSensor for MockAsyncDrySensor {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(25.0)
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    impl RelativeHumiditySensor for MockAsyncDrySensor {
+        async fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(0.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_dew_point_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncComboSensor;
+        let mut_ref = &mut sensor;
+        let result = mut_ref.dew_point().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_DEW_POINT);
+    }
+
+    #[cfg(feature = "libm")]
+    #[tokio::test]
+    async fn test_async_derived_dew_point() {
+        let mut sensor = DerivedDewPoint(MockAsyncComboSensor);
+        let result = sensor.dew_point().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), 9.26, 0.01);
+        assert_approx_eq!(sensor.temperature().await.unwrap(), 20.0);
+    }
+
+    #[cfg(feature = "libm")]
+    #[tokio::test]
+    async fn test_async_derived_dew_point_dry_air() {
+        let mut sensor = DerivedDewPoint(MockAsyncDrySensor);
+        let result = sensor.dew_point().await;
+        assert!(matches!(result, Err(DerivedDewPointError::InvalidSample)));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,525 @@
+//! Async Distance Sensor API
+//!
+//! This API provides generic methods for interfacing with distance sensors specifically.
+//!
+//! The [`DistanceSensor`] trait covers ranging sensors such as time-of-flight (ToF) sensors of
+//! the VL53L1X kind, which report each distance along with a [`DistanceStatus`] telling whether
+//! the target was in range and the return signal strong enough for the distance to be trusted.
+//!
+//! The [`RangeSensor`] trait covers trigger-and-echo ultrasonic rangers (HC-SR04 and the like),
+//! for which every measurement is initiated by a ping and bounded by an echo timeout. Targets
+//! whose echo does not return in time are reported as [`DistanceStatus::OutOfRange`].
+//!
+//! The [`SurfaceHeightSensor`] trait covers downward-looking sensors (typically ultrasonic or
+//! radar) installed at a known height above a reference surface, as found in snow depth and
+//! water level stations. Besides the measured distance to the surface, it reports the depth of
+//! the accumulated snow or water, computed from the configured mounting height.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the DistanceSensor and DistanceThresholdSet
+//! traits for a time-of-flight sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::distance::{
+//!     DistanceSample, DistanceSensor, DistanceStatus, DistanceThresholdSet, RawMillimeters,
+//! };
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a time-of-flight sensor.
+//! pub struct MyTofSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyTofSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl DistanceSensor for MyTofSensor {
+//!     async fn distance(&mut self) -> Result<DistanceSample, Self::Error> {
+//!         // Read range status and distance registers of sensor...
+//!         Ok(DistanceSample {
+//!             distance: 412,
+//!             status: DistanceStatus::Valid,
+//!         })
+//!     }
+//! }
+//!
+//! impl DistanceThresholdSet for MyTofSensor {
+//!     async fn set_distance_threshold_low(
+//!         &mut self,
+//!         threshold: RawMillimeters
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_distance_threshold_high(
+//!         &mut self,
+//!         threshold: RawMillimeters
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+//!
+//! Here is an example for the implementation of the RangeSensor trait for an ultrasonic ranger.
+//!
+//! ```
+//! use embedded_sensors_hal_async::distance::{echo_distance, DistanceSample, DistanceStatus, RangeSensor, SPEED_OF_SOUND};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing an ultrasonic ranger.
+//! pub struct MyUltrasonicRanger {
+//!     echo_timeout_us: u32,
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyUltrasonicRanger {
+//!     type Error = Error;
+//! }
+//!
+//! impl RangeSensor for MyUltrasonicRanger {
+//!     async fn measure(&mut self) -> Result<DistanceSample, Self::Error> {
+//!         // Pulse TRIG pin, then time the ECHO pulse, giving up after the echo timeout...
+//!         let echo_us = 2_400;
+//!         Ok(DistanceSample {
+//!             distance: echo_distance(echo_us, SPEED_OF_SOUND),
+//!             status: DistanceStatus::Valid,
+//!         })
+//!     }
+//!
+//!     fn echo_timeout_us(&self) -> u32 {
+//!         self.echo_timeout_us
+//!     }
+//!
+//!     async fn set_echo_timeout_us(&mut self, timeout_us: u32) -> Result<(), Self::Error> {
+//!         self.echo_timeout_us = timeout_us;
+//!         Ok(())
+//!     }
+//! }
+//! ```
+//!
+//! Here is an example for the implementation of the SurfaceHeightSensor trait for an ultrasonic
+//! snow depth sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::distance::{Meters, SurfaceHeightSensor};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing an ultrasonic snow depth sensor.
+//! pub struct MySnowDepthSensor {
+//!     mounting_height: Meters,
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MySnowDepthSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl SurfaceHeightSensor for MySnowDepthSensor {
+//!     async fn surface_distance(&mut self) -> Result<Meters, Self::Error> {
+//!         // Trigger a ping and compensate time of flight for air temperature...
+//!         Ok(1.6)
+//!     }
+//!
+//!     fn mounting_height(&self) -> Meters {
+//!         self.mounting_height
+//!     }
+//!
+//!     async fn set_mounting_height(&mut self, height: Meters) -> Result<(), Self::Error> {
+//!         self.mounting_height = height;
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::distance::{
+    echo_distance, speed_of_sound, Distance, DistanceSample, DistanceStatus, Meters,
+    RawMillimeters, SurfaceDistance, SurfaceHeight, SPEED_OF_SOUND,
+};
+
+/// Async Distance Sensor methods.
+pub trait DistanceSensor: Sensor<Distance, Sample = DistanceSample> {
+    /// Returns a distance sample in millimeters, along with its status.
+    async fn distance(&mut self) -> Result<DistanceSample, Self::Error>;
+}
+
+impl<T: DistanceSensor + ?Sized> DistanceSensor for &mut T {
+    #[inline]
+    async fn distance(&mut self) -> Result<DistanceSample, Self::Error> {
+        T::distance(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Distance, DistanceSensor, distance, DistanceSample);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait DistanceThresholdSet: DistanceSensor {
+//     async fn set_distance_threshold_low(&mut self, threshold: RawMillimeters) -> Result<(), Self::Error>;
+//     async fn set_distance_threshold_high(&mut self, threshold: RawMillimeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait DistanceHysteresis: DistanceThresholdSet {
+//     async fn set_distance_threshold_hysteresis(&mut self, hysteresis: RawMillimeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait DistanceThresholdControl: DistanceThresholdSet {
+//     async fn disable_distance_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_distance_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait DistanceThresholdWait: DistanceThresholdSet {
+//     async fn wait_for_distance_threshold(&mut self) -> Result<ThresholdEvent<RawMillimeters>, Self::Error>;
+// }
+decl_threshold_traits!(
+    async,
+    Distance,
+    DistanceSensor,
+    RawMillimeters,
+    "millimeters"
+);
+
+// This macro generates the `DistanceAlert` adapter, which allows implementors of
+// `DistanceThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(Distance, RawMillimeters);
+
+/// Async Range Sensor methods, for rangers triggered per measurement.
+pub trait RangeSensor: ErrorType {
+    /// Triggers a measurement and waits for its echo, returning the distance to the target in
+    /// millimeters along with its status.
+    ///
+    /// If no echo is received within the [echo timeout](Self::echo_timeout_us), the sample is
+    /// reported as [`DistanceStatus::OutOfRange`] rather than as an error.
+    async fn measure(&mut self) -> Result<DistanceSample, Self::Error>;
+
+    /// Returns the time to wait for an echo after triggering a measurement, in microseconds.
+    fn echo_timeout_us(&self) -> u32;
+
+    /// Set the time to wait for an echo after triggering a measurement, in microseconds.
+    ///
+    /// Longer timeouts extend the range of the sensor at the cost of a longer measurement.
+    async fn set_echo_timeout_us(&mut self, timeout_us: u32) -> Result<(), Self::Error>;
+}
+
+impl<T: RangeSensor + ?Sized> RangeSensor for &mut T {
+    #[inline]
+    async fn measure(&mut self) -> Result<DistanceSample, Self::Error> {
+        T::measure(self).await
+    }
+
+    #[inline]
+    fn echo_timeout_us(&self) -> u32 {
+        T::echo_timeout_us(self)
+    }
+
+    #[inline]
+    async fn set_echo_timeout_us(&mut self, timeout_us: u32) -> Result<(), Self::Error> {
+        T::set_echo_timeout_us(self, timeout_us).await
+    }
+}
+
+/// Async Surface Height methods.
+pub trait SurfaceHeightSensor: Sensor<SurfaceDistance, Sample = Meters> {
+    /// Returns a sample of the distance to the surface below the sensor in meters.
+    async fn surface_distance(&mut self) -> Result<Meters, Self::Error>;
+
+    /// Returns the height of the sensor above the reference surface in meters.
+    fn mounting_height(&self) -> Meters;
+
+    /// Set the height of the sensor above the reference surface (in meters) used to derive depth.
+    async fn set_mounting_height(&mut self, height: Meters) -> Result<(), Self::Error>;
+
+    /// Returns a distance-to-surface sample along with the depth derived from the mounting height.
+    async fn surface_height(&mut self) -> Result<SurfaceHeight, Self::Error> {
+        let distance = self.surface_distance().await?;
+        Ok(SurfaceHeight::from_distance(
+            self.mounting_height(),
+            distance,
+        ))
+    }
+}
+
+impl<T: SurfaceHeightSensor + ?Sized> SurfaceHeightSensor for &mut T {
+    #[inline]
+    async fn surface_distance(&mut self) -> Result<Meters, Self::Error> {
+        T::surface_distance(self).await
+    }
+
+    #[inline]
+    fn mounting_height(&self) -> Meters {
+        T::mounting_height(self)
+    }
+
+    #[inline]
+    async fn set_mounting_height(&mut self, height: Meters) -> Result<(), Self::Error> {
+        T::set_mounting_height(self, height).await
+    }
+
+    #[inline]
+    async fn surface_height(&mut self) -> Result<SurfaceHeight, Self::Error> {
+        T::surface_height(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    SurfaceDistance,
+    SurfaceHeightSensor,
+    surface_distance,
+    Meters
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_DISTANCE: Meters = 1.75;
+    const TEST_MOUNTING_HEIGHT: Meters = 2.5;
+    const TEST_SAMPLE: DistanceSample = DistanceSample {
+        distance: 412,
+        status: DistanceStatus::Valid,
+    };
+    const TEST_THRESHOLD_LOW: RawMillimeters = 100;
+    const TEST_THRESHOLD_HIGH: RawMillimeters = 1_200;
+    const TEST_ECHO_US: u32 = 12_000;
+    const TEST_ECHO_TIMEOUT_US: u32 = 25_000;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncTofSensor {
+        sample: DistanceSample,
+        threshold_low: RawMillimeters,
+        threshold_high: RawMillimeters,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncTofSensor {
+        type Error = MockError;
+    }
+
+    impl DistanceSensor for MockAsyncTofSensor {
+        async fn distance(&mut self) -> Result<DistanceSample, Self::Error> {
+            Ok(self.sample)
+        }
+    }
+
+    impl DistanceThresholdSet for MockAsyncTofSensor {
+        async fn set_distance_threshold_low(
+            &mut self,
+            threshold: RawMillimeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = threshold;
+            Ok(())
+        }
+
+        async fn set_distance_threshold_high(
+            &mut self,
+            threshold: RawMillimeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = threshold;
+            Ok(())
+        }
+    }
+
+    struct MockAsyncUltrasonicRanger {
+        echo_us: Option<u32>,
+        echo_timeout_us: u32,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncUltrasonicRanger {
+        type Error = MockError;
+    }
+
+    impl RangeSensor for MockAsyncUltrasonicRanger {
+        async fn measure(&mut self) -> Result<DistanceSample, Self::Error> {
+            Ok(match self.echo_us {
+                Some(echo_us) if echo_us <= self.echo_timeout_us => DistanceSample {
+                    distance: echo_distance(echo_us, SPEED_OF_SOUND),
+                    status: DistanceStatus::Valid,
+                },
+                _ => DistanceSample {
+                    distance: 0,
+                    status: DistanceStatus::OutOfRange,
+                },
+            })
+        }
+
+        fn echo_timeout_us(&self) -> u32 {
+            self.echo_timeout_us
+        }
+
+        async fn set_echo_timeout_us(&mut self, timeout_us: u32) -> Result<(), Self::Error> {
+            self.echo_timeout_us = timeout_us;
+            Ok(())
+        }
+    }
+
+    struct MockAsyncSnowDepthSensor {
+        distance: Meters,
+        mounting_height: Meters,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncSnowDepthSensor {
+        type Error = MockError;
+    }
+
+    impl SurfaceHeightSensor for MockAsyncSnowDepthSensor {
+        async fn surface_distance(&mut self) -> Result<Meters, Self::Error> {
+            Ok(self.distance)
+        }
+
+        fn mounting_height(&self) -> Meters {
+            self.mounting_height
+        }
+
+        async fn set_mounting_height(&mut self, height: Meters) -> Result<(), Self::Error> {
+            self.mounting_height = height;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_distance_sensor_trait() {
+        let mut sensor = MockAsyncTofSensor {
+            sample: TEST_SAMPLE,
+            threshold_low: 0,
+            threshold_high: 0,
+        };
+        let result = sensor.distance().await;
+        assert!(result.is_ok());
+        let sample = result.unwrap();
+        assert_eq!(sample, TEST_SAMPLE);
+        assert_eq!(sample.distance(), Some(412));
+
+        sensor.sample.status = DistanceStatus::SignalLow;
+        assert_eq!(sensor.distance().await.unwrap().distance(), None);
+    }
+
+    #[tokio::test]
+    async fn test_async_distance_threshold_set_trait_mut_ref() {
+        let mut sensor = MockAsyncTofSensor {
+            sample: TEST_SAMPLE,
+            threshold_low: 0,
+            threshold_high: 0,
+        };
+
+        {
+            let mut_ref = &mut sensor;
+            let result_low = mut_ref.set_distance_threshold_low(TEST_THRESHOLD_LOW).await;
+            assert!(result_low.is_ok());
+            let result_high = mut_ref
+                .set_distance_threshold_high(TEST_THRESHOLD_HIGH)
+                .await;
+            assert!(result_high.is_ok());
+        }
+
+        assert_eq!(sensor.threshold_low, TEST_THRESHOLD_LOW);
+        assert_eq!(sensor.threshold_high, TEST_THRESHOLD_HIGH);
+    }
+
+    #[tokio::test]
+    async fn test_async_range_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncUltrasonicRanger {
+            echo_us: Some(TEST_ECHO_US),
+            echo_timeout_us: 0,
+        };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_echo_timeout_us(TEST_ECHO_TIMEOUT_US)
+            .await
+            .is_ok());
+        assert_eq!(mut_ref.echo_timeout_us(), TEST_ECHO_TIMEOUT_US);
+
+        let result = mut_ref.measure().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().distance(), Some(2_059));
+
+        // The echo of a target beyond the timeout is never heard.
+        mut_ref.echo_us = Some(TEST_ECHO_TIMEOUT_US + 1);
+        let sample = mut_ref.measure().await.unwrap();
+        assert_eq!(sample.status, DistanceStatus::OutOfRange);
+        assert_eq!(sample.distance(), None);
+    }
+
+    #[tokio::test]
+    async fn test_async_surface_height_sensor_trait() {
+        let mut sensor = MockAsyncSnowDepthSensor {
+            distance: TEST_DISTANCE,
+            mounting_height: TEST_MOUNTING_HEIGHT,
+        };
+        let result = sensor.surface_height().await;
+        assert!(result.is_ok());
+        let sample = result.unwrap();
+        assert_approx_eq!(sample.distance, TEST_DISTANCE);
+        assert_approx_eq!(sample.depth, 0.75);
+    }
+
+    #[tokio::test]
+    async fn test_async_surface_height_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncSnowDepthSensor {
+            distance: TEST_DISTANCE,
+            mounting_height: TEST_MOUNTING_HEIGHT,
+        };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_mounting_height(1.5).await.is_ok());
+        assert_approx_eq!(mut_ref.mounting_height(), 1.5);
+
+        // The surface now lies below the reference surface.
+        let result = mut_ref.surface_height().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap().depth, -0.25);
+    }
+}
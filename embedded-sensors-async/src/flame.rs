@@ -0,0 +1,174 @@
+//! Async Flame Sensor API
+//!
+//! This API provides generic methods for interfacing with infrared flame sensors specifically, as
+//! used for flame supervision in heater and appliance control.
+//!
+//! All flame sensors report whether a flame is detected, while sensors with an analog output can
+//! additionally implement the [`FlameIntensitySensor`] trait. Sensors with a digital output
+//! wired to an interrupt-capable GPIO can implement the [`FlameWait`] trait.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the FlameSensor and FlameWait traits for an IR
+//! flame sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::flame::{FlameSensor, FlameWait};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing an IR flame sensor.
+//! pub struct MyFlameSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyFlameSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl FlameSensor for MyFlameSensor {
+//!     async fn flame_detected(&mut self) -> Result<bool, Self::Error> {
+//!         // Read digital output pin of sensor...
+//!         Ok(false)
+//!     }
+//! }
+//!
+//! impl FlameWait for MyFlameSensor {
+//!     async fn wait_for_flame(&mut self) -> Result<(), Self::Error> {
+//!         // Await active level on digital output pin of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::flame::{Flame, FlameIntensity, Percentage, FLAME_INTENSITY_RANGE};
+
+/// Async Flame Sensor methods.
+pub trait FlameSensor: Sensor<Flame, Sample = bool> {
+    /// Returns `true` if a flame is currently detected.
+    async fn flame_detected(&mut self) -> Result<bool, Self::Error>;
+}
+
+impl<T: FlameSensor + ?Sized> FlameSensor for &mut T {
+    #[inline]
+    async fn flame_detected(&mut self) -> Result<bool, Self::Error> {
+        T::flame_detected(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Flame, FlameSensor, flame_detected, bool);
+
+/// Async Flame Intensity Sensor methods.
+pub trait FlameIntensitySensor: FlameSensor + Sensor<FlameIntensity, Sample = Percentage> {
+    /// Returns a flame intensity sample as a percentage of the full scale of the sensor.
+    async fn flame_intensity(&mut self) -> Result<Percentage, Self::Error>;
+}
+
+impl<T: FlameIntensitySensor + ?Sized> FlameIntensitySensor for &mut T {
+    #[inline]
+    async fn flame_intensity(&mut self) -> Result<Percentage, Self::Error> {
+        T::flame_intensity(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    FlameIntensity,
+    FlameIntensitySensor,
+    flame_intensity,
+    Percentage
+);
+
+/// Async Flame Wait methods.
+pub trait FlameWait: FlameSensor {
+    /// Wait for a flame to be detected, returning immediately if one already is.
+    async fn wait_for_flame(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: FlameWait + ?Sized> FlameWait for &mut T {
+    #[inline]
+    async fn wait_for_flame(&mut self) -> Result<(), Self::Error> {
+        T::wait_for_flame(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_DETECTION_LEVEL: Percentage = 20.0;
+    const TEST_INTENSITY: Percentage = 65.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncFlameSensor {
+        intensity: Percentage,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncFlameSensor {
+        type Error = MockError;
+    }
+
+    impl FlameSensor for MockAsyncFlameSensor {
+        async fn flame_detected(&mut self) -> Result<bool, Self::Error> {
+            Ok(self.intensity >= TEST_DETECTION_LEVEL)
+        }
+    }
+
+    impl FlameIntensitySensor for MockAsyncFlameSensor {
+        async fn flame_intensity(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(self.intensity)
+        }
+    }
+
+    impl FlameWait for MockAsyncFlameSensor {
+        async fn wait_for_flame(&mut self) -> Result<(), Self::Error> {
+            // Ignite the flame
+            self.intensity = TEST_INTENSITY;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_flame_sensor_trait() {
+        let mut sensor = MockAsyncFlameSensor {
+            intensity: TEST_INTENSITY,
+        };
+        let result = sensor.flame_detected().await;
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+        assert_approx_eq!(sensor.flame_intensity().await.unwrap(), TEST_INTENSITY);
+    }
+
+    #[tokio::test]
+    async fn test_async_flame_wait_trait_mut_ref() {
+        let mut sensor = MockAsyncFlameSensor { intensity: 0.0 };
+        let mut_ref = &mut sensor;
+        assert!(!mut_ref.flame_detected().await.unwrap());
+        assert!(mut_ref.wait_for_flame().await.is_ok());
+        assert!(mut_ref.flame_detected().await.unwrap());
+    }
+}
@@ -0,0 +1,282 @@
+//! Async Flow Sensor API
+//!
+//! This API provides generic methods for interfacing with volumetric flow sensors specifically.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the FlowSensor and FlowRateThresholdWait traits for a Hall-effect flow meter.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::flow::{
+//!     FlowRateHysteresis, FlowRateThresholdSet, FlowRateThresholdWait, FlowSensor, Liters, LitersPerMinute,
+//! };
+//!
+//! // A struct representing a Hall-effect flow meter.
+//! pub struct MyFlowSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyFlowSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl FlowSensor for MyFlowSensor {
+//!     async fn flow_rate(&mut self) -> Result<LitersPerMinute, Self::Error> {
+//!         // Convert pulse frequency to flow rate...
+//!         Ok(2.5)
+//!     }
+//!
+//!     async fn total_volume(&mut self) -> Result<Liters, Self::Error> {
+//!         // Convert total pulse count to volume...
+//!         Ok(42.0)
+//!     }
+//!
+//!     async fn reset_total_volume(&mut self) -> Result<(), Self::Error> {
+//!         // Clear pulse counter...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl FlowRateThresholdSet for MyFlowSensor {
+//!     async fn set_flow_rate_threshold_low(
+//!         &mut self,
+//!         threshold: LitersPerMinute
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_flow_rate_threshold_high(
+//!         &mut self,
+//!         threshold: LitersPerMinute
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl FlowRateThresholdWait for MyFlowSensor {
+//!     async fn wait_for_flow_rate_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<LitersPerMinute>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.flow_rate().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl FlowRateHysteresis for MyFlowSensor {
+//!     async fn set_flow_rate_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: LitersPerMinute
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::flow::{FlowRate, Liters, LitersPerMinute, FLOW_RATE_RANGE};
+
+/// Async Flow Sensor methods.
+pub trait FlowSensor: Sensor<FlowRate, Sample = LitersPerMinute> {
+    /// Returns a volumetric flow rate sample in liters per minute.
+    async fn flow_rate(&mut self) -> Result<LitersPerMinute, Self::Error>;
+
+    /// Returns the volume accumulated by the totalizer since its last reset, in liters.
+    async fn total_volume(&mut self) -> Result<Liters, Self::Error>;
+
+    /// Reset the volume accumulated by the totalizer to zero.
+    async fn reset_total_volume(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: FlowSensor + ?Sized> FlowSensor for &mut T {
+    #[inline]
+    async fn flow_rate(&mut self) -> Result<LitersPerMinute, Self::Error> {
+        T::flow_rate(self).await
+    }
+
+    #[inline]
+    async fn total_volume(&mut self) -> Result<Liters, Self::Error> {
+        T::total_volume(self).await
+    }
+
+    #[inline]
+    async fn reset_total_volume(&mut self) -> Result<(), Self::Error> {
+        T::reset_total_volume(self).await
+    }
+}
+
+impl_sensor_refinement!(async, FlowRate, FlowSensor, flow_rate, LitersPerMinute);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait FlowRateThresholdSet: FlowSensor {
+//     async fn set_flow_rate_threshold_low(&mut self, threshold: LitersPerMinute) -> Result<(), Self::Error>;
+//     async fn set_flow_rate_threshold_high(&mut self, threshold: LitersPerMinute) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FlowRateHysteresis: FlowRateThresholdSet {
+//     async fn set_flow_rate_threshold_hysteresis(&mut self, hysteresis: LitersPerMinute) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FlowRateThresholdControl: FlowRateThresholdSet {
+//     async fn disable_flow_rate_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_flow_rate_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FlowRateThresholdWait: FlowRateThresholdSet {
+//     async fn wait_for_flow_rate_threshold(&mut self) -> Result<ThresholdEvent<LitersPerMinute>, Self::Error>;
+// }
+decl_threshold_traits!(
+    async,
+    FlowRate,
+    FlowSensor,
+    LitersPerMinute,
+    "liters per minute"
+);
+
+// This macro generates the `FlowRateAlert` adapter, which allows implementors of
+// `FlowRateThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(FlowRate, LitersPerMinute);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: LitersPerMinute = 1.8;
+    const TEST_THRESHOLD_LOW: LitersPerMinute = 0.5;
+    const TEST_THRESHOLD_HIGH: LitersPerMinute = 10.0;
+    const TEST_INITIAL_THRESHOLD: LitersPerMinute = 0.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncFlowSensor {
+        value: LitersPerMinute,
+        threshold_low: LitersPerMinute,
+        threshold_high: LitersPerMinute,
+        total_volume: Liters,
+    }
+
+    impl MockAsyncFlowSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: TEST_INITIAL_THRESHOLD,
+                threshold_high: TEST_INITIAL_THRESHOLD,
+                total_volume: 42.0,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncFlowSensor {
+        type Error = MockError;
+    }
+
+    impl FlowSensor for MockAsyncFlowSensor {
+        async fn flow_rate(&mut self) -> Result<LitersPerMinute, Self::Error> {
+            Ok(self.value)
+        }
+
+        async fn total_volume(&mut self) -> Result<Liters, Self::Error> {
+            Ok(self.total_volume)
+        }
+
+        async fn reset_total_volume(&mut self) -> Result<(), Self::Error> {
+            self.total_volume = 0.0;
+            Ok(())
+        }
+    }
+
+    impl FlowRateThresholdSet for MockAsyncFlowSensor {
+        async fn set_flow_rate_threshold_low(
+            &mut self,
+            threshold: LitersPerMinute,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = threshold;
+            Ok(())
+        }
+
+        async fn set_flow_rate_threshold_high(
+            &mut self,
+            threshold: LitersPerMinute,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = threshold;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_flow_sensor_trait() {
+        let mut sensor = MockAsyncFlowSensor::new();
+        let result = sensor.flow_rate().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_flow_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncFlowSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.flow_rate().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_flow_threshold_set_trait_mut_ref() {
+        let mut sensor = MockAsyncFlowSensor::new();
+
+        {
+            let mut_ref = &mut sensor;
+            let result_low = mut_ref
+                .set_flow_rate_threshold_low(TEST_THRESHOLD_LOW)
+                .await;
+            assert!(result_low.is_ok());
+            let result_high = mut_ref
+                .set_flow_rate_threshold_high(TEST_THRESHOLD_HIGH)
+                .await;
+            assert!(result_high.is_ok());
+        }
+
+        assert_approx_eq!(sensor.threshold_low, TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high, TEST_THRESHOLD_HIGH);
+    }
+
+    #[tokio::test]
+    async fn test_async_flow_totalizer_mut_ref() {
+        let mut sensor = MockAsyncFlowSensor::new();
+        let mut_ref = &mut sensor;
+        assert_approx_eq!(mut_ref.total_volume().await.unwrap(), 42.0);
+        assert!(mut_ref.reset_total_volume().await.is_ok());
+        assert_approx_eq!(sensor.total_volume().await.unwrap(), 0.0);
+    }
+}
@@ -0,0 +1,251 @@
+//! Async Force Sensor API
+//!
+//! This API provides generic methods for interfacing with force sensors (load cells) specifically.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the ForceSensor and ForceThresholdWait traits for a load cell.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::force::{
+//!     ForceHysteresis, ForceSensor, ForceThresholdSet, ForceThresholdWait, Newtons,
+//! };
+//!
+//! // A struct representing a load cell.
+//! pub struct MyForceSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyForceSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl ForceSensor for MyForceSensor {
+//!     async fn force(&mut self) -> Result<Newtons, Self::Error> {
+//!         // Read raw ADC value, subtract zero offset and apply scale...
+//!         Ok(9.81)
+//!     }
+//!
+//!     async fn tare(&mut self) -> Result<(), Self::Error> {
+//!         // Average a few raw samples and store them as the zero offset...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl ForceThresholdSet for MyForceSensor {
+//!     async fn set_force_threshold_low(
+//!         &mut self,
+//!         threshold: Newtons
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_force_threshold_high(
+//!         &mut self,
+//!         threshold: Newtons
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl ForceThresholdWait for MyForceSensor {
+//!     async fn wait_for_force_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<Newtons>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.force().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl ForceHysteresis for MyForceSensor {
+//!     async fn set_force_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: Newtons
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::force::{Force, Newtons, FORCE_RANGE};
+
+/// Async Force Sensor methods.
+pub trait ForceSensor: Sensor<Force, Sample = Newtons> {
+    /// Returns a force sample in newtons, relative to the load present at the last tare.
+    async fn force(&mut self) -> Result<Newtons, Self::Error>;
+
+    /// Tare the sensor, so that the current load reads as zero force.
+    async fn tare(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: ForceSensor + ?Sized> ForceSensor for &mut T {
+    #[inline]
+    async fn force(&mut self) -> Result<Newtons, Self::Error> {
+        T::force(self).await
+    }
+
+    #[inline]
+    async fn tare(&mut self) -> Result<(), Self::Error> {
+        T::tare(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Force, ForceSensor, force, Newtons);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait ForceThresholdSet: ForceSensor {
+//     async fn set_force_threshold_low(&mut self, threshold: Newtons) -> Result<(), Self::Error>;
+//     async fn set_force_threshold_high(&mut self, threshold: Newtons) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ForceHysteresis: ForceThresholdSet {
+//     async fn set_force_threshold_hysteresis(&mut self, hysteresis: Newtons) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ForceThresholdControl: ForceThresholdSet {
+//     async fn disable_force_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_force_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ForceThresholdWait: ForceThresholdSet {
+//     async fn wait_for_force_threshold(&mut self) -> Result<ThresholdEvent<Newtons>, Self::Error>;
+// }
+decl_threshold_traits!(async, Force, ForceSensor, Newtons, "newtons");
+
+// This macro generates the `ForceAlert` adapter, which allows implementors of
+// `ForceThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(Force, Newtons);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Newtons = 49.0;
+    const TEST_THRESHOLD_LOW: Newtons = 0.0;
+    const TEST_THRESHOLD_HIGH: Newtons = 500.0;
+    const TEST_INITIAL_THRESHOLD: Newtons = 0.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncForceSensor {
+        value: Newtons,
+        threshold_low: Newtons,
+        threshold_high: Newtons,
+        offset: Newtons,
+    }
+
+    impl MockAsyncForceSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: TEST_INITIAL_THRESHOLD,
+                threshold_high: TEST_INITIAL_THRESHOLD,
+                offset: 0.0,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncForceSensor {
+        type Error = MockError;
+    }
+
+    impl ForceSensor for MockAsyncForceSensor {
+        async fn force(&mut self) -> Result<Newtons, Self::Error> {
+            Ok(self.value)
+        }
+
+        async fn tare(&mut self) -> Result<(), Self::Error> {
+            self.offset = self.value;
+            Ok(())
+        }
+    }
+
+    impl ForceThresholdSet for MockAsyncForceSensor {
+        async fn set_force_threshold_low(&mut self, threshold: Newtons) -> Result<(), Self::Error> {
+            self.threshold_low = threshold;
+            Ok(())
+        }
+
+        async fn set_force_threshold_high(
+            &mut self,
+            threshold: Newtons,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = threshold;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_force_sensor_trait() {
+        let mut sensor = MockAsyncForceSensor::new();
+        let result = sensor.force().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_force_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncForceSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.force().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_force_threshold_set_trait_mut_ref() {
+        let mut sensor = MockAsyncForceSensor::new();
+
+        {
+            let mut_ref = &mut sensor;
+            let result_low = mut_ref.set_force_threshold_low(TEST_THRESHOLD_LOW).await;
+            assert!(result_low.is_ok());
+            let result_high = mut_ref.set_force_threshold_high(TEST_THRESHOLD_HIGH).await;
+            assert!(result_high.is_ok());
+        }
+
+        assert_approx_eq!(sensor.threshold_low, TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high, TEST_THRESHOLD_HIGH);
+    }
+
+    #[tokio::test]
+    async fn test_async_force_tare_mut_ref() {
+        let mut sensor = MockAsyncForceSensor::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.tare().await.is_ok());
+        assert_approx_eq!(sensor.offset, TEST_VALUE);
+    }
+}
@@ -0,0 +1,258 @@
+//! Async Formaldehyde Sensor API
+//!
+//! This API provides generic methods for interfacing with formaldehyde (HCHO) sensors specifically,
+//! such as the SFA30, as used in air purifiers and indoor air quality monitors.
+//!
+//! Concentrations are reported in parts per billion, and can be converted to the mass concentration
+//! used by most indoor air guidelines with [`ppb_to_micrograms_per_cubic_meter`].
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the FormaldehydeSensor and
+//! FormaldehydeThresholdWait traits for a formaldehyde sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::formaldehyde::{
+//!     FormaldehydeHysteresis, FormaldehydeSensor, FormaldehydeThresholdSet,
+//!     FormaldehydeThresholdWait, Ppb,
+//! };
+//!
+//! // A struct representing a formaldehyde sensor.
+//! pub struct MyHchoSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyHchoSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl FormaldehydeSensor for MyHchoSensor {
+//!     async fn formaldehyde(&mut self) -> Result<Ppb, Self::Error> {
+//!         // Read measured values of sensor (0.2 ppb per bit)...
+//!         Ok(18.4)
+//!     }
+//! }
+//!
+//! impl FormaldehydeThresholdSet for MyHchoSensor {
+//!     async fn set_formaldehyde_threshold_low(
+//!         &mut self,
+//!         threshold: Ppb
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_formaldehyde_threshold_high(
+//!         &mut self,
+//!         threshold: Ppb
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl FormaldehydeThresholdWait for MyHchoSensor {
+//!     async fn wait_for_formaldehyde_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<Ppb>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.formaldehyde().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl FormaldehydeHysteresis for MyHchoSensor {
+//!     async fn set_formaldehyde_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: Ppb
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::formaldehyde::{
+    ppb_to_micrograms_per_cubic_meter, Formaldehyde, MicrogramsPerCubicMeter, Ppb,
+    FORMALDEHYDE_RANGE, MICROGRAMS_PER_CUBIC_METER_PER_PPB,
+};
+
+/// Async Formaldehyde Sensor methods.
+pub trait FormaldehydeSensor: Sensor<Formaldehyde, Sample = Ppb> {
+    /// Returns a formaldehyde concentration sample in parts per billion.
+    async fn formaldehyde(&mut self) -> Result<Ppb, Self::Error>;
+}
+
+impl<T: FormaldehydeSensor + ?Sized> FormaldehydeSensor for &mut T {
+    #[inline]
+    async fn formaldehyde(&mut self) -> Result<Ppb, Self::Error> {
+        T::formaldehyde(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Formaldehyde, FormaldehydeSensor, formaldehyde, Ppb);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait FormaldehydeThresholdSet: FormaldehydeSensor {
+//     async fn set_formaldehyde_threshold_low(&mut self, threshold: Ppb) -> Result<(), Self::Error>;
+//     async fn set_formaldehyde_threshold_high(&mut self, threshold: Ppb) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FormaldehydeHysteresis: FormaldehydeThresholdSet {
+//     async fn set_formaldehyde_threshold_hysteresis(&mut self, hysteresis: Ppb) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FormaldehydeThresholdControl: FormaldehydeThresholdSet {
+//     async fn disable_formaldehyde_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_formaldehyde_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FormaldehydeThresholdWait: FormaldehydeThresholdSet {
+//     async fn wait_for_formaldehyde_threshold(&mut self) -> Result<ThresholdEvent<Ppb>, Self::Error>;
+// }
+decl_threshold_traits!(
+    async,
+    Formaldehyde,
+    FormaldehydeSensor,
+    Ppb,
+    "parts per billion"
+);
+
+// This macro generates the `FormaldehydeAlert` adapter, which allows implementors of
+// `FormaldehydeThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(Formaldehyde, Ppb);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Ppb = 18.4;
+    const TEST_THRESHOLD_LOW: Ppb = 0.0;
+    const TEST_THRESHOLD_HIGH: Ppb = 81.0;
+    const TEST_HYSTERESIS: Ppb = 2.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncHchoSensor {
+        value: Ppb,
+        threshold_low: Option<Ppb>,
+        threshold_high: Option<Ppb>,
+        hysteresis: Option<Ppb>,
+    }
+
+    impl MockAsyncHchoSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncHchoSensor {
+        type Error = MockError;
+    }
+
+    impl FormaldehydeSensor for MockAsyncHchoSensor {
+        async fn formaldehyde(&mut self) -> Result<Ppb, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl FormaldehydeThresholdSet for MockAsyncHchoSensor {
+        async fn set_formaldehyde_threshold_low(
+            &mut self,
+            threshold: Ppb,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        async fn set_formaldehyde_threshold_high(
+            &mut self,
+            threshold: Ppb,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl FormaldehydeHysteresis for MockAsyncHchoSensor {
+        async fn set_formaldehyde_threshold_hysteresis(
+            &mut self,
+            hysteresis: Ppb,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_formaldehyde_sensor_trait() {
+        let mut sensor = MockAsyncHchoSensor::new();
+        let result = sensor.formaldehyde().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_formaldehyde_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncHchoSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.formaldehyde().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_formaldehyde_threshold_set_mut_ref() {
+        let mut sensor = MockAsyncHchoSensor::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_formaldehyde_threshold_low(TEST_THRESHOLD_LOW)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_formaldehyde_threshold_high(TEST_THRESHOLD_HIGH)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_formaldehyde_threshold_hysteresis(TEST_HYSTERESIS)
+            .await
+            .is_ok());
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), TEST_HYSTERESIS);
+    }
+}
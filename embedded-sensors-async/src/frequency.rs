@@ -0,0 +1,249 @@
+//! Async Frequency Sensor API
+//!
+//! This API provides generic methods for interfacing with frequency-output sensors specifically,
+//! such as light-to-frequency converters (e.g. TSL235) or pulse sensors, whose measurement is
+//! encoded in the frequency of their output signal rather than in a register value.
+//!
+//! The frequency is typically measured by counting output edges with a timer over a gate time, or
+//! by timing the period of the signal with an input capture unit.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the FrequencySensor and FrequencyThresholdWait
+//! traits for a light-to-frequency converter.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::frequency::{
+//!     Hertz, FrequencyHysteresis, FrequencySensor, FrequencyThresholdSet, FrequencyThresholdWait,
+//! };
+//!
+//! // A struct representing a light-to-frequency converter.
+//! pub struct MyLightToFrequency {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyLightToFrequency {
+//!     type Error = Error;
+//! }
+//!
+//! impl FrequencySensor for MyLightToFrequency {
+//!     async fn frequency(&mut self) -> Result<Hertz, Self::Error> {
+//!         // Count output edges over gate time of timer...
+//!         Ok(12_500.0)
+//!     }
+//! }
+//!
+//! impl FrequencyThresholdSet for MyLightToFrequency {
+//!     async fn set_frequency_threshold_low(
+//!         &mut self,
+//!         threshold: Hertz
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_frequency_threshold_high(
+//!         &mut self,
+//!         threshold: Hertz
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl FrequencyThresholdWait for MyLightToFrequency {
+//!     async fn wait_for_frequency_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<Hertz>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.frequency().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl FrequencyHysteresis for MyLightToFrequency {
+//!     async fn set_frequency_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: Hertz
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::frequency::{Frequency, Hertz, FREQUENCY_RANGE};
+
+/// Async Frequency Sensor methods.
+pub trait FrequencySensor: Sensor<Frequency, Sample = Hertz> {
+    /// Returns a frequency sample in hertz.
+    async fn frequency(&mut self) -> Result<Hertz, Self::Error>;
+}
+
+impl<T: FrequencySensor + ?Sized> FrequencySensor for &mut T {
+    #[inline]
+    async fn frequency(&mut self) -> Result<Hertz, Self::Error> {
+        T::frequency(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Frequency, FrequencySensor, frequency, Hertz);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait FrequencyThresholdSet: FrequencySensor {
+//     async fn set_frequency_threshold_low(&mut self, threshold: Hertz) -> Result<(), Self::Error>;
+//     async fn set_frequency_threshold_high(&mut self, threshold: Hertz) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FrequencyHysteresis: FrequencyThresholdSet {
+//     async fn set_frequency_threshold_hysteresis(&mut self, hysteresis: Hertz) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FrequencyThresholdControl: FrequencyThresholdSet {
+//     async fn disable_frequency_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_frequency_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FrequencyThresholdWait: FrequencyThresholdSet {
+//     async fn wait_for_frequency_threshold(&mut self) -> Result<ThresholdEvent<Hertz>, Self::Error>;
+// }
+decl_threshold_traits!(async, Frequency, FrequencySensor, Hertz, "hertz");
+
+// This macro generates the `FrequencyAlert` adapter, which allows implementors of
+// `FrequencyThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(Frequency, Hertz);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Hertz = 12_500.0;
+    const TEST_THRESHOLD_LOW: Hertz = 1_000.0;
+    const TEST_THRESHOLD_HIGH: Hertz = 100_000.0;
+    const TEST_HYSTERESIS: Hertz = 50.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncFrequencySensor {
+        value: Hertz,
+        threshold_low: Option<Hertz>,
+        threshold_high: Option<Hertz>,
+        hysteresis: Option<Hertz>,
+    }
+
+    impl MockAsyncFrequencySensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncFrequencySensor {
+        type Error = MockError;
+    }
+
+    impl FrequencySensor for MockAsyncFrequencySensor {
+        async fn frequency(&mut self) -> Result<Hertz, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl FrequencyThresholdSet for MockAsyncFrequencySensor {
+        async fn set_frequency_threshold_low(
+            &mut self,
+            threshold: Hertz,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        async fn set_frequency_threshold_high(
+            &mut self,
+            threshold: Hertz,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl FrequencyHysteresis for MockAsyncFrequencySensor {
+        async fn set_frequency_threshold_hysteresis(
+            &mut self,
+            hysteresis: Hertz,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_frequency_sensor_trait() {
+        let mut sensor = MockAsyncFrequencySensor::new();
+        let result = sensor.frequency().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_frequency_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncFrequencySensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.frequency().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_frequency_threshold_set_mut_ref() {
+        let mut sensor = MockAsyncFrequencySensor::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_frequency_threshold_low(TEST_THRESHOLD_LOW)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_frequency_threshold_high(TEST_THRESHOLD_HIGH)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_frequency_threshold_hysteresis(TEST_HYSTERESIS)
+            .await
+            .is_ok());
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), TEST_HYSTERESIS);
+    }
+}
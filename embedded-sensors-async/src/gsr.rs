@@ -0,0 +1,118 @@
+//! Async Galvanic Skin Response Sensor API
+//!
+//! This API provides generic methods for interfacing with galvanic skin response (GSR) sensors
+//! specifically, also known as electrodermal activity (EDA) sensors, as used by stress and
+//! wellness wearables.
+//!
+//! Skin conductance is deliberately kept distinct from the conductivity of liquids or soils, as
+//! electrode models and measurement ranges differ.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the GsrSensor trait for an EDA front-end.
+//!
+//! ```
+//! use embedded_sensors_hal_async::gsr::{GsrSensor, Microsiemens};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing an electrodermal activity front-end.
+//! pub struct MyEdaFrontEnd {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyEdaFrontEnd {
+//!     type Error = Error;
+//! }
+//!
+//! impl GsrSensor for MyEdaFrontEnd {
+//!     async fn skin_conductance(&mut self) -> Result<Microsiemens, Self::Error> {
+//!         // Read ADC and compute conductance from excitation voltage and sense current...
+//!         Ok(4.2)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::gsr::{Microsiemens, SkinConductance, SKIN_CONDUCTANCE_RANGE};
+
+/// Async Galvanic Skin Response Sensor methods.
+pub trait GsrSensor: Sensor<SkinConductance, Sample = Microsiemens> {
+    /// Returns a skin conductance sample in microsiemens.
+    async fn skin_conductance(&mut self) -> Result<Microsiemens, Self::Error>;
+}
+
+impl<T: GsrSensor + ?Sized> GsrSensor for &mut T {
+    #[inline]
+    async fn skin_conductance(&mut self) -> Result<Microsiemens, Self::Error> {
+        T::skin_conductance(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    SkinConductance,
+    GsrSensor,
+    skin_conductance,
+    Microsiemens
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test value
+    const TEST_VALUE: Microsiemens = 4.2;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncGsrSensor;
+
+    impl crate::sensor::ErrorType for MockAsyncGsrSensor {
+        type Error = MockError;
+    }
+
+    impl GsrSensor for MockAsyncGsrSensor {
+        async fn skin_conductance(&mut self) -> Result<Microsiemens, Self::Error> {
+            Ok(TEST_VALUE)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_gsr_sensor_trait() {
+        let mut sensor = MockAsyncGsrSensor;
+        let result = sensor.skin_conductance().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_gsr_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncGsrSensor;
+        let mut_ref = &mut sensor;
+        let result = mut_ref.skin_conductance().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+}
@@ -0,0 +1,270 @@
+//! Async Sensor Heater API
+//!
+//! This API provides generic methods for controlling the on-chip heaters of sensors, such as
+//! those of relative humidity sensors used to evaporate condensation or to recover from creep
+//! after prolonged exposure to high humidity.
+//!
+//! Sensors whose heater supports several power levels (e.g. SHT4x, HDC302x or MOX gas sensors)
+//! can additionally implement the [`HeaterPower`] trait.
+//!
+//! With the `delay` feature enabled, the `recondition` helper drives a heater through a
+//! datasheet-style [`ReconditioningCycle`] in a single call, reporting its progress after each
+//! heater pulse.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the HeaterControl trait for a humidity sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::heater::HeaterControl;
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a humidity sensor with an on-chip heater.
+//! pub struct MyHumiditySensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyHumiditySensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl HeaterControl for MyHumiditySensor {
+//!     async fn enable_heater(&mut self) -> Result<(), Self::Error> {
+//!         // Set heater enable bit in configuration register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn disable_heater(&mut self) -> Result<(), Self::Error> {
+//!         // Clear heater enable bit in configuration register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+#[cfg(feature = "delay")]
+use embedded_hal_async::delay::DelayNs;
+pub use embedded_sensors_hal::heater::{Milliwatts, ReconditioningCycle, ReconditioningProgress};
+
+/// Async Heater Control methods.
+pub trait HeaterControl: ErrorType {
+    /// Turn the heater of the sensor on.
+    ///
+    /// Samples taken while the heater is on are not representative of the environment.
+    async fn enable_heater(&mut self) -> Result<(), Self::Error>;
+
+    /// Turn the heater of the sensor off.
+    async fn disable_heater(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: HeaterControl + ?Sized> HeaterControl for &mut T {
+    #[inline]
+    async fn enable_heater(&mut self) -> Result<(), Self::Error> {
+        T::enable_heater(self).await
+    }
+
+    #[inline]
+    async fn disable_heater(&mut self) -> Result<(), Self::Error> {
+        T::disable_heater(self).await
+    }
+}
+
+/// Async Heater Power methods, for heaters supporting several power levels.
+pub trait HeaterPower: HeaterControl {
+    /// Set the power of the heater to the nearest supported level, in milliwatts.
+    ///
+    /// Returns the power level actually selected. The heater is not turned on by this method.
+    async fn set_heater_power(&mut self, power: Milliwatts) -> Result<Milliwatts, Self::Error>;
+}
+
+impl<T: HeaterPower + ?Sized> HeaterPower for &mut T {
+    #[inline]
+    async fn set_heater_power(&mut self, power: Milliwatts) -> Result<Milliwatts, Self::Error> {
+        T::set_heater_power(self, power).await
+    }
+}
+
+/// Drives `heater` through every pulse of `cycle`, calling `progress` after each pulse.
+///
+/// If the heater fails to turn on or off, the cycle is aborted and the heater is turned off on a
+/// best-effort basis before the error is returned.
+///
+/// ```
+/// use embedded_hal_async::delay::DelayNs;
+/// use embedded_sensors_hal_async::heater::{recondition, HeaterControl, ReconditioningCycle};
+///
+/// // Creep recovery procedure, from the datasheet of the sensor.
+/// const CREEP_RECOVERY: ReconditioningCycle = ReconditioningCycle::new(10, 1_000, 9_000);
+///
+/// async fn recover<H: HeaterControl, D: DelayNs>(sensor: H, delay: D) -> Result<(), H::Error> {
+///     recondition(sensor, delay, CREEP_RECOVERY, |progress| {
+///         // Update progress indicator...
+///         let _ = (progress.completed, progress.total);
+///     })
+///     .await
+/// }
+/// ```
+#[cfg(feature = "delay")]
+pub async fn recondition<H: HeaterControl, D: DelayNs>(
+    mut heater: H,
+    mut delay: D,
+    cycle: ReconditioningCycle,
+    mut progress: impl FnMut(ReconditioningProgress),
+) -> Result<(), H::Error> {
+    for pulse in 0..cycle.pulses {
+        let result = match heater.enable_heater().await {
+            Ok(()) => {
+                delay.delay_ms(cycle.on_ms).await;
+                heater.disable_heater().await
+            }
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            let _ = heater.disable_heater().await;
+            return Err(e);
+        }
+
+        delay.delay_ms(cycle.off_ms).await;
+        progress(ReconditioningProgress {
+            completed: pulse + 1,
+            total: cycle.pulses,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "delay"))]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use core::cell::RefCell;
+    use std::vec::Vec;
+
+    #[derive(Debug, PartialEq)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Peripheral
+        }
+    }
+
+    // Records every heater transition and delay in a shared log.
+    #[derive(Debug, PartialEq)]
+    enum Event {
+        On,
+        Off,
+        Delay(u32),
+    }
+
+    struct MockAsyncHumiditySensor<'a> {
+        fail_on_pulse: Option<usize>,
+        log: &'a RefCell<Vec<Event>>,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncHumiditySensor<'_> {
+        type Error = MockError;
+    }
+
+    impl HeaterControl for MockAsyncHumiditySensor<'_> {
+        async fn enable_heater(&mut self) -> Result<(), Self::Error> {
+            let pulses = self
+                .log
+                .borrow()
+                .iter()
+                .filter(|e| **e == Event::On)
+                .count();
+            if self.fail_on_pulse == Some(pulses) {
+                return Err(MockError);
+            }
+            self.log.borrow_mut().push(Event::On);
+            Ok(())
+        }
+
+        async fn disable_heater(&mut self) -> Result<(), Self::Error> {
+            self.log.borrow_mut().push(Event::Off);
+            Ok(())
+        }
+    }
+
+    struct MockDelay<'a> {
+        log: &'a RefCell<Vec<Event>>,
+    }
+
+    impl DelayNs for MockDelay<'_> {
+        async fn delay_ns(&mut self, ns: u32) {
+            self.log.borrow_mut().push(Event::Delay(ns / 1_000_000));
+        }
+
+        async fn delay_ms(&mut self, ms: u32) {
+            self.log.borrow_mut().push(Event::Delay(ms));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_recondition() {
+        let log = RefCell::new(Vec::new());
+        let mut sensor = MockAsyncHumiditySensor {
+            fail_on_pulse: None,
+            log: &log,
+        };
+        let mut reports = Vec::new();
+
+        let cycle = ReconditioningCycle::new(2, 100, 400);
+        let result = recondition(&mut sensor, MockDelay { log: &log }, cycle, |p| {
+            reports.push(p)
+        })
+        .await;
+        assert!(result.is_ok());
+
+        assert_eq!(
+            *log.borrow(),
+            [
+                Event::On,
+                Event::Delay(100),
+                Event::Off,
+                Event::Delay(400),
+                Event::On,
+                Event::Delay(100),
+                Event::Off,
+                Event::Delay(400),
+            ]
+        );
+        assert_eq!(reports.len(), 2);
+        assert!(reports[1].is_done());
+    }
+
+    #[tokio::test]
+    async fn test_async_recondition_error() {
+        let log = RefCell::new(Vec::new());
+        let sensor = MockAsyncHumiditySensor {
+            fail_on_pulse: Some(1),
+            log: &log,
+        };
+        let mut reports = 0;
+
+        let cycle = ReconditioningCycle::new(3, 100, 400);
+        let result = recondition(sensor, MockDelay { log: &log }, cycle, |_| reports += 1).await;
+        assert_eq!(result, Err(MockError));
+        assert_eq!(reports, 1);
+
+        // The heater is left off.
+        assert_eq!(log.borrow().last(), Some(&Event::Off));
+    }
+}
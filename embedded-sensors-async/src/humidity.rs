@@ -2,6 +2,15 @@
 //!
 //! This API provides generic methods for interfacing with humidity sensors specifically.
 //!
+//! Sensors reporting absolute humidity implement the [`AbsoluteHumiditySensor`] trait. With the
+//! `libm` feature enabled, the `DerivedAbsoluteHumidity` adapter derives it for sensors measuring
+//! both relative humidity and temperature instead. Measurements without a finite absolute humidity
+//! are reported as `DerivedAbsoluteHumidityError::InvalidSample` rather than as NaN.
+//!
+//! Drivers for targets without a floating-point unit can implement the
+//! [`FixedPointRelativeHumiditySensor`] trait, reporting samples in integer thousandths of a
+//! percent, along with the fixed-point threshold and calibration traits built upon it.
+//!
 //! # For HAL authors
 //!
 //! Here is an example for the implementation of the RelativeHumiditySensor
@@ -64,10 +73,12 @@
 //! impl RelativeHumidityThresholdWait for MyHumiditySensor {
 //!     async fn wait_for_relative_humidity_threshold(
 //!         &mut self,
-//!     ) -> Result<Percentage, Self::Error> {
+//!     ) -> Result<sensor::ThresholdEvent<Percentage>, Self::Error> {
 //!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
-//!         // Then return current relative humidity so caller can determine which threshold was crossed
-//!         self.relative_humidity().await
+//!         let value = self.relative_humidity().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
 //!     }
 //! }
 //!
@@ -82,11 +93,25 @@
 //! }
 //! ```
 
-use crate::sensor::{decl_threshold_traits, ErrorType};
-pub use embedded_sensors_hal::humidity::Percentage;
+use crate::alert::decl_alert_source;
+#[cfg(feature = "libm")]
+use crate::sensor::checked_sample;
+use crate::sensor::{
+    decl_calibration_trait, decl_configuration_trait, decl_fixed_point_calibration_trait,
+    decl_fixed_point_threshold_traits, decl_fixed_point_trait, decl_sample_read_trait,
+    decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor,
+};
+#[cfg(feature = "libm")]
+use crate::temperature::{DegreesCelsius, TemperatureSensor};
+#[cfg(feature = "libm")]
+pub use embedded_sensors_hal::humidity::{absolute_humidity, DerivedAbsoluteHumidityError};
+pub use embedded_sensors_hal::humidity::{
+    AbsoluteHumidity, GramsPerCubicMeter, MilliPercentage, Percentage, RelativeHumidity,
+    RelativeHumidityConfig, RELATIVE_HUMIDITY_RANGE,
+};
 
 /// Async Relative Humidity Sensor methods.
-pub trait RelativeHumiditySensor: ErrorType {
+pub trait RelativeHumiditySensor: Sensor<RelativeHumidity, Sample = Percentage> {
     /// Returns a relative humidity (RH) sample as a percentage.
     async fn relative_humidity(&mut self) -> Result<Percentage, Self::Error>;
 }
@@ -98,6 +123,14 @@ impl<T: RelativeHumiditySensor + ?Sized> RelativeHumiditySensor for &mut T {
     }
 }
 
+impl_sensor_refinement!(
+    async,
+    RelativeHumidity,
+    RelativeHumiditySensor,
+    relative_humidity,
+    Percentage
+);
+
 // This macro generates the following async threshold traits:
 //
 // pub trait RelativeHumidityThresholdSet: RelativeHumiditySensor {
@@ -109,8 +142,13 @@ impl<T: RelativeHumiditySensor + ?Sized> RelativeHumiditySensor for &mut T {
 //     async fn set_relative_humidity_threshold_hysteresis(&mut self, hysteresis: Percentage) -> Result<(), Self::Error>;
 // }
 //
+// pub trait RelativeHumidityThresholdControl: RelativeHumidityThresholdSet {
+//     async fn disable_relative_humidity_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_relative_humidity_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
 // pub trait RelativeHumidityThresholdWait: RelativeHumidityThresholdSet {
-//     async fn wait_for_relative_humidity_threshold(&mut self) -> Result<Percentage, Self::Error>;
+//     async fn wait_for_relative_humidity_threshold(&mut self) -> Result<ThresholdEvent<Percentage>, Self::Error>;
 // }
 decl_threshold_traits!(
     async,
@@ -120,6 +158,163 @@ decl_threshold_traits!(
     "percentage"
 );
 
+// This macro generates the `RelativeHumidityAlert` adapter, which allows implementors of
+// `RelativeHumidityThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(RelativeHumidity, Percentage);
+
+// This macro generates the following async configuration trait:
+//
+// pub trait RelativeHumidityConfigure: RelativeHumiditySensor {
+//     async fn set_relative_humidity_config(&mut self, config: RelativeHumidityConfig) -> Result<(), Self::Error>;
+// }
+decl_configuration_trait!(
+    async,
+    RelativeHumidity,
+    RelativeHumiditySensor,
+    RelativeHumidityConfig
+);
+
+// This macro generates the following async calibration trait:
+//
+// pub trait RelativeHumidityCalibrate: RelativeHumiditySensor {
+//     async fn set_relative_humidity_offset(&mut self, offset: Percentage) -> Result<(), Self::Error>;
+//     async fn set_relative_humidity_gain(&mut self, gain: f32) -> Result<(), Self::Error>;
+// }
+decl_calibration_trait!(
+    async,
+    RelativeHumidity,
+    RelativeHumiditySensor,
+    Percentage,
+    "percentage"
+);
+
+// This macro generates the following async sample read trait:
+//
+// pub trait RelativeHumiditySampleRead: RelativeHumiditySensor {
+//     async fn relative_humidity_sample(&mut self) -> Result<Sample<Percentage>, Self::Error>;
+// }
+decl_sample_read_trait!(async, RelativeHumidity, RelativeHumiditySensor, Percentage);
+
+// This macro generates the following async fixed-point trait:
+//
+// pub trait FixedPointRelativeHumiditySensor: ErrorType {
+//     async fn relative_humidity_milli(&mut self) -> Result<MilliPercentage, Self::Error>;
+// }
+decl_fixed_point_trait!(
+    async,
+    RelativeHumidity,
+    MilliPercentage,
+    "thousandths of a percent"
+);
+
+// This macro generates the following async fixed-point threshold traits:
+//
+// pub trait FixedPointRelativeHumidityThresholdSet: FixedPointRelativeHumiditySensor {
+//     async fn set_relative_humidity_threshold_low_milli(&mut self, threshold: MilliPercentage) -> Result<(), Self::Error>;
+//     async fn set_relative_humidity_threshold_high_milli(&mut self, threshold: MilliPercentage) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FixedPointRelativeHumidityHysteresis: FixedPointRelativeHumidityThresholdSet {
+//     async fn set_relative_humidity_threshold_hysteresis_milli(&mut self, hysteresis: MilliPercentage) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FixedPointRelativeHumidityThresholdWait: FixedPointRelativeHumidityThresholdSet {
+//     async fn wait_for_relative_humidity_threshold_milli(&mut self) -> Result<ThresholdEvent<MilliPercentage>, Self::Error>;
+// }
+decl_fixed_point_threshold_traits!(
+    async,
+    RelativeHumidity,
+    MilliPercentage,
+    "thousandths of a percent"
+);
+
+// This macro generates the following async fixed-point calibration trait:
+//
+// pub trait FixedPointRelativeHumidityCalibrate: FixedPointRelativeHumiditySensor {
+//     async fn set_relative_humidity_offset_milli(&mut self, offset: MilliPercentage) -> Result<(), Self::Error>;
+//     async fn set_relative_humidity_gain_milli(&mut self, gain: u32) -> Result<(), Self::Error>;
+// }
+decl_fixed_point_calibration_trait!(
+    async,
+    RelativeHumidity,
+    MilliPercentage,
+    "thousandths of a percent"
+);
+
+/// Async Absolute Humidity Sensor methods.
+pub trait AbsoluteHumiditySensor: Sensor<AbsoluteHumidity, Sample = GramsPerCubicMeter> {
+    /// Returns an absolute humidity sample in grams of water vapor per cubic meter of air.
+    async fn absolute_humidity(&mut self) -> Result<GramsPerCubicMeter, Self::Error>;
+}
+
+impl<T: AbsoluteHumiditySensor + ?Sized> AbsoluteHumiditySensor for &mut T {
+    #[inline]
+    async fn absolute_humidity(&mut self) -> Result<GramsPerCubicMeter, Self::Error> {
+        T::absolute_humidity(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    AbsoluteHumidity,
+    AbsoluteHumiditySensor,
+    absolute_humidity,
+    GramsPerCubicMeter
+);
+
+/// Derives the absolute humidity of a sensor measuring both temperature and relative humidity.
+///
+/// The wrapped sensor's temperature and relative humidity remain available through the adapter.
+#[cfg(feature = "libm")]
+pub struct DerivedAbsoluteHumidity<S>(pub S);
+
+#[cfg(feature = "libm")]
+impl<S: ErrorType> ErrorType for DerivedAbsoluteHumidity<S> {
+    type Error = DerivedAbsoluteHumidityError<S::Error>;
+}
+
+#[cfg(feature = "libm")]
+impl<S: TemperatureSensor + RelativeHumiditySensor> AbsoluteHumiditySensor
+    for DerivedAbsoluteHumidity<S>
+{
+    async fn absolute_humidity(&mut self) -> Result<GramsPerCubicMeter, Self::Error> {
+        let temperature = self
+            .0
+            .temperature()
+            .await
+            .map_err(DerivedAbsoluteHumidityError::Sensor)?;
+        let relative_humidity = self
+            .0
+            .relative_humidity()
+            .await
+            .map_err(DerivedAbsoluteHumidityError::Sensor)?;
+        checked_sample(absolute_humidity(temperature, relative_humidity))
+            .map_err(|_| DerivedAbsoluteHumidityError::InvalidSample)
+    }
+}
+
+#[cfg(feature = "libm")]
+impl<S: TemperatureSensor> TemperatureSensor for DerivedAbsoluteHumidity<S> {
+    #[inline]
+    async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        self.0
+            .temperature()
+            .await
+            .map_err(DerivedAbsoluteHumidityError::Sensor)
+    }
+}
+
+#[cfg(feature = "libm")]
+impl<S: RelativeHumiditySensor> RelativeHumiditySensor for DerivedAbsoluteHumidity<S> {
+    #[inline]
+    async fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+        self.0
+            .relative_humidity()
+            .await
+            .map_err(DerivedAbsoluteHumidityError::Sensor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,6 +326,7 @@ mod tests {
     const TEST_THRESHOLD_LOW: Percentage = 30.0;
     const TEST_THRESHOLD_HIGH: Percentage = 80.0;
     const TEST_INITIAL_THRESHOLD: Percentage = 0.0;
+    const TEST_ABSOLUTE_HUMIDITY: GramsPerCubicMeter = 11.2;
 
     #[derive(Debug)]
     struct MockError;
@@ -249,4 +445,83 @@ mod tests {
 
         assert_approx_eq!(sensor.threshold_high, TEST_THRESHOLD_HIGH);
     }
+
+    struct MockAsyncComboSensor;
+
+    impl crate::sensor::ErrorType for MockAsyncComboSensor {
+        type Error = MockError;
+    }
+
+    impl AbsoluteHumiditySensor for MockAsyncComboSensor {
+        async fn absolute_humidity(&mut self) -> Result<GramsPerCubicMeter, Self::Error> {
+            Ok(TEST_ABSOLUTE_HUMIDITY)
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    impl TemperatureSensor for MockAsyncComboSensor {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(20.0)
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    impl RelativeHumiditySensor for MockAsyncComboSensor {
+        async fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(50.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_absolute_humidity_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncComboSensor;
+        let mut_ref = &mut sensor;
+        let result = mut_ref.absolute_humidity().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_ABSOLUTE_HUMIDITY);
+    }
+
+    #[cfg(feature = "libm")]
+    #[tokio::test]
+    async fn test_async_derived_absolute_humidity() {
+        let mut sensor = DerivedAbsoluteHumidity(MockAsyncComboSensor);
+        let result = sensor.absolute_humidity().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), 8.64, 0.02);
+        assert_approx_eq!(sensor.relative_humidity().await.unwrap(), 50.0);
+    }
+
+    // Reports a NaN temperature, e.g. from a failed conversion.
+    #[cfg(feature = "libm")]
+    struct MockAsyncNanSensor;
+
+    #[cfg(feature = "libm")]
+    impl crate::sensor::ErrorType for MockAsyncNanSensor {
+        type Error = MockError;
+    }
+
+    #[cfg(feature = "libm")]
+    impl TemperatureSensor for MockAsyncNanSensor {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(f32::NAN)
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    impl RelativeHumiditySensor for MockAsyncNanSensor {
+        async fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(50.0)
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    #[tokio::test]
+    async fn test_async_derived_absolute_humidity_nan_input() {
+        let mut sensor = DerivedAbsoluteHumidity(MockAsyncNanSensor);
+        let result = sensor.absolute_humidity().await;
+        assert!(matches!(
+            result,
+            Err(DerivedAbsoluteHumidityError::InvalidSample)
+        ));
+    }
 }
@@ -0,0 +1,119 @@
+//! Async Inertial Measurement Unit API
+//!
+//! This API provides generic methods for interfacing with inertial measurement units (IMU)
+//! combining an accelerometer and a gyroscope specifically, such as the LSM6DSO or BMI270.
+//!
+//! Sensor fusion algorithms need acceleration and angular rate samples taken at the same
+//! instant, which separate calls cannot guarantee. The [`Imu`] trait therefore returns both in a
+//! single [`ImuSample`], read from the same output register set or FIFO frame.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the Imu trait for a 6-axis IMU.
+//!
+//! ```
+//! use embedded_sensors_hal_async::imu::{Imu, ImuSample, Vector3};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a 6-axis IMU.
+//! pub struct MyImu {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyImu {
+//!     type Error = Error;
+//! }
+//!
+//! impl Imu for MyImu {
+//!     async fn imu_sample(&mut self) -> Result<ImuSample, Self::Error> {
+//!         // Burst read gyroscope and accelerometer output registers of sensor...
+//!         Ok(ImuSample {
+//!             acceleration: Vector3::new(0.02, -0.05, 9.81),
+//!             angular_rate: Vector3::new(0.1, 0.0, -0.3),
+//!         })
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::imu::{
+    DegreesPerSecond, ImuSample, Inertial, MetersPerSecondSquared, Vector3,
+};
+
+/// Async Inertial Measurement Unit methods.
+pub trait Imu: Sensor<Inertial, Sample = ImuSample> {
+    /// Returns an acceleration and angular rate sample taken at the same instant.
+    async fn imu_sample(&mut self) -> Result<ImuSample, Self::Error>;
+}
+
+impl<T: Imu + ?Sized> Imu for &mut T {
+    #[inline]
+    async fn imu_sample(&mut self) -> Result<ImuSample, Self::Error> {
+        T::imu_sample(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Inertial, Imu, imu_sample, ImuSample);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_SAMPLE: ImuSample = ImuSample {
+        acceleration: Vector3::new(0.02, -0.05, 9.81),
+        angular_rate: Vector3::new(0.1, 0.0, -0.3),
+    };
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncImu;
+
+    impl crate::sensor::ErrorType for MockAsyncImu {
+        type Error = MockError;
+    }
+
+    impl Imu for MockAsyncImu {
+        async fn imu_sample(&mut self) -> Result<ImuSample, Self::Error> {
+            Ok(TEST_SAMPLE)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_imu_trait() {
+        let mut sensor = MockAsyncImu;
+        let result = sensor.imu_sample().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_SAMPLE);
+    }
+
+    #[tokio::test]
+    async fn test_async_imu_trait_mut_ref() {
+        let mut sensor = MockAsyncImu;
+        let mut_ref = &mut sensor;
+        let result = mut_ref.imu_sample().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_SAMPLE);
+    }
+}
@@ -0,0 +1,125 @@
+//! Async Inclination Sensor API
+//!
+//! This API provides generic methods for interfacing with inclination (tilt) sensors
+//! specifically.
+//!
+//! The API is suited to both dedicated inclinometer ICs and implementations deriving inclination
+//! from the gravity vector measured by an accelerometer.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the InclinationSensor trait for an inclinometer.
+//!
+//! ```
+//! use embedded_sensors_hal_async::inclination::{Inclination, InclinationSensor};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing an inclinometer.
+//! pub struct MyInclinometer {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyInclinometer {
+//!     type Error = Error;
+//! }
+//!
+//! impl InclinationSensor for MyInclinometer {
+//!     async fn inclination(&mut self) -> Result<Inclination, Self::Error> {
+//!         // Read angle registers of sensor...
+//!         Ok(Inclination {
+//!             pitch: 1.5,
+//!             roll: -0.25,
+//!         })
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::inclination::{Degrees, Inclination, Tilt, PITCH_RANGE, ROLL_RANGE};
+
+/// Async Inclination Sensor methods.
+pub trait InclinationSensor: Sensor<Tilt, Sample = Inclination> {
+    /// Returns an inclination sample with angles in degrees.
+    async fn inclination(&mut self) -> Result<Inclination, Self::Error>;
+}
+
+impl<T: InclinationSensor + ?Sized> InclinationSensor for &mut T {
+    #[inline]
+    async fn inclination(&mut self) -> Result<Inclination, Self::Error> {
+        T::inclination(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Tilt, InclinationSensor, inclination, Inclination);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_INCLINATION: Inclination = Inclination {
+        pitch: 12.5,
+        roll: -3.0,
+    };
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncInclinometer {
+        value: Inclination,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncInclinometer {
+        type Error = MockError;
+    }
+
+    impl InclinationSensor for MockAsyncInclinometer {
+        async fn inclination(&mut self) -> Result<Inclination, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_inclination_sensor_trait() {
+        let mut sensor = MockAsyncInclinometer {
+            value: TEST_INCLINATION,
+        };
+        let result = sensor.inclination().await;
+        assert!(result.is_ok());
+        let inclination = result.unwrap();
+        assert_approx_eq!(inclination.pitch, TEST_INCLINATION.pitch);
+        assert_approx_eq!(inclination.roll, TEST_INCLINATION.roll);
+    }
+
+    #[tokio::test]
+    async fn test_async_inclination_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncInclinometer {
+            value: TEST_INCLINATION,
+        };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.inclination().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_INCLINATION);
+    }
+}
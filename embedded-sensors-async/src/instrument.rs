@@ -0,0 +1,291 @@
+//! Async Latency Instrumentation API
+//!
+//! This module provides an [`Instrumented`] wrapper which measures the latency of every operation
+//! performed on the wrapped sensor, and keeps rolling statistics over the most recent
+//! measurements. It wraps a [`Sensor`] of any quantity, and so every sensor-type trait which
+//! refines [`Sensor`], as well as any [`SampleSource`]. It is primarily intended to diagnose bus contention when many sensors share a
+//! single bus controller.
+//!
+//! Latency is measured using a user-provided [`Clock`].
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal_async::clock::Clock;
+//! use embedded_sensors_hal_async::instrument::Instrumented;
+//! use embedded_sensors_hal_async::sensor::Sensor;
+//! use embedded_sensors_hal_async::temperature::{Temperature, TemperatureSensor};
+//!
+//! async fn diagnose<S: TemperatureSensor>(sensor: S, clock: impl Clock) {
+//!     // Keep statistics over the 16 most recent operations.
+//!     let mut sensor = Instrumented::<_, _, 16>::new(sensor, clock);
+//!
+//!     for _ in 0..16 {
+//!         let _ = Sensor::<Temperature>::measure(&mut sensor).await;
+//!     }
+//!
+//!     let stats = sensor.stats();
+//!     if stats.max > 10 * stats.mean {
+//!         // Operations are occasionally stalled, possibly by other devices on the bus...
+//!     }
+//! }
+//! ```
+
+use crate::clock::Clock;
+use crate::sensor::{ErrorKind, ErrorType, Sensor};
+use crate::source::SampleSource;
+
+/// Latency statistics, in microseconds, over a window of recent operations.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LatencyStats {
+    /// Number of operations in the window.
+    pub count: usize,
+    /// Latency of the most recent operation.
+    pub last: u64,
+    /// Shortest latency in the window.
+    pub min: u64,
+    /// Longest latency in the window.
+    pub max: u64,
+    /// Mean latency over the window.
+    pub mean: u64,
+}
+
+/// A rolling window holding the `N` most recent latency measurements, in microseconds.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LatencyWindow<const N: usize> {
+    latencies: [u64; N],
+    len: usize,
+    next: usize,
+}
+
+impl<const N: usize> LatencyWindow<N> {
+    /// Creates a new, empty window.
+    pub const fn new() -> Self {
+        Self {
+            latencies: [0; N],
+            len: 0,
+            next: 0,
+        }
+    }
+
+    /// Records a latency measurement, evicting the oldest one if the window is full.
+    pub fn record(&mut self, latency: u64) {
+        if N == 0 {
+            return;
+        }
+
+        self.latencies[self.next] = latency;
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+    }
+
+    /// Discards all measurements.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.next = 0;
+    }
+
+    /// Returns statistics over the measurements currently in the window.
+    pub fn stats(&self) -> LatencyStats {
+        if self.len == 0 {
+            return LatencyStats::default();
+        }
+
+        let window = &self.latencies[..self.len];
+        let sum: u64 = window.iter().sum();
+        LatencyStats {
+            count: self.len,
+            last: self.latencies[(self.next + N - 1) % N],
+            min: window.iter().copied().min().unwrap_or_default(),
+            max: window.iter().copied().max().unwrap_or_default(),
+            mean: sum / self.len as u64,
+        }
+    }
+}
+
+impl<const N: usize> Default for LatencyWindow<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Wraps a sensor or sample source, measuring the latency of each operation performed on it.
+///
+/// Latencies of failed operations are recorded as well. Statistics are kept over the `N` most
+/// recent operations.
+pub struct Instrumented<S, C, const N: usize> {
+    sensor: S,
+    clock: C,
+    window: LatencyWindow<N>,
+}
+
+impl<S, C: Clock, const N: usize> Instrumented<S, C, N> {
+    /// Wraps `sensor`, measuring latency with `clock`.
+    pub fn new(sensor: S, clock: C) -> Self {
+        Self {
+            sensor,
+            clock,
+            window: LatencyWindow::new(),
+        }
+    }
+
+    /// Returns latency statistics over the most recent operations.
+    pub fn stats(&self) -> LatencyStats {
+        self.window.stats()
+    }
+
+    /// Discards all latency measurements.
+    pub fn reset_stats(&mut self) {
+        self.window.clear();
+    }
+
+    /// Returns a mutable reference to the wrapped sensor.
+    ///
+    /// Operations performed directly on the wrapped sensor are not measured.
+    pub fn inner_mut(&mut self) -> &mut S {
+        &mut self.sensor
+    }
+
+    /// Consumes the wrapper, returning the wrapped sensor.
+    pub fn into_inner(self) -> S {
+        self.sensor
+    }
+}
+
+impl<S: ErrorType, C, const N: usize> ErrorType for Instrumented<S, C, N> {
+    type Error = S::Error;
+}
+
+impl<Q, S: Sensor<Q>, C: Clock, const N: usize> Sensor<Q> for Instrumented<S, C, N> {
+    type Sample = S::Sample;
+
+    async fn measure(&mut self) -> Result<Self::Sample, Self::Error> {
+        let start = self.clock.now_us();
+        let result = self.sensor.measure().await;
+        self.window
+            .record(self.clock.now_us().saturating_sub(start));
+        result
+    }
+}
+
+impl<S: SampleSource, C: Clock, const N: usize> SampleSource for Instrumented<S, C, N> {
+    type Sample = S::Sample;
+
+    async fn sample(&mut self) -> Result<Self::Sample, ErrorKind> {
+        let start = self.clock.now_us();
+        let result = self.sensor.sample().await;
+        self.window
+            .record(self.clock.now_us().saturating_sub(start));
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::sensor::Error;
+    use crate::source::TemperatureSource;
+    use crate::temperature::{DegreesCelsius, Temperature, TemperatureSensor};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_TEMP: DegreesCelsius = 25.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    // Each read advances the shared mock clock by a programmed latency.
+    struct MockAsyncTempSensor<'a> {
+        clock: &'a ManualClock,
+        latencies: &'a [u64],
+        reads: usize,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncTempSensor<'_> {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockAsyncTempSensor<'_> {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            let latency = self.latencies[self.reads % self.latencies.len()];
+            self.clock.advance_us(latency);
+            self.reads += 1;
+            Ok(TEST_TEMP)
+        }
+    }
+
+    #[test]
+    fn test_latency_window() {
+        let mut window = LatencyWindow::<3>::new();
+        assert_eq!(window.stats(), LatencyStats::default());
+
+        for latency in [100, 400, 200, 300] {
+            window.record(latency);
+        }
+
+        // The first measurement has been evicted.
+        let stats = window.stats();
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.last, 300);
+        assert_eq!(stats.min, 200);
+        assert_eq!(stats.max, 400);
+        assert_eq!(stats.mean, 300);
+
+        window.clear();
+        assert_eq!(window.stats(), LatencyStats::default());
+    }
+
+    #[tokio::test]
+    async fn test_async_instrumented_temperature() {
+        let clock = ManualClock::new();
+        let sensor = MockAsyncTempSensor {
+            clock: &clock,
+            latencies: &[50, 150],
+            reads: 0,
+        };
+        let mut sensor = Instrumented::<_, _, 4>::new(sensor, &clock);
+
+        for _ in 0..4 {
+            let result = Sensor::<Temperature>::measure(&mut sensor).await;
+            assert!(result.is_ok());
+            assert_approx_eq!(result.unwrap(), TEST_TEMP);
+        }
+
+        let stats = sensor.stats();
+        assert_eq!(stats.count, 4);
+        assert_eq!(stats.last, 150);
+        assert_eq!(stats.min, 50);
+        assert_eq!(stats.max, 150);
+        assert_eq!(stats.mean, 100);
+
+        sensor.reset_stats();
+        assert_eq!(sensor.stats().count, 0);
+        assert_eq!(sensor.into_inner().reads, 4);
+    }
+
+    #[tokio::test]
+    async fn test_async_instrumented_source() {
+        let clock = ManualClock::new();
+        let sensor = MockAsyncTempSensor {
+            clock: &clock,
+            latencies: &[50, 150],
+            reads: 0,
+        };
+        let mut source = Instrumented::<_, _, 4>::new(TemperatureSource(sensor), &clock);
+
+        for _ in 0..2 {
+            assert_approx_eq!(source.sample().await.unwrap(), TEST_TEMP);
+        }
+        assert_eq!(source.stats().count, 2);
+        assert_eq!(source.stats().mean, 100);
+    }
+}
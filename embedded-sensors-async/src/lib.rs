@@ -4,6 +4,75 @@
 #![no_std]
 #![allow(async_fn_in_trait)]
 
+pub mod alert;
+pub mod altitude;
+pub mod angle;
+pub mod array;
+pub mod battery;
+pub mod biopotential;
+pub mod blood_pressure;
+pub mod capacitance;
+pub mod color;
+pub mod combustible_gas;
+pub mod compensation;
+pub mod derating;
+pub mod dew_point;
+pub mod distance;
+pub mod fifo;
+pub mod flame;
+pub mod flicker;
+pub mod flow;
+pub mod force;
+pub mod formaldehyde;
+pub mod frequency;
+pub mod gas;
+pub mod gsr;
+pub mod heater;
 pub mod humidity;
+pub mod imu;
+pub mod inclination;
+pub mod instrument;
+pub mod lightning;
+pub mod liquid_level;
+pub mod magnetic_switch;
+pub mod motion;
+pub mod object_temperature;
+pub mod occupancy;
+pub mod orientation;
+#[cfg(feature = "delay")]
+pub mod overheat;
+pub mod power;
+pub mod precipitation;
+pub mod presence;
+pub mod pressure;
+pub mod radiation;
+pub mod recovery;
+pub mod resistance;
+pub mod respiration;
+pub mod rtd;
+pub mod sanitize;
 pub mod sensor;
+pub mod shock;
+pub mod smoke;
+pub mod soil;
+pub mod sound;
+pub mod source;
+pub mod spectral;
+pub mod spo2;
+pub mod step_counter;
+pub mod tap;
+#[cfg(feature = "embassy")]
+pub mod task;
 pub mod temperature;
+pub mod thermal_array;
+pub mod timing;
+pub mod touch;
+pub mod vibration;
+pub mod virtual_sensor;
+pub mod weight;
+pub mod wind;
+
+pub use embedded_sensors_hal::clock;
+pub use embedded_sensors_hal::registry;
+pub use embedded_sensors_hal::units;
+pub use embedded_sensors_hal::wire;
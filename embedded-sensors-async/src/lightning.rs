@@ -0,0 +1,193 @@
+//! Async Lightning Detector API
+//!
+//! This API provides generic methods for interfacing with lightning detectors (e.g. AS3935)
+//! specifically.
+//!
+//! Unlike most sensors, lightning detectors are event-centric: they report the estimated distance
+//! to the head of the storm and the energy of the most recent strike, rather than a continuously
+//! sampled quantity. Detectors with an interrupt output can implement the [`LightningWait`] trait
+//! so that applications can await strikes instead of polling.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the LightningDetector and LightningWait traits
+//! for an AS3935.
+//!
+//! ```
+//! use embedded_sensors_hal_async::lightning::{
+//!     Kilometers, LightningDetector, LightningStrike, LightningWait, StrikeEnergy,
+//! };
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a lightning detector.
+//! pub struct MyLightningDetector {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyLightningDetector {
+//!     type Error = Error;
+//! }
+//!
+//! impl LightningDetector for MyLightningDetector {
+//!     async fn strike_distance(&mut self) -> Result<Option<Kilometers>, Self::Error> {
+//!         // Read distance estimation register of detector (0x3F means out of range)...
+//!         Ok(Some(14.0))
+//!     }
+//!
+//!     async fn strike_energy(&mut self) -> Result<StrikeEnergy, Self::Error> {
+//!         // Read lightning energy registers of detector...
+//!         Ok(0x0001_2345)
+//!     }
+//! }
+//!
+//! impl LightningWait for MyLightningDetector {
+//!     async fn wait_for_strike(&mut self) -> Result<LightningStrike, Self::Error> {
+//!         // Await IRQ pin, read interrupt register and ignore noise and disturber events...
+//!         // Then return the strike which raised the interrupt
+//!         self.last_strike().await
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::lightning::{Kilometers, Lightning, LightningStrike, StrikeEnergy};
+
+/// Async Lightning Detector methods.
+pub trait LightningDetector: Sensor<Lightning, Sample = LightningStrike> {
+    /// Returns the estimated distance to the head of the storm in kilometers, or `None` if the
+    /// storm is out of range.
+    async fn strike_distance(&mut self) -> Result<Option<Kilometers>, Self::Error>;
+
+    /// Returns the raw energy of the most recent strike.
+    async fn strike_energy(&mut self) -> Result<StrikeEnergy, Self::Error>;
+
+    /// Returns the estimated distance and energy of the most recent strike.
+    async fn last_strike(&mut self) -> Result<LightningStrike, Self::Error> {
+        Ok(LightningStrike {
+            distance: self.strike_distance().await?,
+            energy: self.strike_energy().await?,
+        })
+    }
+}
+
+impl<T: LightningDetector + ?Sized> LightningDetector for &mut T {
+    #[inline]
+    async fn strike_distance(&mut self) -> Result<Option<Kilometers>, Self::Error> {
+        T::strike_distance(self).await
+    }
+
+    #[inline]
+    async fn strike_energy(&mut self) -> Result<StrikeEnergy, Self::Error> {
+        T::strike_energy(self).await
+    }
+
+    #[inline]
+    async fn last_strike(&mut self) -> Result<LightningStrike, Self::Error> {
+        T::last_strike(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    Lightning,
+    LightningDetector,
+    last_strike,
+    LightningStrike
+);
+
+/// Async Lightning Wait methods.
+pub trait LightningWait: LightningDetector {
+    /// Wait for a lightning strike to be detected and return it.
+    ///
+    /// Implementations should not return on events which are not strikes, such as noise level
+    /// or disturber (man-made interference) events.
+    async fn wait_for_strike(&mut self) -> Result<LightningStrike, Self::Error>;
+}
+
+impl<T: LightningWait + ?Sized> LightningWait for &mut T {
+    #[inline]
+    async fn wait_for_strike(&mut self) -> Result<LightningStrike, Self::Error> {
+        T::wait_for_strike(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_STRIKES: [LightningStrike; 2] = [
+        LightningStrike {
+            distance: None,
+            energy: 0x0000_0800,
+        },
+        LightningStrike {
+            distance: Some(14.0),
+            energy: 0x0001_2345,
+        },
+    ];
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncLightningDetector {
+        strikes: usize,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncLightningDetector {
+        type Error = MockError;
+    }
+
+    impl LightningDetector for MockAsyncLightningDetector {
+        async fn strike_distance(&mut self) -> Result<Option<Kilometers>, Self::Error> {
+            Ok(TEST_STRIKES[self.strikes - 1].distance)
+        }
+
+        async fn strike_energy(&mut self) -> Result<StrikeEnergy, Self::Error> {
+            Ok(TEST_STRIKES[self.strikes - 1].energy)
+        }
+    }
+
+    impl LightningWait for MockAsyncLightningDetector {
+        async fn wait_for_strike(&mut self) -> Result<LightningStrike, Self::Error> {
+            self.strikes += 1;
+            self.last_strike().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_lightning_detector_trait() {
+        let mut sensor = MockAsyncLightningDetector { strikes: 2 };
+        assert_eq!(sensor.strike_distance().await.unwrap(), Some(14.0));
+        assert_eq!(sensor.strike_energy().await.unwrap(), 0x0001_2345);
+    }
+
+    #[tokio::test]
+    async fn test_async_lightning_wait_trait_mut_ref() {
+        let mut sensor = MockAsyncLightningDetector { strikes: 0 };
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.wait_for_strike().await.unwrap(), TEST_STRIKES[0]);
+        assert_eq!(mut_ref.wait_for_strike().await.unwrap(), TEST_STRIKES[1]);
+        assert_eq!(mut_ref.last_strike().await.unwrap(), TEST_STRIKES[1]);
+    }
+}
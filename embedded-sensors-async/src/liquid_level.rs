@@ -0,0 +1,326 @@
+//! Async Liquid Level Sensor API
+//!
+//! This API provides generic methods for interfacing with liquid level sensors specifically, such
+//! as ultrasonic, capacitive, pressure-based or float sensors used by tank-monitoring firmware.
+//!
+//! Sensors measuring the height of the liquid implement the [`LiquidLevelSensor`] trait, while
+//! sensors (or drivers aware of the tank geometry) reporting how full the tank is implement the
+//! [`FillLevelSensor`] trait. The threshold traits of either can be used for low-level and overflow
+//! alarms.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the LiquidLevelSensor and LiquidLevelThresholdWait
+//! traits for a tank level sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::liquid_level::{
+//!     LiquidLevelHysteresis, LiquidLevelSensor, LiquidLevelThresholdSet, LiquidLevelThresholdWait,
+//!     Millimeters,
+//! };
+//!
+//! // A struct representing a tank level sensor.
+//! pub struct MyTankLevelSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyTankLevelSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl LiquidLevelSensor for MyTankLevelSensor {
+//!     async fn liquid_level(&mut self) -> Result<Millimeters, Self::Error> {
+//!         // Read level register of sensor...
+//!         Ok(840.0)
+//!     }
+//! }
+//!
+//! impl LiquidLevelThresholdSet for MyTankLevelSensor {
+//!     async fn set_liquid_level_threshold_low(
+//!         &mut self,
+//!         threshold: Millimeters
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_liquid_level_threshold_high(
+//!         &mut self,
+//!         threshold: Millimeters
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl LiquidLevelThresholdWait for MyTankLevelSensor {
+//!     async fn wait_for_liquid_level_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<Millimeters>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.liquid_level().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl LiquidLevelHysteresis for MyTankLevelSensor {
+//!     async fn set_liquid_level_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: Millimeters
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::liquid_level::{
+    fill_percentage, FillLevel, LiquidLevel, Millimeters, Percentage, FILL_LEVEL_RANGE,
+};
+
+/// Async Liquid Level Sensor methods.
+pub trait LiquidLevelSensor: Sensor<LiquidLevel, Sample = Millimeters> {
+    /// Returns a liquid level sample in millimeters, measured from the bottom of the tank.
+    async fn liquid_level(&mut self) -> Result<Millimeters, Self::Error>;
+}
+
+impl<T: LiquidLevelSensor + ?Sized> LiquidLevelSensor for &mut T {
+    #[inline]
+    async fn liquid_level(&mut self) -> Result<Millimeters, Self::Error> {
+        T::liquid_level(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    LiquidLevel,
+    LiquidLevelSensor,
+    liquid_level,
+    Millimeters
+);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait LiquidLevelThresholdSet: LiquidLevelSensor {
+//     async fn set_liquid_level_threshold_low(&mut self, threshold: Millimeters) -> Result<(), Self::Error>;
+//     async fn set_liquid_level_threshold_high(&mut self, threshold: Millimeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait LiquidLevelHysteresis: LiquidLevelThresholdSet {
+//     async fn set_liquid_level_threshold_hysteresis(&mut self, hysteresis: Millimeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait LiquidLevelThresholdControl: LiquidLevelThresholdSet {
+//     async fn disable_liquid_level_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_liquid_level_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait LiquidLevelThresholdWait: LiquidLevelThresholdSet {
+//     async fn wait_for_liquid_level_threshold(&mut self) -> Result<ThresholdEvent<Millimeters>, Self::Error>;
+// }
+decl_threshold_traits!(
+    async,
+    LiquidLevel,
+    LiquidLevelSensor,
+    Millimeters,
+    "millimeters"
+);
+
+// This macro generates the `LiquidLevelAlert` adapter, which allows implementors of
+// `LiquidLevelThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(LiquidLevel, Millimeters);
+
+/// Async Fill Level Sensor methods.
+pub trait FillLevelSensor: Sensor<FillLevel, Sample = Percentage> {
+    /// Returns a fill level sample as a percentage of the full level of the tank.
+    async fn fill_level(&mut self) -> Result<Percentage, Self::Error>;
+}
+
+impl<T: FillLevelSensor + ?Sized> FillLevelSensor for &mut T {
+    #[inline]
+    async fn fill_level(&mut self) -> Result<Percentage, Self::Error> {
+        T::fill_level(self).await
+    }
+}
+
+impl_sensor_refinement!(async, FillLevel, FillLevelSensor, fill_level, Percentage);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait FillLevelThresholdSet: FillLevelSensor {
+//     async fn set_fill_level_threshold_low(&mut self, threshold: Percentage) -> Result<(), Self::Error>;
+//     async fn set_fill_level_threshold_high(&mut self, threshold: Percentage) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FillLevelHysteresis: FillLevelThresholdSet {
+//     async fn set_fill_level_threshold_hysteresis(&mut self, hysteresis: Percentage) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FillLevelThresholdControl: FillLevelThresholdSet {
+//     async fn disable_fill_level_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_fill_level_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FillLevelThresholdWait: FillLevelThresholdSet {
+//     async fn wait_for_fill_level_threshold(&mut self) -> Result<ThresholdEvent<Percentage>, Self::Error>;
+// }
+decl_threshold_traits!(async, FillLevel, FillLevelSensor, Percentage, "percent");
+
+// This macro generates the `FillLevelAlert` adapter, which allows implementors of
+// `FillLevelThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(FillLevel, Percentage);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Millimeters = 840.0;
+    const TEST_THRESHOLD_LOW: Millimeters = 100.0;
+    const TEST_THRESHOLD_HIGH: Millimeters = 1_150.0;
+    const TEST_HYSTERESIS: Millimeters = 10.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncTankLevelSensor {
+        value: Millimeters,
+        threshold_low: Option<Millimeters>,
+        threshold_high: Option<Millimeters>,
+        hysteresis: Option<Millimeters>,
+    }
+
+    impl MockAsyncTankLevelSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncTankLevelSensor {
+        type Error = MockError;
+    }
+
+    impl LiquidLevelSensor for MockAsyncTankLevelSensor {
+        async fn liquid_level(&mut self) -> Result<Millimeters, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl LiquidLevelThresholdSet for MockAsyncTankLevelSensor {
+        async fn set_liquid_level_threshold_low(
+            &mut self,
+            threshold: Millimeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        async fn set_liquid_level_threshold_high(
+            &mut self,
+            threshold: Millimeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl LiquidLevelHysteresis for MockAsyncTankLevelSensor {
+        async fn set_liquid_level_threshold_hysteresis(
+            &mut self,
+            hysteresis: Millimeters,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_liquid_level_sensor_trait() {
+        let mut sensor = MockAsyncTankLevelSensor::new();
+        let result = sensor.liquid_level().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_liquid_level_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncTankLevelSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.liquid_level().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_liquid_level_threshold_set_mut_ref() {
+        let mut sensor = MockAsyncTankLevelSensor::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_liquid_level_threshold_low(TEST_THRESHOLD_LOW)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_liquid_level_threshold_high(TEST_THRESHOLD_HIGH)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_liquid_level_threshold_hysteresis(TEST_HYSTERESIS)
+            .await
+            .is_ok());
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), TEST_HYSTERESIS);
+    }
+
+    struct MockAsyncFillLevelSensor;
+
+    impl crate::sensor::ErrorType for MockAsyncFillLevelSensor {
+        type Error = MockError;
+    }
+
+    impl FillLevelSensor for MockAsyncFillLevelSensor {
+        async fn fill_level(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(fill_percentage(TEST_VALUE, 1_200.0))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_fill_level_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncFillLevelSensor;
+        let mut_ref = &mut sensor;
+        let result = mut_ref.fill_level().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), 70.0);
+    }
+}
@@ -0,0 +1,179 @@
+//! Async Magnetic Switch API
+//!
+//! This API provides generic methods for interfacing with Hall effect switches and other
+//! magnetic presence sensors specifically, such as those used for lid-close detection.
+//!
+//! Every magnetic switch reports whether a field is present. Omnipolar switches cannot tell the
+//! polarity of the field, while linear Hall sensors can additionally implement the
+//! [`MagneticFieldStrength`] trait. Switches with an interrupt output (or whose output is wired
+//! to a GPIO) can implement the [`MagneticSwitchWait`] trait.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the MagneticSwitch and MagneticSwitchWait traits
+//! for a Hall effect switch.
+//!
+//! ```
+//! use embedded_sensors_hal_async::magnetic_switch::{
+//!     MagneticField, MagneticSwitch, MagneticSwitchWait,
+//! };
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a Hall effect switch.
+//! pub struct MyHallSwitch {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyHallSwitch {
+//!     type Error = Error;
+//! }
+//!
+//! impl MagneticSwitch for MyHallSwitch {
+//!     async fn field(&mut self) -> Result<MagneticField, Self::Error> {
+//!         // Read output pin of switch...
+//!         Ok(MagneticField::present(None))
+//!     }
+//! }
+//!
+//! impl MagneticSwitchWait for MyHallSwitch {
+//!     async fn wait_for_field_change(&mut self) -> Result<MagneticField, Self::Error> {
+//!         // Await any edge on output pin of switch...
+//!         // Then return the new state of the field
+//!         self.field().await
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::magnetic_switch::{
+    FieldDetection, FieldStrength, MagneticField, Millitesla, Polarity,
+};
+
+/// Async Magnetic Switch methods.
+pub trait MagneticSwitch: Sensor<FieldDetection, Sample = MagneticField> {
+    /// Returns the state of the magnetic field at the switch.
+    async fn field(&mut self) -> Result<MagneticField, Self::Error>;
+}
+
+impl<T: MagneticSwitch + ?Sized> MagneticSwitch for &mut T {
+    #[inline]
+    async fn field(&mut self) -> Result<MagneticField, Self::Error> {
+        T::field(self).await
+    }
+}
+
+impl_sensor_refinement!(async, FieldDetection, MagneticSwitch, field, MagneticField);
+
+/// Async Magnetic Field Strength methods for sensors able to measure it.
+pub trait MagneticFieldStrength:
+    MagneticSwitch + Sensor<FieldStrength, Sample = Millitesla>
+{
+    /// Returns a magnetic flux density sample in millitesla.
+    ///
+    /// The sample is positive for a south pole facing the sensor and negative for a north pole.
+    async fn field_strength(&mut self) -> Result<Millitesla, Self::Error>;
+}
+
+impl<T: MagneticFieldStrength + ?Sized> MagneticFieldStrength for &mut T {
+    #[inline]
+    async fn field_strength(&mut self) -> Result<Millitesla, Self::Error> {
+        T::field_strength(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    FieldStrength,
+    MagneticFieldStrength,
+    field_strength,
+    Millitesla
+);
+
+/// Async Magnetic Switch Wait methods.
+pub trait MagneticSwitchWait: MagneticSwitch {
+    /// Wait for the state of the magnetic field to change (e.g. a lid being opened or closed),
+    /// returning the new state.
+    async fn wait_for_field_change(&mut self) -> Result<MagneticField, Self::Error>;
+}
+
+impl<T: MagneticSwitchWait + ?Sized> MagneticSwitchWait for &mut T {
+    #[inline]
+    async fn wait_for_field_change(&mut self) -> Result<MagneticField, Self::Error> {
+        T::wait_for_field_change(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    // Mock lid switch which toggles each time it is waited on.
+    struct MockAsyncLidSwitch {
+        closed: bool,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncLidSwitch {
+        type Error = MockError;
+    }
+
+    impl MagneticSwitch for MockAsyncLidSwitch {
+        async fn field(&mut self) -> Result<MagneticField, Self::Error> {
+            Ok(if self.closed {
+                MagneticField::present(None)
+            } else {
+                MagneticField::ABSENT
+            })
+        }
+    }
+
+    impl MagneticSwitchWait for MockAsyncLidSwitch {
+        async fn wait_for_field_change(&mut self) -> Result<MagneticField, Self::Error> {
+            self.closed = !self.closed;
+            self.field().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_magnetic_switch_trait() {
+        let mut sensor = MockAsyncLidSwitch { closed: false };
+        let result = sensor.field().await;
+        assert!(result.is_ok());
+        assert!(!result.unwrap().present);
+    }
+
+    #[tokio::test]
+    async fn test_async_magnetic_switch_wait_trait_mut_ref() {
+        let mut sensor = MockAsyncLidSwitch { closed: false };
+        let mut_ref = &mut sensor;
+
+        let result = mut_ref.wait_for_field_change().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), MagneticField::present(None));
+
+        let result = mut_ref.wait_for_field_change().await;
+        assert_eq!(result.unwrap(), MagneticField::ABSENT);
+    }
+}
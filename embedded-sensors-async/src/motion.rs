@@ -0,0 +1,149 @@
+//! Async Motion Sensor API
+//!
+//! This API provides generic methods for interfacing with sources of velocity specifically, such
+//! as GNSS receivers, wheel odometry or optical flow sensors, so that navigation code can consume
+//! speed and heading independently of how they are measured.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the SpeedSensor and HeadingSensor traits for a
+//! GNSS receiver.
+//!
+//! ```
+//! use embedded_sensors_hal_async::motion::{Degrees, HeadingSensor, MetersPerSecond, SpeedSensor};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a GNSS receiver.
+//! pub struct MyGnssReceiver {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyGnssReceiver {
+//!     type Error = Error;
+//! }
+//!
+//! impl SpeedSensor for MyGnssReceiver {
+//!     async fn speed(&mut self) -> Result<MetersPerSecond, Self::Error> {
+//!         // Parse ground speed from latest navigation solution of receiver...
+//!         Ok(12.4)
+//!     }
+//! }
+//!
+//! impl HeadingSensor for MyGnssReceiver {
+//!     async fn heading(&mut self) -> Result<Degrees, Self::Error> {
+//!         // Parse course over ground from latest navigation solution of receiver...
+//!         Ok(271.5)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::motion::{Degrees, Heading, MetersPerSecond, Speed, HEADING_RANGE};
+
+/// Async Speed Sensor methods.
+pub trait SpeedSensor: Sensor<Speed, Sample = MetersPerSecond> {
+    /// Returns a speed sample in meters per second, i.e. the magnitude of the velocity over the
+    /// ground.
+    async fn speed(&mut self) -> Result<MetersPerSecond, Self::Error>;
+}
+
+impl<T: SpeedSensor + ?Sized> SpeedSensor for &mut T {
+    #[inline]
+    async fn speed(&mut self) -> Result<MetersPerSecond, Self::Error> {
+        T::speed(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Speed, SpeedSensor, speed, MetersPerSecond);
+
+/// Async Heading Sensor methods.
+pub trait HeadingSensor: Sensor<Heading, Sample = Degrees> {
+    /// Returns a heading sample in degrees clockwise from north, i.e. the direction of motion.
+    ///
+    /// Fails with an error of kind [`ErrorKind::NotReady`](crate::sensor::ErrorKind::NotReady)
+    /// if the heading cannot currently be determined (e.g. a GNSS receiver at standstill).
+    async fn heading(&mut self) -> Result<Degrees, Self::Error>;
+}
+
+impl<T: HeadingSensor + ?Sized> HeadingSensor for &mut T {
+    #[inline]
+    async fn heading(&mut self) -> Result<Degrees, Self::Error> {
+        T::heading(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Heading, HeadingSensor, heading, Degrees);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_SPEED: MetersPerSecond = 12.4;
+    const TEST_HEADING: Degrees = 271.5;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::NotReady
+        }
+    }
+
+    struct MockAsyncGnssReceiver {
+        speed: MetersPerSecond,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncGnssReceiver {
+        type Error = MockError;
+    }
+
+    impl SpeedSensor for MockAsyncGnssReceiver {
+        async fn speed(&mut self) -> Result<MetersPerSecond, Self::Error> {
+            Ok(self.speed)
+        }
+    }
+
+    impl HeadingSensor for MockAsyncGnssReceiver {
+        async fn heading(&mut self) -> Result<Degrees, Self::Error> {
+            // Heading is undefined at standstill
+            if self.speed > 0.5 {
+                Ok(TEST_HEADING)
+            } else {
+                Err(MockError)
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_motion_sensor_traits() {
+        let mut sensor = MockAsyncGnssReceiver { speed: TEST_SPEED };
+        assert_approx_eq!(sensor.speed().await.unwrap(), TEST_SPEED);
+        assert_approx_eq!(sensor.heading().await.unwrap(), TEST_HEADING);
+    }
+
+    #[tokio::test]
+    async fn test_async_motion_sensor_traits_mut_ref() {
+        let mut sensor = MockAsyncGnssReceiver { speed: 0.0 };
+        let mut_ref = &mut sensor;
+        assert_approx_eq!(mut_ref.speed().await.unwrap(), 0.0);
+        let result = mut_ref.heading().await;
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::NotReady);
+    }
+}
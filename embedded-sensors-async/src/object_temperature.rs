@@ -0,0 +1,256 @@
+//! Async Object Temperature Sensor API
+//!
+//! This API provides generic methods for interfacing with non-contact (radiometric) temperature
+//! sensors specifically, such as MLX90614-class IR thermopiles.
+//!
+//! Unlike a [`TemperatureSensor`](crate::temperature::TemperatureSensor), which measures the
+//! temperature of whatever it is in contact with, a thermopile infers the temperature of an
+//! object in its field of view from the infrared radiation the object emits. It also reports the
+//! temperature of its own die (the ambient temperature), against which the object temperature is
+//! compensated. Keeping both in a separate module lets thermal monitoring code tell contact and
+//! radiometric readings apart.
+//!
+//! The accuracy of the object temperature depends on the emissivity of the object, which
+//! sensors implementing [`EmissivitySet`] allow to be configured.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the ObjectTemperatureSensor and EmissivitySet
+//! traits for an IR thermopile.
+//!
+//! ```
+//! use embedded_sensors_hal_async::object_temperature::{
+//!     DegreesCelsius, Emissivity, EmissivitySet, ObjectTemperatureSensor,
+//!     ObjectTemperatureThresholdSet, ObjectTemperatureThresholdWait,
+//! };
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing an IR thermopile.
+//! pub struct MyThermopile {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyThermopile {
+//!     type Error = Error;
+//! }
+//!
+//! impl ObjectTemperatureSensor for MyThermopile {
+//!     async fn object_temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+//!         // Read object temperature RAM register of sensor...
+//!         Ok(36.6)
+//!     }
+//!
+//!     async fn ambient_temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+//!         // Read die temperature RAM register of sensor...
+//!         Ok(24.0)
+//!     }
+//! }
+//!
+//! impl EmissivitySet for MyThermopile {
+//!     async fn set_emissivity(&mut self, emissivity: Emissivity) -> Result<(), Self::Error> {
+//!         // Write emissivity EEPROM cell of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl ObjectTemperatureThresholdSet for MyThermopile {
+//!     async fn set_object_temperature_threshold_low(
+//!         &mut self,
+//!         threshold: DegreesCelsius
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_object_temperature_threshold_high(
+//!         &mut self,
+//!         threshold: DegreesCelsius
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl ObjectTemperatureThresholdWait for MyThermopile {
+//!     async fn wait_for_object_temperature_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<DegreesCelsius>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.object_temperature().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::object_temperature::{
+    DegreesCelsius, Emissivity, ObjectTemperature, EMISSIVITY_RANGE,
+};
+
+/// Async Object Temperature Sensor methods.
+pub trait ObjectTemperatureSensor: Sensor<ObjectTemperature, Sample = DegreesCelsius> {
+    /// Returns a sample of the temperature of the object in the field of view of the sensor, in
+    /// degrees Celsius.
+    async fn object_temperature(&mut self) -> Result<DegreesCelsius, Self::Error>;
+
+    /// Returns a sample of the ambient (die) temperature of the sensor, in degrees Celsius.
+    async fn ambient_temperature(&mut self) -> Result<DegreesCelsius, Self::Error>;
+}
+
+impl<T: ObjectTemperatureSensor + ?Sized> ObjectTemperatureSensor for &mut T {
+    #[inline]
+    async fn object_temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        T::object_temperature(self).await
+    }
+
+    #[inline]
+    async fn ambient_temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        T::ambient_temperature(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    ObjectTemperature,
+    ObjectTemperatureSensor,
+    object_temperature,
+    DegreesCelsius
+);
+
+/// Async Emissivity configuration methods.
+pub trait EmissivitySet: ObjectTemperatureSensor {
+    /// Set the emissivity of the object used to compensate object temperature samples.
+    ///
+    /// See [`EMISSIVITY_RANGE`] for the range of valid emissivities.
+    async fn set_emissivity(&mut self, emissivity: Emissivity) -> Result<(), Self::Error>;
+}
+
+impl<T: EmissivitySet + ?Sized> EmissivitySet for &mut T {
+    #[inline]
+    async fn set_emissivity(&mut self, emissivity: Emissivity) -> Result<(), Self::Error> {
+        T::set_emissivity(self, emissivity).await
+    }
+}
+
+// This macro generates the following async threshold traits:
+//
+// pub trait ObjectTemperatureThresholdSet: ObjectTemperatureSensor {
+//     async fn set_object_temperature_threshold_low(&mut self, threshold: DegreesCelsius) -> Result<(), Self::Error>;
+//     async fn set_object_temperature_threshold_high(&mut self, threshold: DegreesCelsius) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ObjectTemperatureHysteresis: ObjectTemperatureThresholdSet {
+//     async fn set_object_temperature_threshold_hysteresis(&mut self, hysteresis: DegreesCelsius) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ObjectTemperatureThresholdControl: ObjectTemperatureThresholdSet {
+//     async fn disable_object_temperature_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_object_temperature_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ObjectTemperatureThresholdWait: ObjectTemperatureThresholdSet {
+//     async fn wait_for_object_temperature_threshold(&mut self) -> Result<ThresholdEvent<DegreesCelsius>, Self::Error>;
+// }
+decl_threshold_traits!(
+    async,
+    ObjectTemperature,
+    ObjectTemperatureSensor,
+    DegreesCelsius,
+    "degrees Celsius"
+);
+
+// This macro generates the `ObjectTemperatureAlert` adapter, which allows implementors of
+// `ObjectTemperatureThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(ObjectTemperature, DegreesCelsius);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_OBJECT_TEMP: DegreesCelsius = 36.6;
+    const TEST_AMBIENT_TEMP: DegreesCelsius = 24.0;
+    const TEST_EMISSIVITY: Emissivity = 0.98;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::InvalidInput
+        }
+    }
+
+    struct MockAsyncThermopile {
+        emissivity: Emissivity,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncThermopile {
+        type Error = MockError;
+    }
+
+    impl ObjectTemperatureSensor for MockAsyncThermopile {
+        async fn object_temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(TEST_OBJECT_TEMP)
+        }
+
+        async fn ambient_temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(TEST_AMBIENT_TEMP)
+        }
+    }
+
+    impl EmissivitySet for MockAsyncThermopile {
+        async fn set_emissivity(&mut self, emissivity: Emissivity) -> Result<(), Self::Error> {
+            self.emissivity = EMISSIVITY_RANGE
+                .validate(emissivity)
+                .map_err(|_| MockError)?;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_object_temperature_sensor_trait() {
+        let mut sensor = MockAsyncThermopile { emissivity: 1.0 };
+        let result = sensor.object_temperature().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_OBJECT_TEMP);
+        let result = sensor.ambient_temperature().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_AMBIENT_TEMP);
+    }
+
+    #[tokio::test]
+    async fn test_async_emissivity_set_trait_mut_ref() {
+        let mut sensor = MockAsyncThermopile { emissivity: 1.0 };
+
+        {
+            let mut_ref = &mut sensor;
+            assert!(mut_ref.set_emissivity(TEST_EMISSIVITY).await.is_ok());
+            assert!(mut_ref.set_emissivity(1.5).await.is_err());
+            assert_approx_eq!(
+                mut_ref.object_temperature().await.unwrap(),
+                TEST_OBJECT_TEMP
+            );
+        }
+
+        assert_approx_eq!(sensor.emissivity, TEST_EMISSIVITY);
+    }
+}
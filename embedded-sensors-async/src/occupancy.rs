@@ -0,0 +1,147 @@
+//! Async Occupancy Sensor API
+//!
+//! This API provides generic methods for interfacing with occupancy (presence) sensors
+//! specifically, such as passive infrared (PIR) motion sensors or human presence radars, as used
+//! for presence-based power management.
+//!
+//! Sensors with an interrupt output (or whose output is wired to a GPIO) can implement the
+//! [`OccupancyWait`] trait.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the OccupancySensor and OccupancyWait traits for
+//! a PIR sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::occupancy::{OccupancySensor, OccupancyWait};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a PIR sensor.
+//! pub struct MyPirSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyPirSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl OccupancySensor for MyPirSensor {
+//!     async fn presence(&mut self) -> Result<bool, Self::Error> {
+//!         // Read output pin of sensor...
+//!         Ok(true)
+//!     }
+//! }
+//!
+//! impl OccupancyWait for MyPirSensor {
+//!     async fn wait_for_presence_change(&mut self) -> Result<bool, Self::Error> {
+//!         // Await any edge on output pin of sensor...
+//!         // Then return whether presence is now detected
+//!         self.presence().await
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::occupancy::Occupancy;
+
+/// Async Occupancy Sensor methods.
+pub trait OccupancySensor: Sensor<Occupancy, Sample = bool> {
+    /// Returns `true` if presence is currently detected.
+    ///
+    /// Most sensors hold presence for some time after the last motion, so this reports the
+    /// held state rather than instantaneous motion.
+    async fn presence(&mut self) -> Result<bool, Self::Error>;
+}
+
+impl<T: OccupancySensor + ?Sized> OccupancySensor for &mut T {
+    #[inline]
+    async fn presence(&mut self) -> Result<bool, Self::Error> {
+        T::presence(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Occupancy, OccupancySensor, presence, bool);
+
+/// Async Occupancy Wait methods.
+pub trait OccupancyWait: OccupancySensor {
+    /// Wait for presence to be detected or lost, returning `true` if presence is now detected.
+    async fn wait_for_presence_change(&mut self) -> Result<bool, Self::Error>;
+}
+
+impl<T: OccupancyWait + ?Sized> OccupancyWait for &mut T {
+    #[inline]
+    async fn wait_for_presence_change(&mut self) -> Result<bool, Self::Error> {
+        T::wait_for_presence_change(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    // Mock PIR sensor whose presence toggles each time it is waited on.
+    struct MockAsyncPirSensor {
+        present: bool,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncPirSensor {
+        type Error = MockError;
+    }
+
+    impl OccupancySensor for MockAsyncPirSensor {
+        async fn presence(&mut self) -> Result<bool, Self::Error> {
+            Ok(self.present)
+        }
+    }
+
+    impl OccupancyWait for MockAsyncPirSensor {
+        async fn wait_for_presence_change(&mut self) -> Result<bool, Self::Error> {
+            self.present = !self.present;
+            Ok(self.present)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_occupancy_sensor_trait() {
+        let mut sensor = MockAsyncPirSensor { present: true };
+        let result = sensor.presence().await;
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_async_occupancy_wait_trait_mut_ref() {
+        let mut sensor = MockAsyncPirSensor { present: true };
+        let mut_ref = &mut sensor;
+
+        let result = mut_ref.wait_for_presence_change().await;
+        assert!(result.is_ok());
+        assert!(!result.unwrap());
+        assert!(!mut_ref.presence().await.unwrap());
+
+        assert!(mut_ref.wait_for_presence_change().await.unwrap());
+    }
+}
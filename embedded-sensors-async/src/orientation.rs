@@ -0,0 +1,124 @@
+//! Async Orientation Sensor API
+//!
+//! This API provides generic methods for interfacing with orientation sensors specifically, such
+//! as inertial measurement units with on-chip sensor fusion (e.g. BNO055 or BNO08x).
+//!
+//! Depending on the device, orientation is natively reported either as a unit quaternion or as
+//! Euler angles, so the [`OrientationSensor`] trait returns an [`Orientation`] holding either.
+//! With the `libm` feature enabled, applications can convert it to the representation they
+//! consume, regardless of whether fusion runs on-chip or in software.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the OrientationSensor trait for a fusion IMU.
+//!
+//! ```
+//! use embedded_sensors_hal_async::orientation::{Orientation, OrientationSensor, Quaternion};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing an IMU with on-chip sensor fusion.
+//! pub struct MyFusionImu {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyFusionImu {
+//!     type Error = Error;
+//! }
+//!
+//! impl OrientationSensor for MyFusionImu {
+//!     async fn orientation(&mut self) -> Result<Orientation, Self::Error> {
+//!         // Read rotation vector report of sensor (Q14 fixed point)...
+//!         Ok(Orientation::Quaternion(Quaternion {
+//!             w: 1.0,
+//!             x: 0.0,
+//!             y: 0.0,
+//!             z: 0.0,
+//!         }))
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::orientation::{
+    Attitude, Degrees, EulerAngles, Orientation, Quaternion,
+};
+
+/// Async Orientation Sensor methods.
+pub trait OrientationSensor: Sensor<Attitude, Sample = Orientation> {
+    /// Returns an orientation sample, relative to the reference frame of the sensor.
+    async fn orientation(&mut self) -> Result<Orientation, Self::Error>;
+}
+
+impl<T: OrientationSensor + ?Sized> OrientationSensor for &mut T {
+    #[inline]
+    async fn orientation(&mut self) -> Result<Orientation, Self::Error> {
+        T::orientation(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Attitude, OrientationSensor, orientation, Orientation);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_QUATERNION: Quaternion = Quaternion {
+        w: 0.5,
+        x: 0.5,
+        y: 0.5,
+        z: 0.5,
+    };
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncFusionImu;
+
+    impl crate::sensor::ErrorType for MockAsyncFusionImu {
+        type Error = MockError;
+    }
+
+    impl OrientationSensor for MockAsyncFusionImu {
+        async fn orientation(&mut self) -> Result<Orientation, Self::Error> {
+            Ok(TEST_QUATERNION.into())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_orientation_sensor_trait() {
+        let mut sensor = MockAsyncFusionImu;
+        let result = sensor.orientation().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Orientation::Quaternion(TEST_QUATERNION));
+    }
+
+    #[tokio::test]
+    async fn test_async_orientation_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncFusionImu;
+        let mut_ref = &mut sensor;
+        let result = mut_ref.orientation().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Orientation::Quaternion(TEST_QUATERNION));
+    }
+}
@@ -0,0 +1,186 @@
+//! Async Overheat Detection API
+//!
+//! This module (enabled by the `delay` feature) provides an [`OverheatDetector`] which watches
+//! any [`TemperatureSensor`] for overheating using the fixed temperature and rate of rise criteria
+//! of heat detectors in fire alarm systems. See the blocking crate for details of the criteria.
+//!
+//! The detector samples its sensor periodically and is an [`AlertSource`] reporting every
+//! overheat as a [`Severity::Critical`] crossing of its upper threshold, so that it can be
+//! multiplexed with the alerts of other sensors by an [`AlertMux`](crate::alert::AlertMux).
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_hal_async::delay::DelayNs;
+//! use embedded_sensors_hal_async::clock::Clock;
+//! use embedded_sensors_hal_async::overheat::{OverheatCriteria, OverheatDetector};
+//! use embedded_sensors_hal_async::temperature::TemperatureSensor;
+//!
+//! async fn protect<S: TemperatureSensor, C: Clock, D: DelayNs>(
+//!     sensor: S,
+//!     clock: C,
+//!     delay: D,
+//! ) -> Result<(), S::Error> {
+//!     let mut detector =
+//!         OverheatDetector::new(sensor, clock, delay, OverheatCriteria::HEAT_DETECTOR)
+//!             .with_period_ms(500);
+//!     let event = detector.wait_for_overheat().await?;
+//!     // Cut power to the enclosure and raise the alarm...
+//!     let _ = event.cause;
+//!     Ok(())
+//! }
+//! ```
+
+use embedded_hal_async::delay::DelayNs;
+
+use crate::alert::AlertSource;
+use crate::clock::Clock;
+use crate::sensor::{Error, ErrorKind, Severity, ThresholdEvent, ThresholdKind};
+use crate::temperature::{DegreesCelsius, TemperatureSensor};
+pub use embedded_sensors_hal::overheat::{
+    DegreesCelsiusPerMinute, OverheatCause, OverheatCriteria, OverheatEvent, OverheatMonitor,
+};
+
+/// Default period at which an [`OverheatDetector`] samples its sensor, in milliseconds.
+pub const DEFAULT_OVERHEAT_PERIOD_MS: u32 = 1_000;
+
+/// Watches a [`TemperatureSensor`] for overheating.
+pub struct OverheatDetector<S, C, D> {
+    sensor: S,
+    clock: C,
+    delay: D,
+    monitor: OverheatMonitor,
+    period_ms: u32,
+}
+
+impl<S: TemperatureSensor, C: Clock, D: DelayNs> OverheatDetector<S, C, D> {
+    /// Creates a new detector evaluating `criteria` over samples of `sensor`, timed by `clock`
+    /// and taken every [`DEFAULT_OVERHEAT_PERIOD_MS`].
+    pub fn new(sensor: S, clock: C, delay: D, criteria: OverheatCriteria) -> Self {
+        Self {
+            sensor,
+            clock,
+            delay,
+            monitor: OverheatMonitor::new(criteria),
+            period_ms: DEFAULT_OVERHEAT_PERIOD_MS,
+        }
+    }
+
+    /// Set the period at which the sensor is sampled, in milliseconds.
+    ///
+    /// This should be several times shorter than the rate of rise window.
+    pub fn with_period_ms(mut self, period_ms: u32) -> Self {
+        self.period_ms = period_ms;
+        self
+    }
+
+    /// Returns the monitor holding the detection state.
+    pub fn monitor(&self) -> &OverheatMonitor {
+        &self.monitor
+    }
+
+    /// Samples the sensor once, returning an event if the enclosure is overheating.
+    pub async fn poll(&mut self) -> Result<Option<OverheatEvent>, S::Error> {
+        let temperature = self.sensor.temperature().await?;
+        Ok(self.monitor.update(temperature, self.clock.now_us()))
+    }
+
+    /// Samples the sensor periodically until the enclosure is overheating, returning the event.
+    pub async fn wait_for_overheat(&mut self) -> Result<OverheatEvent, S::Error> {
+        loop {
+            if let Some(event) = self.poll().await? {
+                return Ok(event);
+            }
+            self.delay.delay_ms(self.period_ms).await;
+        }
+    }
+
+    /// Consumes the detector, returning the sensor, clock and delay.
+    pub fn into_inner(self) -> (S, C, D) {
+        (self.sensor, self.clock, self.delay)
+    }
+}
+
+impl<S: TemperatureSensor, C: Clock, D: DelayNs> AlertSource for OverheatDetector<S, C, D> {
+    type Sample = DegreesCelsius;
+
+    async fn wait_for_alert(&mut self) -> Result<ThresholdEvent<Self::Sample>, ErrorKind> {
+        self.wait_for_overheat()
+            .await
+            .map(ThresholdEvent::from)
+            .map_err(|e| e.kind())
+    }
+
+    #[inline]
+    fn severity(&self, _kind: ThresholdKind) -> Severity {
+        Severity::Critical
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_TEMPS: [DegreesCelsius; 5] = [25.0, 28.0, 26.0, 30.0, 33.0];
+    const TEST_CRITERIA: OverheatCriteria = OverheatCriteria::new(60.0, 10.0, 20_000_000);
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    // Returns each of the test values in turn.
+    struct MockAsyncTempSensor {
+        reads: usize,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncTempSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockAsyncTempSensor {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            let sample = TEST_TEMPS.get(self.reads).copied().ok_or(MockError);
+            self.reads += 1;
+            sample
+        }
+    }
+
+    // Advances the clock by the requested delay.
+    struct MockDelay<'a> {
+        clock: &'a ManualClock,
+    }
+
+    impl DelayNs for MockDelay<'_> {
+        async fn delay_ns(&mut self, ns: u32) {
+            self.clock.advance_us(u64::from(ns / 1_000));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_overheat_detector_alert_source() {
+        let clock = ManualClock::new();
+        let sensor = MockAsyncTempSensor { reads: 0 };
+        let mut detector =
+            OverheatDetector::new(sensor, &clock, MockDelay { clock: &clock }, TEST_CRITERIA)
+                .with_period_ms(10_000);
+        assert_eq!(detector.severity(ThresholdKind::High), Severity::Critical);
+
+        // 25 -> 26 °C over the first window is 3 °C/min; 26 -> 33 °C over the second is 21.
+        let event = detector.wait_for_alert().await.unwrap();
+        assert_eq!(event.kind, ThresholdKind::High);
+        assert_approx_eq!(event.value, 33.0);
+        assert_eq!(event.timestamp_us, Some(40_000_000));
+        assert_eq!(detector.monitor().rate_of_rise(), Some(21.0));
+
+        // The sensor runs out of values.
+        assert_eq!(detector.wait_for_alert().await, Err(ErrorKind::Other));
+    }
+}
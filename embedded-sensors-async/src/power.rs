@@ -0,0 +1,433 @@
+//! Async Sensor Power API
+//!
+//! This API provides generic methods for transitioning sensors between their powered-down and
+//! operational states, independently of the sensor type.
+//!
+//! Sensors with a low-power sleep state in addition to shutdown can implement the
+//! [`SensorPowerControl`] trait, which also reports their current [`PowerState`].
+//!
+//! With the `delay` feature enabled, a `PowerCoordinator` additionally sequences a group of
+//! sensors through these transitions, so that system sleep flows can suspend and resume all of
+//! them with a single call.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the PowerMode trait for a sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::power::PowerMode;
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a sensor.
+//! pub struct MySensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MySensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl PowerMode for MySensor {
+//!     async fn shutdown(&mut self) -> Result<(), Self::Error> {
+//!         // Write shutdown bit to configuration register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn wake(&mut self) -> Result<(), Self::Error> {
+//!         // Clear shutdown bit in configuration register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+#[cfg(feature = "delay")]
+use crate::sensor::{Error, ErrorKind};
+#[cfg(feature = "delay")]
+use embedded_hal_async::delay::DelayNs;
+pub use embedded_sensors_hal::power::PowerState;
+
+/// Async Power Mode methods.
+pub trait PowerMode: ErrorType {
+    /// Put the sensor into its lowest power state, in which no samples are taken.
+    async fn shutdown(&mut self) -> Result<(), Self::Error>;
+
+    /// Return the sensor to its operational state.
+    ///
+    /// Samples may not be valid until the sensor has settled, see its datasheet.
+    async fn wake(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: PowerMode + ?Sized> PowerMode for &mut T {
+    #[inline]
+    async fn shutdown(&mut self) -> Result<(), Self::Error> {
+        T::shutdown(self).await
+    }
+
+    #[inline]
+    async fn wake(&mut self) -> Result<(), Self::Error> {
+        T::wake(self).await
+    }
+}
+
+/// Async Sensor Power Control methods, for sensors with a low-power sleep state in addition to
+/// shutdown.
+///
+/// This lets firmware managing many sensors idle them uniformly (e.g. during system suspend)
+/// without knowledge of the concrete drivers.
+pub trait SensorPowerControl: PowerMode {
+    /// Put the sensor into a low-power sleep state, retaining its configuration.
+    ///
+    /// Sensors without a distinct sleep state should enter their shutdown state instead.
+    async fn sleep(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns the current power state of the sensor.
+    async fn power_state(&mut self) -> Result<PowerState, Self::Error>;
+
+    /// Transition the sensor to the given power state.
+    async fn set_power_state(&mut self, state: PowerState) -> Result<(), Self::Error> {
+        match state {
+            PowerState::Active => self.wake().await,
+            PowerState::Sleep => self.sleep().await,
+            PowerState::Shutdown => self.shutdown().await,
+        }
+    }
+}
+
+impl<T: SensorPowerControl + ?Sized> SensorPowerControl for &mut T {
+    #[inline]
+    async fn sleep(&mut self) -> Result<(), Self::Error> {
+        T::sleep(self).await
+    }
+
+    #[inline]
+    async fn power_state(&mut self) -> Result<PowerState, Self::Error> {
+        T::power_state(self).await
+    }
+
+    #[inline]
+    async fn set_power_state(&mut self, state: PowerState) -> Result<(), Self::Error> {
+        T::set_power_state(self, state).await
+    }
+}
+
+/// A sensor managed by a [`PowerCoordinator`], along with the time it needs to settle after
+/// each power transition.
+#[cfg(feature = "delay")]
+pub struct PowerStep<S> {
+    /// The managed sensor.
+    pub sensor: S,
+    /// Time to wait after transitioning the sensor, in milliseconds.
+    pub settle_ms: u32,
+}
+
+#[cfg(feature = "delay")]
+impl<S: PowerMode> PowerStep<S> {
+    /// Creates a new power step.
+    pub fn new(sensor: S, settle_ms: u32) -> Self {
+        Self { sensor, settle_ms }
+    }
+}
+
+/// Error returned by a [`PowerCoordinator`] when a sensor fails to transition.
+#[cfg(feature = "delay")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PowerError {
+    /// Index of the sensor which failed, in the order sensors were given to the coordinator.
+    pub sensor: usize,
+    /// Kind of error reported by the sensor.
+    pub kind: ErrorKind,
+}
+
+#[cfg(feature = "delay")]
+impl Error for PowerError {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+/// Transitions a group of sensors to shutdown and back in a defined order.
+///
+/// Sensors are held as a tuple of between two and four [`PowerStep`]s, which may be of different
+/// types. Sensors are suspended in the order they appear in the tuple and resumed in the reverse
+/// order, waiting for each sensor's settle time after its transition.
+///
+/// A failing transition aborts the sequence, leaving the preceding sensors transitioned.
+///
+/// ```
+/// use embedded_hal_async::delay::DelayNs;
+/// use embedded_sensors_hal_async::power::{PowerCoordinator, PowerError, PowerMode, PowerStep};
+///
+/// async fn system_sleep<A: PowerMode, B: PowerMode, D: DelayNs>(
+///     als: A,
+///     imu: B,
+///     delay: D,
+/// ) -> Result<(), PowerError> {
+///     // The IMU needs 10ms after each transition before the next sensor may be touched.
+///     let steps = (PowerStep::new(als, 0), PowerStep::new(imu, 10));
+///     let mut group = PowerCoordinator::new(steps, delay);
+///
+///     group.suspend_all().await?;
+///     // Sleep...
+///     group.resume_all().await
+/// }
+/// ```
+#[cfg(feature = "delay")]
+pub struct PowerCoordinator<S, D> {
+    steps: S,
+    delay: D,
+}
+
+#[cfg(feature = "delay")]
+impl<S, D: DelayNs> PowerCoordinator<S, D> {
+    /// Creates a new coordinator over the given tuple of power steps.
+    pub fn new(steps: S, delay: D) -> Self {
+        Self { steps, delay }
+    }
+
+    /// Returns a mutable reference to the tuple of power steps.
+    pub fn steps_mut(&mut self) -> &mut S {
+        &mut self.steps
+    }
+
+    /// Consumes the coordinator, returning the tuple of power steps and the delay provider.
+    pub fn into_inner(self) -> (S, D) {
+        (self.steps, self.delay)
+    }
+}
+
+#[cfg(feature = "delay")]
+macro_rules! impl_power_coordinator {
+    ($($Sensor:ident => $idx:tt),+; $($rev:tt),+) => {
+        impl<$($Sensor: PowerMode,)+ D: DelayNs> PowerCoordinator<($(PowerStep<$Sensor>,)+), D> {
+            /// Shut down every sensor, in order.
+            pub async fn suspend_all(&mut self) -> Result<(), PowerError> {
+                $(
+                    self.steps.$idx.sensor.shutdown().await.map_err(|e| PowerError {
+                        sensor: $idx,
+                        kind: e.kind(),
+                    })?;
+                    self.delay.delay_ms(self.steps.$idx.settle_ms).await;
+                )+
+                Ok(())
+            }
+
+            /// Wake every sensor, in reverse order.
+            pub async fn resume_all(&mut self) -> Result<(), PowerError> {
+                $(
+                    self.steps.$rev.sensor.wake().await.map_err(|e| PowerError {
+                        sensor: $rev,
+                        kind: e.kind(),
+                    })?;
+                    self.delay.delay_ms(self.steps.$rev.settle_ms).await;
+                )+
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "delay")]
+impl_power_coordinator!(S0 => 0, S1 => 1; 1, 0);
+#[cfg(feature = "delay")]
+impl_power_coordinator!(S0 => 0, S1 => 1, S2 => 2; 2, 1, 0);
+#[cfg(feature = "delay")]
+impl_power_coordinator!(S0 => 0, S1 => 1, S2 => 2, S3 => 3; 3, 2, 1, 0);
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "delay")]
+    extern crate std;
+
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    #[cfg(feature = "delay")]
+    use core::cell::RefCell;
+    #[cfg(feature = "delay")]
+    use std::vec::Vec;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Peripheral
+        }
+    }
+
+    // Records every transition and delay in a shared log.
+    #[cfg(feature = "delay")]
+    #[derive(Debug, PartialEq)]
+    enum Event {
+        Shutdown(u8),
+        Wake(u8),
+        Delay(u32),
+    }
+
+    #[cfg(feature = "delay")]
+    struct MockSensor<'a> {
+        id: u8,
+        fail: bool,
+        log: &'a RefCell<Vec<Event>>,
+    }
+
+    #[cfg(feature = "delay")]
+    impl crate::sensor::ErrorType for MockSensor<'_> {
+        type Error = MockError;
+    }
+
+    #[cfg(feature = "delay")]
+    impl PowerMode for MockSensor<'_> {
+        async fn shutdown(&mut self) -> Result<(), Self::Error> {
+            if self.fail {
+                return Err(MockError);
+            }
+            self.log.borrow_mut().push(Event::Shutdown(self.id));
+            Ok(())
+        }
+
+        async fn wake(&mut self) -> Result<(), Self::Error> {
+            self.log.borrow_mut().push(Event::Wake(self.id));
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "delay")]
+    struct MockDelay<'a> {
+        log: &'a RefCell<Vec<Event>>,
+    }
+
+    #[cfg(feature = "delay")]
+    impl DelayNs for MockDelay<'_> {
+        async fn delay_ns(&mut self, ns: u32) {
+            self.log.borrow_mut().push(Event::Delay(ns / 1_000_000));
+        }
+    }
+
+    #[cfg(feature = "delay")]
+    fn sensor(id: u8, log: &RefCell<Vec<Event>>) -> MockSensor<'_> {
+        MockSensor {
+            id,
+            fail: false,
+            log,
+        }
+    }
+
+    #[cfg(feature = "delay")]
+    #[tokio::test]
+    async fn test_async_power_coordinator_order() {
+        let log = RefCell::new(Vec::new());
+        let mut group = PowerCoordinator::new(
+            (
+                PowerStep::new(sensor(0, &log), 1),
+                PowerStep::new(sensor(1, &log), 2),
+                PowerStep::new(sensor(2, &log), 3),
+            ),
+            MockDelay { log: &log },
+        );
+
+        assert!(group.suspend_all().await.is_ok());
+        assert!(group.resume_all().await.is_ok());
+        assert_eq!(
+            *log.borrow(),
+            [
+                Event::Shutdown(0),
+                Event::Delay(1),
+                Event::Shutdown(1),
+                Event::Delay(2),
+                Event::Shutdown(2),
+                Event::Delay(3),
+                Event::Wake(2),
+                Event::Delay(3),
+                Event::Wake(1),
+                Event::Delay(2),
+                Event::Wake(0),
+                Event::Delay(1),
+            ]
+        );
+    }
+
+    #[cfg(feature = "delay")]
+    #[tokio::test]
+    async fn test_async_power_coordinator_error() {
+        let log = RefCell::new(Vec::new());
+        let mut failing = sensor(1, &log);
+        failing.fail = true;
+        let mut group = PowerCoordinator::new(
+            (
+                PowerStep::new(sensor(0, &log), 0),
+                PowerStep::new(failing, 0),
+            ),
+            MockDelay { log: &log },
+        );
+
+        assert_eq!(
+            group.suspend_all().await,
+            Err(PowerError {
+                sensor: 1,
+                kind: ErrorKind::Peripheral,
+            })
+        );
+        assert_eq!(*log.borrow(), [Event::Shutdown(0), Event::Delay(0)]);
+    }
+
+    struct MockAsyncSleepySensor {
+        state: PowerState,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncSleepySensor {
+        type Error = MockError;
+    }
+
+    impl PowerMode for MockAsyncSleepySensor {
+        async fn shutdown(&mut self) -> Result<(), Self::Error> {
+            self.state = PowerState::Shutdown;
+            Ok(())
+        }
+
+        async fn wake(&mut self) -> Result<(), Self::Error> {
+            self.state = PowerState::Active;
+            Ok(())
+        }
+    }
+
+    impl SensorPowerControl for MockAsyncSleepySensor {
+        async fn sleep(&mut self) -> Result<(), Self::Error> {
+            self.state = PowerState::Sleep;
+            Ok(())
+        }
+
+        async fn power_state(&mut self) -> Result<PowerState, Self::Error> {
+            Ok(self.state)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_sensor_power_control_trait_mut_ref() {
+        let mut sensor = MockAsyncSleepySensor {
+            state: PowerState::default(),
+        };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.sleep().await.is_ok());
+        assert_eq!(mut_ref.power_state().await.unwrap(), PowerState::Sleep);
+        assert!(mut_ref.set_power_state(PowerState::Shutdown).await.is_ok());
+        assert_eq!(mut_ref.power_state().await.unwrap(), PowerState::Shutdown);
+        assert!(mut_ref.set_power_state(PowerState::Active).await.is_ok());
+        assert_eq!(sensor.state, PowerState::Active);
+    }
+}
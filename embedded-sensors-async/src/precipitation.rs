@@ -0,0 +1,269 @@
+//! Async Precipitation Sensor API
+//!
+//! This API provides generic methods for interfacing with precipitation sensors (rain gauges) specifically.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the PrecipitationSensor and PrecipitationThresholdWait traits for a tipping-bucket rain gauge.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::precipitation::{
+//!     Millimeters, PrecipitationHysteresis, PrecipitationSensor, PrecipitationThresholdSet, PrecipitationThresholdWait,
+//! };
+//!
+//! // A struct representing a tipping-bucket rain gauge.
+//! pub struct MyPrecipitationSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyPrecipitationSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl PrecipitationSensor for MyPrecipitationSensor {
+//!     async fn accumulated_precipitation(&mut self) -> Result<Millimeters, Self::Error> {
+//!         // Convert bucket tip count to accumulated rainfall...
+//!         Ok(12.4)
+//!     }
+//!
+//!     async fn reset_accumulation(&mut self) -> Result<(), Self::Error> {
+//!         // Clear bucket tip counter...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl PrecipitationThresholdSet for MyPrecipitationSensor {
+//!     async fn set_precipitation_threshold_low(
+//!         &mut self,
+//!         threshold: Millimeters
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_precipitation_threshold_high(
+//!         &mut self,
+//!         threshold: Millimeters
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl PrecipitationThresholdWait for MyPrecipitationSensor {
+//!     async fn wait_for_precipitation_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<Millimeters>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.accumulated_precipitation().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl PrecipitationHysteresis for MyPrecipitationSensor {
+//!     async fn set_precipitation_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: Millimeters
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::precipitation::{Millimeters, Precipitation, PRECIPITATION_RANGE};
+
+/// Async Precipitation Sensor methods.
+pub trait PrecipitationSensor: Sensor<Precipitation, Sample = Millimeters> {
+    /// Returns the precipitation accumulated since the last reset, in millimeters.
+    async fn accumulated_precipitation(&mut self) -> Result<Millimeters, Self::Error>;
+
+    /// Reset the accumulated precipitation to zero.
+    async fn reset_accumulation(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: PrecipitationSensor + ?Sized> PrecipitationSensor for &mut T {
+    #[inline]
+    async fn accumulated_precipitation(&mut self) -> Result<Millimeters, Self::Error> {
+        T::accumulated_precipitation(self).await
+    }
+
+    #[inline]
+    async fn reset_accumulation(&mut self) -> Result<(), Self::Error> {
+        T::reset_accumulation(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    Precipitation,
+    PrecipitationSensor,
+    accumulated_precipitation,
+    Millimeters
+);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait PrecipitationThresholdSet: PrecipitationSensor {
+//     async fn set_precipitation_threshold_low(&mut self, threshold: Millimeters) -> Result<(), Self::Error>;
+//     async fn set_precipitation_threshold_high(&mut self, threshold: Millimeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait PrecipitationHysteresis: PrecipitationThresholdSet {
+//     async fn set_precipitation_threshold_hysteresis(&mut self, hysteresis: Millimeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait PrecipitationThresholdControl: PrecipitationThresholdSet {
+//     async fn disable_precipitation_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_precipitation_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait PrecipitationThresholdWait: PrecipitationThresholdSet {
+//     async fn wait_for_precipitation_threshold(&mut self) -> Result<ThresholdEvent<Millimeters>, Self::Error>;
+// }
+decl_threshold_traits!(
+    async,
+    Precipitation,
+    PrecipitationSensor,
+    Millimeters,
+    "millimeters"
+);
+
+// This macro generates the `PrecipitationAlert` adapter, which allows implementors of
+// `PrecipitationThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(Precipitation, Millimeters);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Millimeters = 3.2;
+    const TEST_THRESHOLD_LOW: Millimeters = 0.0;
+    const TEST_THRESHOLD_HIGH: Millimeters = 50.0;
+    const TEST_INITIAL_THRESHOLD: Millimeters = 0.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncPrecipitationSensor {
+        value: Millimeters,
+        threshold_low: Millimeters,
+        threshold_high: Millimeters,
+    }
+
+    impl MockAsyncPrecipitationSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: TEST_INITIAL_THRESHOLD,
+                threshold_high: TEST_INITIAL_THRESHOLD,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncPrecipitationSensor {
+        type Error = MockError;
+    }
+
+    impl PrecipitationSensor for MockAsyncPrecipitationSensor {
+        async fn accumulated_precipitation(&mut self) -> Result<Millimeters, Self::Error> {
+            Ok(self.value)
+        }
+
+        async fn reset_accumulation(&mut self) -> Result<(), Self::Error> {
+            self.value = 0.0;
+            Ok(())
+        }
+    }
+
+    impl PrecipitationThresholdSet for MockAsyncPrecipitationSensor {
+        async fn set_precipitation_threshold_low(
+            &mut self,
+            threshold: Millimeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = threshold;
+            Ok(())
+        }
+
+        async fn set_precipitation_threshold_high(
+            &mut self,
+            threshold: Millimeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = threshold;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_precipitation_sensor_trait() {
+        let mut sensor = MockAsyncPrecipitationSensor::new();
+        let result = sensor.accumulated_precipitation().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_precipitation_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncPrecipitationSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.accumulated_precipitation().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_precipitation_threshold_set_trait_mut_ref() {
+        let mut sensor = MockAsyncPrecipitationSensor::new();
+
+        {
+            let mut_ref = &mut sensor;
+            let result_low = mut_ref
+                .set_precipitation_threshold_low(TEST_THRESHOLD_LOW)
+                .await;
+            assert!(result_low.is_ok());
+            let result_high = mut_ref
+                .set_precipitation_threshold_high(TEST_THRESHOLD_HIGH)
+                .await;
+            assert!(result_high.is_ok());
+        }
+
+        assert_approx_eq!(sensor.threshold_low, TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high, TEST_THRESHOLD_HIGH);
+    }
+
+    #[tokio::test]
+    async fn test_async_precipitation_reset_accumulation_mut_ref() {
+        let mut sensor = MockAsyncPrecipitationSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.reset_accumulation().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(sensor.accumulated_precipitation().await.unwrap(), 0.0);
+    }
+}
@@ -0,0 +1,172 @@
+//! Async Presence Radar API
+//!
+//! This API provides generic methods for interfacing with radar presence sensors specifically,
+//! such as LD2410-class mmWave radars.
+//!
+//! Unlike PIR sensors (see the `occupancy` module), radars can detect people who are not moving
+//! and estimate how far away they are, so each [`PresenceSample`] classifies the presence as
+//! moving or stationary and carries the distance to the target when known. Radars with an
+//! interrupt output (or streaming reports) can implement the [`PresenceWait`] trait.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the PresenceSensor and PresenceWait traits for a
+//! radar presence sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::presence::{
+//!     PresenceSample, PresenceSensor, PresenceState, PresenceWait,
+//! };
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a radar presence sensor.
+//! pub struct MyPresenceRadar {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyPresenceRadar {
+//!     type Error = Error;
+//! }
+//!
+//! impl PresenceSensor for MyPresenceRadar {
+//!     async fn presence_sample(&mut self) -> Result<PresenceSample, Self::Error> {
+//!         // Parse target state and distance from latest report frame of sensor...
+//!         Ok(PresenceSample {
+//!             state: PresenceState::Stationary,
+//!             distance: Some(1.8),
+//!         })
+//!     }
+//! }
+//!
+//! impl PresenceWait for MyPresenceRadar {
+//!     async fn wait_for_presence_state_change(&mut self) -> Result<PresenceSample, Self::Error> {
+//!         // Await report frames until the target state changes...
+//!         self.presence_sample().await
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::presence::{Meters, Presence, PresenceSample, PresenceState};
+
+/// Async Presence Sensor methods.
+pub trait PresenceSensor: Sensor<Presence, Sample = PresenceSample> {
+    /// Returns a presence sample, classifying the presence and the distance to the target.
+    async fn presence_sample(&mut self) -> Result<PresenceSample, Self::Error>;
+}
+
+impl<T: PresenceSensor + ?Sized> PresenceSensor for &mut T {
+    #[inline]
+    async fn presence_sample(&mut self) -> Result<PresenceSample, Self::Error> {
+        T::presence_sample(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    Presence,
+    PresenceSensor,
+    presence_sample,
+    PresenceSample
+);
+
+/// Async Presence Wait methods.
+pub trait PresenceWait: PresenceSensor {
+    /// Wait for the presence state to change, returning the new presence sample.
+    ///
+    /// Changes in distance alone do not complete the wait.
+    async fn wait_for_presence_state_change(&mut self) -> Result<PresenceSample, Self::Error>;
+}
+
+impl<T: PresenceWait + ?Sized> PresenceWait for &mut T {
+    #[inline]
+    async fn wait_for_presence_state_change(&mut self) -> Result<PresenceSample, Self::Error> {
+        T::wait_for_presence_state_change(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_SAMPLES: [PresenceSample; 3] = [
+        PresenceSample {
+            state: PresenceState::Moving,
+            distance: Some(3.2),
+        },
+        PresenceSample {
+            state: PresenceState::Stationary,
+            distance: Some(1.8),
+        },
+        PresenceSample {
+            state: PresenceState::Absent,
+            distance: None,
+        },
+    ];
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncPresenceRadar {
+        index: usize,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncPresenceRadar {
+        type Error = MockError;
+    }
+
+    impl PresenceSensor for MockAsyncPresenceRadar {
+        async fn presence_sample(&mut self) -> Result<PresenceSample, Self::Error> {
+            Ok(TEST_SAMPLES[self.index])
+        }
+    }
+
+    impl PresenceWait for MockAsyncPresenceRadar {
+        async fn wait_for_presence_state_change(&mut self) -> Result<PresenceSample, Self::Error> {
+            self.index += 1;
+            self.presence_sample().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_presence_sensor_trait() {
+        let mut sensor = MockAsyncPresenceRadar { index: 0 };
+        let result = sensor.presence_sample().await;
+        assert!(result.is_ok());
+        let sample = result.unwrap();
+        assert!(sample.is_present());
+        assert_eq!(sample, TEST_SAMPLES[0]);
+    }
+
+    #[tokio::test]
+    async fn test_async_presence_wait_trait_mut_ref() {
+        let mut sensor = MockAsyncPresenceRadar { index: 0 };
+        let mut_ref = &mut sensor;
+        let sample = mut_ref.wait_for_presence_state_change().await.unwrap();
+        assert_eq!(sample.state, PresenceState::Stationary);
+        let sample = mut_ref.wait_for_presence_state_change().await.unwrap();
+        assert!(!sample.is_present());
+        assert_eq!(sample.distance, None);
+    }
+}
@@ -0,0 +1,221 @@
+//! Async Pressure Sensor API
+//!
+//! This API provides generic methods for interfacing with pressure sensors specifically, such as
+//! barometers (e.g. BMP390 or LPS22HB) and gauge or differential pressure transducers.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the PressureSensor and PressureThresholdWait traits for a barometer.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::pressure::{
+//!     Pascals, PressureSensor, PressureThresholdSet, PressureThresholdWait,
+//! };
+//!
+//! // A struct representing a barometer.
+//! pub struct MyBarometer {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyBarometer {
+//!     type Error = Error;
+//! }
+//!
+//! impl PressureSensor for MyBarometer {
+//!     async fn pressure(&mut self) -> Result<Pascals, Self::Error> {
+//!         // Read and compensate pressure registers of sensor...
+//!         Ok(101_325.0)
+//!     }
+//! }
+//!
+//! impl PressureThresholdSet for MyBarometer {
+//!     async fn set_pressure_threshold_low(&mut self, threshold: Pascals) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_pressure_threshold_high(&mut self, threshold: Pascals) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl PressureThresholdWait for MyBarometer {
+//!     async fn wait_for_pressure_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<Pascals>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on INT pin)...
+//!         let value = self.pressure().await?;
+//!         // Then read interrupt source register of sensor to determine which threshold was
+//!         // crossed (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::Low))
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::pressure::{Pascals, Pressure, PRESSURE_RANGE};
+
+/// Async Pressure Sensor methods.
+pub trait PressureSensor: Sensor<Pressure, Sample = Pascals> {
+    /// Returns a pressure sample in pascals.
+    async fn pressure(&mut self) -> Result<Pascals, Self::Error>;
+}
+
+impl<T: PressureSensor + ?Sized> PressureSensor for &mut T {
+    #[inline]
+    async fn pressure(&mut self) -> Result<Pascals, Self::Error> {
+        T::pressure(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Pressure, PressureSensor, pressure, Pascals);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait PressureThresholdSet: PressureSensor {
+//     async fn set_pressure_threshold_low(&mut self, threshold: Pascals) -> Result<(), Self::Error>;
+//     async fn set_pressure_threshold_high(&mut self, threshold: Pascals) -> Result<(), Self::Error>;
+// }
+//
+// pub trait PressureHysteresis: PressureThresholdSet {
+//     async fn set_pressure_threshold_hysteresis(&mut self, hysteresis: Pascals) -> Result<(), Self::Error>;
+// }
+//
+// pub trait PressureThresholdControl: PressureThresholdSet {
+//     async fn disable_pressure_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_pressure_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait PressureThresholdWait: PressureThresholdSet {
+//     async fn wait_for_pressure_threshold(&mut self) -> Result<ThresholdEvent<Pascals>, Self::Error>;
+// }
+decl_threshold_traits!(async, Pressure, PressureSensor, Pascals, "pascals");
+
+// This macro generates the `PressureAlert` adapter, which allows implementors of
+// `PressureThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(Pressure, Pascals);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Pascals = 101_325.0;
+    const TEST_THRESHOLD_LOW: Pascals = 95_000.0;
+    const TEST_THRESHOLD_HIGH: Pascals = 105_000.0;
+    const TEST_INITIAL_THRESHOLD: Pascals = 0.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncBarometer {
+        value: Pascals,
+        threshold_low: Pascals,
+        threshold_high: Pascals,
+    }
+
+    impl MockAsyncBarometer {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: TEST_INITIAL_THRESHOLD,
+                threshold_high: TEST_INITIAL_THRESHOLD,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncBarometer {
+        type Error = MockError;
+    }
+
+    impl PressureSensor for MockAsyncBarometer {
+        async fn pressure(&mut self) -> Result<Pascals, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl PressureThresholdSet for MockAsyncBarometer {
+        async fn set_pressure_threshold_low(
+            &mut self,
+            threshold: Pascals,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = threshold;
+            Ok(())
+        }
+
+        async fn set_pressure_threshold_high(
+            &mut self,
+            threshold: Pascals,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = threshold;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_pressure_sensor_trait() {
+        let mut sensor = MockAsyncBarometer::new();
+        let result = sensor.pressure().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_pressure_sensor_measure() {
+        // Every pressure sensor is a `Sensor` of pressure.
+        let mut sensor = MockAsyncBarometer::new();
+        let result = Sensor::<Pressure>::measure(&mut sensor).await;
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_pressure_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncBarometer::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.pressure().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_pressure_threshold_set_trait_mut_ref() {
+        let mut sensor = MockAsyncBarometer::new();
+
+        {
+            let mut_ref = &mut sensor;
+            let result_low = mut_ref.set_pressure_threshold_low(TEST_THRESHOLD_LOW).await;
+            assert!(result_low.is_ok());
+            let result_high = mut_ref
+                .set_pressure_threshold_high(TEST_THRESHOLD_HIGH)
+                .await;
+            assert!(result_high.is_ok());
+        }
+
+        assert_approx_eq!(sensor.threshold_low, TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high, TEST_THRESHOLD_HIGH);
+    }
+}
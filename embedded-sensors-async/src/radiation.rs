@@ -0,0 +1,196 @@
+//! Async Radiation Counter API
+//!
+//! This API provides generic methods for interfacing with ionizing radiation counters (e.g.
+//! Geiger-Müller tube front-ends) specifically.
+//!
+//! Unlike most sensors, radiation counters do not sample an instantaneous value: they
+//! accumulate pulses over a window of time, from which a count rate and dose rate are derived.
+//! The longer the window, the lower the statistical noise of the rates, so the application
+//! decides when to start a new window through [`RadiationCounter::reset_window`].
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the RadiationCounter trait for a Geiger tube
+//! front-end.
+//!
+//! ```
+//! use embedded_sensors_hal_async::radiation::{CountWindow, RadiationCounter, SBM20_CONVERSION_FACTOR};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a Geiger tube front-end counting pulses on a timer input.
+//! pub struct MyGeigerCounter {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyGeigerCounter {
+//!     type Error = Error;
+//! }
+//!
+//! impl RadiationCounter for MyGeigerCounter {
+//!     async fn counts(&mut self) -> Result<CountWindow, Self::Error> {
+//!         // Read pulse counter and time elapsed since the window was reset...
+//!         Ok(CountWindow {
+//!             counts: 42,
+//!             duration_ms: 120_000,
+//!         })
+//!     }
+//!
+//!     async fn reset_window(&mut self) -> Result<(), Self::Error> {
+//!         // Clear pulse counter and restart window timer...
+//!         Ok(())
+//!     }
+//!
+//!     fn conversion_factor(&self) -> f32 {
+//!         SBM20_CONVERSION_FACTOR
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::radiation::{
+    CountWindow, CountsPerMinute, MicrosievertsPerHour, Radiation, COUNT_RATE_RANGE,
+    DOSE_RATE_RANGE, SBM20_CONVERSION_FACTOR,
+};
+
+/// Async Radiation Counter methods.
+pub trait RadiationCounter: Sensor<Radiation, Sample = CountsPerMinute> {
+    /// Returns the pulses counted since the window was last reset.
+    async fn counts(&mut self) -> Result<CountWindow, Self::Error>;
+
+    /// Start a new counting window, discarding the pulses counted so far.
+    async fn reset_window(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns the conversion factor of the detector, in counts per minute per µSv/h.
+    fn conversion_factor(&self) -> f32;
+
+    /// Returns the average count rate over the current window in counts per minute.
+    async fn counts_per_minute(&mut self) -> Result<CountsPerMinute, Self::Error> {
+        Ok(self.counts().await?.counts_per_minute())
+    }
+
+    /// Returns the average dose rate over the current window in microsieverts per hour,
+    /// derived from the count rate and the conversion factor.
+    async fn dose_rate(&mut self) -> Result<MicrosievertsPerHour, Self::Error> {
+        Ok(self.counts_per_minute().await? / self.conversion_factor())
+    }
+}
+
+impl<T: RadiationCounter + ?Sized> RadiationCounter for &mut T {
+    #[inline]
+    async fn counts(&mut self) -> Result<CountWindow, Self::Error> {
+        T::counts(self).await
+    }
+
+    #[inline]
+    async fn reset_window(&mut self) -> Result<(), Self::Error> {
+        T::reset_window(self).await
+    }
+
+    #[inline]
+    fn conversion_factor(&self) -> f32 {
+        T::conversion_factor(self)
+    }
+
+    #[inline]
+    async fn counts_per_minute(&mut self) -> Result<CountsPerMinute, Self::Error> {
+        T::counts_per_minute(self).await
+    }
+
+    #[inline]
+    async fn dose_rate(&mut self) -> Result<MicrosievertsPerHour, Self::Error> {
+        T::dose_rate(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    Radiation,
+    RadiationCounter,
+    counts_per_minute,
+    CountsPerMinute
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_WINDOW: CountWindow = CountWindow {
+        counts: 50,
+        duration_ms: 120_000,
+    };
+    const TEST_CONVERSION_FACTOR: f32 = 125.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncGeigerCounter {
+        window: CountWindow,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncGeigerCounter {
+        type Error = MockError;
+    }
+
+    impl RadiationCounter for MockAsyncGeigerCounter {
+        async fn counts(&mut self) -> Result<CountWindow, Self::Error> {
+            Ok(self.window)
+        }
+
+        async fn reset_window(&mut self) -> Result<(), Self::Error> {
+            self.window = CountWindow::default();
+            Ok(())
+        }
+
+        fn conversion_factor(&self) -> f32 {
+            TEST_CONVERSION_FACTOR
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_radiation_counter_trait() {
+        let mut sensor = MockAsyncGeigerCounter {
+            window: TEST_WINDOW,
+        };
+        let result = sensor.counts_per_minute().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), 25.0);
+        let result = sensor.dose_rate().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), 0.2);
+    }
+
+    #[tokio::test]
+    async fn test_async_radiation_counter_trait_mut_ref() {
+        let mut sensor = MockAsyncGeigerCounter {
+            window: TEST_WINDOW,
+        };
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.counts().await.unwrap(), TEST_WINDOW);
+        assert!(mut_ref.reset_window().await.is_ok());
+
+        // An empty window reports a zero rate rather than dividing by zero.
+        assert_approx_eq!(mut_ref.dose_rate().await.unwrap(), 0.0);
+    }
+}
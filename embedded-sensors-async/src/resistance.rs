@@ -0,0 +1,250 @@
+//! Async Resistance Sensor API
+//!
+//! This API provides generic methods for interfacing with sensors whose raw measurement is
+//! resistive specifically, such as thermistor front-ends, the heater and sensing elements of metal-
+//! oxide gas sensors, or resistive moisture probes.
+//!
+//! Adapters deriving another physical quantity from the resistance (e.g. thermistor to temperature)
+//! can be built on top of the [`ResistanceSensor`] trait independently of the front-end.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the ResistanceSensor and ResistanceThresholdWait
+//! traits for a thermistor front-end.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::resistance::{
+//!     Ohms, ResistanceHysteresis, ResistanceSensor, ResistanceThresholdSet,
+//!     ResistanceThresholdWait,
+//! };
+//!
+//! // A struct representing a thermistor front-end.
+//! pub struct MyThermistorFrontEnd {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyThermistorFrontEnd {
+//!     type Error = Error;
+//! }
+//!
+//! impl ResistanceSensor for MyThermistorFrontEnd {
+//!     async fn resistance(&mut self) -> Result<Ohms, Self::Error> {
+//!         // Read ADC and compute resistance from voltage divider ratio...
+//!         Ok(10_000.0)
+//!     }
+//! }
+//!
+//! impl ResistanceThresholdSet for MyThermistorFrontEnd {
+//!     async fn set_resistance_threshold_low(
+//!         &mut self,
+//!         threshold: Ohms
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_resistance_threshold_high(
+//!         &mut self,
+//!         threshold: Ohms
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl ResistanceThresholdWait for MyThermistorFrontEnd {
+//!     async fn wait_for_resistance_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<Ohms>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.resistance().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl ResistanceHysteresis for MyThermistorFrontEnd {
+//!     async fn set_resistance_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: Ohms
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::resistance::{Ohms, Resistance, RESISTANCE_RANGE};
+
+/// Async Resistance Sensor methods.
+pub trait ResistanceSensor: Sensor<Resistance, Sample = Ohms> {
+    /// Returns a resistance sample in ohms.
+    async fn resistance(&mut self) -> Result<Ohms, Self::Error>;
+}
+
+impl<T: ResistanceSensor + ?Sized> ResistanceSensor for &mut T {
+    #[inline]
+    async fn resistance(&mut self) -> Result<Ohms, Self::Error> {
+        T::resistance(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Resistance, ResistanceSensor, resistance, Ohms);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait ResistanceThresholdSet: ResistanceSensor {
+//     async fn set_resistance_threshold_low(&mut self, threshold: Ohms) -> Result<(), Self::Error>;
+//     async fn set_resistance_threshold_high(&mut self, threshold: Ohms) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ResistanceHysteresis: ResistanceThresholdSet {
+//     async fn set_resistance_threshold_hysteresis(&mut self, hysteresis: Ohms) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ResistanceThresholdControl: ResistanceThresholdSet {
+//     async fn disable_resistance_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_resistance_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ResistanceThresholdWait: ResistanceThresholdSet {
+//     async fn wait_for_resistance_threshold(&mut self) -> Result<ThresholdEvent<Ohms>, Self::Error>;
+// }
+decl_threshold_traits!(async, Resistance, ResistanceSensor, Ohms, "ohms");
+
+// This macro generates the `ResistanceAlert` adapter, which allows implementors of
+// `ResistanceThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(Resistance, Ohms);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Ohms = 10_000.0;
+    const TEST_THRESHOLD_LOW: Ohms = 1_000.0;
+    const TEST_THRESHOLD_HIGH: Ohms = 50_000.0;
+    const TEST_HYSTERESIS: Ohms = 100.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncResistanceSensor {
+        value: Ohms,
+        threshold_low: Option<Ohms>,
+        threshold_high: Option<Ohms>,
+        hysteresis: Option<Ohms>,
+    }
+
+    impl MockAsyncResistanceSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncResistanceSensor {
+        type Error = MockError;
+    }
+
+    impl ResistanceSensor for MockAsyncResistanceSensor {
+        async fn resistance(&mut self) -> Result<Ohms, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl ResistanceThresholdSet for MockAsyncResistanceSensor {
+        async fn set_resistance_threshold_low(
+            &mut self,
+            threshold: Ohms,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        async fn set_resistance_threshold_high(
+            &mut self,
+            threshold: Ohms,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl ResistanceHysteresis for MockAsyncResistanceSensor {
+        async fn set_resistance_threshold_hysteresis(
+            &mut self,
+            hysteresis: Ohms,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_resistance_sensor_trait() {
+        let mut sensor = MockAsyncResistanceSensor::new();
+        let result = sensor.resistance().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_resistance_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncResistanceSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.resistance().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_resistance_threshold_set_mut_ref() {
+        let mut sensor = MockAsyncResistanceSensor::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_resistance_threshold_low(TEST_THRESHOLD_LOW)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_resistance_threshold_high(TEST_THRESHOLD_HIGH)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_resistance_threshold_hysteresis(TEST_HYSTERESIS)
+            .await
+            .is_ok());
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), TEST_HYSTERESIS);
+    }
+}
@@ -0,0 +1,146 @@
+//! Async Respiration Rate Sensor API
+//!
+//! This API provides generic methods for interfacing with respiration rate sensors specifically,
+//! for medical and wellness devices deriving the respiration rate from pressure, radar or
+//! photoplethysmography (PPG) signals.
+//!
+//! Respiration rate is estimated over several breaths and its reliability depends on motion and
+//! signal quality, so each sample carries the confidence the sensor has in the estimate.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the RespirationSensor trait for a radar sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::respiration::{RespirationSample, RespirationSensor};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing a vital signs radar sensor.
+//! pub struct MyRespirationRadar {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyRespirationRadar {
+//!     type Error = Error;
+//! }
+//!
+//! impl RespirationSensor for MyRespirationRadar {
+//!     async fn respiration_rate(&mut self) -> Result<RespirationSample, Self::Error> {
+//!         // Read respiration rate and confidence registers of sensor...
+//!         Ok(RespirationSample {
+//!             rate: 14.0,
+//!             confidence: 85.0,
+//!         })
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::respiration::{
+    BreathsPerMinute, Percentage, Respiration, RespirationSample, CONFIDENCE_RANGE,
+    RESPIRATION_RATE_RANGE,
+};
+
+/// Async Respiration Rate Sensor methods.
+pub trait RespirationSensor: Sensor<Respiration, Sample = RespirationSample> {
+    /// Returns a respiration rate sample in breaths per minute, along with its confidence.
+    async fn respiration_rate(&mut self) -> Result<RespirationSample, Self::Error>;
+}
+
+impl<T: RespirationSensor + ?Sized> RespirationSensor for &mut T {
+    #[inline]
+    async fn respiration_rate(&mut self) -> Result<RespirationSample, Self::Error> {
+        T::respiration_rate(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    Respiration,
+    RespirationSensor,
+    respiration_rate,
+    RespirationSample
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_SAMPLE: RespirationSample = RespirationSample {
+        rate: 14.0,
+        confidence: 85.0,
+    };
+    const TEST_MIN_CONFIDENCE: Percentage = 50.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncRespirationRadar {
+        sample: RespirationSample,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncRespirationRadar {
+        type Error = MockError;
+    }
+
+    impl RespirationSensor for MockAsyncRespirationRadar {
+        async fn respiration_rate(&mut self) -> Result<RespirationSample, Self::Error> {
+            Ok(self.sample)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_respiration_sensor_trait() {
+        let mut sensor = MockAsyncRespirationRadar {
+            sample: TEST_SAMPLE,
+        };
+        let result = sensor.respiration_rate().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(
+            result
+                .unwrap()
+                .rate_with_confidence(TEST_MIN_CONFIDENCE)
+                .unwrap(),
+            TEST_SAMPLE.rate
+        );
+    }
+
+    #[tokio::test]
+    async fn test_async_respiration_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncRespirationRadar {
+            sample: RespirationSample {
+                rate: 22.0,
+                confidence: 30.0,
+            },
+        };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.respiration_rate().await;
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap().rate_with_confidence(TEST_MIN_CONFIDENCE),
+            None
+        );
+    }
+}
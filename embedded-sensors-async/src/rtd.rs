@@ -0,0 +1,157 @@
+//! Async RTD Sensor API
+//!
+//! This API provides generic methods for interfacing with resistance temperature detectors
+//! (RTDs, e.g. PT100 or PT1000 probes) through MAX31865-class converters specifically.
+//!
+//! Besides the temperature, each [`RtdSample`] carries the raw resistance of the RTD in ohms,
+//! for applications applying their own linearization, and the [`RtdFaults`] latched by the
+//! converter.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the RtdSensor trait for an RTD converter.
+//!
+//! ```
+//! use embedded_sensors_hal_async::rtd::{RtdFaults, RtdSample, RtdSensor};
+//! use embedded_sensors_hal_async::sensor;
+//!
+//! // A struct representing an RTD-to-digital converter.
+//! pub struct MyRtdConverter {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyRtdConverter {
+//!     type Error = Error;
+//! }
+//!
+//! impl RtdSensor for MyRtdConverter {
+//!     async fn rtd(&mut self) -> Result<RtdSample, Self::Error> {
+//!         // Read RTD and fault status registers of converter, then convert the ratio to the
+//!         // reference resistor into resistance and temperature...
+//!         Ok(RtdSample {
+//!             temperature: 21.3,
+//!             resistance: 108.3,
+//!             faults: RtdFaults::NONE,
+//!         })
+//!     }
+//!
+//!     async fn clear_faults(&mut self) -> Result<(), Self::Error> {
+//!         // Write fault status clear bit of configuration register...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::rtd::{
+    Ohms, RtdFaults, RtdSample, RtdTemperature, PT1000_R0, PT100_R0,
+};
+
+/// Async RTD Sensor methods.
+pub trait RtdSensor: Sensor<RtdTemperature, Sample = RtdSample> {
+    /// Returns an RTD sample with temperature in degrees Celsius, resistance in ohms and the
+    /// faults latched by the converter.
+    async fn rtd(&mut self) -> Result<RtdSample, Self::Error>;
+
+    /// Clear the faults latched by the converter.
+    async fn clear_faults(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: RtdSensor + ?Sized> RtdSensor for &mut T {
+    #[inline]
+    async fn rtd(&mut self) -> Result<RtdSample, Self::Error> {
+        T::rtd(self).await
+    }
+
+    #[inline]
+    async fn clear_faults(&mut self) -> Result<(), Self::Error> {
+        T::clear_faults(self).await
+    }
+}
+
+impl_sensor_refinement!(async, RtdTemperature, RtdSensor, rtd, RtdSample);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_SAMPLE: RtdSample = RtdSample {
+        temperature: 21.3,
+        resistance: 108.3,
+        faults: RtdFaults::NONE,
+    };
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncRtdConverter {
+        sample: RtdSample,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncRtdConverter {
+        type Error = MockError;
+    }
+
+    impl RtdSensor for MockAsyncRtdConverter {
+        async fn rtd(&mut self) -> Result<RtdSample, Self::Error> {
+            Ok(self.sample)
+        }
+
+        async fn clear_faults(&mut self) -> Result<(), Self::Error> {
+            self.sample.faults = RtdFaults::NONE;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_rtd_sensor_trait() {
+        let mut sensor = MockAsyncRtdConverter {
+            sample: TEST_SAMPLE,
+        };
+        let result = sensor.rtd().await;
+        assert!(result.is_ok());
+        let sample = result.unwrap();
+        assert_approx_eq!(sample.resistance, 108.3);
+        assert_approx_eq!(sample.temperature().unwrap(), 21.3);
+    }
+
+    #[tokio::test]
+    async fn test_async_rtd_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncRtdConverter {
+            sample: RtdSample {
+                faults: RtdFaults::OPEN_CIRCUIT | RtdFaults::HIGH_THRESHOLD,
+                ..TEST_SAMPLE
+            },
+        };
+        let mut_ref = &mut sensor;
+        let sample = mut_ref.rtd().await.unwrap();
+        assert!(sample.faults.contains(RtdFaults::OPEN_CIRCUIT));
+        assert!(!sample.faults.contains(RtdFaults::SHORT_CIRCUIT));
+        assert_eq!(sample.temperature(), None);
+
+        assert!(mut_ref.clear_faults().await.is_ok());
+        assert!(mut_ref.rtd().await.unwrap().faults.is_empty());
+    }
+}
@@ -0,0 +1,288 @@
+//! Async Sample Sanitization API
+//!
+//! This module provides a [`Sanitized`] wrapper which catches the NaN and infinite samples a
+//! driver may produce and handles them according to an [`InvalidSamplePolicy`], so that
+//! downstream components (filters, thresholds, telemetry) only ever see finite samples unless
+//! told otherwise. Samples may also be checked against the range of plausible values of their
+//! quantity (e.g. [`TEMPERATURE_RANGE`]), catching finite but corrupt samples such as those of a
+//! misread register.
+//!
+//! The policy is chosen per wrapper, so that each adapter chain can handle invalid samples as
+//! suits its consumer.
+//!
+//! [`Sanitized`] only implements the altitude, dew point, relative humidity, sound pressure level,
+//! temperature and wind speed sensor traits, the quantities with a range of plausible values.
+//! Samples of other sensors can be passed through a [`SampleSanitizer`] directly.
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal_async::sanitize::{InvalidSamplePolicy, Sanitized};
+//! use embedded_sensors_hal_async::temperature::TemperatureSensor;
+//!
+//! fn monitored_temperature<S: TemperatureSensor>(sensor: S) -> impl TemperatureSensor {
+//!     Sanitized::new(sensor, InvalidSamplePolicy::Reject).with_range_check()
+//! }
+//! ```
+
+use crate::altitude::{AltitudeSensor, Meters, Pascals, ALTITUDE_RANGE};
+use crate::dew_point::DewPointSensor;
+use crate::humidity::{Percentage, RelativeHumiditySensor, RELATIVE_HUMIDITY_RANGE};
+use crate::sensor::{ErrorKind, ErrorType};
+use crate::sound::{Decibels, SoundPressureLevelSensor, SOUND_PRESSURE_LEVEL_RANGE};
+use crate::temperature::{DegreesCelsius, TemperatureSensor, TEMPERATURE_RANGE};
+use crate::wind::{MetersPerSecond, WindSpeedSensor, WIND_SPEED_RANGE};
+
+pub use embedded_sensors_hal::sanitize::{InvalidSamplePolicy, SampleSanitizer, SanitizeError};
+
+/// Wraps a sensor, handling the non-finite samples it produces according to an
+/// [`InvalidSamplePolicy`].
+///
+/// Only the sensor traits listed in the module documentation are implemented. Each of them has a
+/// sanitizer of its own, so that the samples of a sensor measuring several quantities are never
+/// substituted for one another.
+pub struct Sanitized<S> {
+    sensor: S,
+    sanitizers: [SampleSanitizer; SANITIZED_QUANTITIES],
+    check_range: bool,
+}
+
+impl<S> Sanitized<S> {
+    /// Wraps `sensor`, applying `policy` to its invalid samples.
+    pub fn new(sensor: S, policy: InvalidSamplePolicy) -> Self {
+        Self {
+            sensor,
+            sanitizers: [SampleSanitizer::new(policy); SANITIZED_QUANTITIES],
+            check_range: false,
+        }
+    }
+
+    /// Also treats samples outside the range of plausible values of their quantity as invalid.
+    pub fn with_range_check(mut self) -> Self {
+        self.check_range = true;
+        self
+    }
+
+    /// Returns a mutable reference to the wrapped sensor.
+    pub fn inner_mut(&mut self) -> &mut S {
+        &mut self.sensor
+    }
+
+    /// Consumes the wrapper, returning the wrapped sensor.
+    pub fn into_inner(self) -> S {
+        self.sensor
+    }
+}
+
+impl<S: ErrorType> ErrorType for Sanitized<S> {
+    type Error = SanitizeError<S::Error>;
+}
+
+// Number of sensor traits implemented by `Sanitized`, each with a sanitizer of its own.
+const SANITIZED_QUANTITIES: usize = 6;
+
+// The first method of each trait returns the samples to sanitize, which are checked against the
+// range of their quantity if enabled by the sanitizer at the given index; any others are
+// forwarded to the wrapped sensor unchanged.
+macro_rules! impl_sanitized {
+    ($SensorTrait:ident[$index:literal] within $RANGE:ident {
+        fn $sample:ident() -> $Sample:ty;
+        $(fn $method:ident($($arg:ident: $ArgType:ty),*) -> $Ret:ty;)*
+    }) => {
+        paste::paste! {
+            impl<S> Sanitized<S> {
+                #[doc = concat!(" Returns the sanitizer applied to the `", stringify!($sample), "` samples of the wrapped sensor.")]
+                pub fn [<$sample _sanitizer>](&self) -> &SampleSanitizer {
+                    &self.sanitizers[$index]
+                }
+            }
+        }
+
+        impl<S: $SensorTrait> $SensorTrait for Sanitized<S> {
+            async fn $sample(&mut self) -> Result<$Sample, Self::Error> {
+                let sample = self.sensor.$sample().await.map_err(SanitizeError::Sensor)?;
+                let sanitizer = &mut self.sanitizers[$index];
+                let sample = if self.check_range {
+                    sanitizer.apply_in_range(sample, $RANGE)
+                } else {
+                    sanitizer.apply(sample)
+                };
+                sample.map_err(|kind| match kind {
+                    ErrorKind::OutOfRange => SanitizeError::OutOfRange,
+                    _ => SanitizeError::InvalidSample,
+                })
+            }
+
+            $(
+                async fn $method(&mut self, $($arg: $ArgType),*) -> Result<$Ret, Self::Error> {
+                    self.sensor.$method($($arg),*).await.map_err(SanitizeError::Sensor)
+                }
+            )*
+        }
+    };
+}
+
+impl_sanitized!(AltitudeSensor[0] within ALTITUDE_RANGE {
+    fn altitude() -> Meters;
+    fn set_sea_level_pressure(pressure: Pascals) -> ();
+});
+impl_sanitized!(DewPointSensor[1] within TEMPERATURE_RANGE {
+    fn dew_point() -> DegreesCelsius;
+});
+impl_sanitized!(RelativeHumiditySensor[2] within RELATIVE_HUMIDITY_RANGE {
+    fn relative_humidity() -> Percentage;
+});
+impl_sanitized!(SoundPressureLevelSensor[3] within SOUND_PRESSURE_LEVEL_RANGE {
+    fn sound_pressure_level() -> Decibels;
+});
+impl_sanitized!(TemperatureSensor[4] within TEMPERATURE_RANGE {
+    fn temperature() -> DegreesCelsius;
+});
+impl_sanitized!(WindSpeedSensor[5] within WIND_SPEED_RANGE {
+    fn wind_speed() -> MetersPerSecond;
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::Error;
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_TEMPS: [DegreesCelsius; 4] = [f32::NAN, 24.5, f32::INFINITY, 25.0];
+    const TEST_CORRUPT_TEMPS: [DegreesCelsius; 3] = [24.5, -300.0, 25.0];
+
+    #[derive(Debug, PartialEq)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Peripheral
+        }
+    }
+
+    // Returns each of the test values in turn.
+    struct MockAsyncTempSensor {
+        samples: &'static [DegreesCelsius],
+        reads: usize,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncTempSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockAsyncTempSensor {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            let sample = self.samples.get(self.reads).copied().ok_or(MockError);
+            self.reads += 1;
+            sample
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_sanitized_reject() {
+        let mut sensor = Sanitized::new(
+            MockAsyncTempSensor {
+                samples: &TEST_TEMPS,
+                reads: 0,
+            },
+            InvalidSamplePolicy::Reject,
+        );
+        assert_eq!(
+            sensor.temperature().await,
+            Err(SanitizeError::InvalidSample)
+        );
+        assert_approx_eq!(sensor.temperature().await.unwrap(), 24.5);
+        assert_eq!(
+            sensor.temperature().await.map_err(|e| e.kind()),
+            Err(ErrorKind::InvalidInput)
+        );
+        assert_approx_eq!(sensor.temperature().await.unwrap(), 25.0);
+        assert_eq!(
+            sensor.temperature().await,
+            Err(SanitizeError::Sensor(MockError))
+        );
+        assert_eq!(sensor.temperature_sanitizer().invalid_samples(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_async_sanitized_last_good() {
+        let mut sensor = Sanitized::new(
+            MockAsyncTempSensor {
+                samples: &TEST_TEMPS,
+                reads: 0,
+            },
+            InvalidSamplePolicy::LastGood,
+        );
+
+        // There is no good sample to substitute yet.
+        assert_eq!(
+            sensor.temperature().await,
+            Err(SanitizeError::InvalidSample)
+        );
+        assert_approx_eq!(sensor.temperature().await.unwrap(), 24.5);
+        assert_approx_eq!(sensor.temperature().await.unwrap(), 24.5);
+        assert_approx_eq!(sensor.temperature().await.unwrap(), 25.0);
+    }
+
+    #[tokio::test]
+    async fn test_async_sanitized_propagate() {
+        let mut sensor = Sanitized::new(
+            MockAsyncTempSensor {
+                samples: &TEST_TEMPS,
+                reads: 0,
+            },
+            InvalidSamplePolicy::Propagate,
+        );
+        assert!(sensor.temperature().await.unwrap().is_nan());
+        assert_approx_eq!(sensor.temperature().await.unwrap(), 24.5);
+        assert!(sensor.temperature().await.unwrap().is_infinite());
+        assert_eq!(sensor.temperature_sanitizer().invalid_samples(), 2);
+        assert_eq!(sensor.into_inner().reads, 3);
+    }
+
+    #[tokio::test]
+    async fn test_async_sanitized_range_check() {
+        let sensor = MockAsyncTempSensor {
+            samples: &TEST_CORRUPT_TEMPS,
+            reads: 0,
+        };
+        let mut sensor = Sanitized::new(sensor, InvalidSamplePolicy::Reject).with_range_check();
+        assert_approx_eq!(sensor.temperature().await.unwrap(), 24.5);
+        assert_eq!(sensor.temperature().await, Err(SanitizeError::OutOfRange));
+        assert_approx_eq!(sensor.temperature().await.unwrap(), 25.0);
+        assert_eq!(sensor.temperature_sanitizer().invalid_samples(), 1);
+    }
+
+    // Measures a valid temperature alongside an invalid relative humidity.
+    struct MockAsyncTempHumiditySensor;
+
+    impl crate::sensor::ErrorType for MockAsyncTempHumiditySensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockAsyncTempHumiditySensor {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(24.5)
+        }
+    }
+
+    impl RelativeHumiditySensor for MockAsyncTempHumiditySensor {
+        async fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(f32::NAN)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_sanitized_multi_quantity() {
+        let mut sensor = Sanitized::new(MockAsyncTempHumiditySensor, InvalidSamplePolicy::LastGood);
+        assert_approx_eq!(sensor.temperature().await.unwrap(), 24.5);
+        // The last good temperature is never substituted for a humidity.
+        assert_eq!(
+            sensor.relative_humidity().await,
+            Err(SanitizeError::InvalidSample)
+        );
+        assert_eq!(sensor.temperature_sanitizer().invalid_samples(), 0);
+        assert_eq!(sensor.relative_humidity_sanitizer().invalid_samples(), 1);
+    }
+}
@@ -4,9 +4,439 @@
 //!
 //! Please see specific sensor-type modules for additional example usage
 //! (e.g. see temperature.rs for TemperatureSensor examples).
+//!
+//! # For HAL authors
+//!
+//! Implementations of the `*ThresholdWait` traits report which threshold was crossed, which most
+//! sensors latch in an alert status register. Here is an example for a temperature sensor with
+//! one status bit per threshold.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor::{self, ThresholdEvent, ThresholdKind};
+//! use embedded_sensors_hal_async::temperature::{
+//!     DegreesCelsius, TemperatureSensor, TemperatureThresholdSet, TemperatureThresholdWait,
+//! };
+//!
+//! // Bits of the alert status register of the sensor.
+//! const STATUS_HIGH_ALERT: u8 = 1 << 0;
+//! const STATUS_LOW_ALERT: u8 = 1 << 1;
+//!
+//! // A struct representing a temperature sensor.
+//! pub struct MyTempSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyTempSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl MyTempSensor {
+//!     async fn read_alert_status(&mut self) -> Result<u8, Error> {
+//!         // Read (and thereby clear) alert status register of sensor...
+//!         Ok(STATUS_LOW_ALERT)
+//!     }
+//! }
+//!
+//! impl TemperatureSensor for MyTempSensor {
+//!     async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+//!         // ...
+//!         Ok(-5.0)
+//!     }
+//! }
+//!
+//! impl TemperatureThresholdSet for MyTempSensor {
+//!     async fn set_temperature_threshold_low(
+//!         &mut self,
+//!         threshold: DegreesCelsius
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_temperature_threshold_high(
+//!         &mut self,
+//!         threshold: DegreesCelsius
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl TemperatureThresholdWait for MyTempSensor {
+//!     async fn wait_for_temperature_threshold(
+//!         &mut self
+//!     ) -> Result<ThresholdEvent<DegreesCelsius>, Self::Error> {
+//!         loop {
+//!             // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!             let status = self.read_alert_status().await?;
+//!             let kind = if status & STATUS_HIGH_ALERT != 0 {
+//!                 ThresholdKind::High
+//!             } else if status & STATUS_LOW_ALERT != 0 {
+//!                 ThresholdKind::Low
+//!             } else {
+//!                 // Spurious alert, keep waiting.
+//!                 continue;
+//!             };
+//!             let value = self.temperature().await?;
+//!             return Ok(ThresholdEvent::new(value, kind));
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::frequency::Hertz;
+pub use embedded_sensors_hal::sensor::{
+    checked_sample, AlertMode, AlertOutput, AlertPinConfig, AlertPolarity, Capabilities, Channel,
+    ConversionTime, Error, ErrorKind, ErrorType, MeasurementInfo, OperatingMode, Resolution,
+    Sample, SampleQuality, SensorCapabilities, SensorIdentity, SensorInfo, Severity,
+    ThresholdEvent, ThresholdKind, TimestampedSample, ValidRange,
+};
 
-pub use embedded_sensors_hal::sensor::{Error, ErrorKind, ErrorType};
+// Re-export the sensor refinement macro from the blocking crate, used in `async` mode.
+pub use embedded_sensors_hal::impl_sensor_refinement;
 
 // Re-export the unified threshold traits macro from the blocking crate.
 // The async crate uses the `async` mode to generate async versions of the traits.
 pub use embedded_sensors_hal::decl_threshold_traits;
+
+// Re-export the configuration trait macro from the blocking crate, used in `async` mode.
+pub use embedded_sensors_hal::decl_configuration_trait;
+
+// Re-export the calibration trait macro from the blocking crate, used in `async` mode.
+pub use embedded_sensors_hal::decl_calibration_trait;
+
+// Re-export the sample read trait macro from the blocking crate, used in `async` mode.
+pub use embedded_sensors_hal::decl_sample_read_trait;
+
+// Re-export the channel traits macro from the blocking crate, used in `async` mode.
+pub use embedded_sensors_hal::decl_channel_traits;
+
+// Re-export the fixed-point trait macro from the blocking crate, used in `async` mode.
+pub use embedded_sensors_hal::decl_fixed_point_trait;
+
+// Re-export the fixed-point threshold traits macro from the blocking crate, used in `async` mode.
+pub use embedded_sensors_hal::decl_fixed_point_threshold_traits;
+
+// Re-export the fixed-point calibration trait macro from the blocking crate, used in `async` mode.
+pub use embedded_sensors_hal::decl_fixed_point_calibration_trait;
+
+/// Async conversion mode control, generic to all sensor types.
+///
+/// Battery-powered applications can put sensors in one-shot mode and trigger a conversion only
+/// when a sample is needed, letting the sensor idle in standby between samples.
+pub trait ConversionMode: ErrorType {
+    /// Set whether the sensor converts samples continuously or one at a time when triggered.
+    async fn set_conversion_mode(&mut self, mode: OperatingMode) -> Result<(), Self::Error>;
+
+    /// Trigger a single conversion while in one-shot mode.
+    ///
+    /// The sample can be read through the sensor-type trait once the conversion time of the
+    /// sensor has elapsed (see [`ConversionTime`] or its datasheet). Sensors return an error of
+    /// kind `NotReady` if they are in continuous mode.
+    async fn trigger_one_shot(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: ConversionMode + ?Sized> ConversionMode for &mut T {
+    #[inline]
+    async fn set_conversion_mode(&mut self, mode: OperatingMode) -> Result<(), Self::Error> {
+        T::set_conversion_mode(self, mode).await
+    }
+
+    #[inline]
+    async fn trigger_one_shot(&mut self) -> Result<(), Self::Error> {
+        T::trigger_one_shot(self).await
+    }
+}
+
+/// Async Output data rate (sampling rate) configuration, generic to all sensor types.
+///
+/// Sensors only support a discrete set of output data rates, so drivers map requested rates to
+/// the nearest supported rate and report the rate actually selected. This lets generic code
+/// (e.g. a data logger) negotiate rates without knowledge of vendor-specific rate settings.
+pub trait SampleRateConfig: ErrorType {
+    /// Set the output data rate of the sensor to the nearest supported rate, in hertz.
+    ///
+    /// Returns the rate actually selected, in hertz. Sensors return an error of kind
+    /// `InvalidInput` for rates which are not positive and finite.
+    async fn set_sample_rate_hz(&mut self, rate: Hertz) -> Result<Hertz, Self::Error>;
+
+    /// Returns the current output data rate of the sensor, in hertz.
+    async fn sample_rate_hz(&mut self) -> Result<Hertz, Self::Error>;
+}
+
+impl<T: SampleRateConfig + ?Sized> SampleRateConfig for &mut T {
+    #[inline]
+    async fn set_sample_rate_hz(&mut self, rate: Hertz) -> Result<Hertz, Self::Error> {
+        T::set_sample_rate_hz(self, rate).await
+    }
+
+    #[inline]
+    async fn sample_rate_hz(&mut self) -> Result<Hertz, Self::Error> {
+        T::sample_rate_hz(self).await
+    }
+}
+
+/// Async Measurement resolution or oversampling configuration, generic to all sensor types.
+///
+/// Higher resolutions or oversampling factors lower the noise of samples at the cost of a longer
+/// conversion time (and usually a higher power consumption), see the datasheet of the sensor.
+pub trait ResolutionConfig: ErrorType {
+    /// Set the resolution of the sensor to the nearest supported setting.
+    ///
+    /// Returns the effective resolution actually selected. Sensors return an error of kind
+    /// `InvalidInput` if they do not support the requested kind of setting (e.g. oversampling on
+    /// a sensor with a configurable bit depth).
+    async fn set_resolution(&mut self, resolution: Resolution) -> Result<Resolution, Self::Error>;
+
+    /// Returns the current resolution of the sensor.
+    async fn resolution(&mut self) -> Result<Resolution, Self::Error>;
+}
+
+impl<T: ResolutionConfig + ?Sized> ResolutionConfig for &mut T {
+    #[inline]
+    async fn set_resolution(&mut self, resolution: Resolution) -> Result<Resolution, Self::Error> {
+        T::set_resolution(self, resolution).await
+    }
+
+    #[inline]
+    async fn resolution(&mut self) -> Result<Resolution, Self::Error> {
+        T::resolution(self).await
+    }
+}
+
+/// Async Measurement range (full-scale) configuration, generic to all sensor types.
+///
+/// The type parameter is the unit samples are measured in (e.g. `shock::GForce` for an
+/// accelerometer), so that sensors measuring several quantities can implement the trait once
+/// for each of them.
+pub trait RangeConfig<T>: ErrorType {
+    /// Set the full-scale range of the sensor to the smallest supported range covering `min` to
+    /// `max`.
+    ///
+    /// Returns the range actually granted. Sensors return an error of kind `InvalidInput` if no
+    /// supported range covers the requested one.
+    async fn set_range(&mut self, min: T, max: T) -> Result<ValidRange<T>, Self::Error>;
+
+    /// Returns the current full-scale range of the sensor.
+    async fn full_scale(&mut self) -> Result<ValidRange<T>, Self::Error>;
+}
+
+impl<T, R: RangeConfig<T> + ?Sized> RangeConfig<T> for &mut R {
+    #[inline]
+    async fn set_range(&mut self, min: T, max: T) -> Result<ValidRange<T>, Self::Error> {
+        R::set_range(self, min, max).await
+    }
+
+    #[inline]
+    async fn full_scale(&mut self) -> Result<ValidRange<T>, Self::Error> {
+        R::full_scale(self).await
+    }
+}
+
+/// Async Data-ready query, generic to all sensor types.
+///
+/// This lets polling loops check for a new sample before reading it, rather than reading
+/// and handling an error of kind `NotReady`.
+pub trait DataReady: ErrorType {
+    /// Returns `true` if a new sample is available to be read.
+    async fn is_ready(&mut self) -> Result<bool, Self::Error>;
+}
+
+impl<T: DataReady + ?Sized> DataReady for &mut T {
+    #[inline]
+    async fn is_ready(&mut self) -> Result<bool, Self::Error> {
+        T::is_ready(self).await
+    }
+}
+
+/// Async Device identification, generic to all sensor types.
+///
+/// This lets system bring-up and inventory code verify that the expected part is present on the
+/// bus without knowledge of the concrete driver.
+pub trait SensorIdentify: ErrorType {
+    /// Reads the identification of the sensor.
+    async fn identify(&mut self) -> Result<SensorIdentity, Self::Error>;
+}
+
+impl<T: SensorIdentify + ?Sized> SensorIdentify for &mut T {
+    #[inline]
+    async fn identify(&mut self) -> Result<SensorIdentity, Self::Error> {
+        T::identify(self).await
+    }
+}
+
+/// Async Calibration data persistence, generic to all sensor types.
+///
+/// Sensors which learn calibration on-chip (e.g. the baselines of SGP30 or SGP40 gas sensors)
+/// lose it on power cycles. This lets applications read it back as an opaque blob, store it
+/// (e.g. in flash) and restore it later. The format of the blob is specific to the driver.
+pub trait CalibrationData: ErrorType {
+    /// Reads the calibration data of the sensor into `buf`, returning the number of bytes read.
+    ///
+    /// Sensors return an error of kind `InvalidInput` if `buf` is too small to hold the data,
+    /// or of kind `NotReady` if no calibration is available yet.
+    async fn calibration_data(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error>;
+
+    /// Restores calibration data previously read by
+    /// [`calibration_data`](Self::calibration_data).
+    ///
+    /// Sensors return an error of kind `InvalidInput` if the data is malformed.
+    async fn restore_calibration(&mut self, data: &[u8]) -> Result<(), Self::Error>;
+}
+
+impl<T: CalibrationData + ?Sized> CalibrationData for &mut T {
+    #[inline]
+    async fn calibration_data(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        T::calibration_data(self, buf).await
+    }
+
+    #[inline]
+    async fn restore_calibration(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        T::restore_calibration(self, data).await
+    }
+}
+
+/// Async Alert (interrupt) output configuration, generic to all sensor types.
+pub trait AlertConfig: ErrorType {
+    /// Set the configuration of the alert output of the sensor.
+    ///
+    /// Sensors round the fault queue depth to the nearest depth they support, and return an
+    /// error of kind `InvalidInput` for settings they do not support.
+    async fn set_alert_config(&mut self, config: AlertPinConfig) -> Result<(), Self::Error>;
+}
+
+impl<T: AlertConfig + ?Sized> AlertConfig for &mut T {
+    #[inline]
+    async fn set_alert_config(&mut self, config: AlertPinConfig) -> Result<(), Self::Error> {
+        T::set_alert_config(self, config).await
+    }
+}
+
+/// Async Sample measurement, generic to all sensor types.
+///
+/// Generic infrastructure (e.g. loggers, schedulers or filters) can be written once over this
+/// trait rather than once per sensor type. Every sensor-type trait whose samples are read without
+/// arguments (e.g. `temperature::TemperatureSensor` or `pressure::PressureSensor`) refines
+/// `Sensor` for the marker type of its quantity (e.g. `temperature::Temperature`), which a blanket
+/// implementation provides, so drivers only ever implement the sensor-type trait. Traits reading
+/// samples into a buffer or for a given channel, gas or segment do not, nor do those whose own
+/// sample method is named `measure`, which would be ambiguous. Sensors measuring other quantities
+/// implement `Sensor` directly, for a marker type of their own implementing [`CustomQuantity`].
+///
+/// The quantity parameter `Q` lets sensors measuring several quantities (e.g. temperature and
+/// relative humidity) be a `Sensor` of each, in which case callers name the quantity to measure
+/// (e.g. `Sensor::<Temperature>::measure(&mut sensor)`).
+pub trait Sensor<Q>: ErrorType {
+    /// Sample type measured by the sensor.
+    type Sample;
+
+    /// Returns a new sample.
+    async fn measure(&mut self) -> Result<Self::Sample, Self::Error>;
+}
+
+impl<Q: CustomQuantity, T: Sensor<Q> + ?Sized> Sensor<Q> for &mut T {
+    type Sample = T::Sample;
+
+    #[inline]
+    async fn measure(&mut self) -> Result<Self::Sample, Self::Error> {
+        T::measure(self).await
+    }
+}
+
+/// Marker trait of the quantities which no sensor-type trait refines [`Sensor`] for, such as
+/// those defined by applications.
+///
+/// Implementing it for the marker type of such a quantity makes mutable references to its sensors
+/// `Sensor`s too. References to sensors of the quantities refined by a sensor-type trait already
+/// are, through that trait.
+pub trait CustomQuantity {}
+
+/// Async Timestamped sample read, generic to all sensor types.
+///
+/// Fusion and logging pipelines need to know when a sample was taken, not just its value.
+/// Sensors with a timestamp counter of their own can implement this trait directly, while the
+/// `source::ClockTimestamped` adapter timestamps the samples of any sensor with a clock.
+pub trait TimestampedRead: ErrorType {
+    /// Sample type produced by the sensor.
+    type Sample;
+
+    /// Returns a new sample along with the time at which it was taken.
+    async fn sample_with_timestamp(
+        &mut self,
+    ) -> Result<TimestampedSample<Self::Sample>, Self::Error>;
+}
+
+impl<T: TimestampedRead + ?Sized> TimestampedRead for &mut T {
+    type Sample = T::Sample;
+
+    #[inline]
+    async fn sample_with_timestamp(
+        &mut self,
+    ) -> Result<TimestampedSample<Self::Sample>, Self::Error> {
+        T::sample_with_timestamp(self).await
+    }
+}
+
+/// Async data-ready wait, generic to all sensor types.
+pub trait DataReadyWait: DataReady {
+    /// Wait for a new sample to be available to be read.
+    ///
+    /// Sensors with a data-ready (DRDY) interrupt output should await it rather than poll.
+    async fn wait_for_data_ready(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: DataReadyWait + ?Sized> DataReadyWait for &mut T {
+    #[inline]
+    async fn wait_for_data_ready(&mut self) -> Result<(), Self::Error> {
+        T::wait_for_data_ready(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockAsyncSensor {
+        ready: bool,
+    }
+
+    impl ErrorType for MockAsyncSensor {
+        type Error = ErrorKind;
+    }
+
+    impl DataReady for MockAsyncSensor {
+        async fn is_ready(&mut self) -> Result<bool, Self::Error> {
+            Ok(self.ready)
+        }
+    }
+
+    impl DataReadyWait for MockAsyncSensor {
+        async fn wait_for_data_ready(&mut self) -> Result<(), Self::Error> {
+            // Simulate the data-ready interrupt firing.
+            self.ready = true;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_data_ready_wait_trait_mut_ref() {
+        let mut sensor = MockAsyncSensor { ready: false };
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.is_ready().await, Ok(false));
+        assert!(mut_ref.wait_for_data_ready().await.is_ok());
+        assert_eq!(mut_ref.is_ready().await, Ok(true));
+    }
+}
@@ -0,0 +1,160 @@
+//! Async Shock Sensor API
+//!
+//! This API provides generic methods for interfacing with the shock (impact) detection engines of
+//! accelerometers, as used by shipment monitoring devices.
+//!
+//! A shock is detected when the magnitude of acceleration exceeds the threshold of the configured
+//! [`ShockEnvelope`] for at least its minimum duration, and is reported as a [`ShockEvent`] with
+//! the peak acceleration and duration of the impact.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the ShockDetection and ShockWait traits for an
+//! accelerometer.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::shock::{ShockDetection, ShockEnvelope, ShockEvent, ShockWait};
+//!
+//! // A struct representing an accelerometer with a shock detection engine.
+//! pub struct MyAccelerometer {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyAccelerometer {
+//!     type Error = Error;
+//! }
+//!
+//! impl ShockDetection for MyAccelerometer {
+//!     async fn set_shock_envelope(&mut self, envelope: ShockEnvelope) -> Result<(), Self::Error> {
+//!         // Write threshold and duration registers of the shock detection engine...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl ShockWait for MyAccelerometer {
+//!     async fn wait_for_shock(&mut self) -> Result<ShockEvent, Self::Error> {
+//!         // Await shock interrupt (e.g. await GPIO level change on INT pin)...
+//!         // Then read peak and duration registers of the shock detection engine
+//!         Ok(ShockEvent {
+//!             peak: 12.5,
+//!             duration_us: 2_000,
+//!         })
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+pub use embedded_sensors_hal::shock::{GForce, ShockEnvelope, ShockEvent};
+
+/// Async Shock Detection methods.
+pub trait ShockDetection: ErrorType {
+    /// Set the envelope within which accelerations are detected as shocks.
+    ///
+    /// Sensors round the envelope to the nearest threshold and duration they support.
+    async fn set_shock_envelope(&mut self, envelope: ShockEnvelope) -> Result<(), Self::Error>;
+}
+
+impl<T: ShockDetection + ?Sized> ShockDetection for &mut T {
+    #[inline]
+    async fn set_shock_envelope(&mut self, envelope: ShockEnvelope) -> Result<(), Self::Error> {
+        T::set_shock_envelope(self, envelope).await
+    }
+}
+
+/// Async Shock Wait methods.
+pub trait ShockWait: ShockDetection {
+    /// Wait for a shock to be detected, returning its peak acceleration and duration.
+    async fn wait_for_shock(&mut self) -> Result<ShockEvent, Self::Error>;
+}
+
+impl<T: ShockWait + ?Sized> ShockWait for &mut T {
+    #[inline]
+    async fn wait_for_shock(&mut self) -> Result<ShockEvent, Self::Error> {
+        T::wait_for_shock(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_ENVELOPE: ShockEnvelope = ShockEnvelope::new(8.0, 500);
+    const TEST_EVENT: ShockEvent = ShockEvent {
+        peak: 15.5,
+        duration_us: 1_250,
+    };
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncAccelerometer {
+        envelope: Option<ShockEnvelope>,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncAccelerometer {
+        type Error = MockError;
+    }
+
+    impl ShockDetection for MockAsyncAccelerometer {
+        async fn set_shock_envelope(&mut self, envelope: ShockEnvelope) -> Result<(), Self::Error> {
+            self.envelope = Some(envelope);
+            Ok(())
+        }
+    }
+
+    impl ShockWait for MockAsyncAccelerometer {
+        async fn wait_for_shock(&mut self) -> Result<ShockEvent, Self::Error> {
+            // Report a shock as if the envelope had been exceeded
+            match self.envelope {
+                Some(envelope) if TEST_EVENT.peak > envelope.threshold => Ok(TEST_EVENT),
+                _ => Err(MockError),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_shock_wait_trait() {
+        let mut sensor = MockAsyncAccelerometer { envelope: None };
+        assert!(sensor.set_shock_envelope(TEST_ENVELOPE).await.is_ok());
+
+        let result = sensor.wait_for_shock().await;
+        assert!(result.is_ok());
+        let event = result.unwrap();
+        assert_approx_eq!(event.peak, TEST_EVENT.peak);
+        assert_eq!(event.duration_us, TEST_EVENT.duration_us);
+    }
+
+    #[tokio::test]
+    async fn test_async_shock_wait_trait_mut_ref() {
+        let mut sensor = MockAsyncAccelerometer { envelope: None };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_shock_envelope(TEST_ENVELOPE).await.is_ok());
+
+        let result = mut_ref.wait_for_shock().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_EVENT);
+    }
+}
@@ -0,0 +1,256 @@
+//! Async Smoke Sensor API
+//!
+//! This API provides generic methods for interfacing with photoelectric smoke sensing front-ends
+//! specifically, as used in smoke alarms and other safety devices.
+//!
+//! The smoke level is reported as obscuration, i.e. the percentage of light attenuated by smoke per
+//! meter. Alarm thresholds are configured with the obscuration threshold traits, which in the async
+//! API also allow awaiting the alarm.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the SmokeSensor and ObscurationThresholdWait traits
+//! for a photoelectric smoke sensing front-end.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::smoke::{
+//!     ObscurationHysteresis, ObscurationThresholdSet, ObscurationThresholdWait, PercentPerMeter,
+//!     SmokeSensor,
+//! };
+//!
+//! // A struct representing a photoelectric smoke sensing front-end.
+//! pub struct MySmokeFrontEnd {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MySmokeFrontEnd {
+//!     type Error = Error;
+//! }
+//!
+//! impl SmokeSensor for MySmokeFrontEnd {
+//!     async fn obscuration(&mut self) -> Result<PercentPerMeter, Self::Error> {
+//!         // Read photodiode signal and subtract clean air baseline...
+//!         Ok(0.8)
+//!     }
+//! }
+//!
+//! impl ObscurationThresholdSet for MySmokeFrontEnd {
+//!     async fn set_obscuration_threshold_low(
+//!         &mut self,
+//!         threshold: PercentPerMeter
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_obscuration_threshold_high(
+//!         &mut self,
+//!         threshold: PercentPerMeter
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl ObscurationThresholdWait for MySmokeFrontEnd {
+//!     async fn wait_for_obscuration_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<PercentPerMeter>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.obscuration().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl ObscurationHysteresis for MySmokeFrontEnd {
+//!     async fn set_obscuration_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: PercentPerMeter
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::smoke::{PercentPerMeter, Smoke, OBSCURATION_RANGE};
+
+/// Async Smoke Sensor methods.
+pub trait SmokeSensor: Sensor<Smoke, Sample = PercentPerMeter> {
+    /// Returns an obscuration sample in percent per meter.
+    async fn obscuration(&mut self) -> Result<PercentPerMeter, Self::Error>;
+}
+
+impl<T: SmokeSensor + ?Sized> SmokeSensor for &mut T {
+    #[inline]
+    async fn obscuration(&mut self) -> Result<PercentPerMeter, Self::Error> {
+        T::obscuration(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Smoke, SmokeSensor, obscuration, PercentPerMeter);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait ObscurationThresholdSet: SmokeSensor {
+//     async fn set_obscuration_threshold_low(&mut self, threshold: PercentPerMeter) -> Result<(), Self::Error>;
+//     async fn set_obscuration_threshold_high(&mut self, threshold: PercentPerMeter) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ObscurationHysteresis: ObscurationThresholdSet {
+//     async fn set_obscuration_threshold_hysteresis(&mut self, hysteresis: PercentPerMeter) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ObscurationThresholdControl: ObscurationThresholdSet {
+//     async fn disable_obscuration_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_obscuration_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ObscurationThresholdWait: ObscurationThresholdSet {
+//     async fn wait_for_obscuration_threshold(&mut self) -> Result<ThresholdEvent<PercentPerMeter>, Self::Error>;
+// }
+decl_threshold_traits!(
+    async,
+    Obscuration,
+    SmokeSensor,
+    PercentPerMeter,
+    "percent per meter"
+);
+
+// This macro generates the `ObscurationAlert` adapter, which allows implementors of
+// `ObscurationThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(Obscuration, PercentPerMeter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: PercentPerMeter = 0.8;
+    const TEST_THRESHOLD_LOW: PercentPerMeter = 0.0;
+    const TEST_THRESHOLD_HIGH: PercentPerMeter = 4.0;
+    const TEST_HYSTERESIS: PercentPerMeter = 0.5;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncSmokeFrontEnd {
+        value: PercentPerMeter,
+        threshold_low: Option<PercentPerMeter>,
+        threshold_high: Option<PercentPerMeter>,
+        hysteresis: Option<PercentPerMeter>,
+    }
+
+    impl MockAsyncSmokeFrontEnd {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncSmokeFrontEnd {
+        type Error = MockError;
+    }
+
+    impl SmokeSensor for MockAsyncSmokeFrontEnd {
+        async fn obscuration(&mut self) -> Result<PercentPerMeter, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl ObscurationThresholdSet for MockAsyncSmokeFrontEnd {
+        async fn set_obscuration_threshold_low(
+            &mut self,
+            threshold: PercentPerMeter,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        async fn set_obscuration_threshold_high(
+            &mut self,
+            threshold: PercentPerMeter,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl ObscurationHysteresis for MockAsyncSmokeFrontEnd {
+        async fn set_obscuration_threshold_hysteresis(
+            &mut self,
+            hysteresis: PercentPerMeter,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_smoke_sensor_trait() {
+        let mut sensor = MockAsyncSmokeFrontEnd::new();
+        let result = sensor.obscuration().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_smoke_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncSmokeFrontEnd::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.obscuration().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_smoke_threshold_set_mut_ref() {
+        let mut sensor = MockAsyncSmokeFrontEnd::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_obscuration_threshold_low(TEST_THRESHOLD_LOW)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_obscuration_threshold_high(TEST_THRESHOLD_HIGH)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_obscuration_threshold_hysteresis(TEST_HYSTERESIS)
+            .await
+            .is_ok());
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), TEST_HYSTERESIS);
+    }
+}
@@ -0,0 +1,200 @@
+//! Async Soil Probe API
+//!
+//! This API provides generic methods for interfacing with multi-depth soil probes specifically.
+//!
+//! A soil probe has one or more sensing segments at different depths, identified by their index.
+//! The [`SoilProbe`] trait describes the depth of each segment, while the [`SoilTemperatureProbe`]
+//! and [`SoilMoistureProbe`] traits sample a segment.
+//!
+//! Sampling a segment index not less than the number of segments fails with an error of kind
+//! [`ErrorKind::InvalidInput`](crate::sensor::ErrorKind::InvalidInput).
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the soil probe traits for a multi-depth probe.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::soil::{
+//!     Centimeters, SoilMoistureProbe, SoilProbe, SoilTemperatureProbe, VolumetricWaterContent,
+//! };
+//! use embedded_sensors_hal_async::temperature::DegreesCelsius;
+//!
+//! // A struct representing a soil probe.
+//! pub struct MySoilProbe {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     InvalidSegment,
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             Error::InvalidSegment => sensor::ErrorKind::InvalidInput,
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MySoilProbe {
+//!     type Error = Error;
+//! }
+//!
+//! impl SoilProbe for MySoilProbe {
+//!     fn depths(&self) -> &[Centimeters] {
+//!         &[10.0, 20.0, 40.0]
+//!     }
+//! }
+//!
+//! impl SoilTemperatureProbe for MySoilProbe {
+//!     async fn soil_temperature(
+//!         &mut self,
+//!         segment: usize,
+//!     ) -> Result<DegreesCelsius, Self::Error> {
+//!         if segment >= self.depths().len() {
+//!             return Err(Error::InvalidSegment);
+//!         }
+//!         // Await probe measurement, then query segment temperature from probe...
+//!         Ok(14.5)
+//!     }
+//! }
+//!
+//! impl SoilMoistureProbe for MySoilProbe {
+//!     async fn soil_moisture(
+//!         &mut self,
+//!         segment: usize,
+//!     ) -> Result<VolumetricWaterContent, Self::Error> {
+//!         if segment >= self.depths().len() {
+//!             return Err(Error::InvalidSegment);
+//!         }
+//!         // Await probe measurement, then query segment moisture from probe...
+//!         Ok(27.0)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+use crate::temperature::DegreesCelsius;
+pub use embedded_sensors_hal::soil::{Centimeters, VolumetricWaterContent, SOIL_MOISTURE_RANGE};
+
+/// Async Soil Probe methods.
+pub trait SoilProbe: ErrorType {
+    /// Returns the depth below the surface of each segment of the probe, indexed by segment.
+    fn depths(&self) -> &[Centimeters];
+}
+
+impl<T: SoilProbe + ?Sized> SoilProbe for &mut T {
+    #[inline]
+    fn depths(&self) -> &[Centimeters] {
+        T::depths(self)
+    }
+}
+
+/// Async Soil Temperature methods for probes able to measure it.
+pub trait SoilTemperatureProbe: SoilProbe {
+    /// Returns a temperature sample of the given segment in degrees celsius.
+    async fn soil_temperature(&mut self, segment: usize) -> Result<DegreesCelsius, Self::Error>;
+}
+
+impl<T: SoilTemperatureProbe + ?Sized> SoilTemperatureProbe for &mut T {
+    #[inline]
+    async fn soil_temperature(&mut self, segment: usize) -> Result<DegreesCelsius, Self::Error> {
+        T::soil_temperature(self, segment).await
+    }
+}
+
+/// Async Soil Moisture methods for probes able to measure it.
+pub trait SoilMoistureProbe: SoilProbe {
+    /// Returns a moisture sample of the given segment as volumetric water content in percent.
+    async fn soil_moisture(
+        &mut self,
+        segment: usize,
+    ) -> Result<VolumetricWaterContent, Self::Error>;
+}
+
+impl<T: SoilMoistureProbe + ?Sized> SoilMoistureProbe for &mut T {
+    #[inline]
+    async fn soil_moisture(
+        &mut self,
+        segment: usize,
+    ) -> Result<VolumetricWaterContent, Self::Error> {
+        T::soil_moisture(self, segment).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_DEPTHS: [Centimeters; 2] = [10.0, 30.0];
+    const TEST_TEMPERATURES: [DegreesCelsius; 2] = [18.0, 15.5];
+    const TEST_MOISTURES: [VolumetricWaterContent; 2] = [22.0, 31.0];
+
+    #[derive(Debug, PartialEq)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::InvalidInput
+        }
+    }
+
+    struct MockAsyncSoilProbe {}
+
+    impl crate::sensor::ErrorType for MockAsyncSoilProbe {
+        type Error = MockError;
+    }
+
+    impl SoilProbe for MockAsyncSoilProbe {
+        fn depths(&self) -> &[Centimeters] {
+            &TEST_DEPTHS
+        }
+    }
+
+    impl SoilTemperatureProbe for MockAsyncSoilProbe {
+        async fn soil_temperature(
+            &mut self,
+            segment: usize,
+        ) -> Result<DegreesCelsius, Self::Error> {
+            TEST_TEMPERATURES.get(segment).copied().ok_or(MockError)
+        }
+    }
+
+    impl SoilMoistureProbe for MockAsyncSoilProbe {
+        async fn soil_moisture(
+            &mut self,
+            segment: usize,
+        ) -> Result<VolumetricWaterContent, Self::Error> {
+            TEST_MOISTURES.get(segment).copied().ok_or(MockError)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_soil_probe_trait() {
+        let mut probe = MockAsyncSoilProbe {};
+        assert_eq!(probe.depths(), TEST_DEPTHS);
+
+        let result = probe.soil_temperature(1).await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_TEMPERATURES[1]);
+        assert_eq!(probe.soil_temperature(2).await, Err(MockError));
+    }
+
+    #[tokio::test]
+    async fn test_async_soil_probe_trait_mut_ref() {
+        let mut probe = MockAsyncSoilProbe {};
+        let mut_ref = &mut probe;
+        assert_eq!(mut_ref.depths().len(), 2);
+
+        let result = mut_ref.soil_moisture(0).await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_MOISTURES[0]);
+    }
+}
@@ -0,0 +1,275 @@
+//! Async Sound Pressure Level Sensor API
+//!
+//! This API provides generic methods for interfacing with sound pressure level (SPL) sensors specifically.
+//!
+//! Microphone and SPL front-ends with a built-in event detection engine (glass break, clap, etc.)
+//! can additionally implement the [`AcousticEventWait`] trait.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the SoundPressureLevelSensor and SoundPressureLevelThresholdWait traits for a sound pressure level sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::sound::{
+//!     Decibels, SoundPressureLevelHysteresis, SoundPressureLevelSensor, SoundPressureLevelThresholdSet, SoundPressureLevelThresholdWait,
+//! };
+//!
+//! // A struct representing a sound pressure level sensor.
+//! pub struct MySoundSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MySoundSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl SoundPressureLevelSensor for MySoundSensor {
+//!     async fn sound_pressure_level(&mut self) -> Result<Decibels, Self::Error> {
+//!         // ...
+//!         Ok(42.0)
+//!     }
+//! }
+//!
+//! impl SoundPressureLevelThresholdSet for MySoundSensor {
+//!     async fn set_sound_pressure_level_threshold_low(
+//!         &mut self,
+//!         threshold: Decibels
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_sound_pressure_level_threshold_high(
+//!         &mut self,
+//!         threshold: Decibels
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl SoundPressureLevelThresholdWait for MySoundSensor {
+//!     async fn wait_for_sound_pressure_level_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<Decibels>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.sound_pressure_level().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl SoundPressureLevelHysteresis for MySoundSensor {
+//!     async fn set_sound_pressure_level_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: Decibels
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::sound::{
+    AcousticEvent, Decibels, SoundPressureLevel, SOUND_PRESSURE_LEVEL_RANGE,
+};
+
+/// Async Sound Pressure Level Sensor methods.
+pub trait SoundPressureLevelSensor: Sensor<SoundPressureLevel, Sample = Decibels> {
+    /// Returns an A-weighted sound pressure level sample in decibels (dB SPL).
+    async fn sound_pressure_level(&mut self) -> Result<Decibels, Self::Error>;
+}
+
+impl<T: SoundPressureLevelSensor + ?Sized> SoundPressureLevelSensor for &mut T {
+    #[inline]
+    async fn sound_pressure_level(&mut self) -> Result<Decibels, Self::Error> {
+        T::sound_pressure_level(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    SoundPressureLevel,
+    SoundPressureLevelSensor,
+    sound_pressure_level,
+    Decibels
+);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait SoundPressureLevelThresholdSet: SoundPressureLevelSensor {
+//     async fn set_sound_pressure_level_threshold_low(&mut self, threshold: Decibels) -> Result<(), Self::Error>;
+//     async fn set_sound_pressure_level_threshold_high(&mut self, threshold: Decibels) -> Result<(), Self::Error>;
+// }
+//
+// pub trait SoundPressureLevelHysteresis: SoundPressureLevelThresholdSet {
+//     async fn set_sound_pressure_level_threshold_hysteresis(&mut self, hysteresis: Decibels) -> Result<(), Self::Error>;
+// }
+//
+// pub trait SoundPressureLevelThresholdControl: SoundPressureLevelThresholdSet {
+//     async fn disable_sound_pressure_level_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_sound_pressure_level_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait SoundPressureLevelThresholdWait: SoundPressureLevelThresholdSet {
+//     async fn wait_for_sound_pressure_level_threshold(&mut self) -> Result<ThresholdEvent<Decibels>, Self::Error>;
+// }
+decl_threshold_traits!(
+    async,
+    SoundPressureLevel,
+    SoundPressureLevelSensor,
+    Decibels,
+    "A-weighted decibels"
+);
+
+// This macro generates the `SoundPressureLevelAlert` adapter, which allows implementors of
+// `SoundPressureLevelThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(SoundPressureLevel, Decibels);
+
+/// Async Acoustic Event methods for front-ends with a built-in event detection engine.
+pub trait AcousticEventWait: ErrorType {
+    /// Wait for the event engine to recognize an acoustic event, returning the event.
+    async fn wait_for_acoustic_event(&mut self) -> Result<AcousticEvent, Self::Error>;
+}
+
+impl<T: AcousticEventWait + ?Sized> AcousticEventWait for &mut T {
+    #[inline]
+    async fn wait_for_acoustic_event(&mut self) -> Result<AcousticEvent, Self::Error> {
+        T::wait_for_acoustic_event(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Decibels = 55.0;
+    const TEST_THRESHOLD_LOW: Decibels = 40.0;
+    const TEST_THRESHOLD_HIGH: Decibels = 85.0;
+    const TEST_INITIAL_THRESHOLD: Decibels = 0.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncSoundSensor {
+        value: Decibels,
+        threshold_low: Decibels,
+        threshold_high: Decibels,
+    }
+
+    impl MockAsyncSoundSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: TEST_INITIAL_THRESHOLD,
+                threshold_high: TEST_INITIAL_THRESHOLD,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncSoundSensor {
+        type Error = MockError;
+    }
+
+    impl SoundPressureLevelSensor for MockAsyncSoundSensor {
+        async fn sound_pressure_level(&mut self) -> Result<Decibels, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl SoundPressureLevelThresholdSet for MockAsyncSoundSensor {
+        async fn set_sound_pressure_level_threshold_low(
+            &mut self,
+            threshold: Decibels,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = threshold;
+            Ok(())
+        }
+
+        async fn set_sound_pressure_level_threshold_high(
+            &mut self,
+            threshold: Decibels,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = threshold;
+            Ok(())
+        }
+    }
+
+    impl AcousticEventWait for MockAsyncSoundSensor {
+        async fn wait_for_acoustic_event(&mut self) -> Result<AcousticEvent, Self::Error> {
+            Ok(AcousticEvent::GlassBreak)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_sound_sensor_trait() {
+        let mut sensor = MockAsyncSoundSensor::new();
+        let result = sensor.sound_pressure_level().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_sound_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncSoundSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.sound_pressure_level().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_sound_threshold_set_trait_mut_ref() {
+        let mut sensor = MockAsyncSoundSensor::new();
+
+        {
+            let mut_ref = &mut sensor;
+            let result_low = mut_ref
+                .set_sound_pressure_level_threshold_low(TEST_THRESHOLD_LOW)
+                .await;
+            assert!(result_low.is_ok());
+            let result_high = mut_ref
+                .set_sound_pressure_level_threshold_high(TEST_THRESHOLD_HIGH)
+                .await;
+            assert!(result_high.is_ok());
+        }
+
+        assert_approx_eq!(sensor.threshold_low, TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high, TEST_THRESHOLD_HIGH);
+    }
+
+    #[tokio::test]
+    async fn test_async_acoustic_event_wait_mut_ref() {
+        let mut sensor = MockAsyncSoundSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.wait_for_acoustic_event().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), AcousticEvent::GlassBreak);
+    }
+}
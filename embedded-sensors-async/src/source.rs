@@ -0,0 +1,235 @@
+//! Async Sample Source API
+//!
+//! This module provides the [`SampleSource`] trait, through which generic components (such as a
+//! [`SensorArray`](crate::array::SensorArray) or a sensor task) sample a sensor without knowing
+//! which quantity it measures, along with adapters turning sensors of each type into a source.
+//! [`SensorSource`] turns a [`Sensor`] of any quantity into a source.
+//!
+//! Wrapping a source in [`ClockTimestamped`] additionally timestamps each of its samples with a
+//! [`Clock`], turning it into a [`TimestampedRead`] implementor.
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor::ErrorKind;
+//! use embedded_sensors_hal_async::source::{SampleSource, TemperatureSource};
+//! use embedded_sensors_hal_async::temperature::TemperatureSensor;
+//!
+//! async fn sample_twice<S: SampleSource>(source: &mut S) -> Result<[S::Sample; 2], ErrorKind> {
+//!     Ok([source.sample().await?, source.sample().await?])
+//! }
+//!
+//! async fn temperatures<S: TemperatureSensor>(sensor: S) -> Result<[f32; 2], ErrorKind> {
+//!     sample_twice(&mut TemperatureSource(sensor)).await
+//! }
+//! ```
+
+use crate::clock::Clock;
+use crate::humidity::{Percentage, RelativeHumiditySensor};
+use crate::sensor::{ErrorKind, ErrorType, Sensor, TimestampedRead, TimestampedSample};
+use crate::temperature::{DegreesCelsius, TemperatureSensor};
+use core::marker::PhantomData;
+
+/// A sensor which can be sampled by generic components.
+pub trait SampleSource {
+    /// Sample type produced by the source.
+    type Sample;
+
+    /// Returns a new sample.
+    async fn sample(&mut self) -> Result<Self::Sample, ErrorKind>;
+}
+
+impl<T: SampleSource + ?Sized> SampleSource for &mut T {
+    type Sample = T::Sample;
+
+    #[inline]
+    async fn sample(&mut self) -> Result<Self::Sample, ErrorKind> {
+        T::sample(self).await
+    }
+}
+
+macro_rules! impl_sample_source {
+    ($Source:ident, $SensorTrait:ident, $method:ident, $SampleType:ty) => {
+        #[doc = concat!(" Adapts a [`", stringify!($SensorTrait), "`] implementor into a [`SampleSource`].")]
+        pub struct $Source<S>(pub S);
+
+        impl<S: $SensorTrait> SampleSource for $Source<S> {
+            type Sample = $SampleType;
+
+            #[inline]
+            async fn sample(&mut self) -> Result<Self::Sample, ErrorKind> {
+                self.0.$method().await.map_err(|e| crate::sensor::Error::kind(&e))
+            }
+        }
+    };
+}
+
+impl_sample_source!(
+    TemperatureSource,
+    TemperatureSensor,
+    temperature,
+    DegreesCelsius
+);
+impl_sample_source!(
+    RelativeHumiditySource,
+    RelativeHumiditySensor,
+    relative_humidity,
+    Percentage
+);
+
+/// Adapts a [`Sensor`] of quantity `Q` into a [`SampleSource`].
+pub struct SensorSource<Q, S> {
+    sensor: S,
+    quantity: PhantomData<Q>,
+}
+
+impl<Q, S> SensorSource<Q, S> {
+    /// Creates a new source sampling the `Q` quantity of `sensor`.
+    pub fn new(sensor: S) -> Self {
+        Self {
+            sensor,
+            quantity: PhantomData,
+        }
+    }
+
+    /// Consumes the adapter, returning the underlying sensor.
+    pub fn into_inner(self) -> S {
+        self.sensor
+    }
+}
+
+impl<Q, S: Sensor<Q>> SampleSource for SensorSource<Q, S> {
+    type Sample = S::Sample;
+
+    #[inline]
+    async fn sample(&mut self) -> Result<Self::Sample, ErrorKind> {
+        self.sensor
+            .measure()
+            .await
+            .map_err(|e| crate::sensor::Error::kind(&e))
+    }
+}
+
+/// Adapts a [`SampleSource`] into a [`TimestampedRead`] implementor, timestamping each sample
+/// with the time read from a [`Clock`] once the sample is taken.
+pub struct ClockTimestamped<S, C> {
+    source: S,
+    clock: C,
+}
+
+impl<S: SampleSource, C: Clock> ClockTimestamped<S, C> {
+    /// Creates a new timestamped source.
+    pub fn new(source: S, clock: C) -> Self {
+        Self { source, clock }
+    }
+
+    /// Consumes the adapter, returning the underlying source and clock.
+    pub fn into_inner(self) -> (S, C) {
+        (self.source, self.clock)
+    }
+}
+
+impl<S, C> ErrorType for ClockTimestamped<S, C> {
+    type Error = ErrorKind;
+}
+
+impl<S: SampleSource, C: Clock> TimestampedRead for ClockTimestamped<S, C> {
+    type Sample = S::Sample;
+
+    async fn sample_with_timestamp(
+        &mut self,
+    ) -> Result<TimestampedSample<Self::Sample>, Self::Error> {
+        let value = self.source.sample().await?;
+        Ok(TimestampedSample::new(value, self.clock.now_us()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::sensor::CustomQuantity;
+    use crate::temperature::Temperature;
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_TEMP: DegreesCelsius = 23.5;
+
+    struct MockAsyncTempSensor;
+
+    impl crate::sensor::ErrorType for MockAsyncTempSensor {
+        type Error = ErrorKind;
+    }
+
+    impl TemperatureSensor for MockAsyncTempSensor {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(TEST_TEMP)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_clock_timestamped_source() {
+        let clock = ManualClock::new();
+        let mut source = ClockTimestamped::new(TemperatureSource(MockAsyncTempSensor), &clock);
+
+        clock.set_us(1_000);
+        let sample = source.sample_with_timestamp().await.unwrap();
+        assert_approx_eq!(sample.value, TEST_TEMP);
+        assert_eq!(sample.timestamp_us, 1_000);
+
+        clock.advance_ms(10);
+        let mut_ref = &mut source;
+        assert_eq!(
+            mut_ref.sample_with_timestamp().await.unwrap().timestamp_us,
+            11_000
+        );
+    }
+
+    async fn measure_twice<Q, S: Sensor<Q>>(sensor: &mut S) -> Result<[S::Sample; 2], S::Error> {
+        Ok([sensor.measure().await?, sensor.measure().await?])
+    }
+
+    #[tokio::test]
+    async fn test_async_sensor_source() {
+        // Every temperature sensor is a `Sensor` of temperature.
+        let mut sensor = MockAsyncTempSensor;
+        let samples = measure_twice::<Temperature, _>(&mut sensor).await.unwrap();
+        assert_approx_eq!(samples[1], TEST_TEMP);
+
+        let mut source = SensorSource::<Temperature, _>::new(sensor);
+        assert_approx_eq!(source.sample().await.unwrap(), TEST_TEMP);
+    }
+
+    // A quantity without a sensor-type trait of its own.
+    struct Count;
+
+    impl CustomQuantity for Count {}
+
+    struct MockAsyncCounter(u32);
+
+    impl crate::sensor::ErrorType for MockAsyncCounter {
+        type Error = ErrorKind;
+    }
+
+    impl Sensor<Count> for MockAsyncCounter {
+        type Sample = u32;
+
+        async fn measure(&mut self) -> Result<u32, Self::Error> {
+            self.0 += 1;
+            Ok(self.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_custom_quantity_source() {
+        // Sensors of custom quantities are forwarded through `&mut` like the refined ones.
+        let mut sensor = MockAsyncCounter(0);
+        assert_eq!(
+            measure_twice::<Count, _>(&mut &mut sensor).await.unwrap(),
+            [1, 2]
+        );
+
+        let mut source = SensorSource::<Count, _>::new(&mut sensor);
+        assert_eq!(source.sample().await, Ok(3));
+    }
+}
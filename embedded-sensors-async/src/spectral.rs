@@ -0,0 +1,179 @@
+//! Async Spectral Light Sensor API
+//!
+//! This API provides generic methods for interfacing with multi-band spectral light sensors
+//! specifically.
+//!
+//! Spectral sensors sample irradiance through several optical filters. Each filter is described
+//! by a [`SpectralChannel`], allowing generic color-science pipelines to interpret readings
+//! without knowledge of the part.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the SpectralSensor trait for a spectral sensor.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::spectral::{Irradiance, SpectralChannel, SpectralSensor};
+//!
+//! // Filters of the sensor, from its datasheet.
+//! const CHANNELS: [SpectralChannel; 3] = [
+//!     SpectralChannel::new(445.0, 30.0),
+//!     SpectralChannel::new(555.0, 40.0),
+//!     SpectralChannel::new(680.0, 52.0),
+//! ];
+//!
+//! // A struct representing a spectral sensor.
+//! pub struct MySpectralSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MySpectralSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl SpectralSensor for MySpectralSensor {
+//!     fn channels(&self) -> &[SpectralChannel] {
+//!         &CHANNELS
+//!     }
+//!
+//!     async fn irradiance(&mut self, readings: &mut [Irradiance]) -> Result<usize, Self::Error> {
+//!         // Await end of integration cycle, then read and calibrate channel data registers...
+//!         let count = readings.len().min(CHANNELS.len());
+//!         readings[..count].fill(12.5);
+//!         Ok(count)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+pub use embedded_sensors_hal::spectral::{
+    Irradiance, Nanometers, SpectralChannel, IRRADIANCE_RANGE,
+};
+
+/// Async Spectral Sensor methods.
+pub trait SpectralSensor: ErrorType {
+    /// Returns a description of each channel of the sensor, in the order readings are reported.
+    fn channels(&self) -> &[SpectralChannel];
+
+    /// Returns the index of the channel covering the wavelength, or `None` if no channel does.
+    ///
+    /// If the passbands of several channels cover the wavelength, the channel whose center
+    /// wavelength is nearest is returned.
+    fn channel_at(&self, wavelength: Nanometers) -> Option<usize> {
+        self.channels()
+            .iter()
+            .enumerate()
+            .filter(|(_, channel)| channel.covers(wavelength))
+            .min_by(|(_, a), (_, b)| {
+                a.offset_from(wavelength)
+                    .total_cmp(&b.offset_from(wavelength))
+            })
+            .map(|(index, _)| index)
+    }
+
+    /// Fills `readings` with an irradiance sample of each channel, in microwatts per square
+    /// centimeter.
+    ///
+    /// Returns the number of readings written, which is less than `readings.len()` if the
+    /// sensor has fewer channels.
+    async fn irradiance(&mut self, readings: &mut [Irradiance]) -> Result<usize, Self::Error>;
+}
+
+impl<T: SpectralSensor + ?Sized> SpectralSensor for &mut T {
+    #[inline]
+    fn channels(&self) -> &[SpectralChannel] {
+        T::channels(self)
+    }
+
+    #[inline]
+    fn channel_at(&self, wavelength: Nanometers) -> Option<usize> {
+        T::channel_at(self, wavelength)
+    }
+
+    #[inline]
+    async fn irradiance(&mut self, readings: &mut [Irradiance]) -> Result<usize, Self::Error> {
+        T::irradiance(self, readings).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_CHANNELS: [SpectralChannel; 2] = [
+        SpectralChannel::new(480.0, 36.0),
+        SpectralChannel::new(630.0, 40.0),
+    ];
+    const TEST_READINGS: [Irradiance; 2] = [8.5, 21.0];
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncSpectralSensor {}
+
+    impl crate::sensor::ErrorType for MockAsyncSpectralSensor {
+        type Error = MockError;
+    }
+
+    impl SpectralSensor for MockAsyncSpectralSensor {
+        fn channels(&self) -> &[SpectralChannel] {
+            &TEST_CHANNELS
+        }
+
+        async fn irradiance(&mut self, readings: &mut [Irradiance]) -> Result<usize, Self::Error> {
+            let count = readings.len().min(TEST_READINGS.len());
+            readings[..count].copy_from_slice(&TEST_READINGS[..count]);
+            Ok(count)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_spectral_sensor_trait() {
+        let mut sensor = MockAsyncSpectralSensor {};
+        let mut readings = [0.0; 4];
+        let result = sensor.irradiance(&mut readings).await;
+        assert_eq!(result.unwrap(), 2);
+        assert_approx_eq!(readings[0], TEST_READINGS[0]);
+        assert_approx_eq!(readings[1], TEST_READINGS[1]);
+    }
+
+    #[tokio::test]
+    async fn test_async_spectral_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncSpectralSensor {};
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.channels(), TEST_CHANNELS);
+
+        let mut readings = [0.0; 1];
+        assert_eq!(mut_ref.irradiance(&mut readings).await.unwrap(), 1);
+        assert_approx_eq!(readings[0], TEST_READINGS[0]);
+    }
+
+    #[tokio::test]
+    async fn test_async_spectral_channel_at() {
+        let sensor = MockAsyncSpectralSensor {};
+        assert_eq!(sensor.channel_at(490.0), Some(0));
+        assert_eq!(sensor.channel_at(550.0), None);
+    }
+}
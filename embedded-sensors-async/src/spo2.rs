@@ -0,0 +1,124 @@
+//! Async Pulse Oximetry Sensor API
+//!
+//! This API provides generic methods for interfacing with pulse oximetry (SpO2) sensors
+//! specifically.
+//!
+//! Pulse oximeters can only estimate oxygen saturation while they have a good optical signal
+//! (e.g. skin contact and little motion), so each sample carries a validity flag. Devices which
+//! also measure heart rate expose it independently of this API.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the Spo2Sensor trait for a pulse oximeter.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::spo2::{Spo2Sample, Spo2Sensor};
+//!
+//! // A struct representing a pulse oximeter.
+//! pub struct MyPulseOximeter {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyPulseOximeter {
+//!     type Error = Error;
+//! }
+//!
+//! impl Spo2Sensor for MyPulseOximeter {
+//!     async fn spo2(&mut self) -> Result<Spo2Sample, Self::Error> {
+//!         // Await end of measurement, then read saturation and signal quality registers...
+//!         Ok(Spo2Sample {
+//!             saturation: 97.0,
+//!             valid: true,
+//!         })
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::spo2::{OxygenSaturation, Percentage, Spo2Sample, SPO2_RANGE};
+
+/// Async Pulse Oximetry Sensor methods.
+pub trait Spo2Sensor: Sensor<OxygenSaturation, Sample = Spo2Sample> {
+    /// Returns an oxygen saturation sample as a percentage, along with its validity.
+    async fn spo2(&mut self) -> Result<Spo2Sample, Self::Error>;
+}
+
+impl<T: Spo2Sensor + ?Sized> Spo2Sensor for &mut T {
+    #[inline]
+    async fn spo2(&mut self) -> Result<Spo2Sample, Self::Error> {
+        T::spo2(self).await
+    }
+}
+
+impl_sensor_refinement!(async, OxygenSaturation, Spo2Sensor, spo2, Spo2Sample);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_SAMPLE: Spo2Sample = Spo2Sample {
+        saturation: 96.5,
+        valid: true,
+    };
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncPulseOximeter {
+        sample: Spo2Sample,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncPulseOximeter {
+        type Error = MockError;
+    }
+
+    impl Spo2Sensor for MockAsyncPulseOximeter {
+        async fn spo2(&mut self) -> Result<Spo2Sample, Self::Error> {
+            Ok(self.sample)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_spo2_sensor_trait() {
+        let mut sensor = MockAsyncPulseOximeter {
+            sample: TEST_SAMPLE,
+        };
+        let result = sensor.spo2().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap().saturation, TEST_SAMPLE.saturation);
+    }
+
+    #[tokio::test]
+    async fn test_async_spo2_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncPulseOximeter {
+            sample: TEST_SAMPLE,
+        };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.spo2().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_SAMPLE);
+    }
+}
@@ -0,0 +1,163 @@
+//! Async Step Counter API
+//!
+//! This API provides generic methods for interfacing with step counters (pedometers)
+//! specifically, such as the pedometer engines embedded in accelerometers like the BMA456 or
+//! LSM6DSO.
+//!
+//! Sensors able to raise an interrupt when a step is detected can implement the [`StepWait`]
+//! trait.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the StepCounter and StepWait traits for an
+//! accelerometer.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::step_counter::{StepCount, StepCounter, StepWait};
+//!
+//! // A struct representing an accelerometer with an embedded pedometer.
+//! pub struct MyAccelerometer {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyAccelerometer {
+//!     type Error = Error;
+//! }
+//!
+//! impl StepCounter for MyAccelerometer {
+//!     async fn step_count(&mut self) -> Result<StepCount, Self::Error> {
+//!         // Read step counter registers of sensor...
+//!         Ok(4_812)
+//!     }
+//!
+//!     async fn reset_step_count(&mut self) -> Result<(), Self::Error> {
+//!         // Write step counter reset bit of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl StepWait for MyAccelerometer {
+//!     async fn wait_for_step(&mut self) -> Result<StepCount, Self::Error> {
+//!         // Await step detector interrupt (e.g. await GPIO level change on INT pin)...
+//!         // Then return the updated step count
+//!         self.step_count().await
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::step_counter::{StepCount, Steps};
+
+/// Async Step Counter methods.
+pub trait StepCounter: Sensor<Steps, Sample = StepCount> {
+    /// Returns the number of steps counted since the counter was last reset.
+    ///
+    /// The width of hardware step counters varies, so the count may wrap around once it exceeds
+    /// the width of the counter of the sensor.
+    async fn step_count(&mut self) -> Result<StepCount, Self::Error>;
+
+    /// Resets the step count to zero.
+    async fn reset_step_count(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: StepCounter + ?Sized> StepCounter for &mut T {
+    #[inline]
+    async fn step_count(&mut self) -> Result<StepCount, Self::Error> {
+        T::step_count(self).await
+    }
+
+    #[inline]
+    async fn reset_step_count(&mut self) -> Result<(), Self::Error> {
+        T::reset_step_count(self).await
+    }
+}
+
+impl_sensor_refinement!(async, Steps, StepCounter, step_count, StepCount);
+
+/// Async Step Wait methods.
+pub trait StepWait: StepCounter {
+    /// Wait for a step to be detected, returning the updated step count.
+    async fn wait_for_step(&mut self) -> Result<StepCount, Self::Error>;
+}
+
+impl<T: StepWait + ?Sized> StepWait for &mut T {
+    #[inline]
+    async fn wait_for_step(&mut self) -> Result<StepCount, Self::Error> {
+        T::wait_for_step(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_STEPS: StepCount = 4_812;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncPedometer {
+        steps: StepCount,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncPedometer {
+        type Error = MockError;
+    }
+
+    impl StepCounter for MockAsyncPedometer {
+        async fn step_count(&mut self) -> Result<StepCount, Self::Error> {
+            Ok(self.steps)
+        }
+
+        async fn reset_step_count(&mut self) -> Result<(), Self::Error> {
+            self.steps = 0;
+            Ok(())
+        }
+    }
+
+    impl StepWait for MockAsyncPedometer {
+        async fn wait_for_step(&mut self) -> Result<StepCount, Self::Error> {
+            self.steps += 1;
+            self.step_count().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_step_counter_trait() {
+        let mut sensor = MockAsyncPedometer { steps: TEST_STEPS };
+        let result = sensor.step_count().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_STEPS);
+    }
+
+    #[tokio::test]
+    async fn test_async_step_wait_trait_mut_ref() {
+        let mut sensor = MockAsyncPedometer { steps: TEST_STEPS };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.reset_step_count().await.is_ok());
+        assert_eq!(mut_ref.wait_for_step().await.unwrap(), 1);
+        assert_eq!(mut_ref.wait_for_step().await.unwrap(), 2);
+    }
+}
@@ -0,0 +1,155 @@
+//! Async Tap Detection API
+//!
+//! This API provides generic methods for interfacing with the tap detection engines of
+//! accelerometers specifically.
+//!
+//! A tap is detected when the acceleration exceeds the threshold of the configured [`TapConfig`]
+//! and falls back below it within the maximum tap duration. A second tap within the double tap
+//! window is reported as a [`TapKind::Double`] tap.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the TapDetection and TapWait traits for an
+//! accelerometer.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::tap::{TapConfig, TapDetection, TapKind, TapWait};
+//!
+//! // A struct representing an accelerometer with a tap detection engine.
+//! pub struct MyAccelerometer {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyAccelerometer {
+//!     type Error = Error;
+//! }
+//!
+//! impl TapDetection for MyAccelerometer {
+//!     async fn set_tap_config(&mut self, config: TapConfig) -> Result<(), Self::Error> {
+//!         // Write threshold, shock, quiet and latency registers of the tap detection engine...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl TapWait for MyAccelerometer {
+//!     async fn wait_for_tap(&mut self) -> Result<TapKind, Self::Error> {
+//!         // Await tap interrupt (e.g. await GPIO level change on INT pin)...
+//!         // Then read interrupt source register to tell single and double taps apart
+//!         Ok(TapKind::Double)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+pub use embedded_sensors_hal::tap::{GForce, TapConfig, TapKind};
+
+/// Async Tap Detection methods.
+pub trait TapDetection: ErrorType {
+    /// Set the configuration of the tap detection engine.
+    ///
+    /// Sensors round the threshold and timings to the nearest values they support.
+    async fn set_tap_config(&mut self, config: TapConfig) -> Result<(), Self::Error>;
+}
+
+impl<T: TapDetection + ?Sized> TapDetection for &mut T {
+    #[inline]
+    async fn set_tap_config(&mut self, config: TapConfig) -> Result<(), Self::Error> {
+        T::set_tap_config(self, config).await
+    }
+}
+
+/// Async Tap Wait methods.
+pub trait TapWait: TapDetection {
+    /// Wait for a tap to be detected, returning whether it was a single or double tap.
+    ///
+    /// When double tap detection is enabled, a double tap is reported once rather than as a
+    /// single tap followed by a double tap.
+    async fn wait_for_tap(&mut self) -> Result<TapKind, Self::Error>;
+}
+
+impl<T: TapWait + ?Sized> TapWait for &mut T {
+    #[inline]
+    async fn wait_for_tap(&mut self) -> Result<TapKind, Self::Error> {
+        T::wait_for_tap(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_SINGLE_TAP_CONFIG: TapConfig = TapConfig::new(2.5, 60_000, 0);
+    const TEST_DOUBLE_TAP_CONFIG: TapConfig = TapConfig::new(2.5, 60_000, 300_000);
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncAccelerometer {
+        config: Option<TapConfig>,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncAccelerometer {
+        type Error = MockError;
+    }
+
+    impl TapDetection for MockAsyncAccelerometer {
+        async fn set_tap_config(&mut self, config: TapConfig) -> Result<(), Self::Error> {
+            self.config = Some(config);
+            Ok(())
+        }
+    }
+
+    impl TapWait for MockAsyncAccelerometer {
+        async fn wait_for_tap(&mut self) -> Result<TapKind, Self::Error> {
+            // Report two quick taps, as a double tap if enabled
+            match self.config {
+                Some(config) if config.double_tap_window_us > 0 => Ok(TapKind::Double),
+                Some(_) => Ok(TapKind::Single),
+                None => Err(MockError),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_tap_wait_trait() {
+        let mut sensor = MockAsyncAccelerometer { config: None };
+        assert!(sensor.set_tap_config(TEST_SINGLE_TAP_CONFIG).await.is_ok());
+
+        let result = sensor.wait_for_tap().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TapKind::Single);
+    }
+
+    #[tokio::test]
+    async fn test_async_tap_wait_trait_mut_ref() {
+        let mut sensor = MockAsyncAccelerometer { config: None };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_tap_config(TEST_DOUBLE_TAP_CONFIG).await.is_ok());
+
+        let result = mut_ref.wait_for_tap().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TapKind::Double);
+    }
+}
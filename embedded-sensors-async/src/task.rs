@@ -0,0 +1,555 @@
+//! Embassy Sensor Task API
+//!
+//! This module (enabled by the `embassy` feature) provides a builder for the periodic
+//! sample-filter-publish loop found in most firmware sensor tasks.
+//!
+//! A [`SensorTaskBuilder`] is given a [`SampleSource`], a delay provider, a [`Clock`] and a
+//! sampling policy (period, filter and thresholds). Building it with an `embassy-sync` channel
+//! sender produces a [`SensorTask`] whose [`run`](SensorTask::run) method never returns,
+//! publishing each filtered sample, every threshold crossing and every error as a
+//! [`SensorMessage`].
+//!
+//! Samples are taken against a deadline read from the clock, so that the time spent sampling
+//! and publishing does not add to the period. Messages are published without waiting: a message
+//! which does not fit in the channel is dropped and counted (see
+//! [`dropped_messages`](SensorTask::dropped_messages)), so that a slow consumer cannot stall
+//! sampling and threshold detection.
+//!
+//! Since embassy tasks cannot be generic, the task itself is declared by the application with
+//! concrete types and simply runs the built [`SensorTask`].
+//!
+//! # For application authors
+//!
+//! ```
+//! use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+//! use embassy_sync::channel::Channel;
+//! use embedded_hal_async::delay::DelayNs;
+//! use embedded_sensors_hal_async::clock::Clock;
+//! use embedded_sensors_hal_async::sensor::Severity;
+//! use embedded_sensors_hal_async::task::{
+//!     SensorMessage, SensorTask, SensorTaskBuilder, TemperatureSource,
+//! };
+//! use embedded_sensors_hal_async::temperature::{DegreesCelsius, TemperatureSensor};
+//!
+//! type Message = SensorMessage<DegreesCelsius>;
+//!
+//! static TEMPERATURE_CHANNEL: Channel<CriticalSectionRawMutex, Message, 4> = Channel::new();
+//!
+//! fn build_task<S: TemperatureSensor, D: DelayNs, C: Clock>(
+//!     sensor: S,
+//!     delay: D,
+//!     clock: C,
+//! ) -> SensorTask<
+//!     'static,
+//!     TemperatureSource<S>,
+//!     D,
+//!     C,
+//!     impl FnMut(DegreesCelsius) -> DegreesCelsius,
+//!     CriticalSectionRawMutex,
+//!     4,
+//! > {
+//!     let mut average = None;
+//!     SensorTaskBuilder::new(TemperatureSource(sensor), delay, clock)
+//!         .period_ms(500)
+//!         .filter(move |sample: DegreesCelsius| {
+//!             // Exponential moving average...
+//!             let filtered = average.map_or(sample, |avg: f32| avg + (sample - avg) / 4.0);
+//!             average = Some(filtered);
+//!             filtered
+//!         })
+//!         .thresholds(5.0, 70.0)
+//!         .severity(Severity::Info, Severity::Critical)
+//!         .build(TEMPERATURE_CHANNEL.sender())
+//! }
+//!
+//! // In the application, with concrete sensor, delay and clock types:
+//! //
+//! // #[embassy_executor::task]
+//! // async fn temperature_task(task: SensorTask<'static, TemperatureSource<MySensor>, Delay, MyClock, ...>) -> ! {
+//! //     task.run().await
+//! // }
+//! ```
+
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::channel::Sender;
+use embedded_hal_async::delay::DelayNs;
+
+use crate::clock::Clock;
+use crate::sensor::{ErrorKind, Severity, ThresholdEvent, ThresholdKind};
+pub use crate::source::{RelativeHumiditySource, SampleSource, TemperatureSource};
+
+/// Default sampling period of a [`SensorTask`], in milliseconds.
+pub const DEFAULT_PERIOD_MS: u32 = 1000;
+
+/// Filter applied to each sample before it is published by a [`SensorTask`].
+///
+/// This is implemented for any `FnMut(T) -> T` closure.
+pub trait SampleFilter<T> {
+    /// Returns the filtered sample.
+    fn filter(&mut self, sample: T) -> T;
+}
+
+impl<T, F: FnMut(T) -> T> SampleFilter<T> for F {
+    #[inline]
+    fn filter(&mut self, sample: T) -> T {
+        self(sample)
+    }
+}
+
+/// A [`SampleFilter`] which passes samples through unchanged.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct NoFilter;
+
+impl<T> SampleFilter<T> for NoFilter {
+    #[inline]
+    fn filter(&mut self, sample: T) -> T {
+        sample
+    }
+}
+
+/// A message published by a [`SensorTask`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SensorMessage<T> {
+    /// A new filtered sample.
+    Sample(T),
+    /// A filtered sample crossed one of the configured thresholds.
+    ///
+    /// Published before the [`Sample`](Self::Sample) message of the same sample, so that it is
+    /// not the one dropped when the channel only has room for one more message.
+    Threshold {
+        /// The threshold crossing.
+        event: ThresholdEvent<T>,
+        /// Severity assigned to the crossed threshold.
+        severity: Severity,
+    },
+    /// Sampling the sensor failed.
+    Error(ErrorKind),
+}
+
+/// Builds a [`SensorTask`].
+pub struct SensorTaskBuilder<S: SampleSource, D, C, F> {
+    source: S,
+    delay: D,
+    clock: C,
+    period_ms: u32,
+    filter: F,
+    thresholds: Option<(S::Sample, S::Sample)>,
+    severities: (Severity, Severity),
+}
+
+impl<S: SampleSource, D: DelayNs, C: Clock> SensorTaskBuilder<S, D, C, NoFilter> {
+    /// Creates a new builder sampling `source` every [`DEFAULT_PERIOD_MS`], without filtering
+    /// or thresholds.
+    ///
+    /// `delay` waits out the remainder of each period, as measured by `clock`.
+    pub fn new(source: S, delay: D, clock: C) -> Self {
+        Self {
+            source,
+            delay,
+            clock,
+            period_ms: DEFAULT_PERIOD_MS,
+            filter: NoFilter,
+            thresholds: None,
+            severities: (Severity::Warning, Severity::Warning),
+        }
+    }
+}
+
+impl<S: SampleSource, D: DelayNs, C: Clock, F: SampleFilter<S::Sample>>
+    SensorTaskBuilder<S, D, C, F>
+{
+    /// Sets the sampling period, in milliseconds.
+    pub fn period_ms(mut self, period_ms: u32) -> Self {
+        self.period_ms = period_ms;
+        self
+    }
+
+    /// Sets the filter applied to each sample before it is published.
+    pub fn filter<G: SampleFilter<S::Sample>>(self, filter: G) -> SensorTaskBuilder<S, D, C, G> {
+        SensorTaskBuilder {
+            source: self.source,
+            delay: self.delay,
+            clock: self.clock,
+            period_ms: self.period_ms,
+            filter,
+            thresholds: self.thresholds,
+            severities: self.severities,
+        }
+    }
+
+    /// Sets the lower and upper thresholds checked against each filtered sample.
+    ///
+    /// A [`SensorMessage::Threshold`] is published each time a filtered sample leaves the
+    /// range between the thresholds.
+    pub fn thresholds(mut self, threshold_low: S::Sample, threshold_high: S::Sample) -> Self {
+        self.thresholds = Some((threshold_low, threshold_high));
+        self
+    }
+
+    /// Sets the severity reported for crossings of the lower and upper thresholds.
+    ///
+    /// Both default to [`Severity::Warning`].
+    pub fn severity(mut self, severity_low: Severity, severity_high: Severity) -> Self {
+        self.severities = (severity_low, severity_high);
+        self
+    }
+
+    /// Builds the task, which will publish its messages through `sender`.
+    pub fn build<'a, M: RawMutex, const N: usize>(
+        self,
+        sender: Sender<'a, M, SensorMessage<S::Sample>, N>,
+    ) -> SensorTask<'a, S, D, C, F, M, N> {
+        SensorTask {
+            source: self.source,
+            delay: self.delay,
+            clock: self.clock,
+            period_ms: self.period_ms,
+            filter: self.filter,
+            thresholds: self.thresholds,
+            severities: self.severities,
+            region: None,
+            deadline_us: None,
+            dropped_messages: 0,
+            sender,
+        }
+    }
+}
+
+/// A periodic sensor task built by a [`SensorTaskBuilder`].
+pub struct SensorTask<'a, S: SampleSource, D, C, F, M: RawMutex, const N: usize> {
+    source: S,
+    delay: D,
+    clock: C,
+    period_ms: u32,
+    filter: F,
+    thresholds: Option<(S::Sample, S::Sample)>,
+    severities: (Severity, Severity),
+    region: Option<ThresholdKind>,
+    deadline_us: Option<u64>,
+    dropped_messages: u32,
+    sender: Sender<'a, M, SensorMessage<S::Sample>, N>,
+}
+
+impl<S, D, C, F, M, const N: usize> SensorTask<'_, S, D, C, F, M, N>
+where
+    S: SampleSource,
+    S::Sample: PartialOrd + Copy,
+    D: DelayNs,
+    C: Clock,
+    F: SampleFilter<S::Sample>,
+    M: RawMutex,
+{
+    /// Runs the task forever.
+    pub async fn run(mut self) -> ! {
+        loop {
+            self.run_once().await;
+        }
+    }
+
+    /// Samples the source once, publishes the resulting messages, then waits until the
+    /// sampling period has elapsed since the sample was due.
+    ///
+    /// If sampling and publishing overrun the period, the next sample is taken immediately and
+    /// the schedule restarts from it, rather than sampling in a burst to catch up.
+    pub async fn run_once(&mut self) {
+        let deadline_us = *self.deadline_us.get_or_insert_with(|| self.clock.now_us());
+
+        match self.source.sample().await {
+            Ok(sample) => {
+                let sample = self.filter.filter(sample);
+                if let Some(event) = self.check_thresholds(sample) {
+                    let severity = match event.kind {
+                        ThresholdKind::Low => self.severities.0,
+                        ThresholdKind::High => self.severities.1,
+                    };
+                    self.publish(SensorMessage::Threshold { event, severity });
+                }
+                self.publish(SensorMessage::Sample(sample));
+            }
+            Err(kind) => self.publish(SensorMessage::Error(kind)),
+        }
+
+        let next_deadline_us = deadline_us.saturating_add(u64::from(self.period_ms) * 1_000);
+        let mut remaining_us = next_deadline_us.saturating_sub(self.clock.now_us());
+        if remaining_us == 0 {
+            self.deadline_us = None;
+            return;
+        }
+
+        self.deadline_us = Some(next_deadline_us);
+        while remaining_us > 0 {
+            let step_us = u32::try_from(remaining_us).unwrap_or(u32::MAX);
+            self.delay.delay_us(step_us).await;
+            remaining_us -= u64::from(step_us);
+        }
+    }
+
+    /// Returns the number of messages dropped because the channel was full.
+    pub fn dropped_messages(&self) -> u32 {
+        self.dropped_messages
+    }
+
+    // Publishes a message without waiting, dropping it if the channel is full.
+    fn publish(&mut self, message: SensorMessage<S::Sample>) {
+        if self.sender.try_send(message).is_err() {
+            self.dropped_messages = self.dropped_messages.saturating_add(1);
+        }
+    }
+
+    // Returns an event only when the sample enters a region beyond a threshold,
+    // so that a sample remaining beyond a threshold is reported once.
+    fn check_thresholds(&mut self, sample: S::Sample) -> Option<ThresholdEvent<S::Sample>> {
+        let (threshold_low, threshold_high) = self.thresholds?;
+        let region = if sample > threshold_high {
+            Some(ThresholdKind::High)
+        } else if sample < threshold_low {
+            Some(ThresholdKind::Low)
+        } else {
+            None
+        };
+
+        let crossed = if region != self.region { region } else { None };
+        self.region = region;
+        crossed.map(|kind| ThresholdEvent::new(sample, kind))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::sensor::{Error, ErrorType};
+    use crate::temperature::{DegreesCelsius, TemperatureSensor};
+    use assert_approx_eq::assert_approx_eq;
+    use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+    use embassy_sync::channel::Channel;
+
+    // Mock test values
+    const TEST_TEMPS: [DegreesCelsius; 4] = [25.0, 75.0, 80.0, 20.0];
+    const TEST_THRESHOLD_LOW: DegreesCelsius = 5.0;
+    const TEST_THRESHOLD_HIGH: DegreesCelsius = 70.0;
+    const TEST_PERIOD_MS: u32 = 250;
+    const TEST_SAMPLE_MS: u64 = 100;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::NotReady
+        }
+    }
+
+    // Takes `sample_ms` of the clock to return each of the values in turn.
+    struct MockTempSensor<'a> {
+        values: &'static [DegreesCelsius],
+        index: usize,
+        clock: &'a ManualClock,
+        sample_ms: u64,
+    }
+
+    impl ErrorType for MockTempSensor<'_> {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockTempSensor<'_> {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            self.clock.advance_ms(self.sample_ms);
+            let value = self.values.get(self.index).copied().ok_or(MockError)?;
+            self.index += 1;
+            Ok(value)
+        }
+    }
+
+    // Advances the clock by the time delayed.
+    struct MockDelay<'a> {
+        clock: &'a ManualClock,
+        elapsed_us: u64,
+    }
+
+    impl DelayNs for MockDelay<'_> {
+        async fn delay_ns(&mut self, ns: u32) {
+            self.clock.advance_us(u64::from(ns / 1_000));
+            self.elapsed_us += u64::from(ns / 1_000);
+        }
+    }
+
+    fn mock_sensor(clock: &ManualClock, sample_ms: u64) -> MockTempSensor<'_> {
+        MockTempSensor {
+            values: &TEST_TEMPS,
+            index: 0,
+            clock,
+            sample_ms,
+        }
+    }
+
+    fn mock_delay(clock: &ManualClock) -> MockDelay<'_> {
+        MockDelay {
+            clock,
+            elapsed_us: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sensor_task_publishes_samples() {
+        let clock = ManualClock::new();
+        let channel = Channel::<NoopRawMutex, SensorMessage<DegreesCelsius>, 8>::new();
+        let mut task = SensorTaskBuilder::new(
+            TemperatureSource(mock_sensor(&clock, 0)),
+            mock_delay(&clock),
+            &clock,
+        )
+        .period_ms(TEST_PERIOD_MS)
+        .build(channel.sender());
+
+        task.run_once().await;
+        assert_eq!(
+            channel.try_receive(),
+            Ok(SensorMessage::Sample(TEST_TEMPS[0]))
+        );
+        assert!(channel.try_receive().is_err());
+        assert_eq!(task.delay.elapsed_us, u64::from(TEST_PERIOD_MS) * 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_sensor_task_does_not_drift() {
+        let clock = ManualClock::new();
+        let channel = Channel::<NoopRawMutex, SensorMessage<DegreesCelsius>, 8>::new();
+        let mut task = SensorTaskBuilder::new(
+            TemperatureSource(mock_sensor(&clock, TEST_SAMPLE_MS)),
+            mock_delay(&clock),
+            &clock,
+        )
+        .period_ms(TEST_PERIOD_MS)
+        .build(channel.sender());
+
+        // The time spent sampling is deducted from the delay.
+        for _ in 0..3 {
+            task.run_once().await;
+        }
+        assert_eq!(clock.now_us(), 3 * u64::from(TEST_PERIOD_MS) * 1_000);
+        assert_eq!(
+            task.delay.elapsed_us,
+            3 * (u64::from(TEST_PERIOD_MS) - TEST_SAMPLE_MS) * 1_000
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sensor_task_overrun() {
+        let clock = ManualClock::new();
+        let channel = Channel::<NoopRawMutex, SensorMessage<DegreesCelsius>, 8>::new();
+        let sample_ms = u64::from(TEST_PERIOD_MS) + TEST_SAMPLE_MS;
+        let mut task = SensorTaskBuilder::new(
+            TemperatureSource(mock_sensor(&clock, sample_ms)),
+            mock_delay(&clock),
+            &clock,
+        )
+        .period_ms(TEST_PERIOD_MS)
+        .build(channel.sender());
+
+        // Overrunning the period samples again immediately, without catching up afterwards.
+        task.run_once().await;
+        task.run_once().await;
+        assert_eq!(task.delay.elapsed_us, 0);
+        task.source.0.sample_ms = 0;
+        task.run_once().await;
+        assert_eq!(task.delay.elapsed_us, u64::from(TEST_PERIOD_MS) * 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_sensor_task_filter() {
+        let clock = ManualClock::new();
+        let channel = Channel::<NoopRawMutex, SensorMessage<DegreesCelsius>, 8>::new();
+        let mut task = SensorTaskBuilder::new(
+            TemperatureSource(mock_sensor(&clock, 0)),
+            mock_delay(&clock),
+            &clock,
+        )
+        .filter(|sample: DegreesCelsius| sample / 2.0)
+        .build(channel.sender());
+
+        task.run_once().await;
+        match channel.try_receive() {
+            Ok(SensorMessage::Sample(sample)) => assert_approx_eq!(sample, TEST_TEMPS[0] / 2.0),
+            other => panic!("unexpected message: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sensor_task_thresholds() {
+        let clock = ManualClock::new();
+        let channel = Channel::<NoopRawMutex, SensorMessage<DegreesCelsius>, 16>::new();
+        let mut task = SensorTaskBuilder::new(
+            TemperatureSource(mock_sensor(&clock, 0)),
+            mock_delay(&clock),
+            &clock,
+        )
+        .thresholds(TEST_THRESHOLD_LOW, TEST_THRESHOLD_HIGH)
+        .severity(Severity::Info, Severity::Critical)
+        .build(channel.sender());
+
+        for _ in 0..TEST_TEMPS.len() {
+            task.run_once().await;
+        }
+
+        let mut events = 0;
+        while let Ok(message) = channel.try_receive() {
+            if let SensorMessage::Threshold { event, severity } = message {
+                assert_eq!(event.kind, ThresholdKind::High);
+                assert_eq!(severity, Severity::Critical);
+                assert_approx_eq!(event.value, TEST_TEMPS[1]);
+                events += 1;
+            }
+        }
+
+        // Only the first sample above the upper threshold is reported.
+        assert_eq!(events, 1);
+    }
+
+    #[tokio::test]
+    async fn test_sensor_task_full_channel() {
+        let clock = ManualClock::new();
+        let channel = Channel::<NoopRawMutex, SensorMessage<DegreesCelsius>, 1>::new();
+        let sensor = MockTempSensor {
+            values: &TEST_TEMPS[1..],
+            index: 0,
+            clock: &clock,
+            sample_ms: 0,
+        };
+        let mut task =
+            SensorTaskBuilder::new(TemperatureSource(sensor), mock_delay(&clock), &clock)
+                .thresholds(TEST_THRESHOLD_LOW, TEST_THRESHOLD_HIGH)
+                .build(channel.sender());
+
+        // The crossing takes the last free slot, and sampling carries on without a consumer.
+        task.run_once().await;
+        task.run_once().await;
+        assert_eq!(task.dropped_messages(), 2);
+        assert!(matches!(
+            channel.try_receive(),
+            Ok(SensorMessage::Threshold { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sensor_task_error() {
+        let clock = ManualClock::new();
+        let channel = Channel::<NoopRawMutex, SensorMessage<DegreesCelsius>, 8>::new();
+        let sensor = MockTempSensor {
+            values: &[],
+            index: 0,
+            clock: &clock,
+            sample_ms: 0,
+        };
+        let mut task =
+            SensorTaskBuilder::new(TemperatureSource(sensor), mock_delay(&clock), &clock)
+                .build(channel.sender());
+
+        task.run_once().await;
+        assert_eq!(
+            channel.try_receive(),
+            Ok(SensorMessage::Error(ErrorKind::NotReady))
+        );
+    }
+}
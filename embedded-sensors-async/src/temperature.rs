@@ -2,6 +2,19 @@
 //!
 //! This API provides generic methods for interfacing with temperature sensors specifically.
 //!
+//! Sensors measuring several temperatures, such as remote diode monitors with a local channel
+//! (e.g. TMP451, EMC1413 or MAX6697), can implement the [`MultiChannelTemperatureSensor`] trait.
+//! By convention, channel 0 is the local (on-die) channel of sensors which have one, followed by
+//! the remote channels.
+//!
+//! Samples are always reported in degrees Celsius. Application code presenting them in another
+//! unit can convert them through the [`Celsius`], [`Fahrenheit`] and [`Kelvin`] wrappers, which
+//! keep the unit of a temperature explicit in its type.
+//!
+//! Drivers for targets without a floating-point unit can implement the
+//! [`FixedPointTemperatureSensor`] trait, reporting samples in integer millidegrees Celsius, along
+//! with the fixed-point threshold and calibration traits built upon it.
+//!
 //! # For HAL authors
 //!
 //! Here is an example for the implementation of the TemperatureSensor and TemperatureThresholdWait traits for a temperature sensor.
@@ -63,10 +76,12 @@
 //! impl TemperatureThresholdWait for MyTempSensor {
 //!     async fn wait_for_temperature_threshold(
 //!         &mut self
-//!     ) -> Result<DegreesCelsius, Self::Error> {
+//!     ) -> Result<sensor::ThresholdEvent<DegreesCelsius>, Self::Error> {
 //!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
-//!         // Then return current temperature so caller can determine which threshold was crossed
-//!         self.temperature().await
+//!         let value = self.temperature().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
 //!     }
 //! }
 //!
@@ -81,11 +96,19 @@
 //! }
 //! ```
 
-use crate::sensor::{decl_threshold_traits, ErrorType};
-pub use embedded_sensors_hal::temperature::DegreesCelsius;
+use crate::alert::decl_alert_source;
+use crate::sensor::{
+    decl_calibration_trait, decl_channel_traits, decl_configuration_trait,
+    decl_fixed_point_calibration_trait, decl_fixed_point_threshold_traits, decl_fixed_point_trait,
+    decl_sample_read_trait, decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor,
+};
+pub use embedded_sensors_hal::temperature::{
+    Celsius, DegreesCelsius, Fahrenheit, Kelvin, MilliDegreesCelsius, Temperature,
+    TemperatureConfig, TEMPERATURE_RANGE,
+};
 
 /// Async Temperature Sensor methods.
-pub trait TemperatureSensor: ErrorType {
+pub trait TemperatureSensor: Sensor<Temperature, Sample = DegreesCelsius> {
     /// Returns a temperature sample in degrees Celsius.
     async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error>;
 }
@@ -97,6 +120,14 @@ impl<T: TemperatureSensor + ?Sized> TemperatureSensor for &mut T {
     }
 }
 
+impl_sensor_refinement!(
+    async,
+    Temperature,
+    TemperatureSensor,
+    temperature,
+    DegreesCelsius
+);
+
 // This macro generates the following async threshold traits:
 //
 // pub trait TemperatureThresholdSet: TemperatureSensor {
@@ -108,8 +139,13 @@ impl<T: TemperatureSensor + ?Sized> TemperatureSensor for &mut T {
 //     async fn set_temperature_threshold_hysteresis(&mut self, hysteresis: DegreesCelsius) -> Result<(), Self::Error>;
 // }
 //
+// pub trait TemperatureThresholdControl: TemperatureThresholdSet {
+//     async fn disable_temperature_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_temperature_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
 // pub trait TemperatureThresholdWait: TemperatureThresholdSet {
-//     async fn wait_for_temperature_threshold(&mut self) -> Result<DegreesCelsius, Self::Error>;
+//     async fn wait_for_temperature_threshold(&mut self) -> Result<ThresholdEvent<DegreesCelsius>, Self::Error>;
 // }
 decl_threshold_traits!(
     async,
@@ -119,10 +155,101 @@ decl_threshold_traits!(
     "degrees Celsius"
 );
 
+// This macro generates the `TemperatureAlert` adapter, which allows implementors of
+// `TemperatureThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(Temperature, DegreesCelsius);
+
+// This macro generates the following async configuration trait:
+//
+// pub trait TemperatureConfigure: TemperatureSensor {
+//     async fn set_temperature_config(&mut self, config: TemperatureConfig) -> Result<(), Self::Error>;
+// }
+decl_configuration_trait!(async, Temperature, TemperatureSensor, TemperatureConfig);
+
+// This macro generates the following async calibration trait:
+//
+// pub trait TemperatureCalibrate: TemperatureSensor {
+//     async fn set_temperature_offset(&mut self, offset: DegreesCelsius) -> Result<(), Self::Error>;
+//     async fn set_temperature_gain(&mut self, gain: f32) -> Result<(), Self::Error>;
+// }
+decl_calibration_trait!(
+    async,
+    Temperature,
+    TemperatureSensor,
+    DegreesCelsius,
+    "degrees Celsius"
+);
+
+// This macro generates the following async sample read trait:
+//
+// pub trait TemperatureSampleRead: TemperatureSensor {
+//     async fn temperature_sample(&mut self) -> Result<Sample<DegreesCelsius>, Self::Error>;
+// }
+decl_sample_read_trait!(async, Temperature, TemperatureSensor, DegreesCelsius);
+
+// This macro generates the following async fixed-point trait:
+//
+// pub trait FixedPointTemperatureSensor: ErrorType {
+//     async fn temperature_milli(&mut self) -> Result<MilliDegreesCelsius, Self::Error>;
+// }
+decl_fixed_point_trait!(
+    async,
+    Temperature,
+    MilliDegreesCelsius,
+    "millidegrees Celsius"
+);
+
+// This macro generates the following async fixed-point threshold traits:
+//
+// pub trait FixedPointTemperatureThresholdSet: FixedPointTemperatureSensor {
+//     async fn set_temperature_threshold_low_milli(&mut self, threshold: MilliDegreesCelsius) -> Result<(), Self::Error>;
+//     async fn set_temperature_threshold_high_milli(&mut self, threshold: MilliDegreesCelsius) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FixedPointTemperatureHysteresis: FixedPointTemperatureThresholdSet {
+//     async fn set_temperature_threshold_hysteresis_milli(&mut self, hysteresis: MilliDegreesCelsius) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FixedPointTemperatureThresholdWait: FixedPointTemperatureThresholdSet {
+//     async fn wait_for_temperature_threshold_milli(&mut self) -> Result<ThresholdEvent<MilliDegreesCelsius>, Self::Error>;
+// }
+decl_fixed_point_threshold_traits!(
+    async,
+    Temperature,
+    MilliDegreesCelsius,
+    "millidegrees Celsius"
+);
+
+// This macro generates the following async fixed-point calibration trait:
+//
+// pub trait FixedPointTemperatureCalibrate: FixedPointTemperatureSensor {
+//     async fn set_temperature_offset_milli(&mut self, offset: MilliDegreesCelsius) -> Result<(), Self::Error>;
+//     async fn set_temperature_gain_milli(&mut self, gain: u32) -> Result<(), Self::Error>;
+// }
+decl_fixed_point_calibration_trait!(
+    async,
+    Temperature,
+    MilliDegreesCelsius,
+    "millidegrees Celsius"
+);
+
+// This macro generates the following async multi-channel traits:
+//
+// pub trait MultiChannelTemperatureSensor: ErrorType {
+//     fn channel_count(&self) -> Channel;
+//     async fn temperature_on(&mut self, channel: Channel) -> Result<DegreesCelsius, Self::Error>;
+// }
+//
+// pub trait MultiChannelTemperatureThresholdSet: MultiChannelTemperatureSensor {
+//     async fn set_temperature_threshold_low_on(&mut self, channel: Channel, threshold: DegreesCelsius) -> Result<(), Self::Error>;
+//     async fn set_temperature_threshold_high_on(&mut self, channel: Channel, threshold: DegreesCelsius) -> Result<(), Self::Error>;
+// }
+decl_channel_traits!(async, Temperature, DegreesCelsius, "degrees Celsius");
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::sensor::{Error, ErrorKind};
+    use crate::sensor::{AlertPolarity, Error, ErrorKind, OperatingMode};
     use assert_approx_eq::assert_approx_eq;
 
     // Mock test values
@@ -156,6 +283,8 @@ mod tests {
         }
     }
 
+    impl TemperatureSampleRead for MockAsyncTempSensor {}
+
     impl TemperatureThresholdSet for MockAsyncTempSensor {
         async fn set_temperature_threshold_low(
             &mut self,
@@ -199,6 +328,19 @@ mod tests {
         assert_approx_eq!(result.unwrap(), TEST_TEMP);
     }
 
+    #[tokio::test]
+    async fn test_async_temperature_sample_read_mut_ref() {
+        let mut sensor = MockAsyncTempSensor {
+            value: TEST_TEMP,
+            threshold_low: TEST_INITIAL_THRESHOLD,
+            threshold_high: TEST_INITIAL_THRESHOLD,
+        };
+        let mut_ref = &mut sensor;
+        let sample = mut_ref.temperature_sample().await.unwrap();
+        assert_approx_eq!(sample.value, TEST_TEMP);
+        assert!(sample.quality.is_empty());
+    }
+
     #[tokio::test]
     async fn test_async_temperature_threshold_set_trait() {
         let mut sensor = MockAsyncTempSensor {
@@ -248,4 +390,175 @@ mod tests {
 
         assert_approx_eq!(sensor.threshold_high, TEST_THRESHOLD_HIGH);
     }
+
+    struct MockAsyncConfigurableTempSensor {
+        config: TemperatureConfig,
+        offset: DegreesCelsius,
+        gain: f32,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncConfigurableTempSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockAsyncConfigurableTempSensor {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(TEST_TEMP * self.gain + self.offset)
+        }
+    }
+
+    impl TemperatureCalibrate for MockAsyncConfigurableTempSensor {
+        async fn set_temperature_offset(
+            &mut self,
+            offset: DegreesCelsius,
+        ) -> Result<(), Self::Error> {
+            self.offset = offset;
+            Ok(())
+        }
+
+        async fn set_temperature_gain(&mut self, gain: f32) -> Result<(), Self::Error> {
+            self.gain = gain;
+            Ok(())
+        }
+    }
+
+    impl TemperatureConfigure for MockAsyncConfigurableTempSensor {
+        async fn set_temperature_config(
+            &mut self,
+            config: TemperatureConfig,
+        ) -> Result<(), Self::Error> {
+            self.config = config;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_temperature_configure_mut_ref() {
+        let mut sensor = MockAsyncConfigurableTempSensor {
+            config: TemperatureConfig::default(),
+            offset: 0.0,
+            gain: 1.0,
+        };
+        let config = TemperatureConfig::new(4.0, OperatingMode::OneShot, AlertPolarity::ActiveHigh);
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_temperature_config(config).await.is_ok());
+        assert_eq!(sensor.config, config);
+    }
+
+    #[tokio::test]
+    async fn test_async_temperature_calibrate_mut_ref() {
+        let mut sensor = MockAsyncConfigurableTempSensor {
+            config: TemperatureConfig::default(),
+            offset: 0.0,
+            gain: 1.0,
+        };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_temperature_gain(2.0).await.is_ok());
+        assert!(mut_ref.set_temperature_offset(-1.5).await.is_ok());
+        assert_approx_eq!(mut_ref.temperature().await.unwrap(), TEST_TEMP * 2.0 - 1.5);
+    }
+
+    struct MockAsyncRemoteDiodeSensor {
+        temperatures: [DegreesCelsius; 3],
+        thresholds: [(DegreesCelsius, DegreesCelsius); 3],
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncRemoteDiodeSensor {
+        type Error = MockError;
+    }
+
+    impl MultiChannelTemperatureSensor for MockAsyncRemoteDiodeSensor {
+        fn channel_count(&self) -> u8 {
+            self.temperatures.len() as u8
+        }
+
+        async fn temperature_on(&mut self, channel: u8) -> Result<DegreesCelsius, Self::Error> {
+            self.temperatures
+                .get(channel as usize)
+                .copied()
+                .ok_or(MockError)
+        }
+    }
+
+    impl MultiChannelTemperatureThresholdSet for MockAsyncRemoteDiodeSensor {
+        async fn set_temperature_threshold_low_on(
+            &mut self,
+            channel: u8,
+            threshold: DegreesCelsius,
+        ) -> Result<(), Self::Error> {
+            self.thresholds
+                .get_mut(channel as usize)
+                .ok_or(MockError)?
+                .0 = threshold;
+            Ok(())
+        }
+
+        async fn set_temperature_threshold_high_on(
+            &mut self,
+            channel: u8,
+            threshold: DegreesCelsius,
+        ) -> Result<(), Self::Error> {
+            self.thresholds
+                .get_mut(channel as usize)
+                .ok_or(MockError)?
+                .1 = threshold;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_multi_channel_temperature_sensor_mut_ref() {
+        let mut sensor = MockAsyncRemoteDiodeSensor {
+            temperatures: [TEST_TEMP, 61.5, 48.25],
+            thresholds: [(0.0, 0.0); 3],
+        };
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.channel_count(), 3);
+        assert_approx_eq!(mut_ref.temperature_on(0).await.unwrap(), TEST_TEMP);
+        assert_approx_eq!(mut_ref.temperature_on(2).await.unwrap(), 48.25);
+        assert!(mut_ref.temperature_on(3).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_async_multi_channel_temperature_threshold_set_mut_ref() {
+        let mut sensor = MockAsyncRemoteDiodeSensor {
+            temperatures: [TEST_TEMP; 3],
+            thresholds: [(0.0, 0.0); 3],
+        };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_temperature_threshold_low_on(1, 5.0)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_temperature_threshold_high_on(1, 85.0)
+            .await
+            .is_ok());
+        assert!(mut_ref
+            .set_temperature_threshold_high_on(3, 85.0)
+            .await
+            .is_err());
+        assert_eq!(sensor.thresholds, [(0.0, 0.0), (5.0, 85.0), (0.0, 0.0)]);
+    }
+
+    struct MockAsyncFixedPointTempSensor;
+
+    impl crate::sensor::ErrorType for MockAsyncFixedPointTempSensor {
+        type Error = MockError;
+    }
+
+    impl FixedPointTemperatureSensor for MockAsyncFixedPointTempSensor {
+        async fn temperature_milli(&mut self) -> Result<MilliDegreesCelsius, Self::Error> {
+            // 16-bit sample in 1/128 °C, converted without floating-point arithmetic
+            let raw: i16 = 0x0D80;
+            Ok(raw as i32 * 1_000 / 128)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_fixed_point_temperature_sensor_mut_ref() {
+        let mut sensor = MockAsyncFixedPointTempSensor;
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.temperature_milli().await.unwrap(), 27_000);
+    }
 }
@@ -0,0 +1,148 @@
+//! Async Thermal Array Sensor API
+//!
+//! This API provides generic methods for interfacing with thermopile array sensors (thermal
+//! cameras) specifically, such as the AMG8833 or MLX90640.
+//!
+//! A frame holds one temperature per pixel, which is read into a caller-provided buffer in
+//! row-major order so that no allocation is needed. The [`ArrayDimensions`] of the sensor tell
+//! how large the buffer must be and how to index it.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the ThermalArraySensor trait for an 8x8 thermopile
+//! array.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::thermal_array::{
+//!     ArrayDimensions, DegreesCelsius, ThermalArraySensor,
+//! };
+//!
+//! // A struct representing an 8x8 thermopile array.
+//! pub struct MyThermopileArray {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     BufferTooSmall,
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             Error::BufferTooSmall => sensor::ErrorKind::InvalidInput,
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyThermopileArray {
+//!     type Error = Error;
+//! }
+//!
+//! impl ThermalArraySensor for MyThermopileArray {
+//!     fn dimensions(&self) -> ArrayDimensions {
+//!         ArrayDimensions::new(8, 8)
+//!     }
+//!
+//!     async fn read_frame(&mut self, frame: &mut [DegreesCelsius]) -> Result<(), Self::Error> {
+//!         let pixels = self.dimensions().pixel_count();
+//!         if frame.len() < pixels {
+//!             return Err(Error::BufferTooSmall);
+//!         }
+//!         // Burst read pixel registers of sensor (0.25 °C per bit)...
+//!         frame[..pixels].fill(22.5);
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+pub use embedded_sensors_hal::thermal_array::{ArrayDimensions, DegreesCelsius};
+
+/// Async Thermal Array Sensor methods.
+pub trait ThermalArraySensor: ErrorType {
+    /// Returns the dimensions of the array.
+    fn dimensions(&self) -> ArrayDimensions;
+
+    /// Reads a frame of per-pixel temperatures in degrees Celsius into `frame`, in row-major
+    /// order.
+    ///
+    /// Fails with an error of kind
+    /// [`ErrorKind::InvalidInput`](crate::sensor::ErrorKind::InvalidInput) if `frame` holds fewer
+    /// than [`pixel_count`](ArrayDimensions::pixel_count) temperatures. Any extra temperatures are
+    /// left untouched.
+    async fn read_frame(&mut self, frame: &mut [DegreesCelsius]) -> Result<(), Self::Error>;
+}
+
+impl<T: ThermalArraySensor + ?Sized> ThermalArraySensor for &mut T {
+    #[inline]
+    fn dimensions(&self) -> ArrayDimensions {
+        T::dimensions(self)
+    }
+
+    #[inline]
+    async fn read_frame(&mut self, frame: &mut [DegreesCelsius]) -> Result<(), Self::Error> {
+        T::read_frame(self, frame).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_DIMENSIONS: ArrayDimensions = ArrayDimensions::new(2, 3);
+    const TEST_FRAME: [DegreesCelsius; 6] = [21.0, 21.25, 21.5, 22.0, 34.75, 22.25];
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::InvalidInput
+        }
+    }
+
+    struct MockAsyncThermalCamera;
+
+    impl crate::sensor::ErrorType for MockAsyncThermalCamera {
+        type Error = MockError;
+    }
+
+    impl ThermalArraySensor for MockAsyncThermalCamera {
+        fn dimensions(&self) -> ArrayDimensions {
+            TEST_DIMENSIONS
+        }
+
+        async fn read_frame(&mut self, frame: &mut [DegreesCelsius]) -> Result<(), Self::Error> {
+            let frame = frame.get_mut(..TEST_FRAME.len()).ok_or(MockError)?;
+            frame.copy_from_slice(&TEST_FRAME);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_thermal_array_sensor_trait() {
+        let mut sensor = MockAsyncThermalCamera;
+        let mut frame = [0.0; 6];
+        assert!(sensor.read_frame(&mut frame).await.is_ok());
+        let hottest = sensor.dimensions().index(1, 1).unwrap();
+        assert_approx_eq!(frame[hottest], 34.75);
+    }
+
+    #[tokio::test]
+    async fn test_async_thermal_array_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncThermalCamera;
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.dimensions().pixel_count(), 6);
+
+        let mut frame = [0.0; 4];
+        let result = mut_ref.read_frame(&mut frame).await;
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
+}
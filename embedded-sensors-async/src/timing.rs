@@ -0,0 +1,365 @@
+//! Async Time-Dependent Adapter API
+//!
+//! This module provides adapters which wrap a [`SampleSource`] and read the current time from a
+//! [`Clock`] to decide what to return:
+//!
+//! - [`Cached`] returns the last sample for as long as it is younger than a maximum age, rather
+//!   than sampling the source again.
+//! - [`StalenessGuard`] substitutes the last sample for errors of the source for as long as it is
+//!   younger than a maximum age, failing once it has gone stale.
+//! - [`Debounced`] only reports a new sample once the source has returned it continuously for a
+//!   hold time, as suits discrete samples such as those of a presence or magnetic switch.
+//! - [`RateLimited`] fails with [`ErrorKind::NotReady`] rather than sampling the source more
+//!   often than a minimum interval, protecting sensors which self-heat when polled too often.
+//!
+//! Since the time is only ever read from the clock, each adapter can be tested deterministically
+//! with a [`ManualClock`](crate::clock::ManualClock).
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal_async::clock::Clock;
+//! use embedded_sensors_hal_async::source::{SampleSource, TemperatureSource};
+//! use embedded_sensors_hal_async::temperature::TemperatureSensor;
+//! use embedded_sensors_hal_async::timing::Cached;
+//!
+//! // Lets several consumers read the temperature without sampling more than once a second.
+//! fn shared_temperature<S: TemperatureSensor, C: Clock>(
+//!     sensor: S,
+//!     clock: C,
+//! ) -> impl SampleSource<Sample = f32> {
+//!     Cached::new(TemperatureSource(sensor), clock, 1_000_000)
+//! }
+//! ```
+
+use crate::clock::Clock;
+use crate::sensor::ErrorKind;
+use crate::source::SampleSource;
+
+/// Wraps a [`SampleSource`], returning its last sample until it is older than a maximum age.
+pub struct Cached<S: SampleSource, C> {
+    source: S,
+    clock: C,
+    max_age_us: u64,
+    cached: Option<(S::Sample, u64)>,
+}
+
+impl<S: SampleSource, C: Clock> Cached<S, C> {
+    /// Wraps `source`, caching each of its samples for `max_age_us` as measured by `clock`.
+    pub fn new(source: S, clock: C, max_age_us: u64) -> Self {
+        Self {
+            source,
+            clock,
+            max_age_us,
+            cached: None,
+        }
+    }
+
+    /// Discards the cached sample, so that the source is sampled on the next call.
+    pub fn invalidate(&mut self) {
+        self.cached = None;
+    }
+
+    /// Consumes the adapter, returning the underlying source and clock.
+    pub fn into_inner(self) -> (S, C) {
+        (self.source, self.clock)
+    }
+}
+
+impl<S: SampleSource, C: Clock> SampleSource for Cached<S, C>
+where
+    S::Sample: Copy,
+{
+    type Sample = S::Sample;
+
+    async fn sample(&mut self) -> Result<Self::Sample, ErrorKind> {
+        let now_us = self.clock.now_us();
+        if let Some((sample, taken_us)) = self.cached {
+            if now_us.saturating_sub(taken_us) < self.max_age_us {
+                return Ok(sample);
+            }
+        }
+
+        let sample = self.source.sample().await?;
+        self.cached = Some((sample, now_us));
+        Ok(sample)
+    }
+}
+
+/// Wraps a [`SampleSource`], substituting its last sample for its errors until that sample is
+/// older than a maximum age.
+///
+/// Once the last sample has gone stale, errors of the source are returned unchanged.
+pub struct StalenessGuard<S: SampleSource, C> {
+    source: S,
+    clock: C,
+    max_age_us: u64,
+    last: Option<(S::Sample, u64)>,
+}
+
+impl<S: SampleSource, C: Clock> StalenessGuard<S, C> {
+    /// Wraps `source`, holding each of its samples over errors for `max_age_us` as measured by
+    /// `clock`.
+    pub fn new(source: S, clock: C, max_age_us: u64) -> Self {
+        Self {
+            source,
+            clock,
+            max_age_us,
+            last: None,
+        }
+    }
+
+    /// Returns the age of the last sample returned by the source, in microseconds, if any.
+    pub fn age_us(&self) -> Option<u64> {
+        self.last
+            .as_ref()
+            .map(|(_, taken_us)| self.clock.now_us().saturating_sub(*taken_us))
+    }
+
+    /// Consumes the adapter, returning the underlying source and clock.
+    pub fn into_inner(self) -> (S, C) {
+        (self.source, self.clock)
+    }
+}
+
+impl<S: SampleSource, C: Clock> SampleSource for StalenessGuard<S, C>
+where
+    S::Sample: Copy,
+{
+    type Sample = S::Sample;
+
+    async fn sample(&mut self) -> Result<Self::Sample, ErrorKind> {
+        match self.source.sample().await {
+            Ok(sample) => {
+                self.last = Some((sample, self.clock.now_us()));
+                Ok(sample)
+            }
+            Err(kind) => match self.last {
+                Some((sample, taken_us))
+                    if self.clock.now_us().saturating_sub(taken_us) < self.max_age_us =>
+                {
+                    Ok(sample)
+                }
+                _ => Err(kind),
+            },
+        }
+    }
+}
+
+/// Wraps a [`SampleSource`], only reporting a new sample once the source has returned it
+/// continuously for a hold time.
+///
+/// Until then, the previously reported sample is returned. The first sample of the source is
+/// reported immediately.
+pub struct Debounced<S: SampleSource, C> {
+    source: S,
+    clock: C,
+    hold_us: u64,
+    reported: Option<S::Sample>,
+    candidate: Option<(S::Sample, u64)>,
+}
+
+impl<S: SampleSource, C: Clock> Debounced<S, C> {
+    /// Wraps `source`, debouncing its samples over `hold_us` as measured by `clock`.
+    pub fn new(source: S, clock: C, hold_us: u64) -> Self {
+        Self {
+            source,
+            clock,
+            hold_us,
+            reported: None,
+            candidate: None,
+        }
+    }
+
+    /// Consumes the adapter, returning the underlying source and clock.
+    pub fn into_inner(self) -> (S, C) {
+        (self.source, self.clock)
+    }
+}
+
+impl<S: SampleSource, C: Clock> SampleSource for Debounced<S, C>
+where
+    S::Sample: PartialEq + Copy,
+{
+    type Sample = S::Sample;
+
+    async fn sample(&mut self) -> Result<Self::Sample, ErrorKind> {
+        let sample = self.source.sample().await?;
+        let now_us = self.clock.now_us();
+
+        let reported = match self.reported {
+            Some(reported) if reported != sample => reported,
+            _ => {
+                self.candidate = None;
+                self.reported = Some(sample);
+                return Ok(sample);
+            }
+        };
+
+        match self.candidate {
+            Some((candidate, since_us)) if candidate == sample => {
+                if now_us.saturating_sub(since_us) >= self.hold_us {
+                    self.candidate = None;
+                    self.reported = Some(sample);
+                    return Ok(sample);
+                }
+            }
+            _ => self.candidate = Some((sample, now_us)),
+        }
+        Ok(reported)
+    }
+}
+
+/// Wraps a [`SampleSource`], failing with [`ErrorKind::NotReady`] rather than sampling it more
+/// often than a minimum interval.
+pub struct RateLimited<S, C> {
+    source: S,
+    clock: C,
+    min_interval_us: u64,
+    last_us: Option<u64>,
+}
+
+impl<S: SampleSource, C: Clock> RateLimited<S, C> {
+    /// Wraps `source`, sampling it at most once every `min_interval_us` as measured by `clock`.
+    pub fn new(source: S, clock: C, min_interval_us: u64) -> Self {
+        Self {
+            source,
+            clock,
+            min_interval_us,
+            last_us: None,
+        }
+    }
+
+    /// Consumes the adapter, returning the underlying source and clock.
+    pub fn into_inner(self) -> (S, C) {
+        (self.source, self.clock)
+    }
+}
+
+impl<S: SampleSource, C: Clock> SampleSource for RateLimited<S, C> {
+    type Sample = S::Sample;
+
+    async fn sample(&mut self) -> Result<Self::Sample, ErrorKind> {
+        let now_us = self.clock.now_us();
+        if let Some(last_us) = self.last_us {
+            if now_us.saturating_sub(last_us) < self.min_interval_us {
+                return Err(ErrorKind::NotReady);
+            }
+        }
+
+        self.last_us = Some(now_us);
+        self.source.sample().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+
+    // Returns each of the test values in turn, counting the samples taken.
+    struct MockAsyncSource {
+        samples: &'static [Result<u8, ErrorKind>],
+        reads: usize,
+    }
+
+    impl SampleSource for MockAsyncSource {
+        type Sample = u8;
+
+        async fn sample(&mut self) -> Result<u8, ErrorKind> {
+            let sample = self.samples[self.reads % self.samples.len()];
+            self.reads += 1;
+            sample
+        }
+    }
+
+    fn mock_source(samples: &'static [Result<u8, ErrorKind>]) -> MockAsyncSource {
+        MockAsyncSource { samples, reads: 0 }
+    }
+
+    #[tokio::test]
+    async fn test_async_cached() {
+        let clock = ManualClock::new();
+        let mut source = Cached::new(mock_source(&[Ok(1), Ok(2), Ok(3)]), &clock, 1_000);
+
+        assert_eq!(source.sample().await, Ok(1));
+        clock.advance_us(999);
+        assert_eq!(source.sample().await, Ok(1));
+        clock.advance_us(1);
+        assert_eq!(source.sample().await, Ok(2));
+
+        source.invalidate();
+        assert_eq!(source.sample().await, Ok(3));
+        assert_eq!(source.into_inner().0.reads, 3);
+    }
+
+    #[tokio::test]
+    async fn test_async_cached_error() {
+        let clock = ManualClock::new();
+        let mut source = Cached::new(
+            mock_source(&[Err(ErrorKind::Timeout), Ok(1)]),
+            &clock,
+            1_000,
+        );
+
+        // Errors are not cached.
+        assert_eq!(source.sample().await, Err(ErrorKind::Timeout));
+        assert_eq!(source.sample().await, Ok(1));
+    }
+
+    #[tokio::test]
+    async fn test_async_staleness_guard() {
+        let clock = ManualClock::new();
+        let samples = &[
+            Ok(1),
+            Err(ErrorKind::Timeout),
+            Err(ErrorKind::Timeout),
+            Ok(2),
+        ];
+        let mut source = StalenessGuard::new(mock_source(samples), &clock, 1_000);
+        assert_eq!(source.age_us(), None);
+
+        assert_eq!(source.sample().await, Ok(1));
+        clock.advance_us(500);
+        assert_eq!(source.sample().await, Ok(1));
+        assert_eq!(source.age_us(), Some(500));
+
+        // The last sample has gone stale.
+        clock.advance_us(500);
+        assert_eq!(source.sample().await, Err(ErrorKind::Timeout));
+        assert_eq!(source.sample().await, Ok(2));
+        assert_eq!(source.age_us(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_async_debounced() {
+        let clock = ManualClock::new();
+        let samples = &[Ok(0), Ok(1), Ok(0), Ok(1), Ok(1), Ok(1)];
+        let mut source = Debounced::new(mock_source(samples), &clock, 1_000);
+
+        assert_eq!(source.sample().await, Ok(0));
+
+        // A bounce back to the reported sample restarts the hold time.
+        for _ in 0..3 {
+            clock.advance_us(600);
+            assert_eq!(source.sample().await, Ok(0));
+        }
+        clock.advance_us(600);
+        assert_eq!(source.sample().await, Ok(0));
+        clock.advance_us(400);
+        assert_eq!(source.sample().await, Ok(1));
+    }
+
+    #[tokio::test]
+    async fn test_async_rate_limited() {
+        let clock = ManualClock::new();
+        let mut source = RateLimited::new(mock_source(&[Ok(1), Ok(2)]), &clock, 1_000);
+
+        assert_eq!(source.sample().await, Ok(1));
+        clock.advance_us(999);
+        assert_eq!(source.sample().await, Err(ErrorKind::NotReady));
+        clock.advance_us(1);
+        assert_eq!(source.sample().await, Ok(2));
+        assert_eq!(source.into_inner().0.reads, 2);
+    }
+}
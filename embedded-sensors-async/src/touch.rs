@@ -0,0 +1,244 @@
+//! Async Capacitive Touch Sensor API
+//!
+//! This API provides generic methods for interfacing with capacitive touch and proximity
+//! controllers (e.g. for keypads and sliders) specifically.
+//!
+//! A controller has one or more electrodes, identified by their index. The raw capacitance count
+//! of each electrode can be sampled, while the touch status of all electrodes is reported as a
+//! [`TouchMask`] in which bit `i` is set if electrode `i` is touched. Controllers with an
+//! interrupt output can additionally implement the [`TouchWait`] trait.
+//!
+//! Controllers able to sample and set touch thresholds of a single electrode can implement the
+//! [`MultiChannelCapacitanceSensor`] and [`MultiChannelCapacitanceThresholdSet`] traits, each
+//! electrode being a channel.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the TouchSensor and TouchWait traits for a touch
+//! controller.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::touch::{CapacitanceCounts, TouchMask, TouchSensor, TouchWait};
+//!
+//! // A struct representing an 8-electrode capacitive touch controller.
+//! pub struct MyTouchController {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyTouchController {
+//!     type Error = Error;
+//! }
+//!
+//! impl TouchSensor for MyTouchController {
+//!     fn electrode_count(&self) -> usize {
+//!         8
+//!     }
+//!
+//!     async fn counts(&mut self, counts: &mut [CapacitanceCounts]) -> Result<usize, Self::Error> {
+//!         // Read delta count registers of controller...
+//!         let count = counts.len().min(8);
+//!         counts[..count].fill(0);
+//!         Ok(count)
+//!     }
+//!
+//!     async fn touched(&mut self) -> Result<TouchMask, Self::Error> {
+//!         // Read touch status register of controller...
+//!         Ok(0b0000_0100)
+//!     }
+//! }
+//!
+//! impl TouchWait for MyTouchController {
+//!     async fn wait_for_touch(&mut self) -> Result<TouchMask, Self::Error> {
+//!         // Await touch interrupt (e.g. await GPIO level change on ALERT pin)...
+//!         // Then return the touch status so caller can determine which electrodes are touched
+//!         self.touched().await
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{decl_channel_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::touch::{CapacitanceCounts, Touch, TouchMask, MAX_ELECTRODES};
+
+/// Async Capacitive Touch Sensor methods.
+pub trait TouchSensor: Sensor<Touch, Sample = TouchMask> {
+    /// Returns the number of electrodes of the controller, at most [`MAX_ELECTRODES`].
+    fn electrode_count(&self) -> usize;
+
+    /// Fills `counts` with a capacitance sample of each electrode, in device-specific counts.
+    ///
+    /// Returns the number of samples written, which is less than `counts.len()` if the
+    /// controller has fewer electrodes.
+    async fn counts(&mut self, counts: &mut [CapacitanceCounts]) -> Result<usize, Self::Error>;
+
+    /// Returns the touch status of every electrode.
+    async fn touched(&mut self) -> Result<TouchMask, Self::Error>;
+
+    /// Returns true if the given electrode is touched.
+    ///
+    /// Electrodes the controller does not have are never touched.
+    async fn is_touched(&mut self, electrode: usize) -> Result<bool, Self::Error> {
+        let mask = self.touched().await?;
+        Ok(electrode < MAX_ELECTRODES && mask & (1 << electrode) != 0)
+    }
+}
+
+impl<T: TouchSensor + ?Sized> TouchSensor for &mut T {
+    #[inline]
+    fn electrode_count(&self) -> usize {
+        T::electrode_count(self)
+    }
+
+    #[inline]
+    async fn counts(&mut self, counts: &mut [CapacitanceCounts]) -> Result<usize, Self::Error> {
+        T::counts(self, counts).await
+    }
+
+    #[inline]
+    async fn touched(&mut self) -> Result<TouchMask, Self::Error> {
+        T::touched(self).await
+    }
+
+    #[inline]
+    async fn is_touched(&mut self, electrode: usize) -> Result<bool, Self::Error> {
+        T::is_touched(self, electrode).await
+    }
+}
+
+impl_sensor_refinement!(async, Touch, TouchSensor, touched, TouchMask);
+
+/// Async Touch Wait methods.
+pub trait TouchWait: TouchSensor {
+    /// Wait for at least one electrode to be touched, returning the touch status of every
+    /// electrode.
+    async fn wait_for_touch(&mut self) -> Result<TouchMask, Self::Error>;
+}
+
+impl<T: TouchWait + ?Sized> TouchWait for &mut T {
+    #[inline]
+    async fn wait_for_touch(&mut self) -> Result<TouchMask, Self::Error> {
+        T::wait_for_touch(self).await
+    }
+}
+
+// This macro generates the following async multi-channel traits:
+//
+// pub trait MultiChannelCapacitanceSensor: ErrorType {
+//     fn channel_count(&self) -> Channel;
+//     async fn capacitance_on(&mut self, channel: Channel) -> Result<CapacitanceCounts, Self::Error>;
+// }
+//
+// pub trait MultiChannelCapacitanceThresholdSet: MultiChannelCapacitanceSensor {
+//     async fn set_capacitance_threshold_low_on(&mut self, channel: Channel, threshold: CapacitanceCounts) -> Result<(), Self::Error>;
+//     async fn set_capacitance_threshold_high_on(&mut self, channel: Channel, threshold: CapacitanceCounts) -> Result<(), Self::Error>;
+// }
+decl_channel_traits!(
+    async,
+    Capacitance,
+    CapacitanceCounts,
+    "device-specific counts"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Channel, Error, ErrorKind};
+
+    // Mock test values
+    const TEST_COUNTS: [CapacitanceCounts; 3] = [12, -3, 87];
+    const TEST_TOUCHED: TouchMask = 0b100;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncTouchController {}
+
+    impl crate::sensor::ErrorType for MockAsyncTouchController {
+        type Error = MockError;
+    }
+
+    impl TouchSensor for MockAsyncTouchController {
+        fn electrode_count(&self) -> usize {
+            TEST_COUNTS.len()
+        }
+
+        async fn counts(&mut self, counts: &mut [CapacitanceCounts]) -> Result<usize, Self::Error> {
+            let count = counts.len().min(TEST_COUNTS.len());
+            counts[..count].copy_from_slice(&TEST_COUNTS[..count]);
+            Ok(count)
+        }
+
+        async fn touched(&mut self) -> Result<TouchMask, Self::Error> {
+            Ok(TEST_TOUCHED)
+        }
+    }
+
+    impl TouchWait for MockAsyncTouchController {
+        async fn wait_for_touch(&mut self) -> Result<TouchMask, Self::Error> {
+            self.touched().await
+        }
+    }
+
+    impl MultiChannelCapacitanceSensor for MockAsyncTouchController {
+        fn channel_count(&self) -> Channel {
+            TEST_COUNTS.len() as Channel
+        }
+
+        async fn capacitance_on(
+            &mut self,
+            channel: Channel,
+        ) -> Result<CapacitanceCounts, Self::Error> {
+            TEST_COUNTS.get(channel as usize).copied().ok_or(MockError)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_touch_sensor_trait() {
+        let mut sensor = MockAsyncTouchController {};
+        let mut counts = [0; 2];
+        assert_eq!(sensor.counts(&mut counts).await.unwrap(), 2);
+        assert_eq!(counts, TEST_COUNTS[..2]);
+
+        assert!(sensor.is_touched(2).await.unwrap());
+        assert!(!sensor.is_touched(1).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_async_touch_wait_trait_mut_ref() {
+        let mut sensor = MockAsyncTouchController {};
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.electrode_count(), 3);
+
+        let result = mut_ref.wait_for_touch().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_TOUCHED);
+    }
+
+    #[tokio::test]
+    async fn test_async_multi_channel_capacitance_sensor_mut_ref() {
+        let mut sensor = MockAsyncTouchController {};
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.channel_count(), 3);
+        assert_eq!(mut_ref.capacitance_on(1).await.unwrap(), TEST_COUNTS[1]);
+        assert!(mut_ref.capacitance_on(3).await.is_err());
+    }
+}
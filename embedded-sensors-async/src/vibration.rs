@@ -0,0 +1,373 @@
+//! Async Vibration Sensor API
+//!
+//! This API provides generic methods for interfacing with vibration sensors specifically.
+//!
+//! The [`VibrationSpectrum`] trait exposes the frequency-domain view of vibration used by
+//! predictive-maintenance applications: magnitude bins of the acceleration spectrum and its
+//! dominant frequency. It is implemented by sensors with a built-in spectral engine, or by the
+//! `FftSpectrum` adapter (enabled by the `fft` feature) over buffered acceleration samples.
+//!
+//! Condition-monitoring sensors instead report the overall RMS vibration velocity or
+//! acceleration through the [`VibrationVelocitySensor`] and [`VibrationAccelerationSensor`]
+//! traits. Their threshold traits allow alarms to be raised on the boundaries of the ISO 10816
+//! [`VibrationZone`]s.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the VibrationSpectrum trait for a vibration
+//! sensor with a built-in spectral engine.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::vibration::{
+//!     Hertz, MetersPerSecondSquared, SpectralPeak, VibrationSpectrum,
+//! };
+//!
+//! // A struct representing a vibration sensor.
+//! pub struct MyVibrationSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyVibrationSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl VibrationSpectrum for MyVibrationSensor {
+//!     fn bin_width(&self) -> Hertz {
+//!         // Output data rate divided by FFT length...
+//!         3.125
+//!     }
+//!
+//!     async fn spectrum(
+//!         &mut self,
+//!         bins: &mut [MetersPerSecondSquared],
+//!     ) -> Result<usize, Self::Error> {
+//!         // Await end of spectral computation, then read spectrum bins from sensor...
+//!         bins.fill(0.0);
+//!         Ok(bins.len())
+//!     }
+//!
+//!     async fn dominant_frequency(&mut self) -> Result<SpectralPeak, Self::Error> {
+//!         // Await end of spectral computation, then read peak registers of sensor...
+//!         Ok(SpectralPeak {
+//!             frequency: 50.0,
+//!             magnitude: 0.8,
+//!         })
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+#[cfg(feature = "fft")]
+pub use embedded_sensors_hal::vibration::FftSpectrum;
+pub use embedded_sensors_hal::vibration::{
+    Hertz, MetersPerSecondSquared, MillimetersPerSecond, SpectralPeak, VibrationAcceleration,
+    VibrationVelocity, VibrationZone, VibrationZoneBoundaries,
+};
+
+/// Async Vibration Spectrum methods.
+pub trait VibrationSpectrum: ErrorType {
+    /// Returns the width of each spectrum bin in hertz.
+    ///
+    /// Bin `i` is centered on the frequency `i * bin_width()`, bin 0 being the DC component.
+    fn bin_width(&self) -> Hertz;
+
+    /// Fills `bins` with the acceleration amplitude of consecutive spectrum bins, starting with
+    /// the DC component.
+    ///
+    /// Returns the number of bins written, which is less than `bins.len()` if the spectrum has
+    /// fewer bins.
+    async fn spectrum(&mut self, bins: &mut [MetersPerSecondSquared])
+        -> Result<usize, Self::Error>;
+
+    /// Returns the peak with the largest amplitude, excluding the DC component.
+    async fn dominant_frequency(&mut self) -> Result<SpectralPeak, Self::Error>;
+}
+
+impl<T: VibrationSpectrum + ?Sized> VibrationSpectrum for &mut T {
+    #[inline]
+    fn bin_width(&self) -> Hertz {
+        T::bin_width(self)
+    }
+
+    #[inline]
+    async fn spectrum(
+        &mut self,
+        bins: &mut [MetersPerSecondSquared],
+    ) -> Result<usize, Self::Error> {
+        T::spectrum(self, bins).await
+    }
+
+    #[inline]
+    async fn dominant_frequency(&mut self) -> Result<SpectralPeak, Self::Error> {
+        T::dominant_frequency(self).await
+    }
+}
+
+#[cfg(feature = "fft")]
+impl<const N: usize> VibrationSpectrum for FftSpectrum<N> {
+    #[inline]
+    fn bin_width(&self) -> Hertz {
+        embedded_sensors_hal::vibration::VibrationSpectrum::bin_width(self)
+    }
+
+    #[inline]
+    async fn spectrum(
+        &mut self,
+        bins: &mut [MetersPerSecondSquared],
+    ) -> Result<usize, Self::Error> {
+        embedded_sensors_hal::vibration::VibrationSpectrum::spectrum(self, bins)
+    }
+
+    #[inline]
+    async fn dominant_frequency(&mut self) -> Result<SpectralPeak, Self::Error> {
+        embedded_sensors_hal::vibration::VibrationSpectrum::dominant_frequency(self)
+    }
+}
+
+/// Async Vibration Velocity Sensor methods.
+pub trait VibrationVelocitySensor:
+    Sensor<VibrationVelocity, Sample = MillimetersPerSecond>
+{
+    /// Returns an RMS vibration velocity sample in millimeters per second.
+    async fn rms_velocity(&mut self) -> Result<MillimetersPerSecond, Self::Error>;
+}
+
+impl<T: VibrationVelocitySensor + ?Sized> VibrationVelocitySensor for &mut T {
+    #[inline]
+    async fn rms_velocity(&mut self) -> Result<MillimetersPerSecond, Self::Error> {
+        T::rms_velocity(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    VibrationVelocity,
+    VibrationVelocitySensor,
+    rms_velocity,
+    MillimetersPerSecond
+);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait VibrationVelocityThresholdSet: VibrationVelocitySensor {
+//     async fn set_vibration_velocity_threshold_low(&mut self, threshold: MillimetersPerSecond) -> Result<(), Self::Error>;
+//     async fn set_vibration_velocity_threshold_high(&mut self, threshold: MillimetersPerSecond) -> Result<(), Self::Error>;
+// }
+//
+// pub trait VibrationVelocityHysteresis: VibrationVelocityThresholdSet {
+//     async fn set_vibration_velocity_threshold_hysteresis(&mut self, hysteresis: MillimetersPerSecond) -> Result<(), Self::Error>;
+// }
+//
+// pub trait VibrationVelocityThresholdControl: VibrationVelocityThresholdSet {
+//     async fn disable_vibration_velocity_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_vibration_velocity_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait VibrationVelocityThresholdWait: VibrationVelocityThresholdSet {
+//     async fn wait_for_vibration_velocity_threshold(&mut self) -> Result<ThresholdEvent<MillimetersPerSecond>, Self::Error>;
+// }
+decl_threshold_traits!(
+    async,
+    VibrationVelocity,
+    VibrationVelocitySensor,
+    MillimetersPerSecond,
+    "millimeters per second"
+);
+
+// This macro generates the `VibrationVelocityAlert` adapter, which allows implementors of
+// `VibrationVelocityThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(VibrationVelocity, MillimetersPerSecond);
+
+/// Async Vibration Acceleration Sensor methods.
+pub trait VibrationAccelerationSensor:
+    Sensor<VibrationAcceleration, Sample = MetersPerSecondSquared>
+{
+    /// Returns an RMS vibration acceleration sample in meters per second squared.
+    async fn rms_acceleration(&mut self) -> Result<MetersPerSecondSquared, Self::Error>;
+}
+
+impl<T: VibrationAccelerationSensor + ?Sized> VibrationAccelerationSensor for &mut T {
+    #[inline]
+    async fn rms_acceleration(&mut self) -> Result<MetersPerSecondSquared, Self::Error> {
+        T::rms_acceleration(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    VibrationAcceleration,
+    VibrationAccelerationSensor,
+    rms_acceleration,
+    MetersPerSecondSquared
+);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait VibrationAccelerationThresholdSet: VibrationAccelerationSensor {
+//     async fn set_vibration_acceleration_threshold_low(&mut self, threshold: MetersPerSecondSquared) -> Result<(), Self::Error>;
+//     async fn set_vibration_acceleration_threshold_high(&mut self, threshold: MetersPerSecondSquared) -> Result<(), Self::Error>;
+// }
+//
+// pub trait VibrationAccelerationHysteresis: VibrationAccelerationThresholdSet {
+//     async fn set_vibration_acceleration_threshold_hysteresis(&mut self, hysteresis: MetersPerSecondSquared) -> Result<(), Self::Error>;
+// }
+//
+// pub trait VibrationAccelerationThresholdControl: VibrationAccelerationThresholdSet {
+//     async fn disable_vibration_acceleration_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_vibration_acceleration_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait VibrationAccelerationThresholdWait: VibrationAccelerationThresholdSet {
+//     async fn wait_for_vibration_acceleration_threshold(&mut self) -> Result<ThresholdEvent<MetersPerSecondSquared>, Self::Error>;
+// }
+decl_threshold_traits!(
+    async,
+    VibrationAcceleration,
+    VibrationAccelerationSensor,
+    MetersPerSecondSquared,
+    "meters per second squared"
+);
+
+// This macro generates the `VibrationAccelerationAlert` adapter, which allows implementors of
+// `VibrationAccelerationThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(VibrationAcceleration, MetersPerSecondSquared);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_PEAK: SpectralPeak = SpectralPeak {
+        frequency: 120.0,
+        magnitude: 1.5,
+    };
+    const TEST_VELOCITY: MillimetersPerSecond = 3.2;
+    const TEST_THRESHOLD_LOW: MillimetersPerSecond = 2.8;
+    const TEST_THRESHOLD_HIGH: MillimetersPerSecond = 4.5;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncVibrationSensor {}
+
+    struct MockAsyncVelocitySensor {
+        value: MillimetersPerSecond,
+        threshold_low: Option<MillimetersPerSecond>,
+        threshold_high: Option<MillimetersPerSecond>,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncVelocitySensor {
+        type Error = MockError;
+    }
+
+    impl VibrationVelocitySensor for MockAsyncVelocitySensor {
+        async fn rms_velocity(&mut self) -> Result<MillimetersPerSecond, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl VibrationVelocityThresholdSet for MockAsyncVelocitySensor {
+        async fn set_vibration_velocity_threshold_low(
+            &mut self,
+            threshold: MillimetersPerSecond,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        async fn set_vibration_velocity_threshold_high(
+            &mut self,
+            threshold: MillimetersPerSecond,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncVibrationSensor {
+        type Error = MockError;
+    }
+
+    impl VibrationSpectrum for MockAsyncVibrationSensor {
+        fn bin_width(&self) -> Hertz {
+            10.0
+        }
+
+        async fn spectrum(
+            &mut self,
+            bins: &mut [MetersPerSecondSquared],
+        ) -> Result<usize, Self::Error> {
+            bins.fill(0.0);
+            Ok(bins.len())
+        }
+
+        async fn dominant_frequency(&mut self) -> Result<SpectralPeak, Self::Error> {
+            Ok(TEST_PEAK)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_vibration_spectrum_trait_mut_ref() {
+        let mut sensor = MockAsyncVibrationSensor {};
+        let mut_ref = &mut sensor;
+        assert_approx_eq!(mut_ref.bin_width(), 10.0);
+
+        let mut bins = [1.0; 4];
+        assert_eq!(mut_ref.spectrum(&mut bins).await.unwrap(), 4);
+
+        let result = mut_ref.dominant_frequency().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_PEAK);
+    }
+
+    #[tokio::test]
+    async fn test_async_vibration_velocity_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncVelocitySensor {
+            value: TEST_VELOCITY,
+            threshold_low: None,
+            threshold_high: None,
+        };
+
+        {
+            let mut_ref = &mut sensor;
+            let result = mut_ref.rms_velocity().await;
+            assert!(result.is_ok());
+            assert_approx_eq!(result.unwrap(), TEST_VELOCITY);
+
+            let result_low = mut_ref
+                .set_vibration_velocity_threshold_low(TEST_THRESHOLD_LOW)
+                .await;
+            assert!(result_low.is_ok());
+            let result_high = mut_ref
+                .set_vibration_velocity_threshold_high(TEST_THRESHOLD_HIGH)
+                .await;
+            assert!(result_high.is_ok());
+        }
+
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+    }
+}
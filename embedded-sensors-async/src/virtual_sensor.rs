@@ -0,0 +1,161 @@
+//! Async Virtual Sensor API
+//!
+//! This module provides adapters which estimate a quantity at a location that is not directly
+//! measured, from one or more sensors which are.
+//!
+//! A [`VirtualTemperatureSensor`] combines the readings of several temperature sensors through a
+//! [`LinearModel`] (weights and offset, typically identified offline) and exposes the result
+//! through the [`TemperatureSensor`] trait. Estimates which are not finite (e.g. from a NaN
+//! sample) are reported as [`VirtualSensorError::InvalidSample`].
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal_async::temperature::TemperatureSensor;
+//! use embedded_sensors_hal_async::virtual_sensor::{
+//!     LinearModel, VirtualSensorError, VirtualTemperatureSensor,
+//! };
+//!
+//! async fn skin_temperature<S: TemperatureSensor>(
+//!     cpu: S,
+//!     battery: S,
+//! ) -> Result<f32, VirtualSensorError<S::Error>> {
+//!     // skin = 0.6 * cpu + 0.3 * battery + 2.5
+//!     let model = LinearModel::new([0.6, 0.3], 2.5);
+//!     let mut skin = VirtualTemperatureSensor::new([cpu, battery], model);
+//!     skin.temperature().await
+//! }
+//! ```
+
+use crate::sensor::{checked_sample, ErrorType};
+use crate::temperature::{DegreesCelsius, TemperatureSensor};
+pub use embedded_sensors_hal::virtual_sensor::{LinearModel, VirtualSensorError};
+
+/// Estimates an unmeasured temperature from `N` temperature sensors through a [`LinearModel`].
+///
+/// Sensors are sampled sequentially, in order.
+pub struct VirtualTemperatureSensor<S, const N: usize> {
+    sensors: [S; N],
+    model: LinearModel<N>,
+}
+
+impl<S, const N: usize> VirtualTemperatureSensor<S, N> {
+    /// Creates a new virtual temperature sensor.
+    pub fn new(sensors: [S; N], model: LinearModel<N>) -> Self {
+        Self { sensors, model }
+    }
+
+    /// Returns the model used to compute estimates.
+    pub fn model(&self) -> &LinearModel<N> {
+        &self.model
+    }
+
+    /// Consumes the virtual sensor, returning the underlying sensors.
+    pub fn into_inner(self) -> [S; N] {
+        self.sensors
+    }
+}
+
+impl<S: ErrorType, const N: usize> ErrorType for VirtualTemperatureSensor<S, N> {
+    type Error = VirtualSensorError<S::Error>;
+}
+
+impl<S: TemperatureSensor, const N: usize> TemperatureSensor for VirtualTemperatureSensor<S, N> {
+    async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        let mut samples = [0.0; N];
+        for (sample, sensor) in samples.iter_mut().zip(self.sensors.iter_mut()) {
+            *sample = sensor
+                .temperature()
+                .await
+                .map_err(VirtualSensorError::Sensor)?;
+        }
+
+        checked_sample(self.model.estimate(&samples)).map_err(|_| VirtualSensorError::InvalidSample)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_CPU_TEMP: DegreesCelsius = 60.0;
+    const TEST_BATTERY_TEMP: DegreesCelsius = 35.0;
+    const TEST_MODEL: LinearModel<2> = LinearModel::new([0.5, 0.25], 3.0);
+
+    #[derive(Debug, PartialEq)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Peripheral
+        }
+    }
+
+    struct MockAsyncTempSensor {
+        value: Option<DegreesCelsius>,
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncTempSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockAsyncTempSensor {
+        async fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            self.value.ok_or(MockError)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_virtual_temperature_sensor() {
+        let sensors = [
+            MockAsyncTempSensor {
+                value: Some(TEST_CPU_TEMP),
+            },
+            MockAsyncTempSensor {
+                value: Some(TEST_BATTERY_TEMP),
+            },
+        ];
+        let mut sensor = VirtualTemperatureSensor::new(sensors, TEST_MODEL);
+        let result = sensor.temperature().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(
+            result.unwrap(),
+            3.0 + 0.5 * TEST_CPU_TEMP + 0.25 * TEST_BATTERY_TEMP
+        );
+    }
+
+    #[tokio::test]
+    async fn test_async_virtual_temperature_sensor_error() {
+        let sensors = [
+            MockAsyncTempSensor { value: None },
+            MockAsyncTempSensor {
+                value: Some(TEST_BATTERY_TEMP),
+            },
+        ];
+        let mut sensor = VirtualTemperatureSensor::new(sensors, TEST_MODEL);
+        assert_eq!(
+            sensor.temperature().await,
+            Err(VirtualSensorError::Sensor(MockError))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_async_virtual_temperature_sensor_nan_input() {
+        let sensors = [
+            MockAsyncTempSensor {
+                value: Some(f32::NAN),
+            },
+            MockAsyncTempSensor {
+                value: Some(TEST_BATTERY_TEMP),
+            },
+        ];
+        let mut sensor = VirtualTemperatureSensor::new(sensors, TEST_MODEL);
+        assert_eq!(
+            sensor.temperature().await,
+            Err(VirtualSensorError::InvalidSample)
+        );
+    }
+}
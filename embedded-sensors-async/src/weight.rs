@@ -0,0 +1,270 @@
+//! Async Weight Sensor API
+//!
+//! This API provides generic methods for interfacing with weight scales specifically.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the WeightSensor and WeightThresholdWait traits for a load cell scale.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::weight::{
+//!     Grams, WeightHysteresis, WeightSensor, WeightThresholdSet, WeightThresholdWait,
+//! };
+//!
+//! // A struct representing a load cell scale.
+//! pub struct MyWeightSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyWeightSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl WeightSensor for MyWeightSensor {
+//!     async fn weight(&mut self) -> Result<Grams, Self::Error> {
+//!         // Read raw ADC value, subtract zero offset and apply scale factor...
+//!         Ok(250.0)
+//!     }
+//!
+//!     async fn tare(&mut self) -> Result<(), Self::Error> {
+//!         // Average a few raw samples and store them as the zero offset...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_scale_factor(&mut self, factor: f32) -> Result<(), Self::Error> {
+//!         // Store scale factor used by weight conversion...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl WeightThresholdSet for MyWeightSensor {
+//!     async fn set_weight_threshold_low(
+//!         &mut self,
+//!         threshold: Grams
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_weight_threshold_high(
+//!         &mut self,
+//!         threshold: Grams
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl WeightThresholdWait for MyWeightSensor {
+//!     async fn wait_for_weight_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<Grams>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.weight().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl WeightHysteresis for MyWeightSensor {
+//!     async fn set_weight_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: Grams
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::weight::{Grams, Weight};
+
+/// Async Weight Sensor methods.
+pub trait WeightSensor: Sensor<Weight, Sample = Grams> {
+    /// Returns a weight sample in grams, relative to the load present at the last tare.
+    async fn weight(&mut self) -> Result<Grams, Self::Error>;
+
+    /// Tare the scale, so that the current load reads as zero weight.
+    async fn tare(&mut self) -> Result<(), Self::Error>;
+
+    /// Set the factor converting raw readings (relative to the tare offset) to grams.
+    async fn set_scale_factor(&mut self, factor: f32) -> Result<(), Self::Error>;
+}
+
+impl<T: WeightSensor + ?Sized> WeightSensor for &mut T {
+    #[inline]
+    async fn weight(&mut self) -> Result<Grams, Self::Error> {
+        T::weight(self).await
+    }
+
+    #[inline]
+    async fn tare(&mut self) -> Result<(), Self::Error> {
+        T::tare(self).await
+    }
+
+    #[inline]
+    async fn set_scale_factor(&mut self, factor: f32) -> Result<(), Self::Error> {
+        T::set_scale_factor(self, factor).await
+    }
+}
+
+impl_sensor_refinement!(async, Weight, WeightSensor, weight, Grams);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait WeightThresholdSet: WeightSensor {
+//     async fn set_weight_threshold_low(&mut self, threshold: Grams) -> Result<(), Self::Error>;
+//     async fn set_weight_threshold_high(&mut self, threshold: Grams) -> Result<(), Self::Error>;
+// }
+//
+// pub trait WeightHysteresis: WeightThresholdSet {
+//     async fn set_weight_threshold_hysteresis(&mut self, hysteresis: Grams) -> Result<(), Self::Error>;
+// }
+//
+// pub trait WeightThresholdControl: WeightThresholdSet {
+//     async fn disable_weight_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_weight_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait WeightThresholdWait: WeightThresholdSet {
+//     async fn wait_for_weight_threshold(&mut self) -> Result<ThresholdEvent<Grams>, Self::Error>;
+// }
+decl_threshold_traits!(async, Weight, WeightSensor, Grams, "grams");
+
+// This macro generates the `WeightAlert` adapter, which allows implementors of
+// `WeightThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(Weight, Grams);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Grams = 1_250.0;
+    const TEST_THRESHOLD_LOW: Grams = 0.0;
+    const TEST_THRESHOLD_HIGH: Grams = 5_000.0;
+    const TEST_INITIAL_THRESHOLD: Grams = 0.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncWeightSensor {
+        value: Grams,
+        threshold_low: Grams,
+        threshold_high: Grams,
+        offset: Grams,
+        scale_factor: f32,
+    }
+
+    impl MockAsyncWeightSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: TEST_INITIAL_THRESHOLD,
+                threshold_high: TEST_INITIAL_THRESHOLD,
+                offset: 0.0,
+                scale_factor: 1.0,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncWeightSensor {
+        type Error = MockError;
+    }
+
+    impl WeightSensor for MockAsyncWeightSensor {
+        async fn weight(&mut self) -> Result<Grams, Self::Error> {
+            Ok(self.value)
+        }
+
+        async fn tare(&mut self) -> Result<(), Self::Error> {
+            self.offset = self.value;
+            Ok(())
+        }
+
+        async fn set_scale_factor(&mut self, factor: f32) -> Result<(), Self::Error> {
+            self.scale_factor = factor;
+            Ok(())
+        }
+    }
+
+    impl WeightThresholdSet for MockAsyncWeightSensor {
+        async fn set_weight_threshold_low(&mut self, threshold: Grams) -> Result<(), Self::Error> {
+            self.threshold_low = threshold;
+            Ok(())
+        }
+
+        async fn set_weight_threshold_high(&mut self, threshold: Grams) -> Result<(), Self::Error> {
+            self.threshold_high = threshold;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_weight_sensor_trait() {
+        let mut sensor = MockAsyncWeightSensor::new();
+        let result = sensor.weight().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_weight_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncWeightSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.weight().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_weight_threshold_set_trait_mut_ref() {
+        let mut sensor = MockAsyncWeightSensor::new();
+
+        {
+            let mut_ref = &mut sensor;
+            let result_low = mut_ref.set_weight_threshold_low(TEST_THRESHOLD_LOW).await;
+            assert!(result_low.is_ok());
+            let result_high = mut_ref.set_weight_threshold_high(TEST_THRESHOLD_HIGH).await;
+            assert!(result_high.is_ok());
+        }
+
+        assert_approx_eq!(sensor.threshold_low, TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high, TEST_THRESHOLD_HIGH);
+    }
+
+    #[tokio::test]
+    async fn test_async_weight_calibration_mut_ref() {
+        let mut sensor = MockAsyncWeightSensor::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.tare().await.is_ok());
+        assert!(mut_ref.set_scale_factor(0.5).await.is_ok());
+        assert_approx_eq!(sensor.offset, TEST_VALUE);
+        assert_approx_eq!(sensor.scale_factor, 0.5);
+    }
+}
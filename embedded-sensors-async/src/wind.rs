@@ -0,0 +1,242 @@
+//! Async Wind Speed Sensor API
+//!
+//! This API provides generic methods for interfacing with wind speed sensors (anemometers) specifically.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the WindSpeedSensor and WindSpeedThresholdWait traits for a cup anemometer.
+//!
+//! ```
+//! use embedded_sensors_hal_async::sensor;
+//! use embedded_sensors_hal_async::wind::{
+//!     MetersPerSecond, WindSpeedHysteresis, WindSpeedSensor, WindSpeedThresholdSet, WindSpeedThresholdWait,
+//! };
+//!
+//! // A struct representing a cup anemometer.
+//! pub struct MyWindSpeedSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyWindSpeedSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl WindSpeedSensor for MyWindSpeedSensor {
+//!     async fn wind_speed(&mut self) -> Result<MetersPerSecond, Self::Error> {
+//!         // Convert pulse count over gate time to wind speed...
+//!         Ok(4.2)
+//!     }
+//! }
+//!
+//! impl WindSpeedThresholdSet for MyWindSpeedSensor {
+//!     async fn set_wind_speed_threshold_low(
+//!         &mut self,
+//!         threshold: MetersPerSecond
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     async fn set_wind_speed_threshold_high(
+//!         &mut self,
+//!         threshold: MetersPerSecond
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl WindSpeedThresholdWait for MyWindSpeedSensor {
+//!     async fn wait_for_wind_speed_threshold(
+//!         &mut self,
+//!     ) -> Result<sensor::ThresholdEvent<MetersPerSecond>, Self::Error> {
+//!         // Await threshold alert (e.g. await GPIO level change on ALERT pin)...
+//!         let value = self.wind_speed().await?;
+//!         // Then read alert status register of sensor to determine which threshold was crossed
+//!         // (see the `sensor` module documentation)...
+//!         Ok(sensor::ThresholdEvent::new(value, sensor::ThresholdKind::High))
+//!     }
+//! }
+//!
+//! impl WindSpeedHysteresis for MyWindSpeedSensor {
+//!     async fn set_wind_speed_threshold_hysteresis(
+//!         &mut self,
+//!         hysteresis: MetersPerSecond
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold hysteresis register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::alert::decl_alert_source;
+use crate::sensor::{decl_threshold_traits, impl_sensor_refinement, ErrorType, Sensor};
+pub use embedded_sensors_hal::wind::{MetersPerSecond, WindSpeed, WIND_SPEED_RANGE};
+
+/// Async Wind Speed Sensor methods.
+pub trait WindSpeedSensor: Sensor<WindSpeed, Sample = MetersPerSecond> {
+    /// Returns a wind speed sample in meters per second.
+    async fn wind_speed(&mut self) -> Result<MetersPerSecond, Self::Error>;
+}
+
+impl<T: WindSpeedSensor + ?Sized> WindSpeedSensor for &mut T {
+    #[inline]
+    async fn wind_speed(&mut self) -> Result<MetersPerSecond, Self::Error> {
+        T::wind_speed(self).await
+    }
+}
+
+impl_sensor_refinement!(
+    async,
+    WindSpeed,
+    WindSpeedSensor,
+    wind_speed,
+    MetersPerSecond
+);
+
+// This macro generates the following async threshold traits:
+//
+// pub trait WindSpeedThresholdSet: WindSpeedSensor {
+//     async fn set_wind_speed_threshold_low(&mut self, threshold: MetersPerSecond) -> Result<(), Self::Error>;
+//     async fn set_wind_speed_threshold_high(&mut self, threshold: MetersPerSecond) -> Result<(), Self::Error>;
+// }
+//
+// pub trait WindSpeedHysteresis: WindSpeedThresholdSet {
+//     async fn set_wind_speed_threshold_hysteresis(&mut self, hysteresis: MetersPerSecond) -> Result<(), Self::Error>;
+// }
+//
+// pub trait WindSpeedThresholdControl: WindSpeedThresholdSet {
+//     async fn disable_wind_speed_thresholds(&mut self) -> Result<(), Self::Error>;
+//     async fn clear_wind_speed_alert(&mut self) -> Result<(), Self::Error>;
+// }
+//
+// pub trait WindSpeedThresholdWait: WindSpeedThresholdSet {
+//     async fn wait_for_wind_speed_threshold(&mut self) -> Result<ThresholdEvent<MetersPerSecond>, Self::Error>;
+// }
+decl_threshold_traits!(
+    async,
+    WindSpeed,
+    WindSpeedSensor,
+    MetersPerSecond,
+    "meters per second"
+);
+
+// This macro generates the `WindSpeedAlert` adapter, which allows implementors of
+// `WindSpeedThresholdWait` to be multiplexed with other sensors by an `AlertMux`.
+decl_alert_source!(WindSpeed, MetersPerSecond);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: MetersPerSecond = 7.5;
+    const TEST_THRESHOLD_LOW: MetersPerSecond = 1.0;
+    const TEST_THRESHOLD_HIGH: MetersPerSecond = 25.0;
+    const TEST_INITIAL_THRESHOLD: MetersPerSecond = 0.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAsyncWindSpeedSensor {
+        value: MetersPerSecond,
+        threshold_low: MetersPerSecond,
+        threshold_high: MetersPerSecond,
+    }
+
+    impl MockAsyncWindSpeedSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: TEST_INITIAL_THRESHOLD,
+                threshold_high: TEST_INITIAL_THRESHOLD,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAsyncWindSpeedSensor {
+        type Error = MockError;
+    }
+
+    impl WindSpeedSensor for MockAsyncWindSpeedSensor {
+        async fn wind_speed(&mut self) -> Result<MetersPerSecond, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl WindSpeedThresholdSet for MockAsyncWindSpeedSensor {
+        async fn set_wind_speed_threshold_low(
+            &mut self,
+            threshold: MetersPerSecond,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = threshold;
+            Ok(())
+        }
+
+        async fn set_wind_speed_threshold_high(
+            &mut self,
+            threshold: MetersPerSecond,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = threshold;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_wind_sensor_trait() {
+        let mut sensor = MockAsyncWindSpeedSensor::new();
+        let result = sensor.wind_speed().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_wind_sensor_trait_mut_ref() {
+        let mut sensor = MockAsyncWindSpeedSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.wind_speed().await;
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[tokio::test]
+    async fn test_async_wind_threshold_set_trait_mut_ref() {
+        let mut sensor = MockAsyncWindSpeedSensor::new();
+
+        {
+            let mut_ref = &mut sensor;
+            let result_low = mut_ref
+                .set_wind_speed_threshold_low(TEST_THRESHOLD_LOW)
+                .await;
+            assert!(result_low.is_ok());
+            let result_high = mut_ref
+                .set_wind_speed_threshold_high(TEST_THRESHOLD_HIGH)
+                .await;
+            assert!(result_high.is_ok());
+        }
+
+        assert_approx_eq!(sensor.threshold_low, TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high, TEST_THRESHOLD_HIGH);
+    }
+}
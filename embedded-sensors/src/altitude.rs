@@ -0,0 +1,480 @@
+//! Blocking Altitude Sensor API
+//!
+//! This API provides generic methods for interfacing with altitude sensors specifically.
+//!
+//! Altitude is typically derived by barometric sensors from the measured pressure, which
+//! requires knowledge of the current pressure at sea level. Sensors implementing this API
+//! therefore allow the sea-level reference pressure used for the conversion to be configured.
+//!
+//! Drivers for targets without a floating-point unit can implement the
+//! [`FixedPointAltitudeSensor`] trait, reporting samples in integer millimeters and taking the
+//! reference pressure in integer millipascals, along with the fixed-point threshold and
+//! calibration traits built upon it.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the AltitudeSensor trait for a barometric altitude sensor.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::altitude::{AltitudeSensor, Meters, Pascals};
+//!
+//! // A struct representing a barometric altitude sensor.
+//! pub struct MyAltitudeSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyAltitudeSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl AltitudeSensor for MyAltitudeSensor {
+//!     fn altitude(&mut self) -> Result<Meters, Self::Error> {
+//!         // ...
+//!         Ok(120.0)
+//!     }
+//!
+//!     fn set_sea_level_pressure(&mut self, pressure: Pascals) -> Result<(), Self::Error> {
+//!         // Store reference pressure used by altitude conversion...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::frequency::Hertz;
+use crate::sensor::{AlertPolarity, ErrorType, OperatingMode, Sensor, ValidRange};
+use crate::{
+    decl_calibration_trait, decl_configuration_trait, decl_fixed_point_calibration_trait,
+    decl_fixed_point_threshold_traits, decl_sample_read_trait, decl_threshold_traits,
+    impl_sensor_refinement,
+};
+
+/// Associates the units altitude samples are measured in with the underlying data type.
+pub type Meters = f32;
+
+/// Associates the units the sea-level reference pressure is configured in with the underlying data type.
+pub type Pascals = f32;
+
+/// Associates the units fixed-point altitude samples are measured in with the underlying data type.
+pub type MilliMeters = i32;
+
+/// Associates the units the fixed-point sea-level reference pressure is configured in with the
+/// underlying data type.
+pub type MilliPascals = i32;
+
+/// Standard atmospheric pressure at sea level, used as the reference pressure until another is set.
+pub const STANDARD_SEA_LEVEL_PRESSURE: Pascals = 101_325.0;
+
+/// [`STANDARD_SEA_LEVEL_PRESSURE`] in millipascals, for fixed-point sensors.
+pub const STANDARD_SEA_LEVEL_PRESSURE_MILLI: MilliPascals = 101_325_000;
+
+/// Physically plausible range of altitude samples (from below the Dead Sea shore to the edge of space).
+pub const ALTITUDE_RANGE: ValidRange<Meters> = ValidRange::new(-1_000.0, 100_000.0);
+
+/// Configuration of a barometric pressure sensor.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AltitudeConfig {
+    /// Rate at which samples are converted in continuous mode, in hertz.
+    pub conversion_rate: Hertz,
+    /// Whether samples are converted continuously or on demand.
+    pub mode: OperatingMode,
+    /// Active level of the alert output.
+    pub alert_polarity: AlertPolarity,
+}
+
+impl AltitudeConfig {
+    /// Creates a new barometric pressure sensor configuration.
+    pub const fn new(
+        conversion_rate: Hertz,
+        mode: OperatingMode,
+        alert_polarity: AlertPolarity,
+    ) -> Self {
+        Self {
+            conversion_rate,
+            mode,
+            alert_polarity,
+        }
+    }
+}
+
+/// Marker type of the altitude quantity, of which every [`AltitudeSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Altitude;
+
+/// Blocking Altitude Sensor methods.
+pub trait AltitudeSensor: Sensor<Altitude, Sample = Meters> {
+    /// Returns an altitude sample in meters above sea level.
+    fn altitude(&mut self) -> Result<Meters, Self::Error>;
+
+    /// Set the sea-level reference pressure (in pascals) used to derive altitude.
+    fn set_sea_level_pressure(&mut self, pressure: Pascals) -> Result<(), Self::Error>;
+}
+
+impl<T: AltitudeSensor + ?Sized> AltitudeSensor for &mut T {
+    #[inline]
+    fn altitude(&mut self) -> Result<Meters, Self::Error> {
+        T::altitude(self)
+    }
+
+    #[inline]
+    fn set_sea_level_pressure(&mut self, pressure: Pascals) -> Result<(), Self::Error> {
+        T::set_sea_level_pressure(self, pressure)
+    }
+}
+
+impl_sensor_refinement!(blocking, Altitude, AltitudeSensor, altitude, Meters);
+
+/// Blocking fixed-point Altitude Sensor methods, for targets without a floating-point unit.
+pub trait FixedPointAltitudeSensor: ErrorType {
+    /// Returns an altitude sample in millimeters above sea level.
+    fn altitude_milli(&mut self) -> Result<MilliMeters, Self::Error>;
+
+    /// Set the sea-level reference pressure (in millipascals) used to derive altitude.
+    fn set_sea_level_pressure_milli(&mut self, pressure: MilliPascals) -> Result<(), Self::Error>;
+}
+
+impl<T: FixedPointAltitudeSensor + ?Sized> FixedPointAltitudeSensor for &mut T {
+    #[inline]
+    fn altitude_milli(&mut self) -> Result<MilliMeters, Self::Error> {
+        T::altitude_milli(self)
+    }
+
+    #[inline]
+    fn set_sea_level_pressure_milli(&mut self, pressure: MilliPascals) -> Result<(), Self::Error> {
+        T::set_sea_level_pressure_milli(self, pressure)
+    }
+}
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait AltitudeThresholdSet: AltitudeSensor {
+//     fn set_altitude_threshold_low(&mut self, threshold: Meters) -> Result<(), Self::Error>;
+//     fn set_altitude_threshold_high(&mut self, threshold: Meters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait AltitudeHysteresis: AltitudeThresholdSet {
+//     fn set_altitude_threshold_hysteresis(&mut self, hysteresis: Meters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait AltitudeThresholdControl: AltitudeThresholdSet {
+//     fn disable_altitude_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_altitude_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(blocking, Altitude, AltitudeSensor, Meters, "meters");
+
+// This macro generates the following blocking configuration trait:
+//
+// pub trait AltitudeConfigure: AltitudeSensor {
+//     fn set_altitude_config(&mut self, config: AltitudeConfig) -> Result<(), Self::Error>;
+// }
+decl_configuration_trait!(blocking, Altitude, AltitudeSensor, AltitudeConfig);
+
+// This macro generates the following blocking calibration trait:
+//
+// pub trait AltitudeCalibrate: AltitudeSensor {
+//     fn set_altitude_offset(&mut self, offset: Meters) -> Result<(), Self::Error>;
+//     fn set_altitude_gain(&mut self, gain: f32) -> Result<(), Self::Error>;
+// }
+decl_calibration_trait!(blocking, Altitude, AltitudeSensor, Meters, "meters");
+
+// This macro generates the following blocking sample read trait:
+//
+// pub trait AltitudeSampleRead: AltitudeSensor {
+//     fn altitude_sample(&mut self) -> Result<Sample<Meters>, Self::Error>;
+// }
+decl_sample_read_trait!(blocking, Altitude, AltitudeSensor, Meters);
+
+// This macro generates the following blocking fixed-point threshold traits:
+//
+// pub trait FixedPointAltitudeThresholdSet: FixedPointAltitudeSensor {
+//     fn set_altitude_threshold_low_milli(&mut self, threshold: MilliMeters) -> Result<(), Self::Error>;
+//     fn set_altitude_threshold_high_milli(&mut self, threshold: MilliMeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FixedPointAltitudeHysteresis: FixedPointAltitudeThresholdSet {
+//     fn set_altitude_threshold_hysteresis_milli(&mut self, hysteresis: MilliMeters) -> Result<(), Self::Error>;
+// }
+decl_fixed_point_threshold_traits!(blocking, Altitude, MilliMeters, "millimeters");
+
+// This macro generates the following blocking fixed-point calibration trait:
+//
+// pub trait FixedPointAltitudeCalibrate: FixedPointAltitudeSensor {
+//     fn set_altitude_offset_milli(&mut self, offset: MilliMeters) -> Result<(), Self::Error>;
+//     fn set_altitude_gain_milli(&mut self, gain: u32) -> Result<(), Self::Error>;
+// }
+decl_fixed_point_calibration_trait!(blocking, Altitude, MilliMeters, "millimeters");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test value
+    const TEST_VALUE: Meters = 350.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAltitudeSensor {
+        value: Meters,
+        threshold_low: Option<Meters>,
+        threshold_high: Option<Meters>,
+        hysteresis: Option<Meters>,
+        sea_level_pressure: Pascals,
+    }
+
+    impl MockAltitudeSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+                sea_level_pressure: STANDARD_SEA_LEVEL_PRESSURE,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockAltitudeSensor {
+        type Error = MockError;
+    }
+
+    impl AltitudeSensor for MockAltitudeSensor {
+        fn altitude(&mut self) -> Result<Meters, Self::Error> {
+            Ok(self.value)
+        }
+
+        fn set_sea_level_pressure(&mut self, pressure: Pascals) -> Result<(), Self::Error> {
+            self.sea_level_pressure = pressure;
+            Ok(())
+        }
+    }
+
+    impl AltitudeThresholdSet for MockAltitudeSensor {
+        fn set_altitude_threshold_low(&mut self, threshold: Meters) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_altitude_threshold_high(&mut self, threshold: Meters) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl AltitudeHysteresis for MockAltitudeSensor {
+        fn set_altitude_threshold_hysteresis(
+            &mut self,
+            hysteresis: Meters,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_altitude_sensor_trait() {
+        let mut sensor = MockAltitudeSensor::new();
+        let result = sensor.altitude();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_altitude_sensor_trait_mut_ref() {
+        let mut sensor = MockAltitudeSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.altitude();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_altitude_threshold_set_mut_ref() {
+        let mut sensor = MockAltitudeSensor::new();
+        let mut_ref = &mut sensor;
+        let low_threshold = 0.0;
+        let high_threshold = 3_000.0;
+
+        let result_low = mut_ref.set_altitude_threshold_low(low_threshold);
+        assert!(result_low.is_ok());
+
+        let result_high = mut_ref.set_altitude_threshold_high(high_threshold);
+        assert!(result_high.is_ok());
+
+        assert_approx_eq!(sensor.threshold_low.unwrap(), low_threshold);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), high_threshold);
+    }
+
+    #[test]
+    fn test_altitude_hysteresis_mut_ref() {
+        let mut sensor = MockAltitudeSensor::new();
+        let mut_ref = &mut sensor;
+        let hyst = 10.0;
+        let result = mut_ref.set_altitude_threshold_hysteresis(hyst);
+        assert!(result.is_ok());
+        assert_approx_eq!(sensor.hysteresis.unwrap(), hyst);
+    }
+
+    #[test]
+    fn test_altitude_range() {
+        assert!(ALTITUDE_RANGE.validate(TEST_VALUE).is_ok());
+        assert_eq!(
+            ALTITUDE_RANGE.validate(-2_000.0),
+            Err(ErrorKind::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_altitude_set_sea_level_pressure_mut_ref() {
+        let mut sensor = MockAltitudeSensor::new();
+        let mut_ref = &mut sensor;
+        let pressure = 100_900.0;
+        let result = mut_ref.set_sea_level_pressure(pressure);
+        assert!(result.is_ok());
+        assert_approx_eq!(sensor.sea_level_pressure, pressure);
+    }
+
+    struct MockConfigurableAltitudeSensor {
+        config: AltitudeConfig,
+    }
+
+    impl crate::sensor::ErrorType for MockConfigurableAltitudeSensor {
+        type Error = MockError;
+    }
+
+    impl AltitudeSensor for MockConfigurableAltitudeSensor {
+        fn altitude(&mut self) -> Result<Meters, Self::Error> {
+            Ok(TEST_VALUE)
+        }
+
+        fn set_sea_level_pressure(&mut self, _pressure: Pascals) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl AltitudeConfigure for MockConfigurableAltitudeSensor {
+        fn set_altitude_config(&mut self, config: AltitudeConfig) -> Result<(), Self::Error> {
+            self.config = config;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_altitude_configure_mut_ref() {
+        let mut sensor = MockConfigurableAltitudeSensor {
+            config: AltitudeConfig::default(),
+        };
+        assert_eq!(sensor.config.mode, OperatingMode::Continuous);
+        let config = AltitudeConfig::new(25.0, OperatingMode::OneShot, AlertPolarity::ActiveHigh);
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_altitude_config(config).is_ok());
+        assert_eq!(sensor.config, config);
+    }
+
+    struct MockFixedPointAltitudeSensor {
+        sea_level_pressure: MilliPascals,
+        threshold_low: MilliMeters,
+        threshold_high: MilliMeters,
+        offset: MilliMeters,
+        gain: u32,
+    }
+
+    impl crate::sensor::ErrorType for MockFixedPointAltitudeSensor {
+        type Error = MockError;
+    }
+
+    impl FixedPointAltitudeSensor for MockFixedPointAltitudeSensor {
+        fn altitude_milli(&mut self) -> Result<MilliMeters, Self::Error> {
+            Ok(350_000)
+        }
+
+        fn set_sea_level_pressure_milli(
+            &mut self,
+            pressure: MilliPascals,
+        ) -> Result<(), Self::Error> {
+            self.sea_level_pressure = pressure;
+            Ok(())
+        }
+    }
+
+    impl FixedPointAltitudeThresholdSet for MockFixedPointAltitudeSensor {
+        fn set_altitude_threshold_low_milli(
+            &mut self,
+            threshold: MilliMeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = threshold;
+            Ok(())
+        }
+
+        fn set_altitude_threshold_high_milli(
+            &mut self,
+            threshold: MilliMeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = threshold;
+            Ok(())
+        }
+    }
+
+    impl FixedPointAltitudeCalibrate for MockFixedPointAltitudeSensor {
+        fn set_altitude_offset_milli(&mut self, offset: MilliMeters) -> Result<(), Self::Error> {
+            self.offset = offset;
+            Ok(())
+        }
+
+        fn set_altitude_gain_milli(&mut self, gain: u32) -> Result<(), Self::Error> {
+            self.gain = gain;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_fixed_point_altitude_sensor_mut_ref() {
+        let mut sensor = MockFixedPointAltitudeSensor {
+            sea_level_pressure: STANDARD_SEA_LEVEL_PRESSURE_MILLI,
+            threshold_low: 0,
+            threshold_high: 0,
+            offset: 0,
+            gain: 1_000,
+        };
+
+        {
+            let mut_ref = &mut sensor;
+            assert_eq!(mut_ref.altitude_milli().unwrap(), 350_000);
+            assert!(mut_ref.set_sea_level_pressure_milli(100_900_000).is_ok());
+            assert!(mut_ref.set_altitude_threshold_low_milli(-500_000).is_ok());
+            assert!(mut_ref.set_altitude_threshold_high_milli(3_000_000).is_ok());
+            assert!(mut_ref.set_altitude_offset_milli(-1_250).is_ok());
+            assert!(mut_ref.set_altitude_gain_milli(998).is_ok());
+        }
+
+        assert_eq!(sensor.sea_level_pressure, 100_900_000);
+        assert_eq!(sensor.threshold_low, -500_000);
+        assert_eq!(sensor.threshold_high, 3_000_000);
+        assert_eq!(sensor.offset, -1_250);
+        assert_eq!(sensor.gain, 998);
+    }
+}
@@ -0,0 +1,230 @@
+//! Blocking Angle Sensor API
+//!
+//! This API provides generic methods for interfacing with angle (rotary position) sensors
+//! specifically, such as AS5600-class magnetic encoders, so that motor control code can use any
+//! of them as a position source.
+//!
+//! Sensors which also count full turns (either in hardware or in their driver) can additionally
+//! implement the [`MultiTurnAngleSensor`] trait.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the AngleSensor and MultiTurnAngleSensor traits
+//! for a magnetic encoder.
+//!
+//! ```
+//! use embedded_sensors_hal::angle::{AngleSensor, Degrees, MultiTurnAngleSensor};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a magnetic encoder.
+//! pub struct MyEncoder {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyEncoder {
+//!     type Error = Error;
+//! }
+//!
+//! impl AngleSensor for MyEncoder {
+//!     fn angle(&mut self) -> Result<Degrees, Self::Error> {
+//!         // Read 12-bit angle register of sensor and scale it to degrees...
+//!         let raw: u16 = 1024;
+//!         Ok(f32::from(raw) * 360.0 / 4096.0)
+//!     }
+//! }
+//!
+//! impl MultiTurnAngleSensor for MyEncoder {
+//!     fn turns(&mut self) -> Result<i32, Self::Error> {
+//!         // Read turn counter of sensor...
+//!         Ok(-2)
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+use crate::sensor::Sensor;
+use crate::sensor::ValidRange;
+
+pub use crate::inclination::Degrees;
+
+/// Range of absolute angle samples.
+///
+/// Samples are expected in `[0, 360)`; 360 itself is only included so that sensors rounding up
+/// just below a full turn are not rejected.
+pub const ANGLE_RANGE: ValidRange<Degrees> = ValidRange::new(0.0, 360.0);
+
+/// A rotary position made up of a number of full turns and an absolute angle.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RotaryPosition {
+    /// Number of full turns from the zero position, negative in the reverse direction.
+    pub turns: i32,
+    /// Absolute angle within the current turn in degrees, in `[0, 360)`.
+    pub angle: Degrees,
+}
+
+impl RotaryPosition {
+    /// Returns the position as a total angle from the zero position, in degrees.
+    pub fn total_degrees(&self) -> f32 {
+        self.turns as f32 * 360.0 + self.angle
+    }
+}
+
+/// Returns the shortest signed rotation from angle `from` to angle `to`, in degrees, in
+/// `(-180, 180]`.
+///
+/// Both angles are expected in `[0, 360)`. This is the usual way of computing the velocity of
+/// a shaft from successive absolute angle samples across the wrap at 360 degrees.
+///
+/// ```
+/// use embedded_sensors_hal::angle::angle_delta;
+///
+/// assert_eq!(angle_delta(350.0, 10.0), 20.0);
+/// assert_eq!(angle_delta(10.0, 350.0), -20.0);
+/// ```
+pub fn angle_delta(from: Degrees, to: Degrees) -> Degrees {
+    let delta = to - from;
+    if delta > 180.0 {
+        delta - 360.0
+    } else if delta <= -180.0 {
+        delta + 360.0
+    } else {
+        delta
+    }
+}
+
+/// Marker type of the angle quantity, of which every [`AngleSensor`] implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Angle;
+
+/// Blocking Angle Sensor methods.
+pub trait AngleSensor: Sensor<Angle, Sample = Degrees> {
+    /// Returns an absolute angle sample in degrees, in `[0, 360)`.
+    fn angle(&mut self) -> Result<Degrees, Self::Error>;
+}
+
+impl<T: AngleSensor + ?Sized> AngleSensor for &mut T {
+    #[inline]
+    fn angle(&mut self) -> Result<Degrees, Self::Error> {
+        T::angle(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Angle, AngleSensor, angle, Degrees);
+
+/// Marker type of the turn count quantity, of which every [`MultiTurnAngleSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Turns;
+
+/// Blocking Multi-Turn Angle Sensor methods.
+pub trait MultiTurnAngleSensor: AngleSensor + Sensor<Turns, Sample = i32> {
+    /// Returns the number of full turns from the zero position, negative in the reverse
+    /// direction.
+    fn turns(&mut self) -> Result<i32, Self::Error>;
+
+    /// Returns the rotary position, made up of the turn count and absolute angle.
+    ///
+    /// The default implementation reads the turn count, then the angle. Sensors which can latch
+    /// both at once should override it to avoid tearing across a turn boundary.
+    fn position(&mut self) -> Result<RotaryPosition, Self::Error> {
+        let turns = self.turns()?;
+        let angle = self.angle()?;
+        Ok(RotaryPosition { turns, angle })
+    }
+}
+
+impl<T: MultiTurnAngleSensor + ?Sized> MultiTurnAngleSensor for &mut T {
+    #[inline]
+    fn turns(&mut self) -> Result<i32, Self::Error> {
+        T::turns(self)
+    }
+
+    #[inline]
+    fn position(&mut self) -> Result<RotaryPosition, Self::Error> {
+        T::position(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Turns, MultiTurnAngleSensor, turns, i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_ANGLE: Degrees = 90.0;
+    const TEST_TURNS: i32 = -2;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockEncoder;
+
+    impl crate::sensor::ErrorType for MockEncoder {
+        type Error = MockError;
+    }
+
+    impl AngleSensor for MockEncoder {
+        fn angle(&mut self) -> Result<Degrees, Self::Error> {
+            Ok(TEST_ANGLE)
+        }
+    }
+
+    impl MultiTurnAngleSensor for MockEncoder {
+        fn turns(&mut self) -> Result<i32, Self::Error> {
+            Ok(TEST_TURNS)
+        }
+    }
+
+    #[test]
+    fn test_angle_sensor_trait() {
+        let mut sensor = MockEncoder;
+        let result = sensor.angle();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_ANGLE);
+    }
+
+    #[test]
+    fn test_multi_turn_angle_sensor_trait_mut_ref() {
+        let mut sensor = MockEncoder;
+        let mut_ref = &mut sensor;
+        let result = mut_ref.position();
+        assert!(result.is_ok());
+        let position = result.unwrap();
+        assert_eq!(position.turns, TEST_TURNS);
+        assert_approx_eq!(position.total_degrees(), -630.0);
+    }
+
+    #[test]
+    fn test_angle_delta() {
+        assert_approx_eq!(angle_delta(30.0, 75.0), 45.0);
+        assert_approx_eq!(angle_delta(359.0, 1.0), 2.0);
+        assert_approx_eq!(angle_delta(1.0, 359.0), -2.0);
+        assert_approx_eq!(angle_delta(0.0, 180.0), 180.0);
+        assert_approx_eq!(angle_delta(180.0, 0.0), 180.0);
+    }
+}
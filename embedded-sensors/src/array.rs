@@ -0,0 +1,475 @@
+//! Blocking Sensor Array API
+//!
+//! This module provides a [`SensorArray`] of `N` sensors of the same type (e.g. the thermistors
+//! of a battery pack), which are sampled together into an [`ArrayReading`] with aggregate
+//! minimum and maximum, and checked against per-sensor thresholds.
+//!
+//! [`SensorArray::read_all`] fails as soon as any sensor does, which suits arrays whose samples
+//! are only meaningful together. Where telemetry should keep flowing when one sensor on the bus
+//! dies, [`SensorArray::read_each`] instead samples every sensor into a [`PartialReading`]
+//! holding the result of each one.
+//!
+//! Sensors are given to the array as [`SampleSource`]s, so that arrays of any quantity share the
+//! same implementation.
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal::array::{ArrayError, SensorArray};
+//! use embedded_sensors_hal::source::TemperatureSource;
+//! use embedded_sensors_hal::temperature::TemperatureSensor;
+//!
+//! fn hottest_cell<S: TemperatureSensor>(cells: [S; 4]) -> Result<Option<usize>, ArrayError> {
+//!     let mut array = SensorArray::new(cells.map(TemperatureSource));
+//!     // The cell next to the charger runs hotter by design.
+//!     array.set_thresholds(0, 0.0, 55.0)?;
+//!     for cell in 1..4 {
+//!         array.set_thresholds(cell, 0.0, 45.0)?;
+//!     }
+//!
+//!     let reading = array.read_all()?;
+//!     for (cell, event) in array.crossings(&reading) {
+//!         // Report over- or under-temperature of the cell...
+//!         let _ = (cell, event);
+//!     }
+//!     Ok(reading.max().map(|(cell, _)| cell))
+//! }
+//! ```
+
+use crate::sensor::{Error, ErrorKind, ThresholdEvent, ThresholdKind};
+use crate::source::SampleSource;
+
+/// Samples taken together from every sensor of a [`SensorArray`], indexed by sensor.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ArrayReading<T, const N: usize> {
+    /// Sample of each sensor.
+    pub samples: [T; N],
+}
+
+impl<T: Copy + PartialOrd, const N: usize> ArrayReading<T, N> {
+    /// Returns the index and value of the smallest sample, or `None` if the array is empty.
+    ///
+    /// If several sensors report the smallest sample, the first one is returned.
+    pub fn min(&self) -> Option<(usize, T)> {
+        self.extremum(|sample, min| sample < min)
+    }
+
+    /// Returns the index and value of the largest sample, or `None` if the array is empty.
+    ///
+    /// If several sensors report the largest sample, the first one is returned.
+    pub fn max(&self) -> Option<(usize, T)> {
+        self.extremum(|sample, max| sample > max)
+    }
+
+    fn extremum(&self, better: impl Fn(T, T) -> bool) -> Option<(usize, T)> {
+        extremum(self.samples.iter().copied().enumerate(), better)
+    }
+}
+
+/// Results of sampling every sensor of a [`SensorArray`], indexed by sensor.
+///
+/// Unlike an [`ArrayReading`], a partial reading holds the samples of the sensors which
+/// succeeded even if others failed.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PartialReading<T, const N: usize> {
+    /// Sample of each sensor, or the kind of error it failed with.
+    pub samples: [Result<T, ErrorKind>; N],
+}
+
+impl<T: Copy + PartialOrd, const N: usize> PartialReading<T, N> {
+    /// Returns `true` if every sensor was sampled successfully.
+    pub fn is_complete(&self) -> bool {
+        self.samples.iter().all(Result::is_ok)
+    }
+
+    /// Returns the index and value of every successful sample, in order.
+    pub fn successes(&self) -> impl Iterator<Item = (usize, T)> + '_ {
+        self.samples
+            .iter()
+            .enumerate()
+            .filter_map(|(index, sample)| Some((index, *sample.as_ref().ok()?)))
+    }
+
+    /// Returns the index and kind of error of every failed sensor, in order.
+    pub fn failures(&self) -> impl Iterator<Item = (usize, ErrorKind)> + '_ {
+        self.samples
+            .iter()
+            .enumerate()
+            .filter_map(|(index, sample)| Some((index, *sample.as_ref().err()?)))
+    }
+
+    /// Returns the index and value of the smallest successful sample, or `None` if there is
+    /// none.
+    ///
+    /// If several sensors report the smallest sample, the first one is returned.
+    pub fn min(&self) -> Option<(usize, T)> {
+        extremum(self.successes(), |sample, min| sample < min)
+    }
+
+    /// Returns the index and value of the largest successful sample, or `None` if there is
+    /// none.
+    ///
+    /// If several sensors report the largest sample, the first one is returned.
+    pub fn max(&self) -> Option<(usize, T)> {
+        extremum(self.successes(), |sample, max| sample > max)
+    }
+
+    /// Converts into an [`ArrayReading`] if every sensor was sampled successfully.
+    ///
+    /// Otherwise, the first failed sensor is reported in the error.
+    pub fn complete(&self) -> Result<ArrayReading<T, N>, ArrayError>
+    where
+        T: Default,
+    {
+        let mut samples = [T::default(); N];
+        for (index, (sample, result)) in samples.iter_mut().zip(self.samples).enumerate() {
+            *sample = result.map_err(|kind| ArrayError { index, kind })?;
+        }
+
+        Ok(ArrayReading { samples })
+    }
+}
+
+fn extremum<T: Copy>(
+    samples: impl Iterator<Item = (usize, T)>,
+    better: impl Fn(T, T) -> bool,
+) -> Option<(usize, T)> {
+    samples.reduce(|best, next| if better(next.1, best.1) { next } else { best })
+}
+
+/// Error returned by a [`SensorArray`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ArrayError {
+    /// Index of the sensor the error relates to.
+    pub index: usize,
+    /// Kind of error.
+    pub kind: ErrorKind,
+}
+
+impl Error for ArrayError {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+/// Lower and upper thresholds of each sensor of a [`SensorArray`], indexed by sensor.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ArrayThresholds<T, const N: usize> {
+    thresholds: [Option<(T, T)>; N],
+}
+
+impl<T: Copy + PartialOrd, const N: usize> ArrayThresholds<T, N> {
+    /// Creates a new set of thresholds, none of which are set.
+    pub const fn new() -> Self {
+        Self {
+            thresholds: [None; N],
+        }
+    }
+
+    /// Set the lower and upper thresholds of the sensor at `index`.
+    ///
+    /// Fails with [`ErrorKind::InvalidInput`] if there is no sensor at `index`.
+    pub fn set(&mut self, index: usize, low: T, high: T) -> Result<(), ArrayError> {
+        let thresholds = self.thresholds.get_mut(index).ok_or(ArrayError {
+            index,
+            kind: ErrorKind::InvalidInput,
+        })?;
+        *thresholds = Some((low, high));
+        Ok(())
+    }
+
+    /// Remove the thresholds of the sensor at `index`, if any.
+    pub fn clear(&mut self, index: usize) {
+        if let Some(thresholds) = self.thresholds.get_mut(index) {
+            *thresholds = None;
+        }
+    }
+
+    /// Returns the lower and upper thresholds of the sensor at `index`, if set.
+    pub fn get(&self, index: usize) -> Option<(T, T)> {
+        self.thresholds.get(index).copied().flatten()
+    }
+
+    /// Returns the index and threshold event of every sample of `reading` beyond the thresholds
+    /// of its sensor, in order.
+    pub fn crossings<'a>(
+        &'a self,
+        reading: &'a ArrayReading<T, N>,
+    ) -> impl Iterator<Item = (usize, ThresholdEvent<T>)> + 'a {
+        self.crossings_of(reading.samples.iter().copied().enumerate())
+    }
+
+    /// Returns the index and threshold event of every successful sample of `reading` beyond the
+    /// thresholds of its sensor, in order.
+    pub fn partial_crossings<'a>(
+        &'a self,
+        reading: &'a PartialReading<T, N>,
+    ) -> impl Iterator<Item = (usize, ThresholdEvent<T>)> + 'a {
+        self.crossings_of(reading.successes())
+    }
+
+    fn crossings_of<'a>(
+        &'a self,
+        samples: impl Iterator<Item = (usize, T)> + 'a,
+    ) -> impl Iterator<Item = (usize, ThresholdEvent<T>)> + 'a {
+        samples.filter_map(|(index, sample)| {
+            let (low, high) = self.get(index)?;
+            let kind = if sample > high {
+                ThresholdKind::High
+            } else if sample < low {
+                ThresholdKind::Low
+            } else {
+                return None;
+            };
+            Some((index, ThresholdEvent::new(sample, kind)))
+        })
+    }
+}
+
+impl<T: Copy + PartialOrd, const N: usize> Default for ArrayThresholds<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An array of `N` sensors of the same type, sampled together.
+pub struct SensorArray<S: SampleSource, const N: usize> {
+    sources: [S; N],
+    thresholds: ArrayThresholds<S::Sample, N>,
+}
+
+impl<S: SampleSource, const N: usize> SensorArray<S, N>
+where
+    S::Sample: Copy + Default + PartialOrd,
+{
+    /// Creates a new array without thresholds.
+    pub fn new(sources: [S; N]) -> Self {
+        Self {
+            sources,
+            thresholds: ArrayThresholds::new(),
+        }
+    }
+
+    /// Set the lower and upper thresholds of the sensor at `index`.
+    ///
+    /// Fails with [`ErrorKind::InvalidInput`] if there is no sensor at `index`.
+    pub fn set_thresholds(
+        &mut self,
+        index: usize,
+        low: S::Sample,
+        high: S::Sample,
+    ) -> Result<(), ArrayError> {
+        self.thresholds.set(index, low, high)
+    }
+
+    /// Remove the thresholds of the sensor at `index`, if any.
+    pub fn clear_thresholds(&mut self, index: usize) {
+        self.thresholds.clear(index)
+    }
+
+    /// Returns the thresholds of every sensor.
+    pub fn thresholds(&self) -> &ArrayThresholds<S::Sample, N> {
+        &self.thresholds
+    }
+
+    /// Samples every sensor, in order.
+    ///
+    /// Sampling stops at the first sensor which fails, whose index is reported in the error.
+    pub fn read_all(&mut self) -> Result<ArrayReading<S::Sample, N>, ArrayError> {
+        let mut samples = [S::Sample::default(); N];
+        for (index, (sample, source)) in samples.iter_mut().zip(&mut self.sources).enumerate() {
+            *sample = source.sample().map_err(|kind| ArrayError { index, kind })?;
+        }
+
+        Ok(ArrayReading { samples })
+    }
+
+    /// Samples every sensor, in order, carrying on past sensors which fail.
+    pub fn read_each(&mut self) -> PartialReading<S::Sample, N> {
+        let mut samples = [Err(ErrorKind::Other); N];
+        for (sample, source) in samples.iter_mut().zip(&mut self.sources) {
+            *sample = source.sample();
+        }
+
+        PartialReading { samples }
+    }
+
+    /// Returns the index and threshold event of every sample of `reading` beyond the thresholds
+    /// of its sensor, in order.
+    pub fn crossings<'a>(
+        &'a self,
+        reading: &'a ArrayReading<S::Sample, N>,
+    ) -> impl Iterator<Item = (usize, ThresholdEvent<S::Sample>)> + 'a {
+        self.thresholds.crossings(reading)
+    }
+
+    /// Returns the index and threshold event of every successful sample of `reading` beyond the
+    /// thresholds of its sensor, in order.
+    pub fn partial_crossings<'a>(
+        &'a self,
+        reading: &'a PartialReading<S::Sample, N>,
+    ) -> impl Iterator<Item = (usize, ThresholdEvent<S::Sample>)> + 'a {
+        self.thresholds.partial_crossings(reading)
+    }
+
+    /// Returns a mutable reference to the sensor at `index`.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut S> {
+        self.sources.get_mut(index)
+    }
+
+    /// Consumes the array, returning the sensors.
+    pub fn into_inner(self) -> [S; N] {
+        self.sources
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::TemperatureSource;
+    use crate::temperature::{DegreesCelsius, TemperatureSensor};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_TEMPS: [DegreesCelsius; 4] = [31.0, 47.5, 28.0, 47.5];
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Peripheral
+        }
+    }
+
+    struct MockTempSensor {
+        value: Option<DegreesCelsius>,
+    }
+
+    impl crate::sensor::ErrorType for MockTempSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockTempSensor {
+        fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            self.value.ok_or(MockError)
+        }
+    }
+
+    fn mock_array() -> SensorArray<TemperatureSource<MockTempSensor>, 4> {
+        SensorArray::new(
+            TEST_TEMPS.map(|value| TemperatureSource(MockTempSensor { value: Some(value) })),
+        )
+    }
+
+    #[test]
+    fn test_sensor_array_read_all() {
+        let mut array = mock_array();
+        let result = array.read_all();
+        assert!(result.is_ok());
+        let reading = result.unwrap();
+        assert_eq!(reading.samples, TEST_TEMPS);
+
+        let (index, min) = reading.min().unwrap();
+        assert_eq!(index, 2);
+        assert_approx_eq!(min, 28.0);
+        let (index, max) = reading.max().unwrap();
+        assert_eq!(index, 1);
+        assert_approx_eq!(max, 47.5);
+    }
+
+    #[test]
+    fn test_sensor_array_error() {
+        let mut array = mock_array();
+        array.get_mut(2).unwrap().0.value = None;
+        assert_eq!(
+            array.read_all(),
+            Err(ArrayError {
+                index: 2,
+                kind: ErrorKind::Peripheral
+            })
+        );
+    }
+
+    #[test]
+    fn test_sensor_array_read_each() {
+        let mut array = mock_array();
+        array.get_mut(1).unwrap().0.value = None;
+        assert!(array.set_thresholds(2, 30.0, 45.0).is_ok());
+
+        let reading = array.read_each();
+        assert!(!reading.is_complete());
+        assert_eq!(reading.samples[1], Err(ErrorKind::Peripheral));
+        let mut failures = reading.failures();
+        assert_eq!(failures.next(), Some((1, ErrorKind::Peripheral)));
+        assert_eq!(failures.next(), None);
+        assert_eq!(reading.successes().count(), 3);
+        assert_eq!(
+            reading.complete(),
+            Err(ArrayError {
+                index: 1,
+                kind: ErrorKind::Peripheral
+            })
+        );
+
+        // Aggregates and crossings skip the failed sensor.
+        assert_eq!(reading.min(), Some((2, 28.0)));
+        assert_eq!(reading.max(), Some((3, 47.5)));
+        let mut crossings = array.partial_crossings(&reading);
+        assert_eq!(
+            crossings.next(),
+            Some((2, ThresholdEvent::new(28.0, ThresholdKind::Low)))
+        );
+        assert_eq!(crossings.next(), None);
+        drop(crossings);
+
+        array.get_mut(1).unwrap().0.value = Some(47.5);
+        let reading = array.read_each();
+        assert!(reading.is_complete());
+        assert_eq!(reading.complete(), array.read_all());
+    }
+
+    #[test]
+    fn test_sensor_array_crossings() {
+        let mut array = mock_array();
+        assert!(array.set_thresholds(0, 0.0, 55.0).is_ok());
+        assert!(array.set_thresholds(1, 0.0, 45.0).is_ok());
+        assert!(array.set_thresholds(2, 30.0, 45.0).is_ok());
+        assert_eq!(
+            array.set_thresholds(4, 0.0, 45.0),
+            Err(ArrayError {
+                index: 4,
+                kind: ErrorKind::InvalidInput
+            })
+        );
+
+        let reading = array.read_all().unwrap();
+        let mut crossings = array.crossings(&reading);
+        assert_eq!(
+            crossings.next(),
+            Some((1, ThresholdEvent::new(47.5, ThresholdKind::High)))
+        );
+        assert_eq!(
+            crossings.next(),
+            Some((2, ThresholdEvent::new(28.0, ThresholdKind::Low)))
+        );
+        assert_eq!(crossings.next(), None);
+        drop(crossings);
+
+        array.clear_thresholds(1);
+        assert_eq!(array.thresholds().get(1), None);
+        assert_eq!(array.crossings(&reading).count(), 1);
+    }
+
+    #[test]
+    fn test_empty_array_reading() {
+        let reading: ArrayReading<DegreesCelsius, 0> = ArrayReading { samples: [] };
+        assert_eq!(reading.min(), None);
+        assert_eq!(reading.max(), None);
+    }
+}
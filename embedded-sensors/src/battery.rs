@@ -0,0 +1,350 @@
+//! Blocking Battery Fuel Gauge API
+//!
+//! This API provides generic methods for interfacing with battery fuel gauges (e.g. MAX17048 or
+//! BQ27xxx) specifically, so that battery services can read the state of charge independently
+//! of the gauge vendor.
+//!
+//! The API is independent of the gauging algorithm, and is equally suited to voltage-based
+//! (ModelGauge) and coulomb-counting (Impedance Track) gauges.
+//!
+//! Smart gauges which also track the aging of the battery can additionally implement the
+//! [`StateOfHealthSensor`], [`FullChargeCapacitySensor`] and [`CycleCountSensor`] traits, used by
+//! battery services for reporting and end-of-life policies.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the StateOfChargeSensor trait for a fuel gauge.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::battery::{Percentage, StateOfChargeSensor};
+//!
+//! // A struct representing a fuel gauge.
+//! pub struct MyFuelGauge {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyFuelGauge {
+//!     type Error = Error;
+//! }
+//!
+//! impl StateOfChargeSensor for MyFuelGauge {
+//!     fn state_of_charge(&mut self) -> Result<Percentage, Self::Error> {
+//!         // Read state of charge register of gauge (1/256 % per bit)...
+//!         Ok(87.5)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{Sensor, ValidRange};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units state of charge samples are measured in with the underlying data type.
+pub type Percentage = f32;
+
+/// Associates the units battery capacities are measured in with the underlying data type.
+pub type MilliampHours = u32;
+
+/// Range of state of charge samples.
+pub const STATE_OF_CHARGE_RANGE: ValidRange<Percentage> = ValidRange::new(0.0, 100.0);
+
+/// Marker type of the state of charge quantity, of which every [`StateOfChargeSensor`] implementor
+/// is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct StateOfCharge;
+
+/// Blocking State of Charge Sensor methods.
+pub trait StateOfChargeSensor: Sensor<StateOfCharge, Sample = Percentage> {
+    /// Returns a state of charge sample as a percentage of the full capacity of the battery.
+    fn state_of_charge(&mut self) -> Result<Percentage, Self::Error>;
+}
+
+impl<T: StateOfChargeSensor + ?Sized> StateOfChargeSensor for &mut T {
+    #[inline]
+    fn state_of_charge(&mut self) -> Result<Percentage, Self::Error> {
+        T::state_of_charge(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    StateOfCharge,
+    StateOfChargeSensor,
+    state_of_charge,
+    Percentage
+);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait StateOfChargeThresholdSet: StateOfChargeSensor {
+//     fn set_state_of_charge_threshold_low(&mut self, threshold: Percentage) -> Result<(), Self::Error>;
+//     fn set_state_of_charge_threshold_high(&mut self, threshold: Percentage) -> Result<(), Self::Error>;
+// }
+//
+// pub trait StateOfChargeHysteresis: StateOfChargeThresholdSet {
+//     fn set_state_of_charge_threshold_hysteresis(&mut self, hysteresis: Percentage) -> Result<(), Self::Error>;
+// }
+//
+// pub trait StateOfChargeThresholdControl: StateOfChargeThresholdSet {
+//     fn disable_state_of_charge_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_state_of_charge_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(
+    blocking,
+    StateOfCharge,
+    StateOfChargeSensor,
+    Percentage,
+    "percent"
+);
+
+/// Marker type of the state of health quantity, of which every [`StateOfHealthSensor`] implementor
+/// is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct StateOfHealth;
+
+/// Blocking State of Health Sensor methods.
+pub trait StateOfHealthSensor: Sensor<StateOfHealth, Sample = Percentage> {
+    /// Returns a state of health sample as a percentage, i.e. the full charge capacity of the
+    /// battery relative to its design capacity.
+    fn state_of_health(&mut self) -> Result<Percentage, Self::Error>;
+}
+
+impl<T: StateOfHealthSensor + ?Sized> StateOfHealthSensor for &mut T {
+    #[inline]
+    fn state_of_health(&mut self) -> Result<Percentage, Self::Error> {
+        T::state_of_health(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    StateOfHealth,
+    StateOfHealthSensor,
+    state_of_health,
+    Percentage
+);
+
+/// Marker type of the full charge capacity quantity, of which every [`FullChargeCapacitySensor`]
+/// implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FullChargeCapacity;
+
+/// Blocking Full Charge Capacity Sensor methods.
+pub trait FullChargeCapacitySensor: Sensor<FullChargeCapacity, Sample = MilliampHours> {
+    /// Returns the capacity of the battery when fully charged, as learned by the gauge, in
+    /// milliamp-hours.
+    fn full_charge_capacity(&mut self) -> Result<MilliampHours, Self::Error>;
+}
+
+impl<T: FullChargeCapacitySensor + ?Sized> FullChargeCapacitySensor for &mut T {
+    #[inline]
+    fn full_charge_capacity(&mut self) -> Result<MilliampHours, Self::Error> {
+        T::full_charge_capacity(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    FullChargeCapacity,
+    FullChargeCapacitySensor,
+    full_charge_capacity,
+    MilliampHours
+);
+
+/// Marker type of the charge cycle count quantity, of which every [`CycleCountSensor`] implementor
+/// is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CycleCount;
+
+/// Blocking Cycle Count Sensor methods.
+pub trait CycleCountSensor: Sensor<CycleCount, Sample = u32> {
+    /// Returns the number of full charge-discharge cycles the battery has gone through.
+    fn cycle_count(&mut self) -> Result<u32, Self::Error>;
+}
+
+impl<T: CycleCountSensor + ?Sized> CycleCountSensor for &mut T {
+    #[inline]
+    fn cycle_count(&mut self) -> Result<u32, Self::Error> {
+        T::cycle_count(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, CycleCount, CycleCountSensor, cycle_count, u32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test value
+    const TEST_VALUE: Percentage = 87.5;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockFuelGauge {
+        value: Percentage,
+        threshold_low: Option<Percentage>,
+        threshold_high: Option<Percentage>,
+        hysteresis: Option<Percentage>,
+    }
+
+    impl MockFuelGauge {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockFuelGauge {
+        type Error = MockError;
+    }
+
+    impl StateOfChargeSensor for MockFuelGauge {
+        fn state_of_charge(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl StateOfChargeThresholdSet for MockFuelGauge {
+        fn set_state_of_charge_threshold_low(
+            &mut self,
+            threshold: Percentage,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_state_of_charge_threshold_high(
+            &mut self,
+            threshold: Percentage,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl StateOfChargeHysteresis for MockFuelGauge {
+        fn set_state_of_charge_threshold_hysteresis(
+            &mut self,
+            hysteresis: Percentage,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_battery_sensor_trait() {
+        let mut sensor = MockFuelGauge::new();
+        let result = sensor.state_of_charge();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_battery_sensor_trait_mut_ref() {
+        let mut sensor = MockFuelGauge::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.state_of_charge();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_battery_threshold_set_mut_ref() {
+        let mut sensor = MockFuelGauge::new();
+        let mut_ref = &mut sensor;
+        let low_threshold = 10.0;
+        let high_threshold = 95.0;
+
+        let result_low = mut_ref.set_state_of_charge_threshold_low(low_threshold);
+        assert!(result_low.is_ok());
+
+        let result_high = mut_ref.set_state_of_charge_threshold_high(high_threshold);
+        assert!(result_high.is_ok());
+
+        assert_approx_eq!(sensor.threshold_low.unwrap(), low_threshold);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), high_threshold);
+    }
+
+    #[test]
+    fn test_battery_hysteresis_mut_ref() {
+        let mut sensor = MockFuelGauge::new();
+        let mut_ref = &mut sensor;
+        let hyst = 0.5;
+        let result = mut_ref.set_state_of_charge_threshold_hysteresis(hyst);
+        assert!(result.is_ok());
+        assert_approx_eq!(sensor.hysteresis.unwrap(), hyst);
+    }
+
+    #[test]
+    fn test_battery_range() {
+        assert!(STATE_OF_CHARGE_RANGE.validate(TEST_VALUE).is_ok());
+        assert_eq!(
+            STATE_OF_CHARGE_RANGE.validate(-1.0),
+            Err(ErrorKind::OutOfRange)
+        );
+    }
+
+    struct MockSmartGauge;
+
+    impl crate::sensor::ErrorType for MockSmartGauge {
+        type Error = MockError;
+    }
+
+    impl StateOfHealthSensor for MockSmartGauge {
+        fn state_of_health(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(92.0)
+        }
+    }
+
+    impl FullChargeCapacitySensor for MockSmartGauge {
+        fn full_charge_capacity(&mut self) -> Result<MilliampHours, Self::Error> {
+            Ok(4_600)
+        }
+    }
+
+    impl CycleCountSensor for MockSmartGauge {
+        fn cycle_count(&mut self) -> Result<u32, Self::Error> {
+            Ok(312)
+        }
+    }
+
+    #[test]
+    fn test_battery_health_traits_mut_ref() {
+        let mut sensor = MockSmartGauge;
+        let mut_ref = &mut sensor;
+        assert_approx_eq!(mut_ref.state_of_health().unwrap(), 92.0);
+        assert_eq!(mut_ref.full_charge_capacity().unwrap(), 4_600);
+        assert_eq!(mut_ref.cycle_count().unwrap(), 312);
+    }
+}
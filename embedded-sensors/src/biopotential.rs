@@ -0,0 +1,216 @@
+//! Blocking Biopotential Sensor API
+//!
+//! This API provides generic methods for interfacing with biopotential analog front-ends (AFE)
+//! specifically, such as the ADS1292 or MAX30003, as used to acquire electrocardiogram (ECG) or
+//! electromyogram (EMG) signals.
+//!
+//! These front-ends produce continuous, high-rate data, so samples are read into a buffer rather
+//! than one at a time. Samples of multi-channel front-ends are interleaved, i.e. the buffer holds
+//! one sample per channel for each conversion, in channel order.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the BiopotentialSensor trait for an ECG
+//! front-end.
+//!
+//! ```
+//! use embedded_sensors_hal::biopotential::{BiopotentialSensor, LeadOffStatus, Microvolts};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a single channel ECG front-end.
+//! pub struct MyEcgFrontEnd {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyEcgFrontEnd {
+//!     type Error = Error;
+//! }
+//!
+//! impl BiopotentialSensor for MyEcgFrontEnd {
+//!     fn channel_count(&self) -> usize {
+//!         1
+//!     }
+//!
+//!     fn read_samples(&mut self, buf: &mut [Microvolts]) -> Result<usize, Self::Error> {
+//!         // Drain FIFO of front-end into buffer and scale samples by gain of channel...
+//!         Ok(0)
+//!     }
+//!
+//!     fn lead_off(&mut self) -> Result<LeadOffStatus, Self::Error> {
+//!         // Read lead-off status register of front-end...
+//!         Ok(LeadOffStatus::NONE)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+
+/// Associates the units biopotential samples are measured in with the underlying data type.
+pub type Microvolts = f32;
+
+/// Set of electrodes detected as disconnected by a biopotential front-end.
+///
+/// Bit `n` is set if electrode input `n` of the front-end is off, drivers documenting how their
+/// inputs are numbered.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LeadOffStatus(pub u16);
+
+impl LeadOffStatus {
+    /// All electrodes are connected.
+    pub const NONE: Self = Self(0);
+
+    /// Returns the status with only electrode input `n` off.
+    pub const fn electrode(n: u8) -> Self {
+        Self(1 << n)
+    }
+
+    /// Returns `true` if all electrodes are connected.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if every electrode off in `other` is off.
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the union of both sets of electrodes.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl core::ops::BitOr for LeadOffStatus {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+/// Blocking Biopotential Sensor methods.
+pub trait BiopotentialSensor: ErrorType {
+    /// Returns the number of channels whose samples are interleaved in the buffer.
+    fn channel_count(&self) -> usize;
+
+    /// Reads the available samples into `buf`, in microvolts, returning the number of samples
+    /// read.
+    ///
+    /// Only whole conversions are read, so the number of samples is a multiple of the
+    /// [channel count](Self::channel_count).
+    fn read_samples(&mut self, buf: &mut [Microvolts]) -> Result<usize, Self::Error>;
+
+    /// Returns the electrodes currently detected as disconnected.
+    fn lead_off(&mut self) -> Result<LeadOffStatus, Self::Error>;
+}
+
+impl<T: BiopotentialSensor + ?Sized> BiopotentialSensor for &mut T {
+    #[inline]
+    fn channel_count(&self) -> usize {
+        T::channel_count(self)
+    }
+
+    #[inline]
+    fn read_samples(&mut self, buf: &mut [Microvolts]) -> Result<usize, Self::Error> {
+        T::read_samples(self, buf)
+    }
+
+    #[inline]
+    fn lead_off(&mut self) -> Result<LeadOffStatus, Self::Error> {
+        T::lead_off(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_FIFO: [Microvolts; 6] = [120.0, -35.5, 840.25, 12.0, -410.0, 3.5];
+    const TEST_LEAD_OFF: LeadOffStatus = LeadOffStatus::electrode(1);
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    // Two channel front-end with three conversions in its FIFO
+    struct MockEcgFrontEnd {
+        read: usize,
+    }
+
+    impl crate::sensor::ErrorType for MockEcgFrontEnd {
+        type Error = MockError;
+    }
+
+    impl BiopotentialSensor for MockEcgFrontEnd {
+        fn channel_count(&self) -> usize {
+            2
+        }
+
+        fn read_samples(&mut self, buf: &mut [Microvolts]) -> Result<usize, Self::Error> {
+            let available = &TEST_FIFO[self.read..];
+            let len = available.len().min(buf.len() / 2 * 2);
+            buf[..len].copy_from_slice(&available[..len]);
+            self.read += len;
+            Ok(len)
+        }
+
+        fn lead_off(&mut self) -> Result<LeadOffStatus, Self::Error> {
+            Ok(TEST_LEAD_OFF)
+        }
+    }
+
+    #[test]
+    fn test_biopotential_sensor_trait() {
+        let mut sensor = MockEcgFrontEnd { read: 0 };
+        let mut buf = [0.0; 8];
+        let result = sensor.read_samples(&mut buf);
+        assert_eq!(result.unwrap(), TEST_FIFO.len());
+        for (sample, expected) in buf.iter().zip(TEST_FIFO.iter()) {
+            assert_approx_eq!(*sample, *expected);
+        }
+        assert!(sensor.lead_off().unwrap().contains(TEST_LEAD_OFF));
+    }
+
+    #[test]
+    fn test_biopotential_sensor_trait_mut_ref() {
+        let mut sensor = MockEcgFrontEnd { read: 0 };
+        let mut_ref = &mut sensor;
+        let mut buf = [0.0; 5];
+        assert_eq!(mut_ref.read_samples(&mut buf).unwrap(), 4);
+        assert_eq!(mut_ref.read_samples(&mut buf).unwrap(), 2);
+        assert_eq!(mut_ref.read_samples(&mut buf).unwrap(), 0);
+        assert_eq!(mut_ref.channel_count(), 2);
+    }
+
+    #[test]
+    fn test_lead_off_status() {
+        let status = LeadOffStatus::electrode(0) | LeadOffStatus::electrode(4);
+        assert!(!status.is_empty());
+        assert!(status.contains(LeadOffStatus::electrode(4)));
+        assert!(!status.contains(LeadOffStatus::electrode(1)));
+        assert!(LeadOffStatus::NONE.is_empty());
+    }
+}
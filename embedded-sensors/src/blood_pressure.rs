@@ -0,0 +1,154 @@
+//! Blocking Blood Pressure Monitor API
+//!
+//! This API provides generic methods for interfacing with blood pressure monitors specifically,
+//! such as oscillometric cuff modules.
+//!
+//! A measurement involves inflating and deflating the cuff and typically takes tens of seconds,
+//! so rather than reading the latest sample, the [`BloodPressureMonitor`] trait explicitly
+//! performs a measurement and returns the systolic and diastolic pressures along with the pulse
+//! rate measured during it.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the BloodPressureMonitor trait for a cuff module.
+//!
+//! ```
+//! use embedded_sensors_hal::blood_pressure::{BloodPressureMeasurement, BloodPressureMonitor};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a blood pressure cuff module.
+//! pub struct MyCuffModule {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyCuffModule {
+//!     type Error = Error;
+//! }
+//!
+//! impl BloodPressureMonitor for MyCuffModule {
+//!     fn measure(&mut self) -> Result<BloodPressureMeasurement, Self::Error> {
+//!         // Start pump, wait for oscillometric measurement to complete and deflate cuff...
+//!         Ok(BloodPressureMeasurement {
+//!             systolic: 118.0,
+//!             diastolic: 76.0,
+//!             pulse: 64.0,
+//!         })
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+
+/// Associates the units blood pressures are measured in with the underlying data type.
+pub type MillimetersOfMercury = f32;
+
+/// Associates the units pulse rates are measured in with the underlying data type.
+pub type BeatsPerMinute = f32;
+
+/// A blood pressure measurement.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BloodPressureMeasurement {
+    /// Systolic pressure in millimeters of mercury.
+    pub systolic: MillimetersOfMercury,
+    /// Diastolic pressure in millimeters of mercury.
+    pub diastolic: MillimetersOfMercury,
+    /// Pulse rate during the measurement in beats per minute.
+    pub pulse: BeatsPerMinute,
+}
+
+impl BloodPressureMeasurement {
+    /// Returns the estimated mean arterial pressure, i.e. the diastolic pressure plus a third of
+    /// the pulse pressure.
+    pub fn mean_arterial_pressure(&self) -> MillimetersOfMercury {
+        self.diastolic + (self.systolic - self.diastolic) / 3.0
+    }
+}
+
+/// Blocking Blood Pressure Monitor methods.
+pub trait BloodPressureMonitor: ErrorType {
+    /// Performs a measurement and returns the blood pressure and pulse rate measured.
+    ///
+    /// This blocks for the duration of the measurement, typically tens of seconds.
+    /// Implementations must deflate the cuff before returning, including when the measurement
+    /// fails.
+    fn measure(&mut self) -> Result<BloodPressureMeasurement, Self::Error>;
+}
+
+impl<T: BloodPressureMonitor + ?Sized> BloodPressureMonitor for &mut T {
+    #[inline]
+    fn measure(&mut self) -> Result<BloodPressureMeasurement, Self::Error> {
+        T::measure(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_MEASUREMENT: BloodPressureMeasurement = BloodPressureMeasurement {
+        systolic: 120.0,
+        diastolic: 81.0,
+        pulse: 64.0,
+    };
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockCuffMonitor {
+        inflated: bool,
+    }
+
+    impl crate::sensor::ErrorType for MockCuffMonitor {
+        type Error = MockError;
+    }
+
+    impl BloodPressureMonitor for MockCuffMonitor {
+        fn measure(&mut self) -> Result<BloodPressureMeasurement, Self::Error> {
+            self.inflated = true;
+            // ...
+            self.inflated = false;
+            Ok(TEST_MEASUREMENT)
+        }
+    }
+
+    #[test]
+    fn test_blood_pressure_monitor_trait() {
+        let mut sensor = MockCuffMonitor { inflated: false };
+        let result = sensor.measure();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_MEASUREMENT);
+        assert!(!sensor.inflated);
+    }
+
+    #[test]
+    fn test_blood_pressure_monitor_trait_mut_ref() {
+        let mut sensor = MockCuffMonitor { inflated: false };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.measure();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap().mean_arterial_pressure(), 94.0);
+    }
+}
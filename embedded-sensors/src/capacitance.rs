@@ -0,0 +1,216 @@
+//! Blocking Capacitance Sensor API
+//!
+//! This API provides generic methods for interfacing with capacitance-to-digital converters (CDC)
+//! specifically, such as the FDC1004 or AD7745, as used for liquid level sensing and capacitive
+//! proximity detection.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the CapacitanceSensor trait for a capacitance-to-
+//! digital converter.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::capacitance::{Picofarads, CapacitanceSensor};
+//!
+//! // A struct representing a capacitance-to-digital converter.
+//! pub struct MyCdc {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyCdc {
+//!     type Error = Error;
+//! }
+//!
+//! impl CapacitanceSensor for MyCdc {
+//!     fn capacitance(&mut self) -> Result<Picofarads, Self::Error> {
+//!         // Read measurement registers of converter (24-bit, 2^-19 pF per bit)...
+//!         Ok(4.7)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{Sensor, ValidRange};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units capacitance samples are measured in with the underlying data type.
+pub type Picofarads = f32;
+
+/// Physically plausible range of capacitance samples.
+pub const CAPACITANCE_RANGE: ValidRange<Picofarads> = ValidRange::new(0.0, Picofarads::MAX);
+
+/// Marker type of the capacitance quantity, of which every [`CapacitanceSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Capacitance;
+
+/// Blocking Capacitance Sensor methods.
+pub trait CapacitanceSensor: Sensor<Capacitance, Sample = Picofarads> {
+    /// Returns a capacitance sample in picofarads.
+    fn capacitance(&mut self) -> Result<Picofarads, Self::Error>;
+}
+
+impl<T: CapacitanceSensor + ?Sized> CapacitanceSensor for &mut T {
+    #[inline]
+    fn capacitance(&mut self) -> Result<Picofarads, Self::Error> {
+        T::capacitance(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    Capacitance,
+    CapacitanceSensor,
+    capacitance,
+    Picofarads
+);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait CapacitanceThresholdSet: CapacitanceSensor {
+//     fn set_capacitance_threshold_low(&mut self, threshold: Picofarads) -> Result<(), Self::Error>;
+//     fn set_capacitance_threshold_high(&mut self, threshold: Picofarads) -> Result<(), Self::Error>;
+// }
+//
+// pub trait CapacitanceHysteresis: CapacitanceThresholdSet {
+//     fn set_capacitance_threshold_hysteresis(&mut self, hysteresis: Picofarads) -> Result<(), Self::Error>;
+// }
+//
+// pub trait CapacitanceThresholdControl: CapacitanceThresholdSet {
+//     fn disable_capacitance_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_capacitance_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(
+    blocking,
+    Capacitance,
+    CapacitanceSensor,
+    Picofarads,
+    "picofarads"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Picofarads = 4.7;
+    const TEST_THRESHOLD_LOW: Picofarads = 1.0;
+    const TEST_THRESHOLD_HIGH: Picofarads = 12.0;
+    const TEST_HYSTERESIS: Picofarads = 0.2;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockCdc {
+        value: Picofarads,
+        threshold_low: Option<Picofarads>,
+        threshold_high: Option<Picofarads>,
+        hysteresis: Option<Picofarads>,
+    }
+
+    impl MockCdc {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockCdc {
+        type Error = MockError;
+    }
+
+    impl CapacitanceSensor for MockCdc {
+        fn capacitance(&mut self) -> Result<Picofarads, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl CapacitanceThresholdSet for MockCdc {
+        fn set_capacitance_threshold_low(
+            &mut self,
+            threshold: Picofarads,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_capacitance_threshold_high(
+            &mut self,
+            threshold: Picofarads,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl CapacitanceHysteresis for MockCdc {
+        fn set_capacitance_threshold_hysteresis(
+            &mut self,
+            hysteresis: Picofarads,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_capacitance_sensor_trait() {
+        let mut sensor = MockCdc::new();
+        let result = sensor.capacitance();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_capacitance_sensor_trait_mut_ref() {
+        let mut sensor = MockCdc::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.capacitance();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_capacitance_threshold_set_mut_ref() {
+        let mut sensor = MockCdc::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_capacitance_threshold_low(TEST_THRESHOLD_LOW)
+            .is_ok());
+        assert!(mut_ref
+            .set_capacitance_threshold_high(TEST_THRESHOLD_HIGH)
+            .is_ok());
+        assert!(mut_ref
+            .set_capacitance_threshold_hysteresis(TEST_HYSTERESIS)
+            .is_ok());
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), TEST_HYSTERESIS);
+    }
+}
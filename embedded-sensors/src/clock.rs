@@ -0,0 +1,125 @@
+//! Clock API
+//!
+//! This module provides the [`Clock`] trait, through which time-dependent adapters read the
+//! current time, along with a [`ManualClock`] whose time only advances when told to, allowing
+//! such adapters to be tested deterministically. The caching, staleness, debounce and rate-limit
+//! adapters of the [`timing`](crate::timing) module are built on it.
+//!
+//! [`Clock`] is implemented for any `Fn() -> u64` closure, so a clock can be built from any time
+//! source, such as `embassy-time` or a bare hardware timer.
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal::clock::{Clock, ManualClock};
+//!
+//! fn elapsed_us(clock: &impl Clock, since: u64) -> u64 {
+//!     clock.now_us().saturating_sub(since)
+//! }
+//!
+//! // With embassy-time, for example:
+//! // let clock = || embassy_time::Instant::now().as_micros();
+//! let clock = ManualClock::new();
+//! let start = clock.now_us();
+//! clock.advance_us(1_500);
+//! assert_eq!(elapsed_us(&clock, start), 1_500);
+//! ```
+
+use core::cell::Cell;
+
+/// A monotonic time source.
+pub trait Clock {
+    /// Returns the current time in microseconds, relative to an arbitrary epoch.
+    ///
+    /// Successive calls must never return a smaller value.
+    fn now_us(&self) -> u64;
+}
+
+impl<F: Fn() -> u64> Clock for F {
+    #[inline]
+    fn now_us(&self) -> u64 {
+        self()
+    }
+}
+
+/// A clock whose time only advances when explicitly told to.
+///
+/// Adapters take their clock by value, so a manual clock is usually lent to them by reference
+/// and advanced by the test in between operations.
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    now_us: Cell<u64>,
+}
+
+impl ManualClock {
+    /// Creates a new manual clock starting at time zero.
+    pub const fn new() -> Self {
+        Self {
+            now_us: Cell::new(0),
+        }
+    }
+
+    /// Advances the clock by the given number of microseconds.
+    pub fn advance_us(&self, us: u64) {
+        self.now_us.set(self.now_us.get().saturating_add(us));
+    }
+
+    /// Advances the clock by the given number of milliseconds.
+    pub fn advance_ms(&self, ms: u64) {
+        self.advance_us(ms.saturating_mul(1_000));
+    }
+
+    /// Sets the current time, in microseconds.
+    ///
+    /// The new time must not be earlier than the current time.
+    pub fn set_us(&self, us: u64) {
+        self.now_us.set(us);
+    }
+}
+
+impl Clock for ManualClock {
+    #[inline]
+    fn now_us(&self) -> u64 {
+        self.now_us.get()
+    }
+}
+
+impl Clock for &ManualClock {
+    #[inline]
+    fn now_us(&self) -> u64 {
+        self.now_us.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock() {
+        let clock = ManualClock::new();
+        assert_eq!(clock.now_us(), 0);
+
+        clock.advance_us(250);
+        clock.advance_ms(2);
+        assert_eq!(clock.now_us(), 2_250);
+
+        // Clocks are usually lent to adapters, which take them by value.
+        fn lend<C: Clock>(clock: C) -> u64 {
+            clock.now_us()
+        }
+        clock.set_us(10_000);
+        assert_eq!(lend(&clock), 10_000);
+    }
+
+    #[test]
+    fn test_closure_clock() {
+        let ticks = Cell::new(0);
+        let clock = || {
+            ticks.set(ticks.get() + 1);
+            ticks.get() * 100
+        };
+        assert_eq!(clock.now_us(), 100);
+        assert_eq!(clock.now_us(), 200);
+    }
+}
@@ -0,0 +1,326 @@
+//! Blocking Color Sensor API
+//!
+//! This API provides generic methods for interfacing with color (RGBC) sensors specifically.
+//!
+//! Color sensors report several channels per sample, so samples are represented by the
+//! [`Rgbc`] struct rather than a single scalar. Derived quantities such as correlated color
+//! temperature and illuminance are exposed through separate traits since not every part
+//! (or driver) is able to provide them.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the ColorSensor and CorrelatedColorTemperature
+//! traits for a color sensor.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::color::{ColorSensor, CorrelatedColorTemperature, Kelvin, Rgbc};
+//!
+//! // A struct representing a color sensor.
+//! pub struct MyColorSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyColorSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl ColorSensor for MyColorSensor {
+//!     fn color(&mut self) -> Result<Rgbc, Self::Error> {
+//!         // Read channel data registers of sensor...
+//!         Ok(Rgbc {
+//!             red: 100,
+//!             green: 200,
+//!             blue: 50,
+//!             clear: 400,
+//!         })
+//!     }
+//! }
+//!
+//! impl CorrelatedColorTemperature for MyColorSensor {
+//!     fn correlated_color_temperature(&mut self) -> Result<Kelvin, Self::Error> {
+//!         // Derive CCT from channel data using part-specific coefficients...
+//!         Ok(4000.0)
+//!     }
+//! }
+//! ```
+
+use crate::frequency::Hertz;
+use crate::sensor::{AlertPolarity, OperatingMode, Sensor, ValidRange};
+use crate::{decl_configuration_trait, impl_sensor_refinement};
+
+/// Associates the units correlated color temperature samples are measured in with the underlying data type.
+///
+/// Unrelated to the [`temperature::Kelvin`](crate::temperature::Kelvin) wrapper, from which it is
+/// distinguished by its module path.
+pub type Kelvin = f32;
+
+/// Associates the units illuminance samples are measured in with the underlying data type.
+pub type Lux = f32;
+
+/// Physically plausible range of correlated color temperature samples.
+pub const CORRELATED_COLOR_TEMPERATURE_RANGE: ValidRange<Kelvin> =
+    ValidRange::new(0.0, Kelvin::MAX);
+
+/// Physically plausible range of illuminance samples.
+pub const ILLUMINANCE_RANGE: ValidRange<Lux> = ValidRange::new(0.0, Lux::MAX);
+
+/// A single color sample consisting of raw red, green, blue and clear channel counts.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Rgbc {
+    /// Red channel count.
+    pub red: u16,
+    /// Green channel count.
+    pub green: u16,
+    /// Blue channel count.
+    pub blue: u16,
+    /// Clear (unfiltered) channel count.
+    pub clear: u16,
+}
+
+/// Configuration of a color (light) sensor.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ColorConfig {
+    /// Rate at which samples are converted in continuous mode, in hertz.
+    pub conversion_rate: Hertz,
+    /// Whether samples are converted continuously or on demand.
+    pub mode: OperatingMode,
+    /// Active level of the alert output.
+    pub alert_polarity: AlertPolarity,
+    /// Time over which the channels integrate light for each sample, in microseconds.
+    pub integration_time_us: u32,
+}
+
+impl ColorConfig {
+    /// Creates a new color sensor configuration.
+    pub const fn new(
+        conversion_rate: Hertz,
+        mode: OperatingMode,
+        alert_polarity: AlertPolarity,
+        integration_time_us: u32,
+    ) -> Self {
+        Self {
+            conversion_rate,
+            mode,
+            alert_polarity,
+            integration_time_us,
+        }
+    }
+}
+
+/// Marker type of the color quantity, of which every [`ColorSensor`] implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Color;
+
+/// Blocking Color Sensor methods.
+pub trait ColorSensor: Sensor<Color, Sample = Rgbc> {
+    /// Returns a sample of the red, green, blue and clear channels.
+    fn color(&mut self) -> Result<Rgbc, Self::Error>;
+}
+
+impl<T: ColorSensor + ?Sized> ColorSensor for &mut T {
+    #[inline]
+    fn color(&mut self) -> Result<Rgbc, Self::Error> {
+        T::color(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Color, ColorSensor, color, Rgbc);
+
+/// Marker type of the correlated color temperature quantity, of which every
+/// [`CorrelatedColorTemperature`] implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ColorTemperature;
+
+/// Blocking Correlated Color Temperature methods for color sensors able to derive it.
+pub trait CorrelatedColorTemperature:
+    ColorSensor + Sensor<ColorTemperature, Sample = Kelvin>
+{
+    /// Returns the correlated color temperature (CCT) in kelvin.
+    fn correlated_color_temperature(&mut self) -> Result<Kelvin, Self::Error>;
+}
+
+impl<T: CorrelatedColorTemperature + ?Sized> CorrelatedColorTemperature for &mut T {
+    #[inline]
+    fn correlated_color_temperature(&mut self) -> Result<Kelvin, Self::Error> {
+        T::correlated_color_temperature(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    ColorTemperature,
+    CorrelatedColorTemperature,
+    correlated_color_temperature,
+    Kelvin
+);
+
+/// Marker type of the illuminance quantity, of which every [`ColorIlluminance`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Illuminance;
+
+/// Blocking Illuminance methods for color sensors able to derive it.
+pub trait ColorIlluminance: ColorSensor + Sensor<Illuminance, Sample = Lux> {
+    /// Returns the illuminance in lux.
+    fn illuminance(&mut self) -> Result<Lux, Self::Error>;
+}
+
+impl<T: ColorIlluminance + ?Sized> ColorIlluminance for &mut T {
+    #[inline]
+    fn illuminance(&mut self) -> Result<Lux, Self::Error> {
+        T::illuminance(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Illuminance, ColorIlluminance, illuminance, Lux);
+
+// This macro generates the following blocking configuration trait:
+//
+// pub trait ColorConfigure: ColorSensor {
+//     fn set_color_config(&mut self, config: ColorConfig) -> Result<(), Self::Error>;
+// }
+decl_configuration_trait!(blocking, Color, ColorSensor, ColorConfig);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_COLOR: Rgbc = Rgbc {
+        red: 120,
+        green: 240,
+        blue: 60,
+        clear: 480,
+    };
+    const TEST_CCT: Kelvin = 5000.0;
+    const TEST_LUX: Lux = 320.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockColorSensor {
+        value: Rgbc,
+    }
+
+    impl crate::sensor::ErrorType for MockColorSensor {
+        type Error = MockError;
+    }
+
+    impl ColorSensor for MockColorSensor {
+        fn color(&mut self) -> Result<Rgbc, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl CorrelatedColorTemperature for MockColorSensor {
+        fn correlated_color_temperature(&mut self) -> Result<Kelvin, Self::Error> {
+            Ok(TEST_CCT)
+        }
+    }
+
+    impl ColorIlluminance for MockColorSensor {
+        fn illuminance(&mut self) -> Result<Lux, Self::Error> {
+            Ok(TEST_LUX)
+        }
+    }
+
+    #[test]
+    fn test_color_sensor_trait() {
+        let mut sensor = MockColorSensor { value: TEST_COLOR };
+        let result = sensor.color();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_COLOR);
+    }
+
+    #[test]
+    fn test_color_sensor_trait_mut_ref() {
+        let mut sensor = MockColorSensor { value: TEST_COLOR };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.color();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_COLOR);
+    }
+
+    #[test]
+    fn test_correlated_color_temperature() {
+        let mut sensor = MockColorSensor { value: TEST_COLOR };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.correlated_color_temperature();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_CCT);
+    }
+
+    #[test]
+    fn test_color_illuminance() {
+        let mut sensor = MockColorSensor { value: TEST_COLOR };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.illuminance();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_LUX);
+    }
+
+    struct MockConfigurableColorSensor {
+        config: ColorConfig,
+    }
+
+    impl crate::sensor::ErrorType for MockConfigurableColorSensor {
+        type Error = MockError;
+    }
+
+    impl ColorSensor for MockConfigurableColorSensor {
+        fn color(&mut self) -> Result<Rgbc, Self::Error> {
+            Ok(TEST_COLOR)
+        }
+    }
+
+    impl ColorConfigure for MockConfigurableColorSensor {
+        fn set_color_config(&mut self, config: ColorConfig) -> Result<(), Self::Error> {
+            self.config = config;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_color_configure_mut_ref() {
+        let mut sensor = MockConfigurableColorSensor {
+            config: ColorConfig::default(),
+        };
+        assert_eq!(sensor.config.mode, OperatingMode::Continuous);
+        let config = ColorConfig::new(
+            2.5,
+            OperatingMode::OneShot,
+            AlertPolarity::ActiveHigh,
+            100_000,
+        );
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_color_config(config).is_ok());
+        assert_eq!(sensor.config, config);
+    }
+}
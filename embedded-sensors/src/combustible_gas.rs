@@ -0,0 +1,229 @@
+//! Blocking Combustible Gas Sensor API
+//!
+//! This API provides generic methods for interfacing with combustible gas sensors specifically,
+//! such as catalytic bead and MEMS pellistor sensors used in industrial safety devices.
+//!
+//! Concentrations are reported as a percentage of the lower explosive limit (LEL) of the gas the
+//! sensor is calibrated for, which is how gas detector alarm levels are specified (e.g. 10 % and 20
+//! % LEL). Alarm levels are configured with the lower explosive limit threshold traits.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the CombustibleGasSensor trait for a pellistor.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::combustible_gas::{PercentLel, CombustibleGasSensor};
+//!
+//! // A struct representing a pellistor.
+//! pub struct MyPellistor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyPellistor {
+//!     type Error = Error;
+//! }
+//!
+//! impl CombustibleGasSensor for MyPellistor {
+//!     fn lower_explosive_limit(&mut self) -> Result<PercentLel, Self::Error> {
+//!         // Read bridge voltage and scale by span calibration...
+//!         Ok(4.5)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{Sensor, ValidRange};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units combustible gas concentrations are measured in (percentage of the lower
+/// explosive limit) with the underlying data type.
+pub type PercentLel = f32;
+
+/// Range of combustible gas concentration samples.
+pub const LOWER_EXPLOSIVE_LIMIT_RANGE: ValidRange<PercentLel> = ValidRange::new(0.0, 100.0);
+
+/// Marker type of the combustible gas concentration quantity, of which every
+/// [`CombustibleGasSensor`] implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CombustibleGas;
+
+/// Blocking Combustible Gas Sensor methods.
+pub trait CombustibleGasSensor: Sensor<CombustibleGas, Sample = PercentLel> {
+    /// Returns a combustible gas concentration sample as a percentage of the lower explosive
+    /// limit.
+    fn lower_explosive_limit(&mut self) -> Result<PercentLel, Self::Error>;
+}
+
+impl<T: CombustibleGasSensor + ?Sized> CombustibleGasSensor for &mut T {
+    #[inline]
+    fn lower_explosive_limit(&mut self) -> Result<PercentLel, Self::Error> {
+        T::lower_explosive_limit(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    CombustibleGas,
+    CombustibleGasSensor,
+    lower_explosive_limit,
+    PercentLel
+);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait LowerExplosiveLimitThresholdSet: CombustibleGasSensor {
+//     fn set_lower_explosive_limit_threshold_low(&mut self, threshold: PercentLel) -> Result<(), Self::Error>;
+//     fn set_lower_explosive_limit_threshold_high(&mut self, threshold: PercentLel) -> Result<(), Self::Error>;
+// }
+//
+// pub trait LowerExplosiveLimitHysteresis: LowerExplosiveLimitThresholdSet {
+//     fn set_lower_explosive_limit_threshold_hysteresis(&mut self, hysteresis: PercentLel) -> Result<(), Self::Error>;
+// }
+//
+// pub trait LowerExplosiveLimitThresholdControl: LowerExplosiveLimitThresholdSet {
+//     fn disable_lower_explosive_limit_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_lower_explosive_limit_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(
+    blocking,
+    LowerExplosiveLimit,
+    CombustibleGasSensor,
+    PercentLel,
+    "percent of the lower explosive limit"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: PercentLel = 4.5;
+    const TEST_THRESHOLD_LOW: PercentLel = 10.0;
+    const TEST_THRESHOLD_HIGH: PercentLel = 20.0;
+    const TEST_HYSTERESIS: PercentLel = 1.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockPellistor {
+        value: PercentLel,
+        threshold_low: Option<PercentLel>,
+        threshold_high: Option<PercentLel>,
+        hysteresis: Option<PercentLel>,
+    }
+
+    impl MockPellistor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockPellistor {
+        type Error = MockError;
+    }
+
+    impl CombustibleGasSensor for MockPellistor {
+        fn lower_explosive_limit(&mut self) -> Result<PercentLel, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl LowerExplosiveLimitThresholdSet for MockPellistor {
+        fn set_lower_explosive_limit_threshold_low(
+            &mut self,
+            threshold: PercentLel,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_lower_explosive_limit_threshold_high(
+            &mut self,
+            threshold: PercentLel,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl LowerExplosiveLimitHysteresis for MockPellistor {
+        fn set_lower_explosive_limit_threshold_hysteresis(
+            &mut self,
+            hysteresis: PercentLel,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_combustible_gas_sensor_trait() {
+        let mut sensor = MockPellistor::new();
+        let result = sensor.lower_explosive_limit();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_combustible_gas_sensor_trait_mut_ref() {
+        let mut sensor = MockPellistor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.lower_explosive_limit();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_combustible_gas_threshold_set_mut_ref() {
+        let mut sensor = MockPellistor::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_lower_explosive_limit_threshold_low(TEST_THRESHOLD_LOW)
+            .is_ok());
+        assert!(mut_ref
+            .set_lower_explosive_limit_threshold_high(TEST_THRESHOLD_HIGH)
+            .is_ok());
+        assert!(mut_ref
+            .set_lower_explosive_limit_threshold_hysteresis(TEST_HYSTERESIS)
+            .is_ok());
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), TEST_HYSTERESIS);
+    }
+
+    #[test]
+    fn test_combustible_gas_range() {
+        assert!(LOWER_EXPLOSIVE_LIMIT_RANGE.validate(TEST_VALUE).is_ok());
+        assert_eq!(
+            LOWER_EXPLOSIVE_LIMIT_RANGE.validate(120.0),
+            Err(ErrorKind::OutOfRange)
+        );
+    }
+}
@@ -0,0 +1,258 @@
+//! Blocking Thermal Derating API
+//!
+//! This module provides a [`ThermalDerating`] component which maps the samples of a temperature
+//! sensor through a [`DeratingCurve`] into a limit, such as the maximum charge current of a
+//! battery charger or the minimum duty cycle of a fan.
+//!
+//! A derating curve is a table of [`Breakpoint`]s sorted by increasing temperature. Limits are
+//! linearly interpolated between breakpoints and held constant beyond the first and last ones.
+//! A step in the curve is expressed by two breakpoints at the same temperature. Curves whose
+//! breakpoints are not sorted have no limit at any temperature, and neither has a NaN
+//! temperature. Missing or non-finite limits are reported as [`DeratingError::InvalidSample`].
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal::derating::{
+//!     Breakpoint, DeratingCurve, DeratingError, ThermalDerating,
+//! };
+//! use embedded_sensors_hal::temperature::TemperatureSensor;
+//!
+//! // Maximum charge current in milliamps: none below 0 °C, full from 10 °C to 45 °C, reduced
+//! // linearly to none at 60 °C.
+//! const CHARGE_CURRENT: DeratingCurve<5> = DeratingCurve::new([
+//!     Breakpoint::new(0.0, 0.0),
+//!     Breakpoint::new(0.0, 500.0),
+//!     Breakpoint::new(10.0, 2_000.0),
+//!     Breakpoint::new(45.0, 2_000.0),
+//!     Breakpoint::new(60.0, 0.0),
+//! ]);
+//!
+//! fn max_charge_current<S: TemperatureSensor>(
+//!     battery: S,
+//! ) -> Result<f32, DeratingError<S::Error>> {
+//!     let mut derating = ThermalDerating::new(battery, CHARGE_CURRENT);
+//!     derating.limit()
+//! }
+//! ```
+
+use crate::sensor::{checked_sample, Error, ErrorKind};
+use crate::temperature::{DegreesCelsius, TemperatureSensor};
+
+/// A point of a [`DeratingCurve`].
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Breakpoint {
+    /// Temperature of the breakpoint.
+    pub temperature: DegreesCelsius,
+    /// Limit at that temperature.
+    pub limit: f32,
+}
+
+impl Breakpoint {
+    /// Creates a new breakpoint.
+    pub const fn new(temperature: DegreesCelsius, limit: f32) -> Self {
+        Self { temperature, limit }
+    }
+}
+
+/// A piecewise-linear curve mapping temperatures to limits, made of `N` breakpoints.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DeratingCurve<const N: usize> {
+    breakpoints: [Breakpoint; N],
+}
+
+impl<const N: usize> DeratingCurve<N> {
+    /// Creates a new derating curve.
+    ///
+    /// `breakpoints` must be sorted by increasing temperature, and there must be at least one.
+    /// The order is checked by [`evaluate`](Self::evaluate), which returns `None` for curves whose
+    /// breakpoints are not sorted.
+    pub const fn new(breakpoints: [Breakpoint; N]) -> Self {
+        const { assert!(N >= 1, "a derating curve needs at least one breakpoint") };
+
+        Self { breakpoints }
+    }
+
+    /// Returns the breakpoints of the curve.
+    pub fn breakpoints(&self) -> &[Breakpoint; N] {
+        &self.breakpoints
+    }
+
+    /// Returns the limit at the given temperature, or `None` if the temperature is NaN or the
+    /// breakpoints of the curve are not sorted by increasing temperature.
+    pub fn evaluate(&self, temperature: DegreesCelsius) -> Option<f32> {
+        let sorted = self
+            .breakpoints
+            .windows(2)
+            .all(|pair| pair[0].temperature <= pair[1].temperature);
+        if temperature.is_nan() || !sorted {
+            return None;
+        }
+
+        let first = self.breakpoints[0];
+        if temperature < first.temperature {
+            return Some(first.limit);
+        }
+
+        for pair in self.breakpoints.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            // Previous segments ended at or below the temperature, so `from` does too.
+            if temperature < to.temperature {
+                let ratio = (temperature - from.temperature) / (to.temperature - from.temperature);
+                return Some(from.limit + ratio * (to.limit - from.limit));
+            }
+        }
+
+        Some(self.breakpoints[N - 1].limit)
+    }
+}
+
+/// Error returned by a [`ThermalDerating`] component.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DeratingError<E> {
+    /// The temperature sensor failed.
+    Sensor(E),
+    /// No finite limit could be derived (e.g. from a NaN sample or an unsorted curve).
+    InvalidSample,
+}
+
+impl<E: Error> Error for DeratingError<E> {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Sensor(e) => e.kind(),
+            Self::InvalidSample => ErrorKind::InvalidInput,
+        }
+    }
+}
+
+/// Derives a limit from the samples of a temperature sensor through a [`DeratingCurve`].
+pub struct ThermalDerating<S, const N: usize> {
+    sensor: S,
+    curve: DeratingCurve<N>,
+}
+
+impl<S: TemperatureSensor, const N: usize> ThermalDerating<S, N> {
+    /// Creates a new derating component.
+    pub fn new(sensor: S, curve: DeratingCurve<N>) -> Self {
+        Self { sensor, curve }
+    }
+
+    /// Returns the curve used to derive limits.
+    pub fn curve(&self) -> &DeratingCurve<N> {
+        &self.curve
+    }
+
+    /// Samples the temperature sensor, returning the limit at the current temperature.
+    pub fn limit(&mut self) -> Result<f32, DeratingError<S::Error>> {
+        let temperature = self.sensor.temperature().map_err(DeratingError::Sensor)?;
+        self.curve
+            .evaluate(temperature)
+            .and_then(|limit| checked_sample(limit).ok())
+            .ok_or(DeratingError::InvalidSample)
+    }
+
+    /// Consumes the component, returning the temperature sensor.
+    pub fn into_inner(self) -> S {
+        self.sensor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_CURVE: DeratingCurve<4> = DeratingCurve::new([
+        Breakpoint::new(0.0, 0.0),
+        Breakpoint::new(0.0, 500.0),
+        Breakpoint::new(45.0, 2_000.0),
+        Breakpoint::new(60.0, 0.0),
+    ]);
+
+    #[derive(Debug, PartialEq)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Peripheral
+        }
+    }
+
+    struct MockTempSensor {
+        value: Option<DegreesCelsius>,
+    }
+
+    impl crate::sensor::ErrorType for MockTempSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockTempSensor {
+        fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            self.value.ok_or(MockError)
+        }
+    }
+
+    #[test]
+    fn test_derating_curve_evaluate() {
+        assert_approx_eq!(TEST_CURVE.evaluate(-10.0).unwrap(), 0.0);
+        assert_approx_eq!(TEST_CURVE.evaluate(0.0).unwrap(), 500.0);
+        assert_approx_eq!(TEST_CURVE.evaluate(15.0).unwrap(), 1_000.0);
+        assert_approx_eq!(TEST_CURVE.evaluate(55.0).unwrap(), 2_000.0 / 3.0, 1e-2);
+        assert_approx_eq!(TEST_CURVE.evaluate(80.0).unwrap(), 0.0);
+        assert_eq!(TEST_CURVE.evaluate(f32::NAN), None);
+    }
+
+    #[test]
+    fn test_derating_curve_unsorted() {
+        let curve = DeratingCurve::new([
+            Breakpoint::new(45.0, 2_000.0),
+            Breakpoint::new(0.0, 500.0),
+            Breakpoint::new(60.0, 0.0),
+        ]);
+        assert_eq!(curve.evaluate(-10.0), None);
+        assert_eq!(curve.evaluate(30.0), None);
+        assert_eq!(curve.evaluate(80.0), None);
+
+        let sensor = MockTempSensor { value: Some(30.0) };
+        let mut derating = ThermalDerating::new(sensor, curve);
+        assert_eq!(derating.limit(), Err(DeratingError::InvalidSample));
+    }
+
+    #[test]
+    fn test_derating_curve_single_breakpoint() {
+        let curve = DeratingCurve::new([Breakpoint::new(25.0, 1.0)]);
+        assert_approx_eq!(curve.evaluate(-40.0).unwrap(), 1.0);
+        assert_approx_eq!(curve.evaluate(85.0).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_thermal_derating() {
+        let sensor = MockTempSensor { value: Some(30.0) };
+        let mut derating = ThermalDerating::new(sensor, TEST_CURVE);
+        let result = derating.limit();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), 1_500.0);
+
+        let sensor = MockTempSensor { value: None };
+        let mut derating = ThermalDerating::new(sensor, TEST_CURVE);
+        assert_eq!(derating.limit(), Err(DeratingError::Sensor(MockError)));
+
+        let sensor = MockTempSensor {
+            value: Some(f32::NAN),
+        };
+        let mut derating = ThermalDerating::new(sensor, TEST_CURVE);
+        assert_eq!(derating.limit(), Err(DeratingError::InvalidSample));
+
+        let curve = DeratingCurve::new([Breakpoint::new(25.0, f32::NAN)]);
+        let sensor = MockTempSensor { value: Some(30.0) };
+        let mut derating = ThermalDerating::new(sensor, curve);
+        let result = derating.limit();
+        assert_eq!(result, Err(DeratingError::InvalidSample));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
+}
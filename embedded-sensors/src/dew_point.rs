@@ -0,0 +1,268 @@
+//! Blocking Dew Point Sensor API
+//!
+//! This API provides generic methods for interfacing with sensors reporting the dew point, the
+//! temperature at which the water vapor in the air would start to condense.
+//!
+//! Sensors measuring both temperature and relative humidity do not need to implement the
+//! [`DewPointSensor`] trait themselves: with the `libm` feature enabled, the `DerivedDewPoint`
+//! adapter derives the dew point of any such sensor using the Magnus formula.
+//! Measurements without a finite dew point (e.g. at 0 %RH) are reported as
+//! [`DerivedDewPointError::InvalidSample`] rather than as NaN.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the DewPointSensor trait for a sensor computing
+//! the dew point itself.
+//!
+//! ```
+//! use embedded_sensors_hal::dew_point::{DegreesCelsius, DewPointSensor};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a dew point transmitter.
+//! pub struct MyDewPointSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyDewPointSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl DewPointSensor for MyDewPointSensor {
+//!     fn dew_point(&mut self) -> Result<DegreesCelsius, Self::Error> {
+//!         // Read dew point register of sensor...
+//!         Ok(9.3)
+//!     }
+//! }
+//! ```
+
+#[cfg(feature = "libm")]
+use crate::humidity::{Percentage, RelativeHumiditySensor};
+use crate::impl_sensor_refinement;
+use crate::sensor::Sensor;
+#[cfg(feature = "libm")]
+use crate::sensor::{checked_sample, Error, ErrorKind, ErrorType};
+pub use crate::temperature::DegreesCelsius;
+#[cfg(feature = "libm")]
+use crate::temperature::TemperatureSensor;
+
+/// Marker type of the dew point quantity, of which every [`DewPointSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DewPoint;
+
+/// Blocking Dew Point Sensor methods.
+pub trait DewPointSensor: Sensor<DewPoint, Sample = DegreesCelsius> {
+    /// Returns a dew point sample in degrees Celsius.
+    fn dew_point(&mut self) -> Result<DegreesCelsius, Self::Error>;
+}
+
+impl<T: DewPointSensor + ?Sized> DewPointSensor for &mut T {
+    #[inline]
+    fn dew_point(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        T::dew_point(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    DewPoint,
+    DewPointSensor,
+    dew_point,
+    DegreesCelsius
+);
+
+/// Computes the dew point (in degrees Celsius) of air at the given temperature (in degrees
+/// Celsius) and relative humidity (as a percentage), using the Magnus formula with the
+/// coefficients of Sonntag (1990).
+///
+/// The formula is accurate to within 0.35 °C between -45 °C and 60 °C. A relative humidity of
+/// zero has no dew point, and yields NaN.
+#[cfg(feature = "libm")]
+pub fn magnus_dew_point(
+    temperature: DegreesCelsius,
+    relative_humidity: Percentage,
+) -> DegreesCelsius {
+    const B: f32 = 17.62;
+    const C: DegreesCelsius = 243.12;
+
+    let gamma = libm::logf(relative_humidity / 100.0) + B * temperature / (C + temperature);
+    C * gamma / (B - gamma)
+}
+
+/// Derives the dew point of a sensor measuring both temperature and relative humidity.
+///
+/// The wrapped sensor's temperature and relative humidity remain available through the adapter.
+#[cfg(feature = "libm")]
+pub struct DerivedDewPoint<S>(pub S);
+
+/// Error returned by a [`DerivedDewPoint`] adapter.
+#[cfg(feature = "libm")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DerivedDewPointError<E> {
+    /// The wrapped sensor failed.
+    Sensor(E),
+    /// The measured temperature and relative humidity have no finite dew point (e.g. at 0 %RH).
+    InvalidSample,
+}
+
+#[cfg(feature = "libm")]
+impl<E: Error> Error for DerivedDewPointError<E> {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Sensor(e) => e.kind(),
+            Self::InvalidSample => ErrorKind::InvalidInput,
+        }
+    }
+}
+
+#[cfg(feature = "libm")]
+impl<S: ErrorType> ErrorType for DerivedDewPoint<S> {
+    type Error = DerivedDewPointError<S::Error>;
+}
+
+#[cfg(feature = "libm")]
+impl<S: TemperatureSensor + RelativeHumiditySensor> DewPointSensor for DerivedDewPoint<S> {
+    fn dew_point(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        let temperature = self.0.temperature().map_err(DerivedDewPointError::Sensor)?;
+        let relative_humidity = self
+            .0
+            .relative_humidity()
+            .map_err(DerivedDewPointError::Sensor)?;
+        checked_sample(magnus_dew_point(temperature, relative_humidity))
+            .map_err(|_| DerivedDewPointError::InvalidSample)
+    }
+}
+
+#[cfg(feature = "libm")]
+impl<S: TemperatureSensor> TemperatureSensor for DerivedDewPoint<S> {
+    #[inline]
+    fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        self.0.temperature().map_err(DerivedDewPointError::Sensor)
+    }
+}
+
+#[cfg(feature = "libm")]
+impl<S: RelativeHumiditySensor> RelativeHumiditySensor for DerivedDewPoint<S> {
+    #[inline]
+    fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+        self.0
+            .relative_humidity()
+            .map_err(DerivedDewPointError::Sensor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_DEW_POINT: DegreesCelsius = 9.3;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockComboSensor;
+
+    impl crate::sensor::ErrorType for MockComboSensor {
+        type Error = MockError;
+    }
+
+    impl DewPointSensor for MockComboSensor {
+        fn dew_point(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(TEST_DEW_POINT)
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    impl TemperatureSensor for MockComboSensor {
+        fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(20.0)
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    impl RelativeHumiditySensor for MockComboSensor {
+        fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(50.0)
+        }
+    }
+
+    // Reports bone-dry air, which has no dew point.
+    #[cfg(feature = "libm")]
+    struct MockDrySensor;
+
+    #[cfg(feature = "libm")]
+    impl crate::sensor::ErrorType for MockDrySensor {
+        type Error = MockError;
+    }
+
+    #[cfg(feature = "libm")]
+    impl TemperatureSensor for MockDrySensor {
+        fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(25.0)
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    impl RelativeHumiditySensor for MockDrySensor {
+        fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(0.0)
+        }
+    }
+
+    #[test]
+    fn test_dew_point_sensor_trait_mut_ref() {
+        let mut sensor = MockComboSensor;
+        let mut_ref = &mut sensor;
+        let result = mut_ref.dew_point();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_DEW_POINT);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    fn test_derived_dew_point() {
+        let mut sensor = DerivedDewPoint(MockComboSensor);
+        let result = sensor.dew_point();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), 9.26, 0.01);
+        assert_approx_eq!(sensor.temperature().unwrap(), 20.0);
+
+        // Saturated air condenses at its own temperature.
+        assert_approx_eq!(magnus_dew_point(-10.0, 100.0), -10.0);
+        assert!(magnus_dew_point(25.0, 0.0).is_nan());
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    fn test_derived_dew_point_dry_air() {
+        let mut sensor = DerivedDewPoint(MockDrySensor);
+        let result = sensor.dew_point();
+        assert!(matches!(result, Err(DerivedDewPointError::InvalidSample)));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
+}
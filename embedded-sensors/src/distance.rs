@@ -0,0 +1,612 @@
+//! Blocking Distance Sensor API
+//!
+//! This API provides generic methods for interfacing with distance sensors specifically.
+//!
+//! The [`DistanceSensor`] trait covers ranging sensors such as time-of-flight (ToF) sensors of
+//! the VL53L1X kind, which report each distance along with a [`DistanceStatus`] telling whether
+//! the target was in range and the return signal strong enough for the distance to be trusted.
+//!
+//! The [`RangeSensor`] trait covers trigger-and-echo ultrasonic rangers (HC-SR04 and the like),
+//! for which every measurement is initiated by a ping and bounded by an echo timeout. Targets
+//! whose echo does not return in time are reported as [`DistanceStatus::OutOfRange`].
+//!
+//! The [`SurfaceHeightSensor`] trait covers downward-looking sensors (typically ultrasonic or
+//! radar) installed at a known height above a reference surface, as found in snow depth and
+//! water level stations. Besides the measured distance to the surface, it reports the depth of
+//! the accumulated snow or water, computed from the configured mounting height.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the DistanceSensor and DistanceThresholdSet
+//! traits for a time-of-flight sensor.
+//!
+//! ```
+//! use embedded_sensors_hal::distance::{
+//!     DistanceSample, DistanceSensor, DistanceStatus, DistanceThresholdSet, RawMillimeters,
+//! };
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a time-of-flight sensor.
+//! pub struct MyTofSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyTofSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl DistanceSensor for MyTofSensor {
+//!     fn distance(&mut self) -> Result<DistanceSample, Self::Error> {
+//!         // Read range status and distance registers of sensor...
+//!         Ok(DistanceSample {
+//!             distance: 412,
+//!             status: DistanceStatus::Valid,
+//!         })
+//!     }
+//! }
+//!
+//! impl DistanceThresholdSet for MyTofSensor {
+//!     fn set_distance_threshold_low(
+//!         &mut self,
+//!         threshold: RawMillimeters
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     fn set_distance_threshold_high(
+//!         &mut self,
+//!         threshold: RawMillimeters
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+//!
+//! Here is an example for the implementation of the RangeSensor trait for an ultrasonic ranger.
+//!
+//! ```
+//! use embedded_sensors_hal::distance::{echo_distance, DistanceSample, DistanceStatus, RangeSensor, SPEED_OF_SOUND};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing an ultrasonic ranger.
+//! pub struct MyUltrasonicRanger {
+//!     echo_timeout_us: u32,
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyUltrasonicRanger {
+//!     type Error = Error;
+//! }
+//!
+//! impl RangeSensor for MyUltrasonicRanger {
+//!     fn measure(&mut self) -> Result<DistanceSample, Self::Error> {
+//!         // Pulse TRIG pin, then time the ECHO pulse, giving up after the echo timeout...
+//!         let echo_us = 2_400;
+//!         Ok(DistanceSample {
+//!             distance: echo_distance(echo_us, SPEED_OF_SOUND),
+//!             status: DistanceStatus::Valid,
+//!         })
+//!     }
+//!
+//!     fn echo_timeout_us(&self) -> u32 {
+//!         self.echo_timeout_us
+//!     }
+//!
+//!     fn set_echo_timeout_us(&mut self, timeout_us: u32) -> Result<(), Self::Error> {
+//!         self.echo_timeout_us = timeout_us;
+//!         Ok(())
+//!     }
+//! }
+//! ```
+//!
+//! Here is an example for the implementation of the SurfaceHeightSensor trait for an ultrasonic
+//! snow depth sensor.
+//!
+//! ```
+//! use embedded_sensors_hal::distance::{Meters, SurfaceHeightSensor};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing an ultrasonic snow depth sensor.
+//! pub struct MySnowDepthSensor {
+//!     mounting_height: Meters,
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MySnowDepthSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl SurfaceHeightSensor for MySnowDepthSensor {
+//!     fn surface_distance(&mut self) -> Result<Meters, Self::Error> {
+//!         // Trigger a ping and compensate time of flight for air temperature...
+//!         Ok(1.6)
+//!     }
+//!
+//!     fn mounting_height(&self) -> Meters {
+//!         self.mounting_height
+//!     }
+//!
+//!     fn set_mounting_height(&mut self, height: Meters) -> Result<(), Self::Error> {
+//!         self.mounting_height = height;
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{ErrorType, Sensor};
+use crate::temperature::DegreesCelsius;
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units distances are measured in with the underlying data type.
+pub type Meters = f32;
+
+/// Associates the whole millimeters raw ranging sensor distances are measured in with the
+/// underlying data type.
+pub type RawMillimeters = u16;
+
+/// Status of a ranging measurement.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DistanceStatus {
+    /// The distance is valid.
+    #[default]
+    Valid,
+    /// No target was found within the range of the sensor.
+    OutOfRange,
+    /// The return signal was too weak (e.g. a dark or distant target) for the distance to be
+    /// trusted.
+    SignalLow,
+}
+
+/// A ranging sample.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DistanceSample {
+    /// Distance to the target in millimeters.
+    ///
+    /// Only meaningful if [`status`](Self::status) is [`DistanceStatus::Valid`].
+    pub distance: RawMillimeters,
+    /// Status of the measurement.
+    pub status: DistanceStatus,
+}
+
+impl DistanceSample {
+    /// Returns the distance if the sample is valid.
+    pub fn distance(&self) -> Option<RawMillimeters> {
+        (self.status == DistanceStatus::Valid).then_some(self.distance)
+    }
+}
+
+/// Marker type of the distance quantity, of which every [`DistanceSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Distance;
+
+/// Blocking Distance Sensor methods.
+pub trait DistanceSensor: Sensor<Distance, Sample = DistanceSample> {
+    /// Returns a distance sample in millimeters, along with its status.
+    fn distance(&mut self) -> Result<DistanceSample, Self::Error>;
+}
+
+impl<T: DistanceSensor + ?Sized> DistanceSensor for &mut T {
+    #[inline]
+    fn distance(&mut self) -> Result<DistanceSample, Self::Error> {
+        T::distance(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Distance, DistanceSensor, distance, DistanceSample);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait DistanceThresholdSet: DistanceSensor {
+//     fn set_distance_threshold_low(&mut self, threshold: RawMillimeters) -> Result<(), Self::Error>;
+//     fn set_distance_threshold_high(&mut self, threshold: RawMillimeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait DistanceHysteresis: DistanceThresholdSet {
+//     fn set_distance_threshold_hysteresis(&mut self, hysteresis: RawMillimeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait DistanceThresholdControl: DistanceThresholdSet {
+//     fn disable_distance_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_distance_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(
+    blocking,
+    Distance,
+    DistanceSensor,
+    RawMillimeters,
+    "millimeters"
+);
+
+/// Speed of sound in dry air at 20 °C, in meters per second.
+pub const SPEED_OF_SOUND: f32 = 343.2;
+
+/// Returns the speed of sound in dry air at the given temperature, in meters per second.
+///
+/// Ultrasonic rangers should compensate for air temperature where it is known, as the speed of
+/// sound changes by about 0.18 % per degree Celsius.
+pub fn speed_of_sound(temperature: DegreesCelsius) -> f32 {
+    331.3 + 0.606 * temperature
+}
+
+/// Converts the round-trip time of an ultrasonic echo (in microseconds) to the distance to the
+/// target in millimeters, given the speed of sound in meters per second.
+///
+/// Distances beyond the range of [`RawMillimeters`] saturate.
+pub fn echo_distance(echo_us: u32, speed_of_sound: f32) -> RawMillimeters {
+    (echo_us as f32 * speed_of_sound / 2_000.0) as RawMillimeters
+}
+
+/// Blocking Range Sensor methods, for rangers triggered per measurement.
+pub trait RangeSensor: ErrorType {
+    /// Triggers a measurement and waits for its echo, returning the distance to the target in
+    /// millimeters along with its status.
+    ///
+    /// If no echo is received within the [echo timeout](Self::echo_timeout_us), the sample is
+    /// reported as [`DistanceStatus::OutOfRange`] rather than as an error.
+    fn measure(&mut self) -> Result<DistanceSample, Self::Error>;
+
+    /// Returns the time to wait for an echo after triggering a measurement, in microseconds.
+    fn echo_timeout_us(&self) -> u32;
+
+    /// Set the time to wait for an echo after triggering a measurement, in microseconds.
+    ///
+    /// Longer timeouts extend the range of the sensor at the cost of a longer measurement.
+    fn set_echo_timeout_us(&mut self, timeout_us: u32) -> Result<(), Self::Error>;
+}
+
+impl<T: RangeSensor + ?Sized> RangeSensor for &mut T {
+    #[inline]
+    fn measure(&mut self) -> Result<DistanceSample, Self::Error> {
+        T::measure(self)
+    }
+
+    #[inline]
+    fn echo_timeout_us(&self) -> u32 {
+        T::echo_timeout_us(self)
+    }
+
+    #[inline]
+    fn set_echo_timeout_us(&mut self, timeout_us: u32) -> Result<(), Self::Error> {
+        T::set_echo_timeout_us(self, timeout_us)
+    }
+}
+
+/// A distance-to-surface sample along with the depth derived from it.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SurfaceHeight {
+    /// Distance from the sensor to the surface below it.
+    pub distance: Meters,
+    /// Height of the surface above the reference surface, i.e. the mounting height of the
+    /// sensor minus [`distance`](Self::distance).
+    ///
+    /// Negative if the surface lies below the reference surface, e.g. a water level below the
+    /// datum of the station.
+    pub depth: Meters,
+}
+
+impl SurfaceHeight {
+    /// Computes the depth for a sensor at `mounting_height` measuring `distance` to the surface.
+    pub fn from_distance(mounting_height: Meters, distance: Meters) -> Self {
+        Self {
+            distance,
+            depth: mounting_height - distance,
+        }
+    }
+}
+
+/// Marker type of the surface distance quantity, of which every [`SurfaceHeightSensor`] implementor
+/// is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SurfaceDistance;
+
+/// Blocking Surface Height methods.
+pub trait SurfaceHeightSensor: Sensor<SurfaceDistance, Sample = Meters> {
+    /// Returns a sample of the distance to the surface below the sensor in meters.
+    fn surface_distance(&mut self) -> Result<Meters, Self::Error>;
+
+    /// Returns the height of the sensor above the reference surface in meters.
+    fn mounting_height(&self) -> Meters;
+
+    /// Set the height of the sensor above the reference surface (in meters) used to derive depth.
+    fn set_mounting_height(&mut self, height: Meters) -> Result<(), Self::Error>;
+
+    /// Returns a distance-to-surface sample along with the depth derived from the mounting height.
+    fn surface_height(&mut self) -> Result<SurfaceHeight, Self::Error> {
+        let distance = self.surface_distance()?;
+        Ok(SurfaceHeight::from_distance(
+            self.mounting_height(),
+            distance,
+        ))
+    }
+}
+
+impl<T: SurfaceHeightSensor + ?Sized> SurfaceHeightSensor for &mut T {
+    #[inline]
+    fn surface_distance(&mut self) -> Result<Meters, Self::Error> {
+        T::surface_distance(self)
+    }
+
+    #[inline]
+    fn mounting_height(&self) -> Meters {
+        T::mounting_height(self)
+    }
+
+    #[inline]
+    fn set_mounting_height(&mut self, height: Meters) -> Result<(), Self::Error> {
+        T::set_mounting_height(self, height)
+    }
+
+    #[inline]
+    fn surface_height(&mut self) -> Result<SurfaceHeight, Self::Error> {
+        T::surface_height(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    SurfaceDistance,
+    SurfaceHeightSensor,
+    surface_distance,
+    Meters
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_DISTANCE: Meters = 1.75;
+    const TEST_MOUNTING_HEIGHT: Meters = 2.5;
+    const TEST_SAMPLE: DistanceSample = DistanceSample {
+        distance: 412,
+        status: DistanceStatus::Valid,
+    };
+    const TEST_THRESHOLD_LOW: RawMillimeters = 100;
+    const TEST_THRESHOLD_HIGH: RawMillimeters = 1_200;
+    const TEST_ECHO_US: u32 = 12_000;
+    const TEST_ECHO_TIMEOUT_US: u32 = 25_000;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockTofSensor {
+        sample: DistanceSample,
+        threshold_low: RawMillimeters,
+        threshold_high: RawMillimeters,
+    }
+
+    impl crate::sensor::ErrorType for MockTofSensor {
+        type Error = MockError;
+    }
+
+    impl DistanceSensor for MockTofSensor {
+        fn distance(&mut self) -> Result<DistanceSample, Self::Error> {
+            Ok(self.sample)
+        }
+    }
+
+    impl DistanceThresholdSet for MockTofSensor {
+        fn set_distance_threshold_low(
+            &mut self,
+            threshold: RawMillimeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = threshold;
+            Ok(())
+        }
+
+        fn set_distance_threshold_high(
+            &mut self,
+            threshold: RawMillimeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = threshold;
+            Ok(())
+        }
+    }
+
+    struct MockUltrasonicRanger {
+        echo_us: Option<u32>,
+        echo_timeout_us: u32,
+    }
+
+    impl crate::sensor::ErrorType for MockUltrasonicRanger {
+        type Error = MockError;
+    }
+
+    impl RangeSensor for MockUltrasonicRanger {
+        fn measure(&mut self) -> Result<DistanceSample, Self::Error> {
+            Ok(match self.echo_us {
+                Some(echo_us) if echo_us <= self.echo_timeout_us => DistanceSample {
+                    distance: echo_distance(echo_us, SPEED_OF_SOUND),
+                    status: DistanceStatus::Valid,
+                },
+                _ => DistanceSample {
+                    distance: 0,
+                    status: DistanceStatus::OutOfRange,
+                },
+            })
+        }
+
+        fn echo_timeout_us(&self) -> u32 {
+            self.echo_timeout_us
+        }
+
+        fn set_echo_timeout_us(&mut self, timeout_us: u32) -> Result<(), Self::Error> {
+            self.echo_timeout_us = timeout_us;
+            Ok(())
+        }
+    }
+
+    struct MockSnowDepthSensor {
+        distance: Meters,
+        mounting_height: Meters,
+    }
+
+    impl crate::sensor::ErrorType for MockSnowDepthSensor {
+        type Error = MockError;
+    }
+
+    impl SurfaceHeightSensor for MockSnowDepthSensor {
+        fn surface_distance(&mut self) -> Result<Meters, Self::Error> {
+            Ok(self.distance)
+        }
+
+        fn mounting_height(&self) -> Meters {
+            self.mounting_height
+        }
+
+        fn set_mounting_height(&mut self, height: Meters) -> Result<(), Self::Error> {
+            self.mounting_height = height;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_distance_sensor_trait() {
+        let mut sensor = MockTofSensor {
+            sample: TEST_SAMPLE,
+            threshold_low: 0,
+            threshold_high: 0,
+        };
+        let result = sensor.distance();
+        assert!(result.is_ok());
+        let sample = result.unwrap();
+        assert_eq!(sample, TEST_SAMPLE);
+        assert_eq!(sample.distance(), Some(412));
+
+        sensor.sample.status = DistanceStatus::SignalLow;
+        assert_eq!(sensor.distance().unwrap().distance(), None);
+    }
+
+    #[test]
+    fn test_echo_distance() {
+        assert_eq!(echo_distance(TEST_ECHO_US, SPEED_OF_SOUND), 2_059);
+        assert_eq!(echo_distance(u32::MAX, SPEED_OF_SOUND), RawMillimeters::MAX);
+        assert_approx_eq!(speed_of_sound(20.0), SPEED_OF_SOUND, 0.5);
+        assert!(speed_of_sound(-10.0) < speed_of_sound(30.0));
+    }
+
+    #[test]
+    fn test_distance_threshold_set_trait_mut_ref() {
+        let mut sensor = MockTofSensor {
+            sample: TEST_SAMPLE,
+            threshold_low: 0,
+            threshold_high: 0,
+        };
+
+        {
+            let mut_ref = &mut sensor;
+            let result_low = mut_ref.set_distance_threshold_low(TEST_THRESHOLD_LOW);
+            assert!(result_low.is_ok());
+            let result_high = mut_ref.set_distance_threshold_high(TEST_THRESHOLD_HIGH);
+            assert!(result_high.is_ok());
+        }
+
+        assert_eq!(sensor.threshold_low, TEST_THRESHOLD_LOW);
+        assert_eq!(sensor.threshold_high, TEST_THRESHOLD_HIGH);
+    }
+
+    #[test]
+    fn test_range_sensor_trait_mut_ref() {
+        let mut sensor = MockUltrasonicRanger {
+            echo_us: Some(TEST_ECHO_US),
+            echo_timeout_us: 0,
+        };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_echo_timeout_us(TEST_ECHO_TIMEOUT_US).is_ok());
+        assert_eq!(mut_ref.echo_timeout_us(), TEST_ECHO_TIMEOUT_US);
+
+        let result = mut_ref.measure();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().distance(), Some(2_059));
+
+        // The echo of a target beyond the timeout is never heard.
+        mut_ref.echo_us = Some(TEST_ECHO_TIMEOUT_US + 1);
+        let sample = mut_ref.measure().unwrap();
+        assert_eq!(sample.status, DistanceStatus::OutOfRange);
+        assert_eq!(sample.distance(), None);
+    }
+
+    #[test]
+    fn test_surface_height_sensor_trait() {
+        let mut sensor = MockSnowDepthSensor {
+            distance: TEST_DISTANCE,
+            mounting_height: TEST_MOUNTING_HEIGHT,
+        };
+        let result = sensor.surface_height();
+        assert!(result.is_ok());
+        let sample = result.unwrap();
+        assert_approx_eq!(sample.distance, TEST_DISTANCE);
+        assert_approx_eq!(sample.depth, 0.75);
+    }
+
+    #[test]
+    fn test_surface_height_sensor_trait_mut_ref() {
+        let mut sensor = MockSnowDepthSensor {
+            distance: TEST_DISTANCE,
+            mounting_height: TEST_MOUNTING_HEIGHT,
+        };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_mounting_height(1.5).is_ok());
+        assert_approx_eq!(mut_ref.mounting_height(), 1.5);
+
+        // The surface now lies below the reference surface.
+        let result = mut_ref.surface_height();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap().depth, -0.25);
+    }
+}
@@ -0,0 +1,184 @@
+//! Blocking FIFO API
+//!
+//! This API provides generic methods for reading batches of samples from sensors buffering them
+//! in a hardware FIFO, such as high-rate IMUs or digital microphones, which cannot be used
+//! efficiently one sample at a time.
+//!
+//! The [`FifoRead`] trait is generic over the type of the buffered samples (e.g.
+//! `imu::ImuSample`), so it applies to any sensor type. Sensors implementing it should report
+//! `sensor::Capabilities::FIFO`.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the FifoRead trait for an IMU.
+//!
+//! ```
+//! use embedded_sensors_hal::fifo::{FifoRead};
+//! use embedded_sensors_hal::imu::ImuSample;
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing an IMU with a sample FIFO.
+//! pub struct MyImu {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyImu {
+//!     type Error = Error;
+//! }
+//!
+//! impl FifoRead<ImuSample> for MyImu {
+//!     fn fifo_capacity(&self) -> usize {
+//!         512
+//!     }
+//!
+//!     fn read_fifo(&mut self, buf: &mut [ImuSample]) -> Result<usize, Self::Error> {
+//!         // Burst read FIFO data register of sensor and scale samples...
+//!         Ok(0)
+//!     }
+//!
+//!     fn fifo_level(&mut self) -> Result<usize, Self::Error> {
+//!         // Read FIFO status registers of sensor...
+//!         Ok(0)
+//!     }
+//!
+//!     fn set_fifo_watermark(&mut self, level: usize) -> Result<(), Self::Error> {
+//!         // Write FIFO watermark register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+
+/// Blocking FIFO read methods, generic over the type of the buffered samples.
+pub trait FifoRead<T>: ErrorType {
+    /// Returns the number of samples the FIFO can hold.
+    fn fifo_capacity(&self) -> usize;
+
+    /// Reads samples from the FIFO into `buf`, oldest first, returning the number of samples
+    /// read.
+    ///
+    /// At most `buf.len()` samples are read, any further samples stay in the FIFO.
+    fn read_fifo(&mut self, buf: &mut [T]) -> Result<usize, Self::Error>;
+
+    /// Returns the number of samples currently held in the FIFO.
+    fn fifo_level(&mut self) -> Result<usize, Self::Error>;
+
+    /// Set the FIFO level (in samples) at which the sensor signals that the FIFO should be read.
+    ///
+    /// Sensors return an error of kind `InvalidInput` if the level exceeds the
+    /// [capacity](Self::fifo_capacity) of the FIFO.
+    fn set_fifo_watermark(&mut self, level: usize) -> Result<(), Self::Error>;
+}
+
+impl<T, F: FifoRead<T> + ?Sized> FifoRead<T> for &mut F {
+    #[inline]
+    fn fifo_capacity(&self) -> usize {
+        F::fifo_capacity(self)
+    }
+
+    #[inline]
+    fn read_fifo(&mut self, buf: &mut [T]) -> Result<usize, Self::Error> {
+        F::read_fifo(self, buf)
+    }
+
+    #[inline]
+    fn fifo_level(&mut self) -> Result<usize, Self::Error> {
+        F::fifo_level(self)
+    }
+
+    #[inline]
+    fn set_fifo_watermark(&mut self, level: usize) -> Result<(), Self::Error> {
+        F::set_fifo_watermark(self, level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_SAMPLES: [u16; 6] = [100, 101, 102, 103, 104, 105];
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::InvalidInput
+        }
+    }
+
+    struct MockFifoSensor {
+        level: usize,
+        watermark: usize,
+    }
+
+    impl crate::sensor::ErrorType for MockFifoSensor {
+        type Error = MockError;
+    }
+
+    impl FifoRead<u16> for MockFifoSensor {
+        fn fifo_capacity(&self) -> usize {
+            TEST_SAMPLES.len()
+        }
+
+        fn read_fifo(&mut self, buf: &mut [u16]) -> Result<usize, Self::Error> {
+            let count = self.level.min(buf.len());
+            buf[..count].copy_from_slice(&TEST_SAMPLES[..count]);
+            self.level -= count;
+            Ok(count)
+        }
+
+        fn fifo_level(&mut self) -> Result<usize, Self::Error> {
+            Ok(self.level)
+        }
+
+        fn set_fifo_watermark(&mut self, level: usize) -> Result<(), Self::Error> {
+            if level > self.fifo_capacity() {
+                return Err(MockError);
+            }
+            self.watermark = level;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_fifo_read_trait() {
+        let mut sensor = MockFifoSensor {
+            level: TEST_SAMPLES.len(),
+            watermark: 0,
+        };
+        let mut buf = [0; 4];
+        assert_eq!(sensor.read_fifo(&mut buf).unwrap(), 4);
+        assert_eq!(buf, TEST_SAMPLES[..4]);
+        assert_eq!(sensor.fifo_level().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_fifo_read_trait_mut_ref() {
+        let mut sensor = MockFifoSensor {
+            level: 0,
+            watermark: 0,
+        };
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.fifo_capacity(), TEST_SAMPLES.len());
+        assert!(mut_ref.set_fifo_watermark(4).is_ok());
+        assert!(mut_ref.set_fifo_watermark(7).is_err());
+        assert_eq!(sensor.watermark, 4);
+    }
+}
@@ -0,0 +1,161 @@
+//! Blocking Flame Sensor API
+//!
+//! This API provides generic methods for interfacing with infrared flame sensors specifically, as
+//! used for flame supervision in heater and appliance control.
+//!
+//! All flame sensors report whether a flame is detected, while sensors with an analog output can
+//! additionally implement the [`FlameIntensitySensor`] trait.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the FlameSensor trait for an IR flame sensor.
+//!
+//! ```
+//! use embedded_sensors_hal::flame::FlameSensor;
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing an IR flame sensor.
+//! pub struct MyFlameSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyFlameSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl FlameSensor for MyFlameSensor {
+//!     fn flame_detected(&mut self) -> Result<bool, Self::Error> {
+//!         // Read digital output pin of sensor...
+//!         Ok(false)
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+use crate::sensor::{Sensor, ValidRange};
+
+/// Associates the units flame intensity samples are measured in with the underlying data type.
+pub type Percentage = f32;
+
+/// Range of flame intensity samples.
+pub const FLAME_INTENSITY_RANGE: ValidRange<Percentage> = ValidRange::new(0.0, 100.0);
+
+/// Marker type of the flame detection quantity, of which every [`FlameSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Flame;
+
+/// Blocking Flame Sensor methods.
+pub trait FlameSensor: Sensor<Flame, Sample = bool> {
+    /// Returns `true` if a flame is currently detected.
+    fn flame_detected(&mut self) -> Result<bool, Self::Error>;
+}
+
+impl<T: FlameSensor + ?Sized> FlameSensor for &mut T {
+    #[inline]
+    fn flame_detected(&mut self) -> Result<bool, Self::Error> {
+        T::flame_detected(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Flame, FlameSensor, flame_detected, bool);
+
+/// Marker type of the flame intensity quantity, of which every [`FlameIntensitySensor`] implementor
+/// is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FlameIntensity;
+
+/// Blocking Flame Intensity Sensor methods.
+pub trait FlameIntensitySensor: FlameSensor + Sensor<FlameIntensity, Sample = Percentage> {
+    /// Returns a flame intensity sample as a percentage of the full scale of the sensor.
+    fn flame_intensity(&mut self) -> Result<Percentage, Self::Error>;
+}
+
+impl<T: FlameIntensitySensor + ?Sized> FlameIntensitySensor for &mut T {
+    #[inline]
+    fn flame_intensity(&mut self) -> Result<Percentage, Self::Error> {
+        T::flame_intensity(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    FlameIntensity,
+    FlameIntensitySensor,
+    flame_intensity,
+    Percentage
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_DETECTION_LEVEL: Percentage = 20.0;
+    const TEST_INTENSITY: Percentage = 65.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockFlameSensor {
+        intensity: Percentage,
+    }
+
+    impl crate::sensor::ErrorType for MockFlameSensor {
+        type Error = MockError;
+    }
+
+    impl FlameSensor for MockFlameSensor {
+        fn flame_detected(&mut self) -> Result<bool, Self::Error> {
+            Ok(self.intensity >= TEST_DETECTION_LEVEL)
+        }
+    }
+
+    impl FlameIntensitySensor for MockFlameSensor {
+        fn flame_intensity(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(self.intensity)
+        }
+    }
+
+    #[test]
+    fn test_flame_sensor_trait() {
+        let mut sensor = MockFlameSensor {
+            intensity: TEST_INTENSITY,
+        };
+        let result = sensor.flame_detected();
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+        assert_approx_eq!(sensor.flame_intensity().unwrap(), TEST_INTENSITY);
+    }
+
+    #[test]
+    fn test_flame_sensor_trait_mut_ref() {
+        let mut sensor = MockFlameSensor { intensity: 5.0 };
+        let mut_ref = &mut sensor;
+        assert!(!mut_ref.flame_detected().unwrap());
+        assert!(FLAME_INTENSITY_RANGE.contains(mut_ref.flame_intensity().unwrap()));
+    }
+}
@@ -0,0 +1,128 @@
+//! Blocking Light Flicker API
+//!
+//! This API provides generic methods for interfacing with the flicker detection engines of
+//! ambient light sensors (ALS) specifically.
+//!
+//! Flicker detection reports the frequency at which artificial lighting modulates (typically
+//! twice the mains frequency, i.e. 100 Hz or 120 Hz), which camera-assist and display logic use
+//! to avoid banding artifacts.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the FlickerDetection trait for an ALS.
+//!
+//! ```
+//! use embedded_sensors_hal::flicker::{FlickerDetection, Hertz};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing an ambient light sensor.
+//! pub struct MyLightSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyLightSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl FlickerDetection for MyLightSensor {
+//!     fn flicker_frequency(&mut self) -> Result<Option<Hertz>, Self::Error> {
+//!         // Read flicker status register of sensor...
+//!         Ok(Some(100.0))
+//!     }
+//! }
+//! ```
+
+pub use crate::frequency::Hertz;
+use crate::impl_sensor_refinement;
+use crate::sensor::Sensor;
+
+/// Marker type of the light flicker frequency quantity, of which every [`FlickerDetection`]
+/// implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Flicker;
+
+/// Blocking Flicker Detection methods.
+pub trait FlickerDetection: Sensor<Flicker, Sample = Option<Hertz>> {
+    /// Returns the detected flicker frequency in hertz, or `None` if no flicker is detected.
+    fn flicker_frequency(&mut self) -> Result<Option<Hertz>, Self::Error>;
+}
+
+impl<T: FlickerDetection + ?Sized> FlickerDetection for &mut T {
+    #[inline]
+    fn flicker_frequency(&mut self) -> Result<Option<Hertz>, Self::Error> {
+        T::flicker_frequency(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    Flicker,
+    FlickerDetection,
+    flicker_frequency,
+    Option<Hertz>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_FLICKER: Option<Hertz> = Some(120.0);
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockLightSensor {
+        flicker: Option<Hertz>,
+    }
+
+    impl crate::sensor::ErrorType for MockLightSensor {
+        type Error = MockError;
+    }
+
+    impl FlickerDetection for MockLightSensor {
+        fn flicker_frequency(&mut self) -> Result<Option<Hertz>, Self::Error> {
+            Ok(self.flicker)
+        }
+    }
+
+    #[test]
+    fn test_flicker_detection_trait() {
+        let mut sensor = MockLightSensor { flicker: None };
+        let result = sensor.flicker_frequency();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), None);
+    }
+
+    #[test]
+    fn test_flicker_detection_trait_mut_ref() {
+        let mut sensor = MockLightSensor {
+            flicker: TEST_FLICKER,
+        };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.flicker_frequency();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_FLICKER);
+    }
+}
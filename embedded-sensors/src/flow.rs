@@ -0,0 +1,269 @@
+//! Blocking Flow Sensor API
+//!
+//! This API provides generic methods for interfacing with volumetric flow sensors specifically.
+//!
+//! The API is suited to both pulse-output flow meters (e.g. Hall-effect turbine meters) and
+//! digital mass-flow sensors. Bidirectional sensors report reverse flow as negative flow rates.
+//!
+//! Sensors also expose a totalizer which accumulates the volume that has flowed since it was
+//! last reset.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the FlowSensor trait for a Hall-effect flow meter.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::flow::{FlowSensor, Liters, LitersPerMinute};
+//!
+//! // A struct representing a Hall-effect flow meter.
+//! pub struct MyFlowSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyFlowSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl FlowSensor for MyFlowSensor {
+//!     fn flow_rate(&mut self) -> Result<LitersPerMinute, Self::Error> {
+//!         // Convert pulse frequency to flow rate...
+//!         Ok(2.5)
+//!     }
+//!
+//!     fn total_volume(&mut self) -> Result<Liters, Self::Error> {
+//!         // Convert total pulse count to volume...
+//!         Ok(42.0)
+//!     }
+//!
+//!     fn reset_total_volume(&mut self) -> Result<(), Self::Error> {
+//!         // Clear pulse counter...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{Sensor, ValidRange};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units flow rate samples are measured in with the underlying data type.
+pub type LitersPerMinute = f32;
+
+/// Associates the units accumulated volume is measured in with the underlying data type.
+pub type Liters = f32;
+
+/// Range of flow rate samples, negative for reverse flow through bidirectional meters.
+pub const FLOW_RATE_RANGE: ValidRange<LitersPerMinute> =
+    ValidRange::new(LitersPerMinute::MIN, LitersPerMinute::MAX);
+
+/// Marker type of the flow rate quantity, of which every [`FlowSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FlowRate;
+
+/// Blocking Flow Sensor methods.
+pub trait FlowSensor: Sensor<FlowRate, Sample = LitersPerMinute> {
+    /// Returns a volumetric flow rate sample in liters per minute.
+    fn flow_rate(&mut self) -> Result<LitersPerMinute, Self::Error>;
+
+    /// Returns the volume accumulated by the totalizer since its last reset, in liters.
+    fn total_volume(&mut self) -> Result<Liters, Self::Error>;
+
+    /// Reset the volume accumulated by the totalizer to zero.
+    fn reset_total_volume(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: FlowSensor + ?Sized> FlowSensor for &mut T {
+    #[inline]
+    fn flow_rate(&mut self) -> Result<LitersPerMinute, Self::Error> {
+        T::flow_rate(self)
+    }
+
+    #[inline]
+    fn total_volume(&mut self) -> Result<Liters, Self::Error> {
+        T::total_volume(self)
+    }
+
+    #[inline]
+    fn reset_total_volume(&mut self) -> Result<(), Self::Error> {
+        T::reset_total_volume(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, FlowRate, FlowSensor, flow_rate, LitersPerMinute);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait FlowRateThresholdSet: FlowSensor {
+//     fn set_flow_rate_threshold_low(&mut self, threshold: LitersPerMinute) -> Result<(), Self::Error>;
+//     fn set_flow_rate_threshold_high(&mut self, threshold: LitersPerMinute) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FlowRateHysteresis: FlowRateThresholdSet {
+//     fn set_flow_rate_threshold_hysteresis(&mut self, hysteresis: LitersPerMinute) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FlowRateThresholdControl: FlowRateThresholdSet {
+//     fn disable_flow_rate_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_flow_rate_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(
+    blocking,
+    FlowRate,
+    FlowSensor,
+    LitersPerMinute,
+    "liters per minute"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test value
+    const TEST_VALUE: LitersPerMinute = 1.8;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockFlowSensor {
+        value: LitersPerMinute,
+        threshold_low: Option<LitersPerMinute>,
+        threshold_high: Option<LitersPerMinute>,
+        hysteresis: Option<LitersPerMinute>,
+        total_volume: Liters,
+    }
+
+    impl MockFlowSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+                total_volume: 42.0,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockFlowSensor {
+        type Error = MockError;
+    }
+
+    impl FlowSensor for MockFlowSensor {
+        fn flow_rate(&mut self) -> Result<LitersPerMinute, Self::Error> {
+            Ok(self.value)
+        }
+
+        fn total_volume(&mut self) -> Result<Liters, Self::Error> {
+            Ok(self.total_volume)
+        }
+
+        fn reset_total_volume(&mut self) -> Result<(), Self::Error> {
+            self.total_volume = 0.0;
+            Ok(())
+        }
+    }
+
+    impl FlowRateThresholdSet for MockFlowSensor {
+        fn set_flow_rate_threshold_low(
+            &mut self,
+            threshold: LitersPerMinute,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_flow_rate_threshold_high(
+            &mut self,
+            threshold: LitersPerMinute,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl FlowRateHysteresis for MockFlowSensor {
+        fn set_flow_rate_threshold_hysteresis(
+            &mut self,
+            hysteresis: LitersPerMinute,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_flow_sensor_trait() {
+        let mut sensor = MockFlowSensor::new();
+        let result = sensor.flow_rate();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_flow_sensor_trait_mut_ref() {
+        let mut sensor = MockFlowSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.flow_rate();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_flow_threshold_set_mut_ref() {
+        let mut sensor = MockFlowSensor::new();
+        let mut_ref = &mut sensor;
+        let low_threshold = 0.5;
+        let high_threshold = 10.0;
+
+        let result_low = mut_ref.set_flow_rate_threshold_low(low_threshold);
+        assert!(result_low.is_ok());
+
+        let result_high = mut_ref.set_flow_rate_threshold_high(high_threshold);
+        assert!(result_high.is_ok());
+
+        assert_approx_eq!(sensor.threshold_low.unwrap(), low_threshold);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), high_threshold);
+    }
+
+    #[test]
+    fn test_flow_hysteresis_mut_ref() {
+        let mut sensor = MockFlowSensor::new();
+        let mut_ref = &mut sensor;
+        let hyst = 0.1;
+        let result = mut_ref.set_flow_rate_threshold_hysteresis(hyst);
+        assert!(result.is_ok());
+        assert_approx_eq!(sensor.hysteresis.unwrap(), hyst);
+    }
+
+    #[test]
+    fn test_flow_totalizer_mut_ref() {
+        let mut sensor = MockFlowSensor::new();
+        let mut_ref = &mut sensor;
+        assert_approx_eq!(mut_ref.total_volume().unwrap(), 42.0);
+        assert!(mut_ref.reset_total_volume().is_ok());
+        assert_approx_eq!(sensor.total_volume().unwrap(), 0.0);
+    }
+}
@@ -0,0 +1,233 @@
+//! Blocking Force Sensor API
+//!
+//! This API provides generic methods for interfacing with force sensors (load cells) specifically.
+//!
+//! Load cells are typically read through a bridge amplifier and ADC front-end (e.g. HX711).
+//! Since every installation carries some fixed load (fixtures, containers), the tare
+//! operation is part of the API: after taring, samples are reported relative to the load
+//! present at the time of the tare.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the ForceSensor trait for a load cell.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::force::{ForceSensor, Newtons};
+//!
+//! // A struct representing a load cell.
+//! pub struct MyForceSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyForceSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl ForceSensor for MyForceSensor {
+//!     fn force(&mut self) -> Result<Newtons, Self::Error> {
+//!         // Read raw ADC value, subtract zero offset and apply scale...
+//!         Ok(9.81)
+//!     }
+//!
+//!     fn tare(&mut self) -> Result<(), Self::Error> {
+//!         // Average a few raw samples and store them as the zero offset...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{Sensor, ValidRange};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units force samples are measured in with the underlying data type.
+pub type Newtons = f32;
+
+/// Range of force samples, which are relative to the tare load and thus may be negative.
+pub const FORCE_RANGE: ValidRange<Newtons> = ValidRange::new(Newtons::MIN, Newtons::MAX);
+
+/// Marker type of the force quantity, of which every [`ForceSensor`] implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Force;
+
+/// Blocking Force Sensor methods.
+pub trait ForceSensor: Sensor<Force, Sample = Newtons> {
+    /// Returns a force sample in newtons, relative to the load present at the last tare.
+    fn force(&mut self) -> Result<Newtons, Self::Error>;
+
+    /// Tare the sensor, so that the current load reads as zero force.
+    fn tare(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: ForceSensor + ?Sized> ForceSensor for &mut T {
+    #[inline]
+    fn force(&mut self) -> Result<Newtons, Self::Error> {
+        T::force(self)
+    }
+
+    #[inline]
+    fn tare(&mut self) -> Result<(), Self::Error> {
+        T::tare(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Force, ForceSensor, force, Newtons);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait ForceThresholdSet: ForceSensor {
+//     fn set_force_threshold_low(&mut self, threshold: Newtons) -> Result<(), Self::Error>;
+//     fn set_force_threshold_high(&mut self, threshold: Newtons) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ForceHysteresis: ForceThresholdSet {
+//     fn set_force_threshold_hysteresis(&mut self, hysteresis: Newtons) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ForceThresholdControl: ForceThresholdSet {
+//     fn disable_force_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_force_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(blocking, Force, ForceSensor, Newtons, "newtons");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test value
+    const TEST_VALUE: Newtons = 49.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockForceSensor {
+        value: Newtons,
+        threshold_low: Option<Newtons>,
+        threshold_high: Option<Newtons>,
+        hysteresis: Option<Newtons>,
+        offset: Newtons,
+    }
+
+    impl MockForceSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+                offset: 0.0,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockForceSensor {
+        type Error = MockError;
+    }
+
+    impl ForceSensor for MockForceSensor {
+        fn force(&mut self) -> Result<Newtons, Self::Error> {
+            Ok(self.value)
+        }
+
+        fn tare(&mut self) -> Result<(), Self::Error> {
+            self.offset = self.value;
+            Ok(())
+        }
+    }
+
+    impl ForceThresholdSet for MockForceSensor {
+        fn set_force_threshold_low(&mut self, threshold: Newtons) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_force_threshold_high(&mut self, threshold: Newtons) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl ForceHysteresis for MockForceSensor {
+        fn set_force_threshold_hysteresis(
+            &mut self,
+            hysteresis: Newtons,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_force_sensor_trait() {
+        let mut sensor = MockForceSensor::new();
+        let result = sensor.force();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_force_sensor_trait_mut_ref() {
+        let mut sensor = MockForceSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.force();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_force_threshold_set_mut_ref() {
+        let mut sensor = MockForceSensor::new();
+        let mut_ref = &mut sensor;
+        let low_threshold = 0.0;
+        let high_threshold = 500.0;
+
+        let result_low = mut_ref.set_force_threshold_low(low_threshold);
+        assert!(result_low.is_ok());
+
+        let result_high = mut_ref.set_force_threshold_high(high_threshold);
+        assert!(result_high.is_ok());
+
+        assert_approx_eq!(sensor.threshold_low.unwrap(), low_threshold);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), high_threshold);
+    }
+
+    #[test]
+    fn test_force_hysteresis_mut_ref() {
+        let mut sensor = MockForceSensor::new();
+        let mut_ref = &mut sensor;
+        let hyst = 1.0;
+        let result = mut_ref.set_force_threshold_hysteresis(hyst);
+        assert!(result.is_ok());
+        assert_approx_eq!(sensor.hysteresis.unwrap(), hyst);
+    }
+
+    #[test]
+    fn test_force_tare_mut_ref() {
+        let mut sensor = MockForceSensor::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.tare().is_ok());
+        assert_approx_eq!(sensor.offset, TEST_VALUE);
+    }
+}
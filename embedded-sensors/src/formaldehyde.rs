@@ -0,0 +1,232 @@
+//! Blocking Formaldehyde Sensor API
+//!
+//! This API provides generic methods for interfacing with formaldehyde (HCHO) sensors specifically,
+//! such as the SFA30, as used in air purifiers and indoor air quality monitors.
+//!
+//! Concentrations are reported in parts per billion, and can be converted to the mass concentration
+//! used by most indoor air guidelines with [`ppb_to_micrograms_per_cubic_meter`].
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the FormaldehydeSensor trait for a formaldehyde
+//! sensor.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::formaldehyde::{Ppb, FormaldehydeSensor};
+//!
+//! // A struct representing a formaldehyde sensor.
+//! pub struct MyHchoSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyHchoSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl FormaldehydeSensor for MyHchoSensor {
+//!     fn formaldehyde(&mut self) -> Result<Ppb, Self::Error> {
+//!         // Read measured values of sensor (0.2 ppb per bit)...
+//!         Ok(18.4)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{Sensor, ValidRange};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units formaldehyde concentrations are measured in with the underlying data type.
+pub type Ppb = f32;
+
+/// Associates the units mass concentrations are measured in with the underlying data type.
+pub type MicrogramsPerCubicMeter = f32;
+
+/// Physically plausible range of formaldehyde concentration samples.
+pub const FORMALDEHYDE_RANGE: ValidRange<Ppb> = ValidRange::new(0.0, 1_000_000_000.0);
+
+/// Mass concentration of formaldehyde, in micrograms per cubic meter, per part per billion at
+/// 25 °C and 1013.25 hPa (molar mass of 30.03 g/mol over a molar volume of 24.45 l/mol).
+pub const MICROGRAMS_PER_CUBIC_METER_PER_PPB: f32 = 30.03 / 24.45;
+
+/// Converts a formaldehyde concentration in parts per billion to a mass concentration in
+/// micrograms per cubic meter, at 25 °C and 1013.25 hPa.
+pub fn ppb_to_micrograms_per_cubic_meter(concentration: Ppb) -> MicrogramsPerCubicMeter {
+    concentration * MICROGRAMS_PER_CUBIC_METER_PER_PPB
+}
+
+/// Marker type of the formaldehyde concentration quantity, of which every [`FormaldehydeSensor`]
+/// implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Formaldehyde;
+
+/// Blocking Formaldehyde Sensor methods.
+pub trait FormaldehydeSensor: Sensor<Formaldehyde, Sample = Ppb> {
+    /// Returns a formaldehyde concentration sample in parts per billion.
+    fn formaldehyde(&mut self) -> Result<Ppb, Self::Error>;
+}
+
+impl<T: FormaldehydeSensor + ?Sized> FormaldehydeSensor for &mut T {
+    #[inline]
+    fn formaldehyde(&mut self) -> Result<Ppb, Self::Error> {
+        T::formaldehyde(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    Formaldehyde,
+    FormaldehydeSensor,
+    formaldehyde,
+    Ppb
+);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait FormaldehydeThresholdSet: FormaldehydeSensor {
+//     fn set_formaldehyde_threshold_low(&mut self, threshold: Ppb) -> Result<(), Self::Error>;
+//     fn set_formaldehyde_threshold_high(&mut self, threshold: Ppb) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FormaldehydeHysteresis: FormaldehydeThresholdSet {
+//     fn set_formaldehyde_threshold_hysteresis(&mut self, hysteresis: Ppb) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FormaldehydeThresholdControl: FormaldehydeThresholdSet {
+//     fn disable_formaldehyde_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_formaldehyde_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(
+    blocking,
+    Formaldehyde,
+    FormaldehydeSensor,
+    Ppb,
+    "parts per billion"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Ppb = 18.4;
+    const TEST_THRESHOLD_LOW: Ppb = 0.0;
+    const TEST_THRESHOLD_HIGH: Ppb = 81.0;
+    const TEST_HYSTERESIS: Ppb = 2.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockHchoSensor {
+        value: Ppb,
+        threshold_low: Option<Ppb>,
+        threshold_high: Option<Ppb>,
+        hysteresis: Option<Ppb>,
+    }
+
+    impl MockHchoSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockHchoSensor {
+        type Error = MockError;
+    }
+
+    impl FormaldehydeSensor for MockHchoSensor {
+        fn formaldehyde(&mut self) -> Result<Ppb, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl FormaldehydeThresholdSet for MockHchoSensor {
+        fn set_formaldehyde_threshold_low(&mut self, threshold: Ppb) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_formaldehyde_threshold_high(&mut self, threshold: Ppb) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl FormaldehydeHysteresis for MockHchoSensor {
+        fn set_formaldehyde_threshold_hysteresis(
+            &mut self,
+            hysteresis: Ppb,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_formaldehyde_sensor_trait() {
+        let mut sensor = MockHchoSensor::new();
+        let result = sensor.formaldehyde();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_formaldehyde_sensor_trait_mut_ref() {
+        let mut sensor = MockHchoSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.formaldehyde();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_formaldehyde_threshold_set_mut_ref() {
+        let mut sensor = MockHchoSensor::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_formaldehyde_threshold_low(TEST_THRESHOLD_LOW)
+            .is_ok());
+        assert!(mut_ref
+            .set_formaldehyde_threshold_high(TEST_THRESHOLD_HIGH)
+            .is_ok());
+        assert!(mut_ref
+            .set_formaldehyde_threshold_hysteresis(TEST_HYSTERESIS)
+            .is_ok());
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), TEST_HYSTERESIS);
+    }
+
+    #[test]
+    fn test_ppb_to_micrograms_per_cubic_meter() {
+        // WHO indoor guideline of 100 µg/m³ is about 81 ppb.
+        assert_approx_eq!(ppb_to_micrograms_per_cubic_meter(81.4), 100.0, 0.1);
+        assert_approx_eq!(ppb_to_micrograms_per_cubic_meter(0.0), 0.0);
+    }
+}
@@ -0,0 +1,201 @@
+//! Blocking Frequency Sensor API
+//!
+//! This API provides generic methods for interfacing with frequency-output sensors specifically,
+//! such as light-to-frequency converters (e.g. TSL235) or pulse sensors, whose measurement is
+//! encoded in the frequency of their output signal rather than in a register value.
+//!
+//! The frequency is typically measured by counting output edges with a timer over a gate time, or
+//! by timing the period of the signal with an input capture unit.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the FrequencySensor trait for a light-to-frequency
+//! converter.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::frequency::{Hertz, FrequencySensor};
+//!
+//! // A struct representing a light-to-frequency converter.
+//! pub struct MyLightToFrequency {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyLightToFrequency {
+//!     type Error = Error;
+//! }
+//!
+//! impl FrequencySensor for MyLightToFrequency {
+//!     fn frequency(&mut self) -> Result<Hertz, Self::Error> {
+//!         // Count output edges over gate time of timer...
+//!         Ok(12_500.0)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{Sensor, ValidRange};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units frequencies are measured in with the underlying data type.
+pub type Hertz = f32;
+
+/// Physically plausible range of frequency samples.
+pub const FREQUENCY_RANGE: ValidRange<Hertz> = ValidRange::new(0.0, Hertz::MAX);
+
+/// Marker type of the frequency quantity, of which every [`FrequencySensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Frequency;
+
+/// Blocking Frequency Sensor methods.
+pub trait FrequencySensor: Sensor<Frequency, Sample = Hertz> {
+    /// Returns a frequency sample in hertz.
+    fn frequency(&mut self) -> Result<Hertz, Self::Error>;
+}
+
+impl<T: FrequencySensor + ?Sized> FrequencySensor for &mut T {
+    #[inline]
+    fn frequency(&mut self) -> Result<Hertz, Self::Error> {
+        T::frequency(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Frequency, FrequencySensor, frequency, Hertz);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait FrequencyThresholdSet: FrequencySensor {
+//     fn set_frequency_threshold_low(&mut self, threshold: Hertz) -> Result<(), Self::Error>;
+//     fn set_frequency_threshold_high(&mut self, threshold: Hertz) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FrequencyHysteresis: FrequencyThresholdSet {
+//     fn set_frequency_threshold_hysteresis(&mut self, hysteresis: Hertz) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FrequencyThresholdControl: FrequencyThresholdSet {
+//     fn disable_frequency_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_frequency_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(blocking, Frequency, FrequencySensor, Hertz, "hertz");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Hertz = 12_500.0;
+    const TEST_THRESHOLD_LOW: Hertz = 1_000.0;
+    const TEST_THRESHOLD_HIGH: Hertz = 100_000.0;
+    const TEST_HYSTERESIS: Hertz = 50.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockFrequencySensor {
+        value: Hertz,
+        threshold_low: Option<Hertz>,
+        threshold_high: Option<Hertz>,
+        hysteresis: Option<Hertz>,
+    }
+
+    impl MockFrequencySensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockFrequencySensor {
+        type Error = MockError;
+    }
+
+    impl FrequencySensor for MockFrequencySensor {
+        fn frequency(&mut self) -> Result<Hertz, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl FrequencyThresholdSet for MockFrequencySensor {
+        fn set_frequency_threshold_low(&mut self, threshold: Hertz) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_frequency_threshold_high(&mut self, threshold: Hertz) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl FrequencyHysteresis for MockFrequencySensor {
+        fn set_frequency_threshold_hysteresis(
+            &mut self,
+            hysteresis: Hertz,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_frequency_sensor_trait() {
+        let mut sensor = MockFrequencySensor::new();
+        let result = sensor.frequency();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_frequency_sensor_trait_mut_ref() {
+        let mut sensor = MockFrequencySensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.frequency();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_frequency_threshold_set_mut_ref() {
+        let mut sensor = MockFrequencySensor::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_frequency_threshold_low(TEST_THRESHOLD_LOW)
+            .is_ok());
+        assert!(mut_ref
+            .set_frequency_threshold_high(TEST_THRESHOLD_HIGH)
+            .is_ok());
+        assert!(mut_ref
+            .set_frequency_threshold_hysteresis(TEST_HYSTERESIS)
+            .is_ok());
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), TEST_HYSTERESIS);
+    }
+}
@@ -0,0 +1,186 @@
+//! Blocking Gas Sensor API
+//!
+//! This API provides generic methods for interfacing with gas concentration sensors
+//! specifically.
+//!
+//! Rather than one trait per gas, the [`GasSensor`] trait takes the [`GasType`] to measure as a
+//! channel, so that multi-gas front-ends (e.g. several electrochemical cells behind one analog
+//! front-end) are modeled by a single implementation reporting the gases it supports.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the GasSensor trait for a multi-gas front-end.
+//!
+//! ```
+//! use embedded_sensors_hal::gas::{GasSensor, GasType, Ppm};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a front-end with carbon monoxide and nitrogen dioxide cells.
+//! pub struct MyGasSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     UnsupportedGas,
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             Error::UnsupportedGas => sensor::ErrorKind::InvalidInput,
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyGasSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl GasSensor for MyGasSensor {
+//!     fn concentration(&mut self, gas: GasType) -> Result<Ppm, Self::Error> {
+//!         match gas {
+//!             // Read and scale current of the matching cell...
+//!             GasType::CarbonMonoxide => Ok(3.5),
+//!             GasType::NitrogenDioxide => Ok(0.04),
+//!             _ => Err(Error::UnsupportedGas),
+//!         }
+//!     }
+//!
+//!     fn supported_gases(&self) -> &[GasType] {
+//!         &[GasType::CarbonMonoxide, GasType::NitrogenDioxide]
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{ErrorType, ValidRange};
+
+/// Associates the units gas concentrations are measured in with the underlying data type.
+pub type Ppm = f32;
+
+/// Physically plausible range of gas concentration samples.
+pub const GAS_CONCENTRATION_RANGE: ValidRange<Ppm> = ValidRange::new(0.0, 1_000_000.0);
+
+/// Gas measured by a [`GasSensor`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum GasType {
+    /// Carbon monoxide (CO).
+    CarbonMonoxide,
+    /// Carbon dioxide (CO2).
+    CarbonDioxide,
+    /// Nitrogen dioxide (NO2).
+    NitrogenDioxide,
+    /// Ozone (O3).
+    Ozone,
+    /// Ammonia (NH3).
+    Ammonia,
+    /// Hydrogen sulfide (H2S).
+    HydrogenSulfide,
+    /// Sulfur dioxide (SO2).
+    SulfurDioxide,
+    /// Methane (CH4).
+    Methane,
+    /// Hydrogen (H2).
+    Hydrogen,
+    /// Oxygen (O2).
+    Oxygen,
+}
+
+/// Blocking Gas Sensor methods.
+pub trait GasSensor: ErrorType {
+    /// Returns a concentration sample of the given gas in parts per million.
+    ///
+    /// Fails with an error of kind
+    /// [`ErrorKind::InvalidInput`](crate::sensor::ErrorKind::InvalidInput) if the gas is not one
+    /// of the [supported gases](Self::supported_gases).
+    fn concentration(&mut self, gas: GasType) -> Result<Ppm, Self::Error>;
+
+    /// Returns the gases the sensor can measure.
+    fn supported_gases(&self) -> &[GasType];
+
+    /// Returns `true` if the sensor can measure the given gas.
+    fn supports(&self, gas: GasType) -> bool {
+        self.supported_gases().contains(&gas)
+    }
+}
+
+impl<T: GasSensor + ?Sized> GasSensor for &mut T {
+    #[inline]
+    fn concentration(&mut self, gas: GasType) -> Result<Ppm, Self::Error> {
+        T::concentration(self, gas)
+    }
+
+    #[inline]
+    fn supported_gases(&self) -> &[GasType] {
+        T::supported_gases(self)
+    }
+
+    #[inline]
+    fn supports(&self, gas: GasType) -> bool {
+        T::supports(self, gas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_CO: Ppm = 3.5;
+    const TEST_NO2: Ppm = 0.04;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::InvalidInput
+        }
+    }
+
+    struct MockGasSensor;
+
+    impl crate::sensor::ErrorType for MockGasSensor {
+        type Error = MockError;
+    }
+
+    impl GasSensor for MockGasSensor {
+        fn concentration(&mut self, gas: GasType) -> Result<Ppm, Self::Error> {
+            match gas {
+                GasType::CarbonMonoxide => Ok(TEST_CO),
+                GasType::NitrogenDioxide => Ok(TEST_NO2),
+                _ => Err(MockError),
+            }
+        }
+
+        fn supported_gases(&self) -> &[GasType] {
+            &[GasType::CarbonMonoxide, GasType::NitrogenDioxide]
+        }
+    }
+
+    #[test]
+    fn test_gas_sensor_trait() {
+        let mut sensor = MockGasSensor;
+        let result = sensor.concentration(GasType::CarbonMonoxide);
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_CO);
+        assert!(sensor.concentration(GasType::Ozone).is_err());
+    }
+
+    #[test]
+    fn test_gas_sensor_trait_mut_ref() {
+        let mut sensor = MockGasSensor;
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.supports(GasType::NitrogenDioxide));
+        assert!(!mut_ref.supports(GasType::Methane));
+        let result = mut_ref.concentration(GasType::NitrogenDioxide);
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_NO2);
+    }
+}
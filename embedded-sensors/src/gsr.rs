@@ -0,0 +1,139 @@
+//! Blocking Galvanic Skin Response Sensor API
+//!
+//! This API provides generic methods for interfacing with galvanic skin response (GSR) sensors
+//! specifically, also known as electrodermal activity (EDA) sensors, as used by stress and
+//! wellness wearables.
+//!
+//! Skin conductance is deliberately kept distinct from the conductivity of liquids or soils, as
+//! electrode models and measurement ranges differ.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the GsrSensor trait for an EDA front-end.
+//!
+//! ```
+//! use embedded_sensors_hal::gsr::{GsrSensor, Microsiemens};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing an electrodermal activity front-end.
+//! pub struct MyEdaFrontEnd {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyEdaFrontEnd {
+//!     type Error = Error;
+//! }
+//!
+//! impl GsrSensor for MyEdaFrontEnd {
+//!     fn skin_conductance(&mut self) -> Result<Microsiemens, Self::Error> {
+//!         // Read ADC and compute conductance from excitation voltage and sense current...
+//!         Ok(4.2)
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+use crate::sensor::{Sensor, ValidRange};
+
+/// Associates the units skin conductance samples are measured in with the underlying data type.
+pub type Microsiemens = f32;
+
+/// Physically plausible range of skin conductance samples.
+pub const SKIN_CONDUCTANCE_RANGE: ValidRange<Microsiemens> = ValidRange::new(0.0, 100.0);
+
+/// Marker type of the skin conductance quantity, of which every [`GsrSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SkinConductance;
+
+/// Blocking Galvanic Skin Response Sensor methods.
+pub trait GsrSensor: Sensor<SkinConductance, Sample = Microsiemens> {
+    /// Returns a skin conductance sample in microsiemens.
+    fn skin_conductance(&mut self) -> Result<Microsiemens, Self::Error>;
+}
+
+impl<T: GsrSensor + ?Sized> GsrSensor for &mut T {
+    #[inline]
+    fn skin_conductance(&mut self) -> Result<Microsiemens, Self::Error> {
+        T::skin_conductance(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    SkinConductance,
+    GsrSensor,
+    skin_conductance,
+    Microsiemens
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test value
+    const TEST_VALUE: Microsiemens = 4.2;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockGsrSensor;
+
+    impl crate::sensor::ErrorType for MockGsrSensor {
+        type Error = MockError;
+    }
+
+    impl GsrSensor for MockGsrSensor {
+        fn skin_conductance(&mut self) -> Result<Microsiemens, Self::Error> {
+            Ok(TEST_VALUE)
+        }
+    }
+
+    #[test]
+    fn test_gsr_sensor_trait() {
+        let mut sensor = MockGsrSensor;
+        let result = sensor.skin_conductance();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_gsr_sensor_trait_mut_ref() {
+        let mut sensor = MockGsrSensor;
+        let mut_ref = &mut sensor;
+        let result = mut_ref.skin_conductance();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_gsr_range() {
+        assert!(SKIN_CONDUCTANCE_RANGE.validate(TEST_VALUE).is_ok());
+        assert_eq!(
+            SKIN_CONDUCTANCE_RANGE.validate(-0.5),
+            Err(ErrorKind::OutOfRange)
+        );
+    }
+}
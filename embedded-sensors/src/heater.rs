@@ -0,0 +1,228 @@
+//! Blocking Sensor Heater API
+//!
+//! This API provides generic methods for controlling the on-chip heaters of sensors, such as
+//! those of relative humidity sensors used to evaporate condensation or to recover from creep
+//! after prolonged exposure to high humidity.
+//!
+//! Sensors whose heater supports several power levels (e.g. SHT4x, HDC302x or MOX gas sensors)
+//! can additionally implement the [`HeaterPower`] trait.
+//!
+//! The [`ReconditioningCycle`] type describes a datasheet-style heating procedure, which the
+//! async API can drive through the heater in a single call.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the HeaterControl trait for a humidity sensor.
+//!
+//! ```
+//! use embedded_sensors_hal::heater::HeaterControl;
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a humidity sensor with an on-chip heater.
+//! pub struct MyHumiditySensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyHumiditySensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl HeaterControl for MyHumiditySensor {
+//!     fn enable_heater(&mut self) -> Result<(), Self::Error> {
+//!         // Set heater enable bit in configuration register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     fn disable_heater(&mut self) -> Result<(), Self::Error> {
+//!         // Clear heater enable bit in configuration register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+
+/// Associates the units heater power levels are measured in with the underlying data type.
+pub type Milliwatts = u16;
+
+/// Blocking Heater Control methods.
+pub trait HeaterControl: ErrorType {
+    /// Turn the heater of the sensor on.
+    ///
+    /// Samples taken while the heater is on are not representative of the environment.
+    fn enable_heater(&mut self) -> Result<(), Self::Error>;
+
+    /// Turn the heater of the sensor off.
+    fn disable_heater(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: HeaterControl + ?Sized> HeaterControl for &mut T {
+    #[inline]
+    fn enable_heater(&mut self) -> Result<(), Self::Error> {
+        T::enable_heater(self)
+    }
+
+    #[inline]
+    fn disable_heater(&mut self) -> Result<(), Self::Error> {
+        T::disable_heater(self)
+    }
+}
+
+/// Blocking Heater Power methods, for heaters supporting several power levels.
+pub trait HeaterPower: HeaterControl {
+    /// Set the power of the heater to the nearest supported level, in milliwatts.
+    ///
+    /// Returns the power level actually selected. The heater is not turned on by this method.
+    fn set_heater_power(&mut self, power: Milliwatts) -> Result<Milliwatts, Self::Error>;
+}
+
+impl<T: HeaterPower + ?Sized> HeaterPower for &mut T {
+    #[inline]
+    fn set_heater_power(&mut self, power: Milliwatts) -> Result<Milliwatts, Self::Error> {
+        T::set_heater_power(self, power)
+    }
+}
+
+/// A heating procedure made of identical heater pulses, as specified by sensor datasheets.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReconditioningCycle {
+    /// Number of heater pulses.
+    pub pulses: u16,
+    /// Time the heater is on during each pulse, in milliseconds.
+    pub on_ms: u32,
+    /// Time the heater is off after each pulse, in milliseconds.
+    pub off_ms: u32,
+}
+
+impl ReconditioningCycle {
+    /// Creates a new reconditioning cycle.
+    pub const fn new(pulses: u16, on_ms: u32, off_ms: u32) -> Self {
+        Self {
+            pulses,
+            on_ms,
+            off_ms,
+        }
+    }
+
+    /// Returns the total duration of the cycle in milliseconds.
+    pub const fn duration_ms(&self) -> u64 {
+        self.pulses as u64 * (self.on_ms as u64 + self.off_ms as u64)
+    }
+}
+
+/// Progress of a reconditioning cycle, reported after each heater pulse.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReconditioningProgress {
+    /// Number of pulses completed.
+    pub completed: u16,
+    /// Total number of pulses of the cycle.
+    pub total: u16,
+}
+
+impl ReconditioningProgress {
+    /// Returns true once every pulse of the cycle has completed.
+    pub const fn is_done(&self) -> bool {
+        self.completed >= self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    // Mock test values
+    const TEST_POWER_LEVELS: [Milliwatts; 3] = [20, 110, 200];
+
+    struct MockHumiditySensor {
+        heater_on: bool,
+        power: Milliwatts,
+    }
+
+    impl crate::sensor::ErrorType for MockHumiditySensor {
+        type Error = MockError;
+    }
+
+    impl HeaterControl for MockHumiditySensor {
+        fn enable_heater(&mut self) -> Result<(), Self::Error> {
+            self.heater_on = true;
+            Ok(())
+        }
+
+        fn disable_heater(&mut self) -> Result<(), Self::Error> {
+            self.heater_on = false;
+            Ok(())
+        }
+    }
+
+    impl HeaterPower for MockHumiditySensor {
+        fn set_heater_power(&mut self, power: Milliwatts) -> Result<Milliwatts, Self::Error> {
+            self.power = *TEST_POWER_LEVELS
+                .iter()
+                .min_by_key(|level| level.abs_diff(power))
+                .unwrap();
+            Ok(self.power)
+        }
+    }
+
+    #[test]
+    fn test_heater_control_trait_mut_ref() {
+        let mut sensor = MockHumiditySensor {
+            heater_on: false,
+            power: TEST_POWER_LEVELS[2],
+        };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.enable_heater().is_ok());
+        assert!(mut_ref.heater_on);
+        assert!(mut_ref.disable_heater().is_ok());
+        assert!(!sensor.heater_on);
+    }
+
+    #[test]
+    fn test_heater_power_trait_mut_ref() {
+        let mut sensor = MockHumiditySensor {
+            heater_on: false,
+            power: TEST_POWER_LEVELS[2],
+        };
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.set_heater_power(100).unwrap(), 110);
+        assert_eq!(mut_ref.set_heater_power(0).unwrap(), 20);
+        assert!(!sensor.heater_on);
+    }
+
+    #[test]
+    fn test_reconditioning_cycle() {
+        let cycle = ReconditioningCycle::new(10, 1_000, 5_000);
+        assert_eq!(cycle.duration_ms(), 60_000);
+
+        let progress = ReconditioningProgress {
+            completed: 9,
+            total: cycle.pulses,
+        };
+        assert!(!progress.is_done());
+    }
+}
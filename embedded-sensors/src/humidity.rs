@@ -2,6 +2,15 @@
 //!
 //! This API provides generic methods for interfacing with humidity sensors specifically.
 //!
+//! Sensors reporting absolute humidity implement the [`AbsoluteHumiditySensor`] trait. With the
+//! `libm` feature enabled, the `DerivedAbsoluteHumidity` adapter derives it for sensors measuring
+//! both relative humidity and temperature instead. Measurements without a finite absolute humidity
+//! are reported as `DerivedAbsoluteHumidityError::InvalidSample` rather than as NaN.
+//!
+//! Drivers for targets without a floating-point unit can implement the
+//! [`FixedPointRelativeHumiditySensor`] trait, reporting samples in integer thousandths of a
+//! percent, along with the fixed-point threshold and calibration traits built upon it.
+//!
 //! # For HAL authors
 //!
 //! Here is an example for the implementation of the RelativeHumiditySensor trait for a humidity sensor.
@@ -40,14 +49,67 @@
 //! }
 //! ```
 
-use crate::decl_threshold_traits;
-use crate::sensor::ErrorType;
+use crate::frequency::Hertz;
+#[cfg(feature = "libm")]
+use crate::sensor::{checked_sample, Error, ErrorKind, ErrorType};
+use crate::sensor::{AlertPolarity, OperatingMode, Sensor, ValidRange};
+#[cfg(feature = "libm")]
+use crate::temperature::{DegreesCelsius, TemperatureSensor};
+#[cfg(feature = "libm")]
+use crate::units::KELVIN_OFFSET;
+use crate::{
+    decl_calibration_trait, decl_configuration_trait, decl_fixed_point_calibration_trait,
+    decl_fixed_point_threshold_traits, decl_fixed_point_trait, decl_sample_read_trait,
+    decl_threshold_traits, impl_sensor_refinement,
+};
 
 /// Associates the units relative humidity (RH) samples are measured in with the underlying data type.
 pub type Percentage = f32;
 
+/// Associates the units fixed-point relative humidity samples are measured in with the underlying data type.
+pub type MilliPercentage = i32;
+
+/// Associates the units absolute humidity samples are measured in with the underlying data type.
+pub type GramsPerCubicMeter = f32;
+
+/// Physically plausible range of relative humidity samples.
+pub const RELATIVE_HUMIDITY_RANGE: ValidRange<Percentage> = ValidRange::new(0.0, 100.0);
+
+/// Configuration of a relative humidity sensor.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RelativeHumidityConfig {
+    /// Rate at which samples are converted in continuous mode, in hertz.
+    pub conversion_rate: Hertz,
+    /// Whether samples are converted continuously or on demand.
+    pub mode: OperatingMode,
+    /// Active level of the alert output.
+    pub alert_polarity: AlertPolarity,
+}
+
+impl RelativeHumidityConfig {
+    /// Creates a new relative humidity sensor configuration.
+    pub const fn new(
+        conversion_rate: Hertz,
+        mode: OperatingMode,
+        alert_polarity: AlertPolarity,
+    ) -> Self {
+        Self {
+            conversion_rate,
+            mode,
+            alert_polarity,
+        }
+    }
+}
+
+/// Marker type of the relative humidity quantity, of which every [`RelativeHumiditySensor`]
+/// implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RelativeHumidity;
+
 /// Blocking Relative Humidity Sensor methods.
-pub trait RelativeHumiditySensor: ErrorType {
+pub trait RelativeHumiditySensor: Sensor<RelativeHumidity, Sample = Percentage> {
     /// Returns a relative humidity (RH) sample as a percentage.
     fn relative_humidity(&mut self) -> Result<Percentage, Self::Error>;
 }
@@ -59,6 +121,14 @@ impl<T: RelativeHumiditySensor + ?Sized> RelativeHumiditySensor for &mut T {
     }
 }
 
+impl_sensor_refinement!(
+    blocking,
+    RelativeHumidity,
+    RelativeHumiditySensor,
+    relative_humidity,
+    Percentage
+);
+
 // This macro generates the following blocking threshold traits:
 //
 // pub trait RelativeHumidityThresholdSet: RelativeHumiditySensor {
@@ -69,6 +139,11 @@ impl<T: RelativeHumiditySensor + ?Sized> RelativeHumiditySensor for &mut T {
 // pub trait RelativeHumidityHysteresis: RelativeHumidityThresholdSet {
 //     fn set_relative_humidity_threshold_hysteresis(&mut self, hysteresis: Percentage) -> Result<(), Self::Error>;
 // }
+//
+// pub trait RelativeHumidityThresholdControl: RelativeHumidityThresholdSet {
+//     fn disable_relative_humidity_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_relative_humidity_alert(&mut self) -> Result<(), Self::Error>;
+// }
 decl_threshold_traits!(
     blocking,
     RelativeHumidity,
@@ -77,14 +152,217 @@ decl_threshold_traits!(
     "percentage"
 );
 
+// This macro generates the following blocking configuration trait:
+//
+// pub trait RelativeHumidityConfigure: RelativeHumiditySensor {
+//     fn set_relative_humidity_config(&mut self, config: RelativeHumidityConfig) -> Result<(), Self::Error>;
+// }
+decl_configuration_trait!(
+    blocking,
+    RelativeHumidity,
+    RelativeHumiditySensor,
+    RelativeHumidityConfig
+);
+
+// This macro generates the following blocking calibration trait:
+//
+// pub trait RelativeHumidityCalibrate: RelativeHumiditySensor {
+//     fn set_relative_humidity_offset(&mut self, offset: Percentage) -> Result<(), Self::Error>;
+//     fn set_relative_humidity_gain(&mut self, gain: f32) -> Result<(), Self::Error>;
+// }
+decl_calibration_trait!(
+    blocking,
+    RelativeHumidity,
+    RelativeHumiditySensor,
+    Percentage,
+    "percentage"
+);
+
+// This macro generates the following blocking sample read trait:
+//
+// pub trait RelativeHumiditySampleRead: RelativeHumiditySensor {
+//     fn relative_humidity_sample(&mut self) -> Result<Sample<Percentage>, Self::Error>;
+// }
+decl_sample_read_trait!(
+    blocking,
+    RelativeHumidity,
+    RelativeHumiditySensor,
+    Percentage
+);
+
+// This macro generates the following blocking fixed-point trait:
+//
+// pub trait FixedPointRelativeHumiditySensor: ErrorType {
+//     fn relative_humidity_milli(&mut self) -> Result<MilliPercentage, Self::Error>;
+// }
+decl_fixed_point_trait!(
+    blocking,
+    RelativeHumidity,
+    MilliPercentage,
+    "thousandths of a percent"
+);
+
+// This macro generates the following blocking fixed-point threshold traits:
+//
+// pub trait FixedPointRelativeHumidityThresholdSet: FixedPointRelativeHumiditySensor {
+//     fn set_relative_humidity_threshold_low_milli(&mut self, threshold: MilliPercentage) -> Result<(), Self::Error>;
+//     fn set_relative_humidity_threshold_high_milli(&mut self, threshold: MilliPercentage) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FixedPointRelativeHumidityHysteresis: FixedPointRelativeHumidityThresholdSet {
+//     fn set_relative_humidity_threshold_hysteresis_milli(&mut self, hysteresis: MilliPercentage) -> Result<(), Self::Error>;
+// }
+decl_fixed_point_threshold_traits!(
+    blocking,
+    RelativeHumidity,
+    MilliPercentage,
+    "thousandths of a percent"
+);
+
+// This macro generates the following blocking fixed-point calibration trait:
+//
+// pub trait FixedPointRelativeHumidityCalibrate: FixedPointRelativeHumiditySensor {
+//     fn set_relative_humidity_offset_milli(&mut self, offset: MilliPercentage) -> Result<(), Self::Error>;
+//     fn set_relative_humidity_gain_milli(&mut self, gain: u32) -> Result<(), Self::Error>;
+// }
+decl_fixed_point_calibration_trait!(
+    blocking,
+    RelativeHumidity,
+    MilliPercentage,
+    "thousandths of a percent"
+);
+
+/// Marker type of the absolute humidity quantity, of which every [`AbsoluteHumiditySensor`]
+/// implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AbsoluteHumidity;
+
+/// Blocking Absolute Humidity Sensor methods.
+pub trait AbsoluteHumiditySensor: Sensor<AbsoluteHumidity, Sample = GramsPerCubicMeter> {
+    /// Returns an absolute humidity sample in grams of water vapor per cubic meter of air.
+    fn absolute_humidity(&mut self) -> Result<GramsPerCubicMeter, Self::Error>;
+}
+
+impl<T: AbsoluteHumiditySensor + ?Sized> AbsoluteHumiditySensor for &mut T {
+    #[inline]
+    fn absolute_humidity(&mut self) -> Result<GramsPerCubicMeter, Self::Error> {
+        T::absolute_humidity(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    AbsoluteHumidity,
+    AbsoluteHumiditySensor,
+    absolute_humidity,
+    GramsPerCubicMeter
+);
+
+/// Computes the absolute humidity (in grams per cubic meter) of air at the given temperature (in
+/// degrees Celsius) and relative humidity (as a percentage).
+///
+/// The saturation vapor pressure is computed with the Magnus formula, using the same coefficients
+/// of Sonntag (1990) as [`magnus_dew_point`](crate::dew_point::magnus_dew_point), and water vapor
+/// is treated as an ideal gas.
+#[cfg(feature = "libm")]
+pub fn absolute_humidity(
+    temperature: DegreesCelsius,
+    relative_humidity: Percentage,
+) -> GramsPerCubicMeter {
+    const B: f32 = 17.62;
+    const C: DegreesCelsius = 243.12;
+    // Saturation vapor pressure at 0 °C, in pascals.
+    const E0: f32 = 611.2;
+    // Specific gas constant of water vapor, in J/(kg·K).
+    const RV: f32 = 461.5;
+
+    let saturation = E0 * libm::expf(B * temperature / (C + temperature));
+    let vapor_pressure = saturation * relative_humidity / 100.0;
+    vapor_pressure / (RV * (temperature + KELVIN_OFFSET)) * 1000.0
+}
+
+/// Derives the absolute humidity of a sensor measuring both temperature and relative humidity.
+///
+/// The wrapped sensor's temperature and relative humidity remain available through the adapter.
+#[cfg(feature = "libm")]
+pub struct DerivedAbsoluteHumidity<S>(pub S);
+
+/// Error returned by a [`DerivedAbsoluteHumidity`] adapter.
+#[cfg(feature = "libm")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DerivedAbsoluteHumidityError<E> {
+    /// The wrapped sensor failed.
+    Sensor(E),
+    /// The measured temperature and relative humidity have no finite absolute humidity (e.g. a
+    /// NaN sample).
+    InvalidSample,
+}
+
+#[cfg(feature = "libm")]
+impl<E: Error> Error for DerivedAbsoluteHumidityError<E> {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Sensor(e) => e.kind(),
+            Self::InvalidSample => ErrorKind::InvalidInput,
+        }
+    }
+}
+
+#[cfg(feature = "libm")]
+impl<S: ErrorType> ErrorType for DerivedAbsoluteHumidity<S> {
+    type Error = DerivedAbsoluteHumidityError<S::Error>;
+}
+
+#[cfg(feature = "libm")]
+impl<S: TemperatureSensor + RelativeHumiditySensor> AbsoluteHumiditySensor
+    for DerivedAbsoluteHumidity<S>
+{
+    fn absolute_humidity(&mut self) -> Result<GramsPerCubicMeter, Self::Error> {
+        let temperature = self
+            .0
+            .temperature()
+            .map_err(DerivedAbsoluteHumidityError::Sensor)?;
+        let relative_humidity = self
+            .0
+            .relative_humidity()
+            .map_err(DerivedAbsoluteHumidityError::Sensor)?;
+        checked_sample(absolute_humidity(temperature, relative_humidity))
+            .map_err(|_| DerivedAbsoluteHumidityError::InvalidSample)
+    }
+}
+
+#[cfg(feature = "libm")]
+impl<S: TemperatureSensor> TemperatureSensor for DerivedAbsoluteHumidity<S> {
+    #[inline]
+    fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        self.0
+            .temperature()
+            .map_err(DerivedAbsoluteHumidityError::Sensor)
+    }
+}
+
+#[cfg(feature = "libm")]
+impl<S: RelativeHumiditySensor> RelativeHumiditySensor for DerivedAbsoluteHumidity<S> {
+    #[inline]
+    fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+        self.0
+            .relative_humidity()
+            .map_err(DerivedAbsoluteHumidityError::Sensor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::sensor::{Error, ErrorKind};
     use assert_approx_eq::assert_approx_eq;
 
-    // Mock test value
+    // Mock test values
     const TEST_HUMIDITY: Percentage = 65.0;
+    const TEST_ABSOLUTE_HUMIDITY: GramsPerCubicMeter = 11.2;
 
     #[derive(Debug)]
     struct MockError;
@@ -246,4 +524,140 @@ mod tests {
         assert!(result.is_ok());
         assert_approx_eq!(sensor.hysteresis.unwrap(), hyst);
     }
+
+    #[test]
+    fn test_relative_humidity_range() {
+        assert!(RELATIVE_HUMIDITY_RANGE.validate(TEST_HUMIDITY).is_ok());
+        assert!(RELATIVE_HUMIDITY_RANGE.validate(0.0).is_ok());
+        assert!(RELATIVE_HUMIDITY_RANGE.validate(100.0).is_ok());
+        assert_eq!(
+            RELATIVE_HUMIDITY_RANGE.validate(-0.5),
+            Err(ErrorKind::OutOfRange)
+        );
+        assert_eq!(
+            RELATIVE_HUMIDITY_RANGE.validate(100.5),
+            Err(ErrorKind::OutOfRange)
+        );
+    }
+
+    struct MockComboSensor;
+
+    impl crate::sensor::ErrorType for MockComboSensor {
+        type Error = MockError;
+    }
+
+    impl AbsoluteHumiditySensor for MockComboSensor {
+        fn absolute_humidity(&mut self) -> Result<GramsPerCubicMeter, Self::Error> {
+            Ok(TEST_ABSOLUTE_HUMIDITY)
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    impl TemperatureSensor for MockComboSensor {
+        fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(20.0)
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    impl RelativeHumiditySensor for MockComboSensor {
+        fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(50.0)
+        }
+    }
+
+    #[test]
+    fn test_absolute_humidity_sensor_trait_mut_ref() {
+        let mut sensor = MockComboSensor;
+        let mut_ref = &mut sensor;
+        let result = mut_ref.absolute_humidity();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_ABSOLUTE_HUMIDITY);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    fn test_derived_absolute_humidity() {
+        let mut sensor = DerivedAbsoluteHumidity(MockComboSensor);
+        let result = sensor.absolute_humidity();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), 8.64, 0.02);
+        assert_approx_eq!(sensor.relative_humidity().unwrap(), 50.0);
+
+        // Dry air holds no water vapor, saturated air at 30 °C about 30 g/m³.
+        assert_approx_eq!(absolute_humidity(25.0, 0.0), 0.0);
+        assert_approx_eq!(absolute_humidity(30.0, 100.0), 30.3, 0.1);
+    }
+
+    // Reports a NaN temperature, e.g. from a failed conversion.
+    #[cfg(feature = "libm")]
+    struct MockNanSensor;
+
+    #[cfg(feature = "libm")]
+    impl crate::sensor::ErrorType for MockNanSensor {
+        type Error = MockError;
+    }
+
+    #[cfg(feature = "libm")]
+    impl TemperatureSensor for MockNanSensor {
+        fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(f32::NAN)
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    impl RelativeHumiditySensor for MockNanSensor {
+        fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(50.0)
+        }
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    fn test_derived_absolute_humidity_nan_input() {
+        let mut sensor = DerivedAbsoluteHumidity(MockNanSensor);
+        let result = sensor.absolute_humidity();
+        assert!(matches!(
+            result,
+            Err(DerivedAbsoluteHumidityError::InvalidSample)
+        ));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
+
+    struct MockConfigurableHumiditySensor {
+        config: RelativeHumidityConfig,
+    }
+
+    impl crate::sensor::ErrorType for MockConfigurableHumiditySensor {
+        type Error = MockError;
+    }
+
+    impl RelativeHumiditySensor for MockConfigurableHumiditySensor {
+        fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(TEST_HUMIDITY)
+        }
+    }
+
+    impl RelativeHumidityConfigure for MockConfigurableHumiditySensor {
+        fn set_relative_humidity_config(
+            &mut self,
+            config: RelativeHumidityConfig,
+        ) -> Result<(), Self::Error> {
+            self.config = config;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_humidity_configure_mut_ref() {
+        let mut sensor = MockConfigurableHumiditySensor {
+            config: RelativeHumidityConfig::default(),
+        };
+        assert_eq!(sensor.config.mode, OperatingMode::Continuous);
+        let config =
+            RelativeHumidityConfig::new(1.0, OperatingMode::OneShot, AlertPolarity::ActiveHigh);
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_relative_humidity_config(config).is_ok());
+        assert_eq!(sensor.config, config);
+    }
 }
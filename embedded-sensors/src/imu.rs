@@ -0,0 +1,156 @@
+//! Blocking Inertial Measurement Unit API
+//!
+//! This API provides generic methods for interfacing with inertial measurement units (IMU)
+//! combining an accelerometer and a gyroscope specifically, such as the LSM6DSO or BMI270.
+//!
+//! Sensor fusion algorithms need acceleration and angular rate samples taken at the same
+//! instant, which separate calls cannot guarantee. The [`Imu`] trait therefore returns both in a
+//! single [`ImuSample`], read from the same output register set or FIFO frame.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the Imu trait for a 6-axis IMU.
+//!
+//! ```
+//! use embedded_sensors_hal::imu::{Imu, ImuSample, Vector3};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a 6-axis IMU.
+//! pub struct MyImu {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyImu {
+//!     type Error = Error;
+//! }
+//!
+//! impl Imu for MyImu {
+//!     fn imu_sample(&mut self) -> Result<ImuSample, Self::Error> {
+//!         // Burst read gyroscope and accelerometer output registers of sensor...
+//!         Ok(ImuSample {
+//!             acceleration: Vector3::new(0.02, -0.05, 9.81),
+//!             angular_rate: Vector3::new(0.1, 0.0, -0.3),
+//!         })
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+use crate::sensor::Sensor;
+pub use crate::vibration::MetersPerSecondSquared;
+
+/// Associates the units angular rates are measured in with the underlying data type.
+pub type DegreesPerSecond = f32;
+
+/// Three-axis vector, in the reference frame of the sensor.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Vector3<T> {
+    /// X axis component.
+    pub x: T,
+    /// Y axis component.
+    pub y: T,
+    /// Z axis component.
+    pub z: T,
+}
+
+impl<T> Vector3<T> {
+    /// Creates a new vector from its components.
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+/// Acceleration and angular rate sampled at the same instant.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ImuSample {
+    /// Acceleration, including gravity, in meters per second squared.
+    pub acceleration: Vector3<MetersPerSecondSquared>,
+    /// Angular rate in degrees per second.
+    pub angular_rate: Vector3<DegreesPerSecond>,
+}
+
+/// Marker type of the inertial (acceleration and angular rate) quantity, of which every [`Imu`]
+/// implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Inertial;
+
+/// Blocking Inertial Measurement Unit methods.
+pub trait Imu: Sensor<Inertial, Sample = ImuSample> {
+    /// Returns an acceleration and angular rate sample taken at the same instant.
+    fn imu_sample(&mut self) -> Result<ImuSample, Self::Error>;
+}
+
+impl<T: Imu + ?Sized> Imu for &mut T {
+    #[inline]
+    fn imu_sample(&mut self) -> Result<ImuSample, Self::Error> {
+        T::imu_sample(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Inertial, Imu, imu_sample, ImuSample);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_SAMPLE: ImuSample = ImuSample {
+        acceleration: Vector3::new(0.02, -0.05, 9.81),
+        angular_rate: Vector3::new(0.1, 0.0, -0.3),
+    };
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockImu;
+
+    impl crate::sensor::ErrorType for MockImu {
+        type Error = MockError;
+    }
+
+    impl Imu for MockImu {
+        fn imu_sample(&mut self) -> Result<ImuSample, Self::Error> {
+            Ok(TEST_SAMPLE)
+        }
+    }
+
+    #[test]
+    fn test_imu_trait() {
+        let mut sensor = MockImu;
+        let result = sensor.imu_sample();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_SAMPLE);
+    }
+
+    #[test]
+    fn test_imu_trait_mut_ref() {
+        let mut sensor = MockImu;
+        let mut_ref = &mut sensor;
+        let result = mut_ref.imu_sample();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_SAMPLE);
+    }
+}
@@ -0,0 +1,162 @@
+//! Blocking Inclination Sensor API
+//!
+//! This API provides generic methods for interfacing with inclination (tilt) sensors
+//! specifically.
+//!
+//! The API is suited to both dedicated inclinometer ICs and implementations deriving inclination
+//! from the gravity vector measured by an accelerometer.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the InclinationSensor trait for an inclinometer.
+//!
+//! ```
+//! use embedded_sensors_hal::inclination::{Inclination, InclinationSensor};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing an inclinometer.
+//! pub struct MyInclinometer {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyInclinometer {
+//!     type Error = Error;
+//! }
+//!
+//! impl InclinationSensor for MyInclinometer {
+//!     fn inclination(&mut self) -> Result<Inclination, Self::Error> {
+//!         // Read angle registers of sensor...
+//!         Ok(Inclination {
+//!             pitch: 1.5,
+//!             roll: -0.25,
+//!         })
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+use crate::sensor::{Sensor, ValidRange};
+
+/// Associates the units inclination angles are measured in with the underlying data type.
+pub type Degrees = f32;
+
+/// Range of pitch angles.
+pub const PITCH_RANGE: ValidRange<Degrees> = ValidRange::new(-90.0, 90.0);
+
+/// Range of roll angles.
+pub const ROLL_RANGE: ValidRange<Degrees> = ValidRange::new(-180.0, 180.0);
+
+/// Inclination of a sensor relative to the horizontal plane.
+///
+/// Angles follow the aerospace convention: pitch is the rotation about the lateral (Y) axis,
+/// positive nose up, and roll the rotation about the longitudinal (X) axis, positive right side
+/// down.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Inclination {
+    /// Pitch angle, within [`PITCH_RANGE`].
+    pub pitch: Degrees,
+    /// Roll angle, within [`ROLL_RANGE`].
+    pub roll: Degrees,
+}
+
+/// Marker type of the inclination quantity, of which every [`InclinationSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Tilt;
+
+/// Blocking Inclination Sensor methods.
+pub trait InclinationSensor: Sensor<Tilt, Sample = Inclination> {
+    /// Returns an inclination sample with angles in degrees.
+    fn inclination(&mut self) -> Result<Inclination, Self::Error>;
+}
+
+impl<T: InclinationSensor + ?Sized> InclinationSensor for &mut T {
+    #[inline]
+    fn inclination(&mut self) -> Result<Inclination, Self::Error> {
+        T::inclination(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Tilt, InclinationSensor, inclination, Inclination);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_INCLINATION: Inclination = Inclination {
+        pitch: 12.5,
+        roll: -3.0,
+    };
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockInclinometer {
+        value: Inclination,
+    }
+
+    impl crate::sensor::ErrorType for MockInclinometer {
+        type Error = MockError;
+    }
+
+    impl InclinationSensor for MockInclinometer {
+        fn inclination(&mut self) -> Result<Inclination, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    #[test]
+    fn test_inclination_sensor_trait() {
+        let mut sensor = MockInclinometer {
+            value: TEST_INCLINATION,
+        };
+        let result = sensor.inclination();
+        assert!(result.is_ok());
+        let inclination = result.unwrap();
+        assert_approx_eq!(inclination.pitch, TEST_INCLINATION.pitch);
+        assert_approx_eq!(inclination.roll, TEST_INCLINATION.roll);
+    }
+
+    #[test]
+    fn test_inclination_sensor_trait_mut_ref() {
+        let mut sensor = MockInclinometer {
+            value: TEST_INCLINATION,
+        };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.inclination();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_INCLINATION);
+    }
+
+    #[test]
+    fn test_inclination_ranges() {
+        assert!(PITCH_RANGE.contains(TEST_INCLINATION.pitch));
+        assert!(!PITCH_RANGE.contains(120.0));
+        assert!(ROLL_RANGE.contains(TEST_INCLINATION.roll));
+        assert!(!ROLL_RANGE.contains(-190.0));
+    }
+}
@@ -3,6 +3,74 @@
 #![forbid(unsafe_code)]
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod altitude;
+pub mod angle;
+pub mod array;
+pub mod battery;
+pub mod biopotential;
+pub mod blood_pressure;
+pub mod capacitance;
+pub mod clock;
+pub mod color;
+pub mod combustible_gas;
+pub mod compensation;
+pub mod derating;
+pub mod dew_point;
+pub mod distance;
+pub mod fifo;
+pub mod flame;
+pub mod flicker;
+pub mod flow;
+pub mod force;
+pub mod formaldehyde;
+pub mod frequency;
+pub mod gas;
+pub mod gsr;
+pub mod heater;
 pub mod humidity;
+pub mod imu;
+pub mod inclination;
+pub mod lightning;
+pub mod liquid_level;
+pub mod magnetic_switch;
+pub mod motion;
+pub mod object_temperature;
+pub mod occupancy;
+pub mod orientation;
+pub mod overheat;
+pub mod power;
+pub mod precipitation;
+pub mod presence;
+pub mod pressure;
+pub mod radiation;
+pub mod recovery;
+pub mod registry;
+pub mod resistance;
+pub mod respiration;
+pub mod rtd;
+pub mod sanitize;
 pub mod sensor;
+pub mod shock;
+pub mod smoke;
+#[cfg(feature = "std")]
+pub mod soak;
+pub mod soil;
+pub mod sound;
+pub mod source;
+pub mod spectral;
+pub mod spo2;
+pub mod step_counter;
+pub mod tap;
 pub mod temperature;
+pub mod thermal_array;
+pub mod timing;
+pub mod touch;
+pub mod units;
+pub mod vibration;
+pub mod virtual_sensor;
+pub mod weight;
+pub mod wind;
+pub mod wire;
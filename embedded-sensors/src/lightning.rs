@@ -0,0 +1,177 @@
+//! Blocking Lightning Detector API
+//!
+//! This API provides generic methods for interfacing with lightning detectors (e.g. AS3935)
+//! specifically.
+//!
+//! Unlike most sensors, lightning detectors are event-centric: they report the estimated distance
+//! to the head of the storm and the energy of the most recent strike, rather than a continuously
+//! sampled quantity.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the LightningDetector trait for an AS3935.
+//!
+//! ```
+//! use embedded_sensors_hal::lightning::{Kilometers, LightningDetector, StrikeEnergy};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a lightning detector.
+//! pub struct MyLightningDetector {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyLightningDetector {
+//!     type Error = Error;
+//! }
+//!
+//! impl LightningDetector for MyLightningDetector {
+//!     fn strike_distance(&mut self) -> Result<Option<Kilometers>, Self::Error> {
+//!         // Read distance estimation register of detector (0x3F means out of range)...
+//!         Ok(Some(14.0))
+//!     }
+//!
+//!     fn strike_energy(&mut self) -> Result<StrikeEnergy, Self::Error> {
+//!         // Read lightning energy registers of detector...
+//!         Ok(0x0001_2345)
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+use crate::sensor::Sensor;
+
+/// Associates the units strike distances are estimated in with the underlying data type.
+pub type Kilometers = f32;
+
+/// Associates the energy of a strike with the underlying data type.
+///
+/// The energy is a raw, dimensionless value which is only meaningful relative to other strikes
+/// reported by the same detector.
+pub type StrikeEnergy = u32;
+
+/// Lightning strike reported by a [`LightningDetector`].
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LightningStrike {
+    /// Estimated distance to the head of the storm in kilometers, or `None` if out of range.
+    pub distance: Option<Kilometers>,
+    /// Raw energy of the strike.
+    pub energy: StrikeEnergy,
+}
+
+/// Marker type of the lightning strike quantity, of which every [`LightningDetector`] implementor
+/// is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Lightning;
+
+/// Blocking Lightning Detector methods.
+pub trait LightningDetector: Sensor<Lightning, Sample = LightningStrike> {
+    /// Returns the estimated distance to the head of the storm in kilometers, or `None` if the
+    /// storm is out of range.
+    fn strike_distance(&mut self) -> Result<Option<Kilometers>, Self::Error>;
+
+    /// Returns the raw energy of the most recent strike.
+    fn strike_energy(&mut self) -> Result<StrikeEnergy, Self::Error>;
+
+    /// Returns the estimated distance and energy of the most recent strike.
+    fn last_strike(&mut self) -> Result<LightningStrike, Self::Error> {
+        Ok(LightningStrike {
+            distance: self.strike_distance()?,
+            energy: self.strike_energy()?,
+        })
+    }
+}
+
+impl<T: LightningDetector + ?Sized> LightningDetector for &mut T {
+    #[inline]
+    fn strike_distance(&mut self) -> Result<Option<Kilometers>, Self::Error> {
+        T::strike_distance(self)
+    }
+
+    #[inline]
+    fn strike_energy(&mut self) -> Result<StrikeEnergy, Self::Error> {
+        T::strike_energy(self)
+    }
+
+    #[inline]
+    fn last_strike(&mut self) -> Result<LightningStrike, Self::Error> {
+        T::last_strike(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    Lightning,
+    LightningDetector,
+    last_strike,
+    LightningStrike
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_DISTANCE: Option<Kilometers> = Some(14.0);
+    const TEST_ENERGY: StrikeEnergy = 0x0001_2345;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockLightningDetector;
+
+    impl crate::sensor::ErrorType for MockLightningDetector {
+        type Error = MockError;
+    }
+
+    impl LightningDetector for MockLightningDetector {
+        fn strike_distance(&mut self) -> Result<Option<Kilometers>, Self::Error> {
+            Ok(TEST_DISTANCE)
+        }
+
+        fn strike_energy(&mut self) -> Result<StrikeEnergy, Self::Error> {
+            Ok(TEST_ENERGY)
+        }
+    }
+
+    #[test]
+    fn test_lightning_detector_trait() {
+        let mut sensor = MockLightningDetector;
+        assert_eq!(sensor.strike_distance().unwrap(), TEST_DISTANCE);
+        assert_eq!(sensor.strike_energy().unwrap(), TEST_ENERGY);
+    }
+
+    #[test]
+    fn test_lightning_detector_trait_mut_ref() {
+        let mut sensor = MockLightningDetector;
+        let mut_ref = &mut sensor;
+        assert_eq!(
+            mut_ref.last_strike().unwrap(),
+            LightningStrike {
+                distance: TEST_DISTANCE,
+                energy: TEST_ENERGY,
+            }
+        );
+    }
+}
@@ -0,0 +1,302 @@
+//! Blocking Liquid Level Sensor API
+//!
+//! This API provides generic methods for interfacing with liquid level sensors specifically, such
+//! as ultrasonic, capacitive, pressure-based or float sensors used by tank-monitoring firmware.
+//!
+//! Sensors measuring the height of the liquid implement the [`LiquidLevelSensor`] trait, while
+//! sensors (or drivers aware of the tank geometry) reporting how full the tank is implement the
+//! [`FillLevelSensor`] trait. The threshold traits of either can be used for low-level and overflow
+//! alarms.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the LiquidLevelSensor trait for a tank level
+//! sensor.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::liquid_level::{Millimeters, LiquidLevelSensor};
+//!
+//! // A struct representing a tank level sensor.
+//! pub struct MyTankLevelSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyTankLevelSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl LiquidLevelSensor for MyTankLevelSensor {
+//!     fn liquid_level(&mut self) -> Result<Millimeters, Self::Error> {
+//!         // Read level register of sensor...
+//!         Ok(840.0)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{Sensor, ValidRange};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units liquid levels are measured in with the underlying data type.
+pub type Millimeters = f32;
+
+/// Associates the units fill levels are measured in with the underlying data type.
+pub type Percentage = f32;
+
+/// Range of fill level samples.
+pub const FILL_LEVEL_RANGE: ValidRange<Percentage> = ValidRange::new(0.0, 100.0);
+
+/// Converts a liquid level to a fill level, given the level at which the tank is full.
+///
+/// The result is clamped to [`FILL_LEVEL_RANGE`], so that sensor noise around an empty or full
+/// tank doesn't produce out of range fill levels. Returns 0 % if `full_level` isn't positive.
+pub fn fill_percentage(level: Millimeters, full_level: Millimeters) -> Percentage {
+    if full_level > 0.0 {
+        (level / full_level * 100.0).clamp(FILL_LEVEL_RANGE.min, FILL_LEVEL_RANGE.max)
+    } else {
+        0.0
+    }
+}
+
+/// Marker type of the liquid level quantity, of which every [`LiquidLevelSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LiquidLevel;
+
+/// Blocking Liquid Level Sensor methods.
+pub trait LiquidLevelSensor: Sensor<LiquidLevel, Sample = Millimeters> {
+    /// Returns a liquid level sample in millimeters, measured from the bottom of the tank.
+    fn liquid_level(&mut self) -> Result<Millimeters, Self::Error>;
+}
+
+impl<T: LiquidLevelSensor + ?Sized> LiquidLevelSensor for &mut T {
+    #[inline]
+    fn liquid_level(&mut self) -> Result<Millimeters, Self::Error> {
+        T::liquid_level(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    LiquidLevel,
+    LiquidLevelSensor,
+    liquid_level,
+    Millimeters
+);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait LiquidLevelThresholdSet: LiquidLevelSensor {
+//     fn set_liquid_level_threshold_low(&mut self, threshold: Millimeters) -> Result<(), Self::Error>;
+//     fn set_liquid_level_threshold_high(&mut self, threshold: Millimeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait LiquidLevelHysteresis: LiquidLevelThresholdSet {
+//     fn set_liquid_level_threshold_hysteresis(&mut self, hysteresis: Millimeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait LiquidLevelThresholdControl: LiquidLevelThresholdSet {
+//     fn disable_liquid_level_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_liquid_level_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(
+    blocking,
+    LiquidLevel,
+    LiquidLevelSensor,
+    Millimeters,
+    "millimeters"
+);
+
+/// Marker type of the fill level quantity, of which every [`FillLevelSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FillLevel;
+
+/// Blocking Fill Level Sensor methods.
+pub trait FillLevelSensor: Sensor<FillLevel, Sample = Percentage> {
+    /// Returns a fill level sample as a percentage of the full level of the tank.
+    fn fill_level(&mut self) -> Result<Percentage, Self::Error>;
+}
+
+impl<T: FillLevelSensor + ?Sized> FillLevelSensor for &mut T {
+    #[inline]
+    fn fill_level(&mut self) -> Result<Percentage, Self::Error> {
+        T::fill_level(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, FillLevel, FillLevelSensor, fill_level, Percentage);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait FillLevelThresholdSet: FillLevelSensor {
+//     fn set_fill_level_threshold_low(&mut self, threshold: Percentage) -> Result<(), Self::Error>;
+//     fn set_fill_level_threshold_high(&mut self, threshold: Percentage) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FillLevelHysteresis: FillLevelThresholdSet {
+//     fn set_fill_level_threshold_hysteresis(&mut self, hysteresis: Percentage) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FillLevelThresholdControl: FillLevelThresholdSet {
+//     fn disable_fill_level_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_fill_level_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(blocking, FillLevel, FillLevelSensor, Percentage, "percent");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Millimeters = 840.0;
+    const TEST_THRESHOLD_LOW: Millimeters = 100.0;
+    const TEST_THRESHOLD_HIGH: Millimeters = 1_150.0;
+    const TEST_HYSTERESIS: Millimeters = 10.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockTankLevelSensor {
+        value: Millimeters,
+        threshold_low: Option<Millimeters>,
+        threshold_high: Option<Millimeters>,
+        hysteresis: Option<Millimeters>,
+    }
+
+    impl MockTankLevelSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockTankLevelSensor {
+        type Error = MockError;
+    }
+
+    impl LiquidLevelSensor for MockTankLevelSensor {
+        fn liquid_level(&mut self) -> Result<Millimeters, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl LiquidLevelThresholdSet for MockTankLevelSensor {
+        fn set_liquid_level_threshold_low(
+            &mut self,
+            threshold: Millimeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_liquid_level_threshold_high(
+            &mut self,
+            threshold: Millimeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl LiquidLevelHysteresis for MockTankLevelSensor {
+        fn set_liquid_level_threshold_hysteresis(
+            &mut self,
+            hysteresis: Millimeters,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_liquid_level_sensor_trait() {
+        let mut sensor = MockTankLevelSensor::new();
+        let result = sensor.liquid_level();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_liquid_level_sensor_trait_mut_ref() {
+        let mut sensor = MockTankLevelSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.liquid_level();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_liquid_level_threshold_set_mut_ref() {
+        let mut sensor = MockTankLevelSensor::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_liquid_level_threshold_low(TEST_THRESHOLD_LOW)
+            .is_ok());
+        assert!(mut_ref
+            .set_liquid_level_threshold_high(TEST_THRESHOLD_HIGH)
+            .is_ok());
+        assert!(mut_ref
+            .set_liquid_level_threshold_hysteresis(TEST_HYSTERESIS)
+            .is_ok());
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), TEST_HYSTERESIS);
+    }
+
+    #[test]
+    fn test_fill_percentage() {
+        assert_approx_eq!(fill_percentage(300.0, 1_200.0), 25.0);
+        assert_approx_eq!(fill_percentage(-5.0, 1_200.0), 0.0);
+        assert_approx_eq!(fill_percentage(1_250.0, 1_200.0), 100.0);
+        assert_approx_eq!(fill_percentage(300.0, 0.0), 0.0);
+    }
+
+    struct MockFillLevelSensor;
+
+    impl crate::sensor::ErrorType for MockFillLevelSensor {
+        type Error = MockError;
+    }
+
+    impl FillLevelSensor for MockFillLevelSensor {
+        fn fill_level(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(fill_percentage(TEST_VALUE, 1_200.0))
+        }
+    }
+
+    #[test]
+    fn test_fill_level_sensor_trait_mut_ref() {
+        let mut sensor = MockFillLevelSensor;
+        let mut_ref = &mut sensor;
+        let result = mut_ref.fill_level();
+        assert!(result.is_ok());
+        assert!(FILL_LEVEL_RANGE.contains(result.unwrap()));
+    }
+}
@@ -0,0 +1,215 @@
+//! Blocking Magnetic Switch API
+//!
+//! This API provides generic methods for interfacing with Hall effect switches and other
+//! magnetic presence sensors specifically, such as those used for lid-close detection.
+//!
+//! Every magnetic switch reports whether a field is present. Omnipolar switches cannot tell the
+//! polarity of the field, while linear Hall sensors can additionally implement the
+//! [`MagneticFieldStrength`] trait.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the MagneticSwitch trait for a Hall effect
+//! switch.
+//!
+//! ```
+//! use embedded_sensors_hal::magnetic_switch::{MagneticField, MagneticSwitch, Polarity};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a Hall effect switch.
+//! pub struct MyHallSwitch {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyHallSwitch {
+//!     type Error = Error;
+//! }
+//!
+//! impl MagneticSwitch for MyHallSwitch {
+//!     fn field(&mut self) -> Result<MagneticField, Self::Error> {
+//!         // Read output pins of switch...
+//!         Ok(MagneticField::present(Some(Polarity::South)))
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+use crate::sensor::Sensor;
+
+/// Associates the units magnetic flux density samples are measured in with the underlying data
+/// type.
+pub type Millitesla = f32;
+
+/// Polarity of a magnetic field, as seen from the sensing face of a sensor.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Polarity {
+    /// The north pole of the magnet faces the sensor.
+    North,
+    /// The south pole of the magnet faces the sensor.
+    South,
+}
+
+/// Magnetic field state reported by a magnetic switch.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MagneticField {
+    /// Whether a field above the operate point of the switch is present.
+    pub present: bool,
+    /// Polarity of the field, if present and the switch can tell it.
+    pub polarity: Option<Polarity>,
+}
+
+impl MagneticField {
+    /// No field is present.
+    pub const ABSENT: Self = Self {
+        present: false,
+        polarity: None,
+    };
+
+    /// Creates a present field of the given polarity, if known.
+    pub const fn present(polarity: Option<Polarity>) -> Self {
+        Self {
+            present: true,
+            polarity,
+        }
+    }
+}
+
+/// Marker type of the magnetic field detection quantity, of which every [`MagneticSwitch`]
+/// implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FieldDetection;
+
+/// Blocking Magnetic Switch methods.
+pub trait MagneticSwitch: Sensor<FieldDetection, Sample = MagneticField> {
+    /// Returns the state of the magnetic field at the switch.
+    fn field(&mut self) -> Result<MagneticField, Self::Error>;
+}
+
+impl<T: MagneticSwitch + ?Sized> MagneticSwitch for &mut T {
+    #[inline]
+    fn field(&mut self) -> Result<MagneticField, Self::Error> {
+        T::field(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    FieldDetection,
+    MagneticSwitch,
+    field,
+    MagneticField
+);
+
+/// Marker type of the magnetic field strength quantity, of which every [`MagneticFieldStrength`]
+/// implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FieldStrength;
+
+/// Blocking Magnetic Field Strength methods for sensors able to measure it.
+pub trait MagneticFieldStrength:
+    MagneticSwitch + Sensor<FieldStrength, Sample = Millitesla>
+{
+    /// Returns a magnetic flux density sample in millitesla.
+    ///
+    /// The sample is positive for a south pole facing the sensor and negative for a north pole.
+    fn field_strength(&mut self) -> Result<Millitesla, Self::Error>;
+}
+
+impl<T: MagneticFieldStrength + ?Sized> MagneticFieldStrength for &mut T {
+    #[inline]
+    fn field_strength(&mut self) -> Result<Millitesla, Self::Error> {
+        T::field_strength(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    FieldStrength,
+    MagneticFieldStrength,
+    field_strength,
+    Millitesla
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_OPERATE_POINT: Millitesla = 3.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockLinearHallSensor {
+        value: Millitesla,
+    }
+
+    impl crate::sensor::ErrorType for MockLinearHallSensor {
+        type Error = MockError;
+    }
+
+    impl MagneticSwitch for MockLinearHallSensor {
+        fn field(&mut self) -> Result<MagneticField, Self::Error> {
+            Ok(match self.value {
+                v if v >= TEST_OPERATE_POINT => MagneticField::present(Some(Polarity::South)),
+                v if v <= -TEST_OPERATE_POINT => MagneticField::present(Some(Polarity::North)),
+                _ => MagneticField::ABSENT,
+            })
+        }
+    }
+
+    impl MagneticFieldStrength for MockLinearHallSensor {
+        fn field_strength(&mut self) -> Result<Millitesla, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    #[test]
+    fn test_magnetic_switch_trait() {
+        let mut sensor = MockLinearHallSensor { value: -4.5 };
+        let result = sensor.field();
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            MagneticField::present(Some(Polarity::North))
+        );
+
+        sensor.value = 1.0;
+        assert_eq!(sensor.field().unwrap(), MagneticField::default());
+    }
+
+    #[test]
+    fn test_magnetic_field_strength_trait_mut_ref() {
+        let mut sensor = MockLinearHallSensor { value: 4.5 };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.field_strength();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), 4.5);
+        assert!(mut_ref.field().unwrap().present);
+    }
+}
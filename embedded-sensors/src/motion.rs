@@ -0,0 +1,171 @@
+//! Blocking Motion Sensor API
+//!
+//! This API provides generic methods for interfacing with sources of velocity specifically, such
+//! as GNSS receivers, wheel odometry or optical flow sensors, so that navigation code can consume
+//! speed and heading independently of how they are measured.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the SpeedSensor and HeadingSensor traits for a
+//! GNSS receiver.
+//!
+//! ```
+//! use embedded_sensors_hal::motion::{Degrees, HeadingSensor, MetersPerSecond, SpeedSensor};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a GNSS receiver.
+//! pub struct MyGnssReceiver {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyGnssReceiver {
+//!     type Error = Error;
+//! }
+//!
+//! impl SpeedSensor for MyGnssReceiver {
+//!     fn speed(&mut self) -> Result<MetersPerSecond, Self::Error> {
+//!         // Parse ground speed from latest navigation solution of receiver...
+//!         Ok(12.4)
+//!     }
+//! }
+//!
+//! impl HeadingSensor for MyGnssReceiver {
+//!     fn heading(&mut self) -> Result<Degrees, Self::Error> {
+//!         // Parse course over ground from latest navigation solution of receiver...
+//!         Ok(271.5)
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+pub use crate::inclination::Degrees;
+use crate::sensor::{Sensor, ValidRange};
+pub use crate::wind::MetersPerSecond;
+
+/// Range of headings, clockwise from north.
+pub const HEADING_RANGE: ValidRange<Degrees> = ValidRange::new(0.0, 360.0);
+
+/// Marker type of the speed quantity, of which every [`SpeedSensor`] implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Speed;
+
+/// Blocking Speed Sensor methods.
+pub trait SpeedSensor: Sensor<Speed, Sample = MetersPerSecond> {
+    /// Returns a speed sample in meters per second, i.e. the magnitude of the velocity over the
+    /// ground.
+    fn speed(&mut self) -> Result<MetersPerSecond, Self::Error>;
+}
+
+impl<T: SpeedSensor + ?Sized> SpeedSensor for &mut T {
+    #[inline]
+    fn speed(&mut self) -> Result<MetersPerSecond, Self::Error> {
+        T::speed(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Speed, SpeedSensor, speed, MetersPerSecond);
+
+/// Marker type of the heading quantity, of which every [`HeadingSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Heading;
+
+/// Blocking Heading Sensor methods.
+pub trait HeadingSensor: Sensor<Heading, Sample = Degrees> {
+    /// Returns a heading sample in degrees clockwise from north, i.e. the direction of motion.
+    ///
+    /// Fails with an error of kind [`ErrorKind::NotReady`](crate::sensor::ErrorKind::NotReady)
+    /// if the heading cannot currently be determined (e.g. a GNSS receiver at standstill).
+    fn heading(&mut self) -> Result<Degrees, Self::Error>;
+}
+
+impl<T: HeadingSensor + ?Sized> HeadingSensor for &mut T {
+    #[inline]
+    fn heading(&mut self) -> Result<Degrees, Self::Error> {
+        T::heading(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Heading, HeadingSensor, heading, Degrees);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_SPEED: MetersPerSecond = 12.4;
+    const TEST_HEADING: Degrees = 271.5;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::NotReady
+        }
+    }
+
+    struct MockGnssReceiver {
+        speed: MetersPerSecond,
+    }
+
+    impl crate::sensor::ErrorType for MockGnssReceiver {
+        type Error = MockError;
+    }
+
+    impl SpeedSensor for MockGnssReceiver {
+        fn speed(&mut self) -> Result<MetersPerSecond, Self::Error> {
+            Ok(self.speed)
+        }
+    }
+
+    impl HeadingSensor for MockGnssReceiver {
+        fn heading(&mut self) -> Result<Degrees, Self::Error> {
+            // Heading is undefined at standstill
+            if self.speed > 0.5 {
+                Ok(TEST_HEADING)
+            } else {
+                Err(MockError)
+            }
+        }
+    }
+
+    #[test]
+    fn test_motion_sensor_traits() {
+        let mut sensor = MockGnssReceiver { speed: TEST_SPEED };
+        assert_approx_eq!(sensor.speed().unwrap(), TEST_SPEED);
+        assert_approx_eq!(sensor.heading().unwrap(), TEST_HEADING);
+    }
+
+    #[test]
+    fn test_motion_sensor_traits_mut_ref() {
+        let mut sensor = MockGnssReceiver { speed: 0.0 };
+        let mut_ref = &mut sensor;
+        assert_approx_eq!(mut_ref.speed().unwrap(), 0.0);
+        let result = mut_ref.heading();
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::NotReady);
+    }
+
+    #[test]
+    fn test_heading_range() {
+        assert!(HEADING_RANGE.validate(TEST_HEADING).is_ok());
+        assert_eq!(HEADING_RANGE.validate(-1.0), Err(ErrorKind::OutOfRange));
+    }
+}
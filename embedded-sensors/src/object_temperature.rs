@@ -0,0 +1,247 @@
+//! Blocking Object Temperature Sensor API
+//!
+//! This API provides generic methods for interfacing with non-contact (radiometric) temperature
+//! sensors specifically, such as MLX90614-class IR thermopiles.
+//!
+//! Unlike a [`TemperatureSensor`](crate::temperature::TemperatureSensor), which measures the
+//! temperature of whatever it is in contact with, a thermopile infers the temperature of an
+//! object in its field of view from the infrared radiation the object emits. It also reports the
+//! temperature of its own die (the ambient temperature), against which the object temperature is
+//! compensated. Keeping both in a separate module lets thermal monitoring code tell contact and
+//! radiometric readings apart.
+//!
+//! The accuracy of the object temperature depends on the emissivity of the object, which
+//! sensors implementing [`EmissivitySet`] allow to be configured.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the ObjectTemperatureSensor and EmissivitySet
+//! traits for an IR thermopile.
+//!
+//! ```
+//! use embedded_sensors_hal::object_temperature::{
+//!     DegreesCelsius, Emissivity, EmissivitySet, ObjectTemperatureSensor,
+//!     ObjectTemperatureThresholdSet,
+//! };
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing an IR thermopile.
+//! pub struct MyThermopile {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyThermopile {
+//!     type Error = Error;
+//! }
+//!
+//! impl ObjectTemperatureSensor for MyThermopile {
+//!     fn object_temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+//!         // Read object temperature RAM register of sensor...
+//!         Ok(36.6)
+//!     }
+//!
+//!     fn ambient_temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+//!         // Read die temperature RAM register of sensor...
+//!         Ok(24.0)
+//!     }
+//! }
+//!
+//! impl EmissivitySet for MyThermopile {
+//!     fn set_emissivity(&mut self, emissivity: Emissivity) -> Result<(), Self::Error> {
+//!         // Write emissivity EEPROM cell of sensor...
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl ObjectTemperatureThresholdSet for MyThermopile {
+//!     fn set_object_temperature_threshold_low(
+//!         &mut self,
+//!         threshold: DegreesCelsius
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold low register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     fn set_object_temperature_threshold_high(
+//!         &mut self,
+//!         threshold: DegreesCelsius
+//!     ) -> Result<(), Self::Error> {
+//!         // Write value to threshold high register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{Sensor, ValidRange};
+pub use crate::temperature::DegreesCelsius;
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates emissivity, the ratio of the radiation emitted by an object to that of a black
+/// body at the same temperature, with the underlying data type.
+pub type Emissivity = f32;
+
+/// Range of emissivities accepted by [`EmissivitySet`] implementations.
+///
+/// Sensors may support a narrower range, and reject emissivities outside of it with
+/// [`ErrorKind::InvalidInput`](crate::sensor::ErrorKind::InvalidInput).
+pub const EMISSIVITY_RANGE: ValidRange<Emissivity> = ValidRange::new(0.0, 1.0);
+
+/// Marker type of the object temperature quantity, of which every [`ObjectTemperatureSensor`]
+/// implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ObjectTemperature;
+
+/// Blocking Object Temperature Sensor methods.
+pub trait ObjectTemperatureSensor: Sensor<ObjectTemperature, Sample = DegreesCelsius> {
+    /// Returns a sample of the temperature of the object in the field of view of the sensor, in
+    /// degrees Celsius.
+    fn object_temperature(&mut self) -> Result<DegreesCelsius, Self::Error>;
+
+    /// Returns a sample of the ambient (die) temperature of the sensor, in degrees Celsius.
+    fn ambient_temperature(&mut self) -> Result<DegreesCelsius, Self::Error>;
+}
+
+impl<T: ObjectTemperatureSensor + ?Sized> ObjectTemperatureSensor for &mut T {
+    #[inline]
+    fn object_temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        T::object_temperature(self)
+    }
+
+    #[inline]
+    fn ambient_temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        T::ambient_temperature(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    ObjectTemperature,
+    ObjectTemperatureSensor,
+    object_temperature,
+    DegreesCelsius
+);
+
+/// Blocking Emissivity configuration methods.
+pub trait EmissivitySet: ObjectTemperatureSensor {
+    /// Set the emissivity of the object used to compensate object temperature samples.
+    ///
+    /// See [`EMISSIVITY_RANGE`] for the range of valid emissivities.
+    fn set_emissivity(&mut self, emissivity: Emissivity) -> Result<(), Self::Error>;
+}
+
+impl<T: EmissivitySet + ?Sized> EmissivitySet for &mut T {
+    #[inline]
+    fn set_emissivity(&mut self, emissivity: Emissivity) -> Result<(), Self::Error> {
+        T::set_emissivity(self, emissivity)
+    }
+}
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait ObjectTemperatureThresholdSet: ObjectTemperatureSensor {
+//     fn set_object_temperature_threshold_low(&mut self, threshold: DegreesCelsius) -> Result<(), Self::Error>;
+//     fn set_object_temperature_threshold_high(&mut self, threshold: DegreesCelsius) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ObjectTemperatureHysteresis: ObjectTemperatureThresholdSet {
+//     fn set_object_temperature_threshold_hysteresis(&mut self, hysteresis: DegreesCelsius) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ObjectTemperatureThresholdControl: ObjectTemperatureThresholdSet {
+//     fn disable_object_temperature_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_object_temperature_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(
+    blocking,
+    ObjectTemperature,
+    ObjectTemperatureSensor,
+    DegreesCelsius,
+    "degrees Celsius"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_OBJECT_TEMP: DegreesCelsius = 36.6;
+    const TEST_AMBIENT_TEMP: DegreesCelsius = 24.0;
+    const TEST_EMISSIVITY: Emissivity = 0.98;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::InvalidInput
+        }
+    }
+
+    struct MockThermopile {
+        emissivity: Emissivity,
+    }
+
+    impl crate::sensor::ErrorType for MockThermopile {
+        type Error = MockError;
+    }
+
+    impl ObjectTemperatureSensor for MockThermopile {
+        fn object_temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(TEST_OBJECT_TEMP)
+        }
+
+        fn ambient_temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(TEST_AMBIENT_TEMP)
+        }
+    }
+
+    impl EmissivitySet for MockThermopile {
+        fn set_emissivity(&mut self, emissivity: Emissivity) -> Result<(), Self::Error> {
+            self.emissivity = EMISSIVITY_RANGE
+                .validate(emissivity)
+                .map_err(|_| MockError)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_object_temperature_sensor_trait() {
+        let mut sensor = MockThermopile { emissivity: 1.0 };
+        let result = sensor.object_temperature();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_OBJECT_TEMP);
+        let result = sensor.ambient_temperature();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_AMBIENT_TEMP);
+    }
+
+    #[test]
+    fn test_emissivity_set_trait_mut_ref() {
+        let mut sensor = MockThermopile { emissivity: 1.0 };
+
+        {
+            let mut_ref = &mut sensor;
+            assert!(mut_ref.set_emissivity(TEST_EMISSIVITY).is_ok());
+            assert!(mut_ref.set_emissivity(1.5).is_err());
+            assert_approx_eq!(mut_ref.object_temperature().unwrap(), TEST_OBJECT_TEMP);
+        }
+
+        assert_approx_eq!(sensor.emissivity, TEST_EMISSIVITY);
+    }
+}
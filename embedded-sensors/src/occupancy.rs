@@ -0,0 +1,116 @@
+//! Blocking Occupancy Sensor API
+//!
+//! This API provides generic methods for interfacing with occupancy (presence) sensors
+//! specifically, such as passive infrared (PIR) motion sensors or human presence radars, as used
+//! for presence-based power management.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the OccupancySensor trait for a PIR sensor.
+//!
+//! ```
+//! use embedded_sensors_hal::occupancy::OccupancySensor;
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a PIR sensor.
+//! pub struct MyPirSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyPirSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl OccupancySensor for MyPirSensor {
+//!     fn presence(&mut self) -> Result<bool, Self::Error> {
+//!         // Read output pin of sensor...
+//!         Ok(true)
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+use crate::sensor::Sensor;
+
+/// Marker type of the occupancy quantity, of which every [`OccupancySensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Occupancy;
+
+/// Blocking Occupancy Sensor methods.
+pub trait OccupancySensor: Sensor<Occupancy, Sample = bool> {
+    /// Returns `true` if presence is currently detected.
+    ///
+    /// Most sensors hold presence for some time after the last motion, so this reports the
+    /// held state rather than instantaneous motion.
+    fn presence(&mut self) -> Result<bool, Self::Error>;
+}
+
+impl<T: OccupancySensor + ?Sized> OccupancySensor for &mut T {
+    #[inline]
+    fn presence(&mut self) -> Result<bool, Self::Error> {
+        T::presence(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Occupancy, OccupancySensor, presence, bool);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockPirSensor {
+        present: bool,
+    }
+
+    impl crate::sensor::ErrorType for MockPirSensor {
+        type Error = MockError;
+    }
+
+    impl OccupancySensor for MockPirSensor {
+        fn presence(&mut self) -> Result<bool, Self::Error> {
+            Ok(self.present)
+        }
+    }
+
+    #[test]
+    fn test_occupancy_sensor_trait() {
+        let mut sensor = MockPirSensor { present: true };
+        let result = sensor.presence();
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn test_occupancy_sensor_trait_mut_ref() {
+        let mut sensor = MockPirSensor { present: false };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.presence();
+        assert!(result.is_ok());
+        assert!(!result.unwrap());
+    }
+}
@@ -0,0 +1,284 @@
+//! Blocking Orientation Sensor API
+//!
+//! This API provides generic methods for interfacing with orientation sensors specifically, such
+//! as inertial measurement units with on-chip sensor fusion (e.g. BNO055 or BNO08x).
+//!
+//! Depending on the device, orientation is natively reported either as a unit quaternion or as
+//! Euler angles, so the [`OrientationSensor`] trait returns an [`Orientation`] holding either.
+//! With the `libm` feature enabled, applications can convert it to the representation they
+//! consume, regardless of whether fusion runs on-chip or in software.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the OrientationSensor trait for a fusion IMU.
+//!
+//! ```
+//! use embedded_sensors_hal::orientation::{Orientation, OrientationSensor, Quaternion};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing an IMU with on-chip sensor fusion.
+//! pub struct MyFusionImu {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyFusionImu {
+//!     type Error = Error;
+//! }
+//!
+//! impl OrientationSensor for MyFusionImu {
+//!     fn orientation(&mut self) -> Result<Orientation, Self::Error> {
+//!         // Read rotation vector report of sensor (Q14 fixed point)...
+//!         Ok(Orientation::Quaternion(Quaternion {
+//!             w: 1.0,
+//!             x: 0.0,
+//!             y: 0.0,
+//!             z: 0.0,
+//!         }))
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+pub use crate::inclination::Degrees;
+use crate::sensor::Sensor;
+
+/// Orientation expressed as a unit quaternion.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Quaternion {
+    /// Scalar (real) component.
+    pub w: f32,
+    /// X component of the vector part.
+    pub x: f32,
+    /// Y component of the vector part.
+    pub y: f32,
+    /// Z component of the vector part.
+    pub z: f32,
+}
+
+impl Quaternion {
+    /// Quaternion representing no rotation.
+    pub const IDENTITY: Self = Self {
+        w: 1.0,
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    /// Returns the conjugate of the quaternion, i.e. the inverse rotation of a unit quaternion.
+    pub fn conjugate(self) -> Self {
+        Self {
+            w: self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Orientation expressed as Euler angles, applied in yaw, pitch, roll (Z-Y-X) order.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EulerAngles {
+    /// Rotation about the X axis in degrees.
+    pub roll: Degrees,
+    /// Rotation about the Y axis in degrees.
+    pub pitch: Degrees,
+    /// Rotation about the Z axis in degrees.
+    pub yaw: Degrees,
+}
+
+/// Orientation sample, in the representation natively reported by the sensor.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Orientation {
+    /// Orientation reported as a unit quaternion.
+    Quaternion(Quaternion),
+    /// Orientation reported as Euler angles.
+    Euler(EulerAngles),
+}
+
+impl Default for Orientation {
+    fn default() -> Self {
+        Self::Quaternion(Quaternion::IDENTITY)
+    }
+}
+
+impl From<Quaternion> for Orientation {
+    fn from(quaternion: Quaternion) -> Self {
+        Self::Quaternion(quaternion)
+    }
+}
+
+impl From<EulerAngles> for Orientation {
+    fn from(angles: EulerAngles) -> Self {
+        Self::Euler(angles)
+    }
+}
+
+#[cfg(feature = "libm")]
+impl Orientation {
+    /// Returns the orientation as a unit quaternion, converting from Euler angles if needed.
+    pub fn quaternion(&self) -> Quaternion {
+        match *self {
+            Self::Quaternion(q) => q,
+            Self::Euler(e) => {
+                let (sr, cr) = libm::sincosf(e.roll.to_radians() / 2.0);
+                let (sp, cp) = libm::sincosf(e.pitch.to_radians() / 2.0);
+                let (sy, cy) = libm::sincosf(e.yaw.to_radians() / 2.0);
+                Quaternion {
+                    w: cr * cp * cy + sr * sp * sy,
+                    x: sr * cp * cy - cr * sp * sy,
+                    y: cr * sp * cy + sr * cp * sy,
+                    z: cr * cp * sy - sr * sp * cy,
+                }
+            }
+        }
+    }
+
+    /// Returns the orientation as Euler angles, converting from a quaternion if needed.
+    ///
+    /// Near a pitch of ±90° (gimbal lock), roll and yaw are not uniquely defined.
+    pub fn euler_angles(&self) -> EulerAngles {
+        match *self {
+            Self::Euler(e) => e,
+            Self::Quaternion(Quaternion { w, x, y, z }) => {
+                let roll = libm::atan2f(2.0 * (w * x + y * z), 1.0 - 2.0 * (x * x + y * y));
+                let pitch = libm::asinf((2.0 * (w * y - z * x)).clamp(-1.0, 1.0));
+                let yaw = libm::atan2f(2.0 * (w * z + x * y), 1.0 - 2.0 * (y * y + z * z));
+                EulerAngles {
+                    roll: roll.to_degrees(),
+                    pitch: pitch.to_degrees(),
+                    yaw: yaw.to_degrees(),
+                }
+            }
+        }
+    }
+}
+
+/// Marker type of the orientation quantity, of which every [`OrientationSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Attitude;
+
+/// Blocking Orientation Sensor methods.
+pub trait OrientationSensor: Sensor<Attitude, Sample = Orientation> {
+    /// Returns an orientation sample, relative to the reference frame of the sensor.
+    fn orientation(&mut self) -> Result<Orientation, Self::Error>;
+}
+
+impl<T: OrientationSensor + ?Sized> OrientationSensor for &mut T {
+    #[inline]
+    fn orientation(&mut self) -> Result<Orientation, Self::Error> {
+        T::orientation(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    Attitude,
+    OrientationSensor,
+    orientation,
+    Orientation
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_ANGLES: EulerAngles = EulerAngles {
+        roll: 10.0,
+        pitch: -20.0,
+        yaw: 135.0,
+    };
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockFusionImu;
+
+    impl crate::sensor::ErrorType for MockFusionImu {
+        type Error = MockError;
+    }
+
+    impl OrientationSensor for MockFusionImu {
+        fn orientation(&mut self) -> Result<Orientation, Self::Error> {
+            Ok(TEST_ANGLES.into())
+        }
+    }
+
+    #[test]
+    fn test_orientation_sensor_trait() {
+        let mut sensor = MockFusionImu;
+        let result = sensor.orientation();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Orientation::Euler(TEST_ANGLES));
+    }
+
+    #[test]
+    fn test_orientation_sensor_trait_mut_ref() {
+        let mut sensor = MockFusionImu;
+        let mut_ref = &mut sensor;
+        let result = mut_ref.orientation();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Orientation::Euler(TEST_ANGLES));
+    }
+
+    #[test]
+    fn test_quaternion_conjugate() {
+        let q = Quaternion {
+            w: 0.5,
+            x: 0.5,
+            y: -0.5,
+            z: 0.5,
+        };
+        assert_eq!(q.conjugate().conjugate(), q);
+        assert_eq!(Quaternion::IDENTITY.conjugate(), Quaternion::IDENTITY);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    fn test_orientation_conversions() {
+        use assert_approx_eq::assert_approx_eq;
+
+        let identity = Orientation::default().euler_angles();
+        assert_approx_eq!(identity.roll, 0.0);
+        assert_approx_eq!(identity.pitch, 0.0);
+        assert_approx_eq!(identity.yaw, 0.0);
+
+        let q = Orientation::Euler(TEST_ANGLES).quaternion();
+        assert_approx_eq!(q.w * q.w + q.x * q.x + q.y * q.y + q.z * q.z, 1.0);
+        let angles = Orientation::Quaternion(q).euler_angles();
+        assert_approx_eq!(angles.roll, TEST_ANGLES.roll, 1e-3);
+        assert_approx_eq!(angles.pitch, TEST_ANGLES.pitch, 1e-3);
+        assert_approx_eq!(angles.yaw, TEST_ANGLES.yaw, 1e-3);
+    }
+}
@@ -0,0 +1,285 @@
+//! Blocking Overheat Detection API
+//!
+//! This module provides an [`OverheatDetector`] which watches any [`TemperatureSensor`] for
+//! overheating using the two criteria of heat detectors in fire alarm systems:
+//!
+//! - a fixed temperature, above which the enclosure is considered to be overheating whatever
+//!   its history, and
+//! - a rate of rise, which catches fast-developing fires well before the fixed temperature is
+//!   reached.
+//!
+//! The rate of rise is measured over a window of time read from a [`Clock`], rather than between
+//! consecutive samples, so that it is insensitive to the sampling period and sensor noise.
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal::clock::Clock;
+//! use embedded_sensors_hal::overheat::{OverheatCriteria, OverheatDetector, OverheatEvent};
+//! use embedded_sensors_hal::temperature::TemperatureSensor;
+//!
+//! fn protect<S: TemperatureSensor, C: Clock>(sensor: S, clock: C) -> Result<(), S::Error> {
+//!     let mut detector = OverheatDetector::new(sensor, clock, OverheatCriteria::HEAT_DETECTOR);
+//!     loop {
+//!         if let Some(event) = detector.poll()? {
+//!             // Cut power to the enclosure and raise the alarm...
+//!             let _ = event.cause;
+//!             return Ok(());
+//!         }
+//!         // Wait for the next sampling period...
+//!     }
+//! }
+//! ```
+
+use crate::clock::Clock;
+use crate::sensor::{ThresholdEvent, ThresholdKind};
+use crate::temperature::{DegreesCelsius, TemperatureSensor};
+
+/// Associates the units rates of temperature rise are measured in with the underlying data type.
+pub type DegreesCelsiusPerMinute = f32;
+
+/// Criteria for an enclosure to be considered overheating.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OverheatCriteria {
+    /// Temperature at or above which the enclosure is overheating.
+    pub fixed_temperature: DegreesCelsius,
+    /// Rate of temperature rise at or above which the enclosure is overheating.
+    pub rate_of_rise: DegreesCelsiusPerMinute,
+    /// Window over which the rate of rise is measured, in microseconds.
+    pub window_us: u64,
+}
+
+impl OverheatCriteria {
+    /// Criteria of a typical combined fixed-temperature and rate-of-rise heat detector: 57 °C,
+    /// or a rise of 8.3 °C per minute (15 °F per minute) measured over 30 seconds.
+    pub const HEAT_DETECTOR: Self = Self::new(57.0, 8.3, 30_000_000);
+
+    /// Creates new criteria.
+    pub const fn new(
+        fixed_temperature: DegreesCelsius,
+        rate_of_rise: DegreesCelsiusPerMinute,
+        window_us: u64,
+    ) -> Self {
+        Self {
+            fixed_temperature,
+            rate_of_rise,
+            window_us,
+        }
+    }
+}
+
+/// Criterion which an overheat was detected by.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum OverheatCause {
+    /// The temperature reached the fixed temperature.
+    FixedTemperature,
+    /// The temperature rose at least as fast as the rate of rise.
+    RateOfRise,
+}
+
+/// An overheat detected by an [`OverheatDetector`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OverheatEvent {
+    /// Temperature sampled when the overheat was detected.
+    pub temperature: DegreesCelsius,
+    /// Rate of rise over the last complete window, if any has completed yet.
+    pub rate_of_rise: Option<DegreesCelsiusPerMinute>,
+    /// Criterion which the overheat was detected by.
+    ///
+    /// If both criteria are met, [`OverheatCause::FixedTemperature`] is reported.
+    pub cause: OverheatCause,
+    /// Time at which the overheat was detected, in microseconds.
+    pub timestamp_us: u64,
+}
+
+impl From<OverheatEvent> for ThresholdEvent<DegreesCelsius> {
+    fn from(event: OverheatEvent) -> Self {
+        ThresholdEvent::new(event.temperature, ThresholdKind::High)
+            .with_timestamp(event.timestamp_us)
+    }
+}
+
+/// Evaluates [`OverheatCriteria`] over successive timestamped temperature samples.
+///
+/// This holds the detection logic of an [`OverheatDetector`], for use where samples are not
+/// read from a [`TemperatureSensor`] directly.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OverheatMonitor {
+    criteria: OverheatCriteria,
+    // Time and temperature at the start of the current window.
+    reference: Option<(u64, DegreesCelsius)>,
+    rate_of_rise: Option<DegreesCelsiusPerMinute>,
+}
+
+impl OverheatMonitor {
+    /// Creates a new monitor evaluating `criteria`.
+    pub const fn new(criteria: OverheatCriteria) -> Self {
+        Self {
+            criteria,
+            reference: None,
+            rate_of_rise: None,
+        }
+    }
+
+    /// Returns the criteria evaluated by the monitor.
+    pub fn criteria(&self) -> &OverheatCriteria {
+        &self.criteria
+    }
+
+    /// Returns the rate of rise over the last complete window, if any has completed yet.
+    pub fn rate_of_rise(&self) -> Option<DegreesCelsiusPerMinute> {
+        self.rate_of_rise
+    }
+
+    /// Forgets the samples seen so far, restarting rate of rise measurement.
+    pub fn reset(&mut self) {
+        self.reference = None;
+        self.rate_of_rise = None;
+    }
+
+    /// Evaluates the criteria with a new sample taken at `now_us`, returning an event if the
+    /// enclosure is overheating.
+    ///
+    /// The rate of rise is only re-evaluated once a full window has elapsed since the start of
+    /// the current window, so an event caused by it is reported once per window.
+    pub fn update(&mut self, temperature: DegreesCelsius, now_us: u64) -> Option<OverheatEvent> {
+        let mut rising = false;
+        match self.reference {
+            Some((start_us, start)) => {
+                let elapsed_us = now_us.saturating_sub(start_us);
+                if elapsed_us >= self.criteria.window_us.max(1) {
+                    let rate = (temperature - start) * 60_000_000.0 / elapsed_us as f32;
+                    self.rate_of_rise = Some(rate);
+                    self.reference = Some((now_us, temperature));
+                    rising = rate >= self.criteria.rate_of_rise;
+                }
+            }
+            None => self.reference = Some((now_us, temperature)),
+        }
+
+        let cause = if temperature >= self.criteria.fixed_temperature {
+            OverheatCause::FixedTemperature
+        } else if rising {
+            OverheatCause::RateOfRise
+        } else {
+            return None;
+        };
+
+        Some(OverheatEvent {
+            temperature,
+            rate_of_rise: self.rate_of_rise,
+            cause,
+            timestamp_us: now_us,
+        })
+    }
+}
+
+/// Watches a [`TemperatureSensor`] for overheating.
+pub struct OverheatDetector<S, C> {
+    sensor: S,
+    clock: C,
+    monitor: OverheatMonitor,
+}
+
+impl<S: TemperatureSensor, C: Clock> OverheatDetector<S, C> {
+    /// Creates a new detector evaluating `criteria` over samples of `sensor`, timed by `clock`.
+    pub fn new(sensor: S, clock: C, criteria: OverheatCriteria) -> Self {
+        Self {
+            sensor,
+            clock,
+            monitor: OverheatMonitor::new(criteria),
+        }
+    }
+
+    /// Returns the monitor holding the detection state.
+    pub fn monitor(&self) -> &OverheatMonitor {
+        &self.monitor
+    }
+
+    /// Samples the sensor, returning an event if the enclosure is overheating.
+    ///
+    /// This should be called periodically, several times per rate of rise window.
+    pub fn poll(&mut self) -> Result<Option<OverheatEvent>, S::Error> {
+        let temperature = self.sensor.temperature()?;
+        Ok(self.monitor.update(temperature, self.clock.now_us()))
+    }
+
+    /// Consumes the detector, returning the sensor and clock.
+    pub fn into_inner(self) -> (S, C) {
+        (self.sensor, self.clock)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_CRITERIA: OverheatCriteria = OverheatCriteria::new(60.0, 10.0, 60_000_000);
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockTempSensor {
+        value: DegreesCelsius,
+    }
+
+    impl crate::sensor::ErrorType for MockTempSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockTempSensor {
+        fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    #[test]
+    fn test_overheat_monitor_rate_of_rise() {
+        let mut monitor = OverheatMonitor::new(TEST_CRITERIA);
+        assert_eq!(monitor.update(25.0, 0), None);
+        // A fast rise within the window is only evaluated at its end.
+        assert_eq!(monitor.update(40.0, 30_000_000), None);
+        assert_eq!(monitor.rate_of_rise(), None);
+
+        let event = monitor.update(37.0, 60_000_000).unwrap();
+        assert_eq!(event.cause, OverheatCause::RateOfRise);
+        assert_approx_eq!(event.rate_of_rise.unwrap(), 12.0);
+        assert_eq!(event.timestamp_us, 60_000_000);
+
+        // A slow rise over the next window does not trigger.
+        assert_eq!(monitor.update(42.0, 120_000_000), None);
+        assert_approx_eq!(monitor.rate_of_rise().unwrap(), 5.0);
+    }
+
+    #[test]
+    fn test_overheat_detector_fixed_temperature() {
+        let clock = ManualClock::new();
+        let sensor = MockTempSensor { value: 45.0 };
+        let mut detector = OverheatDetector::new(sensor, &clock, TEST_CRITERIA);
+        assert!(matches!(detector.poll(), Ok(None)));
+
+        clock.advance_ms(1_000);
+        detector.sensor.value = 61.0;
+        let event = detector.poll().unwrap().unwrap();
+        assert_eq!(event.cause, OverheatCause::FixedTemperature);
+        assert_eq!(event.rate_of_rise, None);
+
+        let threshold_event = ThresholdEvent::from(event);
+        assert_eq!(threshold_event.kind, ThresholdKind::High);
+        assert_eq!(threshold_event.timestamp_us, Some(1_000_000));
+    }
+}
@@ -0,0 +1,236 @@
+//! Blocking Sensor Power API
+//!
+//! This API provides generic methods for transitioning sensors between their powered-down and
+//! operational states, independently of the sensor type.
+//!
+//! Sensors with a low-power sleep state in addition to shutdown can implement the
+//! [`SensorPowerControl`] trait, which also reports their current [`PowerState`].
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the PowerMode trait for a sensor.
+//!
+//! ```
+//! use embedded_sensors_hal::power::PowerMode;
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a sensor.
+//! pub struct MySensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MySensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl PowerMode for MySensor {
+//!     fn shutdown(&mut self) -> Result<(), Self::Error> {
+//!         // Write shutdown bit to configuration register of sensor...
+//!         Ok(())
+//!     }
+//!
+//!     fn wake(&mut self) -> Result<(), Self::Error> {
+//!         // Clear shutdown bit in configuration register of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+
+/// Power state of a sensor.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PowerState {
+    /// The sensor is operational and samples can be taken.
+    #[default]
+    Active,
+    /// The sensor is in a low-power state retaining its configuration, from which it wakes
+    /// faster than from shutdown.
+    Sleep,
+    /// The sensor is in its lowest power state.
+    Shutdown,
+}
+
+/// Blocking Power Mode methods.
+pub trait PowerMode: ErrorType {
+    /// Put the sensor into its lowest power state, in which no samples are taken.
+    fn shutdown(&mut self) -> Result<(), Self::Error>;
+
+    /// Return the sensor to its operational state.
+    ///
+    /// Samples may not be valid until the sensor has settled, see its datasheet.
+    fn wake(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: PowerMode + ?Sized> PowerMode for &mut T {
+    #[inline]
+    fn shutdown(&mut self) -> Result<(), Self::Error> {
+        T::shutdown(self)
+    }
+
+    #[inline]
+    fn wake(&mut self) -> Result<(), Self::Error> {
+        T::wake(self)
+    }
+}
+
+/// Blocking Sensor Power Control methods, for sensors with a low-power sleep state in addition to
+/// shutdown.
+///
+/// This lets firmware managing many sensors idle them uniformly (e.g. during system suspend)
+/// without knowledge of the concrete drivers.
+pub trait SensorPowerControl: PowerMode {
+    /// Put the sensor into a low-power sleep state, retaining its configuration.
+    ///
+    /// Sensors without a distinct sleep state should enter their shutdown state instead.
+    fn sleep(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns the current power state of the sensor.
+    fn power_state(&mut self) -> Result<PowerState, Self::Error>;
+
+    /// Transition the sensor to the given power state.
+    fn set_power_state(&mut self, state: PowerState) -> Result<(), Self::Error> {
+        match state {
+            PowerState::Active => self.wake(),
+            PowerState::Sleep => self.sleep(),
+            PowerState::Shutdown => self.shutdown(),
+        }
+    }
+}
+
+impl<T: SensorPowerControl + ?Sized> SensorPowerControl for &mut T {
+    #[inline]
+    fn sleep(&mut self) -> Result<(), Self::Error> {
+        T::sleep(self)
+    }
+
+    #[inline]
+    fn power_state(&mut self) -> Result<PowerState, Self::Error> {
+        T::power_state(self)
+    }
+
+    #[inline]
+    fn set_power_state(&mut self, state: PowerState) -> Result<(), Self::Error> {
+        T::set_power_state(self, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockSensor {
+        awake: bool,
+    }
+
+    impl crate::sensor::ErrorType for MockSensor {
+        type Error = MockError;
+    }
+
+    impl PowerMode for MockSensor {
+        fn shutdown(&mut self) -> Result<(), Self::Error> {
+            self.awake = false;
+            Ok(())
+        }
+
+        fn wake(&mut self) -> Result<(), Self::Error> {
+            self.awake = true;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_power_mode_trait() {
+        let mut sensor = MockSensor { awake: true };
+        assert!(sensor.shutdown().is_ok());
+        assert!(!sensor.awake);
+        assert!(sensor.wake().is_ok());
+        assert!(sensor.awake);
+    }
+
+    #[test]
+    fn test_power_mode_trait_mut_ref() {
+        let mut sensor = MockSensor { awake: true };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.shutdown().is_ok());
+        assert!(!sensor.awake);
+    }
+
+    struct MockSleepySensor {
+        state: PowerState,
+    }
+
+    impl crate::sensor::ErrorType for MockSleepySensor {
+        type Error = MockError;
+    }
+
+    impl PowerMode for MockSleepySensor {
+        fn shutdown(&mut self) -> Result<(), Self::Error> {
+            self.state = PowerState::Shutdown;
+            Ok(())
+        }
+
+        fn wake(&mut self) -> Result<(), Self::Error> {
+            self.state = PowerState::Active;
+            Ok(())
+        }
+    }
+
+    impl SensorPowerControl for MockSleepySensor {
+        fn sleep(&mut self) -> Result<(), Self::Error> {
+            self.state = PowerState::Sleep;
+            Ok(())
+        }
+
+        fn power_state(&mut self) -> Result<PowerState, Self::Error> {
+            Ok(self.state)
+        }
+    }
+
+    #[test]
+    fn test_sensor_power_control_trait() {
+        let mut sensor = MockSleepySensor {
+            state: PowerState::default(),
+        };
+        assert!(sensor.sleep().is_ok());
+        assert_eq!(sensor.power_state().unwrap(), PowerState::Sleep);
+        assert!(sensor.wake().is_ok());
+        assert_eq!(sensor.power_state().unwrap(), PowerState::Active);
+    }
+
+    #[test]
+    fn test_sensor_power_control_set_power_state_mut_ref() {
+        let mut sensor = MockSleepySensor {
+            state: PowerState::default(),
+        };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_power_state(PowerState::Shutdown).is_ok());
+        assert_eq!(mut_ref.power_state().unwrap(), PowerState::Shutdown);
+        assert!(mut_ref.set_power_state(PowerState::Sleep).is_ok());
+        assert_eq!(sensor.state, PowerState::Sleep);
+    }
+}
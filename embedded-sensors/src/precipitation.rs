@@ -0,0 +1,259 @@
+//! Blocking Precipitation Sensor API
+//!
+//! This API provides generic methods for interfacing with precipitation sensors (rain gauges) specifically.
+//!
+//! Rain gauges such as tipping-bucket gauges measure precipitation by accumulating it over
+//! time, rather than by sampling it instantaneously. Sensors implementing this API therefore
+//! report the precipitation accumulated since the accumulation was last reset.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the PrecipitationSensor trait for a tipping-bucket rain gauge.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::precipitation::{Millimeters, PrecipitationSensor};
+//!
+//! // A struct representing a tipping-bucket rain gauge.
+//! pub struct MyPrecipitationSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyPrecipitationSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl PrecipitationSensor for MyPrecipitationSensor {
+//!     fn accumulated_precipitation(&mut self) -> Result<Millimeters, Self::Error> {
+//!         // Convert bucket tip count to accumulated rainfall...
+//!         Ok(12.4)
+//!     }
+//!
+//!     fn reset_accumulation(&mut self) -> Result<(), Self::Error> {
+//!         // Clear bucket tip counter...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{Sensor, ValidRange};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units precipitation samples are measured in with the underlying data type.
+pub type Millimeters = f32;
+
+/// Physically plausible range of accumulated precipitation samples.
+pub const PRECIPITATION_RANGE: ValidRange<Millimeters> = ValidRange::new(0.0, Millimeters::MAX);
+
+/// Marker type of the accumulated precipitation quantity, of which every [`PrecipitationSensor`]
+/// implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Precipitation;
+
+/// Blocking Precipitation Sensor methods.
+pub trait PrecipitationSensor: Sensor<Precipitation, Sample = Millimeters> {
+    /// Returns the precipitation accumulated since the last reset, in millimeters.
+    fn accumulated_precipitation(&mut self) -> Result<Millimeters, Self::Error>;
+
+    /// Reset the accumulated precipitation to zero.
+    fn reset_accumulation(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: PrecipitationSensor + ?Sized> PrecipitationSensor for &mut T {
+    #[inline]
+    fn accumulated_precipitation(&mut self) -> Result<Millimeters, Self::Error> {
+        T::accumulated_precipitation(self)
+    }
+
+    #[inline]
+    fn reset_accumulation(&mut self) -> Result<(), Self::Error> {
+        T::reset_accumulation(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    Precipitation,
+    PrecipitationSensor,
+    accumulated_precipitation,
+    Millimeters
+);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait PrecipitationThresholdSet: PrecipitationSensor {
+//     fn set_precipitation_threshold_low(&mut self, threshold: Millimeters) -> Result<(), Self::Error>;
+//     fn set_precipitation_threshold_high(&mut self, threshold: Millimeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait PrecipitationHysteresis: PrecipitationThresholdSet {
+//     fn set_precipitation_threshold_hysteresis(&mut self, hysteresis: Millimeters) -> Result<(), Self::Error>;
+// }
+//
+// pub trait PrecipitationThresholdControl: PrecipitationThresholdSet {
+//     fn disable_precipitation_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_precipitation_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(
+    blocking,
+    Precipitation,
+    PrecipitationSensor,
+    Millimeters,
+    "millimeters"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test value
+    const TEST_VALUE: Millimeters = 3.2;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockPrecipitationSensor {
+        value: Millimeters,
+        threshold_low: Option<Millimeters>,
+        threshold_high: Option<Millimeters>,
+        hysteresis: Option<Millimeters>,
+    }
+
+    impl MockPrecipitationSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockPrecipitationSensor {
+        type Error = MockError;
+    }
+
+    impl PrecipitationSensor for MockPrecipitationSensor {
+        fn accumulated_precipitation(&mut self) -> Result<Millimeters, Self::Error> {
+            Ok(self.value)
+        }
+
+        fn reset_accumulation(&mut self) -> Result<(), Self::Error> {
+            self.value = 0.0;
+            Ok(())
+        }
+    }
+
+    impl PrecipitationThresholdSet for MockPrecipitationSensor {
+        fn set_precipitation_threshold_low(
+            &mut self,
+            threshold: Millimeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_precipitation_threshold_high(
+            &mut self,
+            threshold: Millimeters,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl PrecipitationHysteresis for MockPrecipitationSensor {
+        fn set_precipitation_threshold_hysteresis(
+            &mut self,
+            hysteresis: Millimeters,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_precipitation_sensor_trait() {
+        let mut sensor = MockPrecipitationSensor::new();
+        let result = sensor.accumulated_precipitation();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_precipitation_sensor_trait_mut_ref() {
+        let mut sensor = MockPrecipitationSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.accumulated_precipitation();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_precipitation_threshold_set_mut_ref() {
+        let mut sensor = MockPrecipitationSensor::new();
+        let mut_ref = &mut sensor;
+        let low_threshold = 0.0;
+        let high_threshold = 50.0;
+
+        let result_low = mut_ref.set_precipitation_threshold_low(low_threshold);
+        assert!(result_low.is_ok());
+
+        let result_high = mut_ref.set_precipitation_threshold_high(high_threshold);
+        assert!(result_high.is_ok());
+
+        assert_approx_eq!(sensor.threshold_low.unwrap(), low_threshold);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), high_threshold);
+    }
+
+    #[test]
+    fn test_precipitation_hysteresis_mut_ref() {
+        let mut sensor = MockPrecipitationSensor::new();
+        let mut_ref = &mut sensor;
+        let hyst = 1.0;
+        let result = mut_ref.set_precipitation_threshold_hysteresis(hyst);
+        assert!(result.is_ok());
+        assert_approx_eq!(sensor.hysteresis.unwrap(), hyst);
+    }
+
+    #[test]
+    fn test_precipitation_range() {
+        assert!(PRECIPITATION_RANGE.validate(TEST_VALUE).is_ok());
+        assert_eq!(
+            PRECIPITATION_RANGE.validate(-1.0),
+            Err(ErrorKind::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_precipitation_reset_accumulation_mut_ref() {
+        let mut sensor = MockPrecipitationSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.reset_accumulation();
+        assert!(result.is_ok());
+        assert_approx_eq!(sensor.accumulated_precipitation().unwrap(), 0.0);
+    }
+}
@@ -0,0 +1,178 @@
+//! Blocking Presence Radar API
+//!
+//! This API provides generic methods for interfacing with radar presence sensors specifically,
+//! such as LD2410-class mmWave radars.
+//!
+//! Unlike PIR sensors (see the `occupancy` module), radars can detect people who are not moving
+//! and estimate how far away they are, so each [`PresenceSample`] classifies the presence as
+//! moving or stationary and carries the distance to the target when known.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the PresenceSensor trait for a radar presence
+//! sensor.
+//!
+//! ```
+//! use embedded_sensors_hal::presence::{PresenceSample, PresenceSensor, PresenceState};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a radar presence sensor.
+//! pub struct MyPresenceRadar {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyPresenceRadar {
+//!     type Error = Error;
+//! }
+//!
+//! impl PresenceSensor for MyPresenceRadar {
+//!     fn presence_sample(&mut self) -> Result<PresenceSample, Self::Error> {
+//!         // Parse target state and distance from latest report frame of sensor...
+//!         Ok(PresenceSample {
+//!             state: PresenceState::Stationary,
+//!             distance: Some(1.8),
+//!         })
+//!     }
+//! }
+//! ```
+
+pub use crate::distance::Meters;
+use crate::impl_sensor_refinement;
+use crate::sensor::Sensor;
+
+/// Presence classification of a radar presence sensor.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PresenceState {
+    /// No presence is detected.
+    #[default]
+    Absent,
+    /// A moving target is detected.
+    ///
+    /// Sensors detecting both moving and stationary targets report them as moving.
+    Moving,
+    /// Only a stationary target (e.g. a person sitting or breathing) is detected.
+    Stationary,
+}
+
+/// A presence sample.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PresenceSample {
+    /// Presence classification.
+    pub state: PresenceState,
+    /// Distance to the detected target in meters, if present and estimated by the sensor.
+    pub distance: Option<Meters>,
+}
+
+impl PresenceSample {
+    /// Returns `true` if a moving or stationary target is detected.
+    pub fn is_present(&self) -> bool {
+        self.state != PresenceState::Absent
+    }
+}
+
+/// Marker type of the presence quantity, of which every [`PresenceSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Presence;
+
+/// Blocking Presence Sensor methods.
+pub trait PresenceSensor: Sensor<Presence, Sample = PresenceSample> {
+    /// Returns a presence sample, classifying the presence and the distance to the target.
+    fn presence_sample(&mut self) -> Result<PresenceSample, Self::Error>;
+}
+
+impl<T: PresenceSensor + ?Sized> PresenceSensor for &mut T {
+    #[inline]
+    fn presence_sample(&mut self) -> Result<PresenceSample, Self::Error> {
+        T::presence_sample(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    Presence,
+    PresenceSensor,
+    presence_sample,
+    PresenceSample
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_SAMPLES: [PresenceSample; 3] = [
+        PresenceSample {
+            state: PresenceState::Moving,
+            distance: Some(3.2),
+        },
+        PresenceSample {
+            state: PresenceState::Stationary,
+            distance: Some(1.8),
+        },
+        PresenceSample {
+            state: PresenceState::Absent,
+            distance: None,
+        },
+    ];
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockPresenceRadar {
+        index: usize,
+    }
+
+    impl crate::sensor::ErrorType for MockPresenceRadar {
+        type Error = MockError;
+    }
+
+    impl PresenceSensor for MockPresenceRadar {
+        fn presence_sample(&mut self) -> Result<PresenceSample, Self::Error> {
+            Ok(TEST_SAMPLES[self.index])
+        }
+    }
+
+    #[test]
+    fn test_presence_sensor_trait() {
+        let mut sensor = MockPresenceRadar { index: 0 };
+        let result = sensor.presence_sample();
+        assert!(result.is_ok());
+        let sample = result.unwrap();
+        assert!(sample.is_present());
+        assert_eq!(sample, TEST_SAMPLES[0]);
+    }
+
+    #[test]
+    fn test_presence_sensor_trait_mut_ref() {
+        let mut sensor = MockPresenceRadar { index: 2 };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.presence_sample();
+        assert!(result.is_ok());
+        assert!(!result.unwrap().is_present());
+        assert!(!PresenceSample::default().is_present());
+    }
+}
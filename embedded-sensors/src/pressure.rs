@@ -0,0 +1,217 @@
+//! Blocking Pressure Sensor API
+//!
+//! This API provides generic methods for interfacing with pressure sensors specifically, such as
+//! barometers (e.g. BMP390 or LPS22HB) and gauge or differential pressure transducers.
+//!
+//! Barometers deriving altitude from pressure can also implement the
+//! [`AltitudeSensor`](crate::altitude::AltitudeSensor) trait.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the PressureSensor trait for a barometer.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::pressure::{Pascals, PressureSensor};
+//!
+//! // A struct representing a barometer.
+//! pub struct MyBarometer {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyBarometer {
+//!     type Error = Error;
+//! }
+//!
+//! impl PressureSensor for MyBarometer {
+//!     fn pressure(&mut self) -> Result<Pascals, Self::Error> {
+//!         // Read and compensate pressure registers of sensor...
+//!         Ok(101_325.0)
+//!     }
+//! }
+//! ```
+
+pub use crate::altitude::Pascals;
+use crate::sensor::{Sensor, ValidRange};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Physically plausible range of absolute pressure samples.
+pub const PRESSURE_RANGE: ValidRange<Pascals> = ValidRange::new(0.0, Pascals::MAX);
+
+/// Marker type of the pressure quantity, of which every [`PressureSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Pressure;
+
+/// Blocking Pressure Sensor methods.
+pub trait PressureSensor: Sensor<Pressure, Sample = Pascals> {
+    /// Returns a pressure sample in pascals.
+    fn pressure(&mut self) -> Result<Pascals, Self::Error>;
+}
+
+impl<T: PressureSensor + ?Sized> PressureSensor for &mut T {
+    #[inline]
+    fn pressure(&mut self) -> Result<Pascals, Self::Error> {
+        T::pressure(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Pressure, PressureSensor, pressure, Pascals);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait PressureThresholdSet: PressureSensor {
+//     fn set_pressure_threshold_low(&mut self, threshold: Pascals) -> Result<(), Self::Error>;
+//     fn set_pressure_threshold_high(&mut self, threshold: Pascals) -> Result<(), Self::Error>;
+// }
+//
+// pub trait PressureHysteresis: PressureThresholdSet {
+//     fn set_pressure_threshold_hysteresis(&mut self, hysteresis: Pascals) -> Result<(), Self::Error>;
+// }
+//
+// pub trait PressureThresholdControl: PressureThresholdSet {
+//     fn disable_pressure_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_pressure_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(blocking, Pressure, PressureSensor, Pascals, "pascals");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test value
+    const TEST_VALUE: Pascals = 101_325.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockBarometer {
+        value: Pascals,
+        threshold_low: Option<Pascals>,
+        threshold_high: Option<Pascals>,
+        hysteresis: Option<Pascals>,
+    }
+
+    impl MockBarometer {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockBarometer {
+        type Error = MockError;
+    }
+
+    impl PressureSensor for MockBarometer {
+        fn pressure(&mut self) -> Result<Pascals, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl PressureThresholdSet for MockBarometer {
+        fn set_pressure_threshold_low(&mut self, threshold: Pascals) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_pressure_threshold_high(&mut self, threshold: Pascals) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl PressureHysteresis for MockBarometer {
+        fn set_pressure_threshold_hysteresis(
+            &mut self,
+            hysteresis: Pascals,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_pressure_sensor_trait() {
+        let mut sensor = MockBarometer::new();
+        let result = sensor.pressure();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_pressure_sensor_measure() {
+        // Every pressure sensor is a `Sensor` of pressure.
+        let mut sensor = MockBarometer::new();
+        let result = Sensor::<Pressure>::measure(&mut sensor);
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_pressure_sensor_trait_mut_ref() {
+        let mut sensor = MockBarometer::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.pressure();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_pressure_threshold_set_mut_ref() {
+        let mut sensor = MockBarometer::new();
+        let mut_ref = &mut sensor;
+        let low_threshold = 95_000.0;
+        let high_threshold = 105_000.0;
+
+        let result_low = mut_ref.set_pressure_threshold_low(low_threshold);
+        assert!(result_low.is_ok());
+
+        let result_high = mut_ref.set_pressure_threshold_high(high_threshold);
+        assert!(result_high.is_ok());
+
+        assert_approx_eq!(sensor.threshold_low.unwrap(), low_threshold);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), high_threshold);
+    }
+
+    #[test]
+    fn test_pressure_hysteresis_mut_ref() {
+        let mut sensor = MockBarometer::new();
+        let mut_ref = &mut sensor;
+        let hyst = 50.0;
+        let result = mut_ref.set_pressure_threshold_hysteresis(hyst);
+        assert!(result.is_ok());
+        assert_approx_eq!(sensor.hysteresis.unwrap(), hyst);
+    }
+
+    #[test]
+    fn test_pressure_range() {
+        assert!(PRESSURE_RANGE.validate(TEST_VALUE).is_ok());
+        assert_eq!(PRESSURE_RANGE.validate(-1.0), Err(ErrorKind::OutOfRange));
+    }
+}
@@ -0,0 +1,238 @@
+//! Blocking Radiation Counter API
+//!
+//! This API provides generic methods for interfacing with ionizing radiation counters (e.g.
+//! Geiger-Müller tube front-ends) specifically.
+//!
+//! Unlike most sensors, radiation counters do not sample an instantaneous value: they
+//! accumulate pulses over a window of time, from which a count rate and dose rate are derived.
+//! The longer the window, the lower the statistical noise of the rates, so the application
+//! decides when to start a new window through [`RadiationCounter::reset_window`].
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the RadiationCounter trait for a Geiger tube
+//! front-end.
+//!
+//! ```
+//! use embedded_sensors_hal::radiation::{CountWindow, RadiationCounter, SBM20_CONVERSION_FACTOR};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a Geiger tube front-end counting pulses on a timer input.
+//! pub struct MyGeigerCounter {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyGeigerCounter {
+//!     type Error = Error;
+//! }
+//!
+//! impl RadiationCounter for MyGeigerCounter {
+//!     fn counts(&mut self) -> Result<CountWindow, Self::Error> {
+//!         // Read pulse counter and time elapsed since the window was reset...
+//!         Ok(CountWindow {
+//!             counts: 42,
+//!             duration_ms: 120_000,
+//!         })
+//!     }
+//!
+//!     fn reset_window(&mut self) -> Result<(), Self::Error> {
+//!         // Clear pulse counter and restart window timer...
+//!         Ok(())
+//!     }
+//!
+//!     fn conversion_factor(&self) -> f32 {
+//!         SBM20_CONVERSION_FACTOR
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+use crate::sensor::{Sensor, ValidRange};
+
+/// Associates the units count rates are measured in with the underlying data type.
+pub type CountsPerMinute = f32;
+
+/// Associates the units dose rates are measured in with the underlying data type.
+pub type MicrosievertsPerHour = f32;
+
+/// Physically plausible range of count rate samples.
+pub const COUNT_RATE_RANGE: ValidRange<CountsPerMinute> =
+    ValidRange::new(0.0, CountsPerMinute::MAX);
+
+/// Physically plausible range of dose rate samples.
+pub const DOSE_RATE_RANGE: ValidRange<MicrosievertsPerHour> =
+    ValidRange::new(0.0, MicrosievertsPerHour::MAX);
+
+/// Conversion factor of the common SBM-20 Geiger tube, in counts per minute per µSv/h
+/// (calibrated against Cs-137).
+pub const SBM20_CONVERSION_FACTOR: f32 = 153.8;
+
+/// Pulses counted over a window of time.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CountWindow {
+    /// Number of pulses counted since the window was reset.
+    pub counts: u32,
+    /// Time elapsed since the window was reset, in milliseconds.
+    pub duration_ms: u32,
+}
+
+impl CountWindow {
+    /// Returns the average count rate over the window, or zero for an empty window.
+    pub fn counts_per_minute(&self) -> CountsPerMinute {
+        if self.duration_ms == 0 {
+            return 0.0;
+        }
+
+        self.counts as f32 * 60_000.0 / self.duration_ms as f32
+    }
+}
+
+/// Marker type of the radiation count rate quantity, of which every [`RadiationCounter`]
+/// implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Radiation;
+
+/// Blocking Radiation Counter methods.
+pub trait RadiationCounter: Sensor<Radiation, Sample = CountsPerMinute> {
+    /// Returns the pulses counted since the window was last reset.
+    fn counts(&mut self) -> Result<CountWindow, Self::Error>;
+
+    /// Start a new counting window, discarding the pulses counted so far.
+    fn reset_window(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns the conversion factor of the detector, in counts per minute per µSv/h.
+    fn conversion_factor(&self) -> f32;
+
+    /// Returns the average count rate over the current window in counts per minute.
+    fn counts_per_minute(&mut self) -> Result<CountsPerMinute, Self::Error> {
+        Ok(self.counts()?.counts_per_minute())
+    }
+
+    /// Returns the average dose rate over the current window in microsieverts per hour,
+    /// derived from the count rate and the conversion factor.
+    fn dose_rate(&mut self) -> Result<MicrosievertsPerHour, Self::Error> {
+        Ok(self.counts_per_minute()? / self.conversion_factor())
+    }
+}
+
+impl<T: RadiationCounter + ?Sized> RadiationCounter for &mut T {
+    #[inline]
+    fn counts(&mut self) -> Result<CountWindow, Self::Error> {
+        T::counts(self)
+    }
+
+    #[inline]
+    fn reset_window(&mut self) -> Result<(), Self::Error> {
+        T::reset_window(self)
+    }
+
+    #[inline]
+    fn conversion_factor(&self) -> f32 {
+        T::conversion_factor(self)
+    }
+
+    #[inline]
+    fn counts_per_minute(&mut self) -> Result<CountsPerMinute, Self::Error> {
+        T::counts_per_minute(self)
+    }
+
+    #[inline]
+    fn dose_rate(&mut self) -> Result<MicrosievertsPerHour, Self::Error> {
+        T::dose_rate(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    Radiation,
+    RadiationCounter,
+    counts_per_minute,
+    CountsPerMinute
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_WINDOW: CountWindow = CountWindow {
+        counts: 50,
+        duration_ms: 120_000,
+    };
+    const TEST_CONVERSION_FACTOR: f32 = 125.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockGeigerCounter {
+        window: CountWindow,
+    }
+
+    impl crate::sensor::ErrorType for MockGeigerCounter {
+        type Error = MockError;
+    }
+
+    impl RadiationCounter for MockGeigerCounter {
+        fn counts(&mut self) -> Result<CountWindow, Self::Error> {
+            Ok(self.window)
+        }
+
+        fn reset_window(&mut self) -> Result<(), Self::Error> {
+            self.window = CountWindow::default();
+            Ok(())
+        }
+
+        fn conversion_factor(&self) -> f32 {
+            TEST_CONVERSION_FACTOR
+        }
+    }
+
+    #[test]
+    fn test_radiation_counter_trait() {
+        let mut sensor = MockGeigerCounter {
+            window: TEST_WINDOW,
+        };
+        let result = sensor.counts_per_minute();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), 25.0);
+        let result = sensor.dose_rate();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), 0.2);
+    }
+
+    #[test]
+    fn test_radiation_counter_trait_mut_ref() {
+        let mut sensor = MockGeigerCounter {
+            window: TEST_WINDOW,
+        };
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.counts().unwrap(), TEST_WINDOW);
+        assert!(mut_ref.reset_window().is_ok());
+
+        // An empty window reports a zero rate rather than dividing by zero.
+        assert_approx_eq!(mut_ref.dose_rate().unwrap(), 0.0);
+    }
+}
@@ -0,0 +1,246 @@
+//! Blocking Bus Recovery API
+//!
+//! This module provides a [`Recovering`] wrapper which, after a number of consecutive bus errors,
+//! invokes a [`BusRecovery`] procedure (e.g. an I2C bus clear or a device power-cycle) and retries
+//! the failed measurement once. It wraps a [`Sensor`] of any quantity, and so every sensor-type
+//! trait which refines [`Sensor`].
+//!
+//! Errors of kind [`ErrorKind::Peripheral`], [`ErrorKind::ChecksumMismatch`] and
+//! [`ErrorKind::Timeout`] are considered bus errors.
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal::recovery::Recovering;
+//! use embedded_sensors_hal::sensor::Sensor;
+//! use embedded_sensors_hal::temperature::{DegreesCelsius, Temperature, TemperatureSensor};
+//!
+//! fn robust<S: TemperatureSensor>(
+//!     sensor: S,
+//! ) -> impl Sensor<Temperature, Sample = DegreesCelsius, Error = S::Error> {
+//!     Recovering::new(sensor, || {
+//!         // Clock SCL until SDA is released, then issue a STOP condition...
+//!     })
+//!     .with_threshold(2)
+//! }
+//! ```
+
+use crate::sensor::{Error, ErrorKind, ErrorType, Sensor};
+
+/// Number of consecutive bus errors after which recovery is attempted by default.
+pub const DEFAULT_RECOVERY_THRESHOLD: u8 = 3;
+
+/// Blocking bus recovery procedure.
+///
+/// This is implemented for any `FnMut()` closure.
+pub trait BusRecovery {
+    /// Attempts to return the bus (or device) to a working state.
+    fn recover(&mut self);
+}
+
+impl<F: FnMut()> BusRecovery for F {
+    #[inline]
+    fn recover(&mut self) {
+        self()
+    }
+}
+
+/// Wraps a sensor, invoking a [`BusRecovery`] procedure and retrying after repeated bus
+/// errors.
+///
+/// Errors of any other kind are returned unchanged and do not count towards the threshold.
+pub struct Recovering<S, R> {
+    sensor: S,
+    recovery: R,
+    threshold: u8,
+    consecutive_errors: u8,
+    recoveries: u32,
+}
+
+impl<S, R: BusRecovery> Recovering<S, R> {
+    /// Wraps `sensor`, recovering with `recovery` after [`DEFAULT_RECOVERY_THRESHOLD`]
+    /// consecutive bus errors.
+    pub fn new(sensor: S, recovery: R) -> Self {
+        Self {
+            sensor,
+            recovery,
+            threshold: DEFAULT_RECOVERY_THRESHOLD,
+            consecutive_errors: 0,
+            recoveries: 0,
+        }
+    }
+
+    /// Set the number of consecutive bus errors after which recovery is attempted.
+    ///
+    /// A threshold of zero is treated as one.
+    pub fn with_threshold(mut self, threshold: u8) -> Self {
+        self.threshold = threshold.max(1);
+        self
+    }
+
+    /// Returns the number of times recovery has been attempted.
+    pub fn recoveries(&self) -> u32 {
+        self.recoveries
+    }
+
+    /// Returns a mutable reference to the wrapped sensor.
+    pub fn inner_mut(&mut self) -> &mut S {
+        &mut self.sensor
+    }
+
+    /// Consumes the wrapper, returning the wrapped sensor and recovery procedure.
+    pub fn into_inner(self) -> (S, R) {
+        (self.sensor, self.recovery)
+    }
+
+    // Updates the consecutive error count, returning true if recovery is due.
+    fn track<T, E: Error>(&mut self, result: &Result<T, E>) -> bool {
+        match result {
+            Ok(_) => self.consecutive_errors = 0,
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    ErrorKind::Peripheral | ErrorKind::ChecksumMismatch | ErrorKind::Timeout
+                ) =>
+            {
+                self.consecutive_errors = self.consecutive_errors.saturating_add(1);
+            }
+            Err(_) => {}
+        }
+
+        self.consecutive_errors >= self.threshold
+    }
+
+    fn recover(&mut self) {
+        self.consecutive_errors = 0;
+        self.recoveries = self.recoveries.saturating_add(1);
+        self.recovery.recover();
+    }
+}
+
+impl<S: ErrorType, R> ErrorType for Recovering<S, R> {
+    type Error = S::Error;
+}
+
+impl<Q, S: Sensor<Q>, R: BusRecovery> Sensor<Q> for Recovering<S, R> {
+    type Sample = S::Sample;
+
+    fn measure(&mut self) -> Result<Self::Sample, Self::Error> {
+        let result = self.sensor.measure();
+        if !self.track(&result) {
+            return result;
+        }
+
+        self.recover();
+        let result = self.sensor.measure();
+        self.track(&result);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temperature::{DegreesCelsius, Temperature, TemperatureSensor};
+    use assert_approx_eq::assert_approx_eq;
+    use core::cell::Cell;
+
+    // Mock test values
+    const TEST_TEMP: DegreesCelsius = 25.0;
+
+    #[derive(Debug)]
+    struct MockError(ErrorKind);
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            self.0
+        }
+    }
+
+    // Fails with the shared error kind, if any, which the recovery procedure may clear.
+    struct MockTempSensor<'a> {
+        failure: &'a Cell<Option<ErrorKind>>,
+        reads: u32,
+    }
+
+    impl crate::sensor::ErrorType for MockTempSensor<'_> {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockTempSensor<'_> {
+        fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            self.reads += 1;
+            match self.failure.get() {
+                Some(kind) => Err(MockError(kind)),
+                None => Ok(TEST_TEMP),
+            }
+        }
+    }
+
+    #[test]
+    fn test_recovering_retries_after_threshold() {
+        let failure = Cell::new(Some(ErrorKind::Peripheral));
+        let sensor = MockTempSensor {
+            failure: &failure,
+            reads: 0,
+        };
+        let mut sensor = Recovering::new(sensor, || failure.set(None)).with_threshold(2);
+
+        assert!(Sensor::<Temperature>::measure(&mut sensor).is_err());
+        assert_eq!(sensor.recoveries(), 0);
+
+        // The second consecutive failure triggers recovery, after which the retry succeeds.
+        let result = Sensor::<Temperature>::measure(&mut sensor);
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_TEMP);
+        assert_eq!(sensor.recoveries(), 1);
+        assert_eq!(sensor.inner_mut().reads, 3);
+    }
+
+    #[test]
+    fn test_recovering_failed_recovery() {
+        let failure = Cell::new(Some(ErrorKind::Peripheral));
+        let sensor = MockTempSensor {
+            failure: &failure,
+            reads: 0,
+        };
+        let mut sensor = Recovering::new(sensor, || {}).with_threshold(1);
+
+        assert!(Sensor::<Temperature>::measure(&mut sensor).is_err());
+        assert!(Sensor::<Temperature>::measure(&mut sensor).is_err());
+        assert_eq!(sensor.recoveries(), 2);
+
+        let (sensor, _) = sensor.into_inner();
+        assert_eq!(sensor.reads, 4);
+    }
+
+    #[test]
+    fn test_recovering_counts_checksum_and_timeout_errors() {
+        let failure = Cell::new(Some(ErrorKind::ChecksumMismatch));
+        let sensor = MockTempSensor {
+            failure: &failure,
+            reads: 0,
+        };
+        let mut sensor = Recovering::new(sensor, || failure.set(None)).with_threshold(2);
+
+        assert!(Sensor::<Temperature>::measure(&mut sensor).is_err());
+        failure.set(Some(ErrorKind::Timeout));
+        assert!(Sensor::<Temperature>::measure(&mut sensor).is_ok());
+        assert_eq!(sensor.recoveries(), 1);
+    }
+
+    #[test]
+    fn test_recovering_ignores_other_errors() {
+        let failure = Cell::new(Some(ErrorKind::Other));
+        let sensor = MockTempSensor {
+            failure: &failure,
+            reads: 0,
+        };
+        let mut sensor = Recovering::new(sensor, || {}).with_threshold(1);
+
+        for _ in 0..3 {
+            assert!(Sensor::<Temperature>::measure(&mut sensor).is_err());
+        }
+        assert_eq!(sensor.recoveries(), 0);
+    }
+}
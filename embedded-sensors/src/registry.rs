@@ -0,0 +1,187 @@
+//! Sensor Registry API
+//!
+//! This module provides a [`SensorRegistry`] which binds sensor instances to stable logical IDs,
+//! so that board support crates can hand applications a typed map of which sensor is where.
+//!
+//! IDs can be of any type which can be compared for equality, typically a board-specific enum
+//! or a `&'static str`. Sensors of different types can be registered together by using trait
+//! objects or a board-specific enum.
+//!
+//! # For board support authors
+//!
+//! ```
+//! use embedded_sensors_hal::registry::SensorRegistry;
+//! use embedded_sensors_hal::temperature::TemperatureSensor;
+//!
+//! #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+//! pub enum Location {
+//!     Cpu,
+//!     Battery,
+//!     Skin,
+//! }
+//!
+//! pub fn thermal_sensors<S: TemperatureSensor>(
+//!     cpu: S,
+//!     battery: S,
+//!     skin: S,
+//! ) -> SensorRegistry<Location, S, 3> {
+//!     SensorRegistry::new([
+//!         (Location::Cpu, cpu),
+//!         (Location::Battery, battery),
+//!         (Location::Skin, skin),
+//!     ])
+//! }
+//!
+//! fn cpu_temperature<S: TemperatureSensor>(
+//!     sensors: &mut SensorRegistry<Location, S, 3>,
+//! ) -> Option<Result<f32, S::Error>> {
+//!     sensors.get_mut(&Location::Cpu).map(|sensor| sensor.temperature())
+//! }
+//! ```
+
+/// A fixed-size map binding `N` sensors to logical IDs.
+///
+/// IDs are expected to be unique. If an ID is registered more than once, lookups return the
+/// first sensor registered with it.
+#[derive(Debug, Clone)]
+pub struct SensorRegistry<Id, S, const N: usize> {
+    entries: [(Id, S); N],
+}
+
+impl<Id: PartialEq, S, const N: usize> SensorRegistry<Id, S, N> {
+    /// Creates a new registry from pairs of IDs and sensors.
+    pub const fn new(entries: [(Id, S); N]) -> Self {
+        Self { entries }
+    }
+
+    /// Returns true if a sensor is registered with the given ID.
+    pub fn contains(&self, id: &Id) -> bool {
+        self.entries.iter().any(|(entry, _)| entry == id)
+    }
+
+    /// Returns a reference to the sensor registered with the given ID.
+    pub fn get(&self, id: &Id) -> Option<&S> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| entry == id)
+            .map(|(_, sensor)| sensor)
+    }
+
+    /// Returns a mutable reference to the sensor registered with the given ID.
+    pub fn get_mut(&mut self, id: &Id) -> Option<&mut S> {
+        self.entries
+            .iter_mut()
+            .find(|(entry, _)| entry == id)
+            .map(|(_, sensor)| sensor)
+    }
+
+    /// Returns an iterator over the registered IDs, in registration order.
+    pub fn ids(&self) -> impl Iterator<Item = &Id> {
+        self.entries.iter().map(|(id, _)| id)
+    }
+
+    /// Returns an iterator over the registered IDs and sensors, in registration order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Id, &S)> {
+        self.entries.iter().map(|(id, sensor)| (id, sensor))
+    }
+
+    /// Returns an iterator over the registered IDs and mutable sensors, in registration order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&Id, &mut S)> {
+        self.entries.iter_mut().map(|(id, sensor)| (&*id, sensor))
+    }
+
+    /// Consumes the registry, returning the pairs of IDs and sensors.
+    pub fn into_inner(self) -> [(Id, S); N] {
+        self.entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use crate::temperature::{DegreesCelsius, TemperatureSensor};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_CPU_TEMP: DegreesCelsius = 60.0;
+    const TEST_SKIN_TEMP: DegreesCelsius = 32.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockTempSensor {
+        value: DegreesCelsius,
+    }
+
+    impl crate::sensor::ErrorType for MockTempSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockTempSensor {
+        fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Location {
+        Cpu,
+        Skin,
+        Battery,
+    }
+
+    #[test]
+    fn test_registry_lookup() {
+        let mut registry = SensorRegistry::new([
+            (
+                Location::Cpu,
+                MockTempSensor {
+                    value: TEST_CPU_TEMP,
+                },
+            ),
+            (
+                Location::Skin,
+                MockTempSensor {
+                    value: TEST_SKIN_TEMP,
+                },
+            ),
+        ]);
+
+        assert!(registry.contains(&Location::Cpu));
+        assert!(!registry.contains(&Location::Battery));
+        assert!(registry.get(&Location::Battery).is_none());
+
+        let result = registry.get_mut(&Location::Skin).unwrap().temperature();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_SKIN_TEMP);
+
+        let ids = [Location::Cpu, Location::Skin];
+        assert!(registry.ids().eq(ids.iter()));
+    }
+
+    #[test]
+    fn test_registry_str_ids_dyn() {
+        let mut cpu = MockTempSensor {
+            value: TEST_CPU_TEMP,
+        };
+        let mut skin = MockTempSensor {
+            value: TEST_SKIN_TEMP,
+        };
+        let mut registry: SensorRegistry<&str, &mut dyn TemperatureSensor<Error = MockError>, 2> =
+            SensorRegistry::new([("cpu", &mut cpu), ("skin", &mut skin)]);
+
+        for (_, sensor) in registry.iter_mut() {
+            assert!(sensor.temperature().is_ok());
+        }
+
+        let result = registry.get_mut(&"cpu").unwrap().temperature();
+        assert_approx_eq!(result.unwrap(), TEST_CPU_TEMP);
+    }
+}
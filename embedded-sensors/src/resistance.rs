@@ -0,0 +1,201 @@
+//! Blocking Resistance Sensor API
+//!
+//! This API provides generic methods for interfacing with sensors whose raw measurement is
+//! resistive specifically, such as thermistor front-ends, the heater and sensing elements of metal-
+//! oxide gas sensors, or resistive moisture probes.
+//!
+//! Adapters deriving another physical quantity from the resistance (e.g. thermistor to temperature)
+//! can be built on top of the [`ResistanceSensor`] trait independently of the front-end.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the ResistanceSensor trait for a thermistor front-
+//! end.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::resistance::{Ohms, ResistanceSensor};
+//!
+//! // A struct representing a thermistor front-end.
+//! pub struct MyThermistorFrontEnd {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyThermistorFrontEnd {
+//!     type Error = Error;
+//! }
+//!
+//! impl ResistanceSensor for MyThermistorFrontEnd {
+//!     fn resistance(&mut self) -> Result<Ohms, Self::Error> {
+//!         // Read ADC and compute resistance from voltage divider ratio...
+//!         Ok(10_000.0)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{Sensor, ValidRange};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units resistances are measured in with the underlying data type.
+pub type Ohms = f32;
+
+/// Physically plausible range of resistance samples.
+pub const RESISTANCE_RANGE: ValidRange<Ohms> = ValidRange::new(0.0, Ohms::MAX);
+
+/// Marker type of the resistance quantity, of which every [`ResistanceSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Resistance;
+
+/// Blocking Resistance Sensor methods.
+pub trait ResistanceSensor: Sensor<Resistance, Sample = Ohms> {
+    /// Returns a resistance sample in ohms.
+    fn resistance(&mut self) -> Result<Ohms, Self::Error>;
+}
+
+impl<T: ResistanceSensor + ?Sized> ResistanceSensor for &mut T {
+    #[inline]
+    fn resistance(&mut self) -> Result<Ohms, Self::Error> {
+        T::resistance(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Resistance, ResistanceSensor, resistance, Ohms);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait ResistanceThresholdSet: ResistanceSensor {
+//     fn set_resistance_threshold_low(&mut self, threshold: Ohms) -> Result<(), Self::Error>;
+//     fn set_resistance_threshold_high(&mut self, threshold: Ohms) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ResistanceHysteresis: ResistanceThresholdSet {
+//     fn set_resistance_threshold_hysteresis(&mut self, hysteresis: Ohms) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ResistanceThresholdControl: ResistanceThresholdSet {
+//     fn disable_resistance_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_resistance_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(blocking, Resistance, ResistanceSensor, Ohms, "ohms");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: Ohms = 10_000.0;
+    const TEST_THRESHOLD_LOW: Ohms = 1_000.0;
+    const TEST_THRESHOLD_HIGH: Ohms = 50_000.0;
+    const TEST_HYSTERESIS: Ohms = 100.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockResistanceSensor {
+        value: Ohms,
+        threshold_low: Option<Ohms>,
+        threshold_high: Option<Ohms>,
+        hysteresis: Option<Ohms>,
+    }
+
+    impl MockResistanceSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockResistanceSensor {
+        type Error = MockError;
+    }
+
+    impl ResistanceSensor for MockResistanceSensor {
+        fn resistance(&mut self) -> Result<Ohms, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl ResistanceThresholdSet for MockResistanceSensor {
+        fn set_resistance_threshold_low(&mut self, threshold: Ohms) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_resistance_threshold_high(&mut self, threshold: Ohms) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl ResistanceHysteresis for MockResistanceSensor {
+        fn set_resistance_threshold_hysteresis(
+            &mut self,
+            hysteresis: Ohms,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_resistance_sensor_trait() {
+        let mut sensor = MockResistanceSensor::new();
+        let result = sensor.resistance();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_resistance_sensor_trait_mut_ref() {
+        let mut sensor = MockResistanceSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.resistance();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_resistance_threshold_set_mut_ref() {
+        let mut sensor = MockResistanceSensor::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_resistance_threshold_low(TEST_THRESHOLD_LOW)
+            .is_ok());
+        assert!(mut_ref
+            .set_resistance_threshold_high(TEST_THRESHOLD_HIGH)
+            .is_ok());
+        assert!(mut_ref
+            .set_resistance_threshold_hysteresis(TEST_HYSTERESIS)
+            .is_ok());
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), TEST_HYSTERESIS);
+    }
+}
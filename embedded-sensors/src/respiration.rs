@@ -0,0 +1,190 @@
+//! Blocking Respiration Rate Sensor API
+//!
+//! This API provides generic methods for interfacing with respiration rate sensors specifically,
+//! for medical and wellness devices deriving the respiration rate from pressure, radar or
+//! photoplethysmography (PPG) signals.
+//!
+//! Respiration rate is estimated over several breaths and its reliability depends on motion and
+//! signal quality, so each sample carries the confidence the sensor has in the estimate.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the RespirationSensor trait for a radar sensor.
+//!
+//! ```
+//! use embedded_sensors_hal::respiration::{RespirationSample, RespirationSensor};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing a vital signs radar sensor.
+//! pub struct MyRespirationRadar {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyRespirationRadar {
+//!     type Error = Error;
+//! }
+//!
+//! impl RespirationSensor for MyRespirationRadar {
+//!     fn respiration_rate(&mut self) -> Result<RespirationSample, Self::Error> {
+//!         // Read respiration rate and confidence registers of sensor...
+//!         Ok(RespirationSample {
+//!             rate: 14.0,
+//!             confidence: 85.0,
+//!         })
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+use crate::sensor::{Sensor, ValidRange};
+
+/// Associates the units respiration rate samples are measured in with the underlying data type.
+pub type BreathsPerMinute = f32;
+
+/// Associates the units confidence values are measured in with the underlying data type.
+pub type Percentage = f32;
+
+/// Physically plausible range of respiration rate samples.
+pub const RESPIRATION_RATE_RANGE: ValidRange<BreathsPerMinute> = ValidRange::new(0.0, 120.0);
+
+/// Range of confidence values.
+pub const CONFIDENCE_RANGE: ValidRange<Percentage> = ValidRange::new(0.0, 100.0);
+
+/// A respiration rate sample.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RespirationSample {
+    /// Respiration rate in breaths per minute.
+    pub rate: BreathsPerMinute,
+    /// Confidence of the sensor in the rate as a percentage, 0 % meaning no estimate is
+    /// available.
+    pub confidence: Percentage,
+}
+
+impl RespirationSample {
+    /// Returns the rate if the confidence is at least `min_confidence`.
+    pub fn rate_with_confidence(&self, min_confidence: Percentage) -> Option<BreathsPerMinute> {
+        (self.confidence > 0.0 && self.confidence >= min_confidence).then_some(self.rate)
+    }
+}
+
+/// Marker type of the respiration rate quantity, of which every [`RespirationSensor`] implementor
+/// is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Respiration;
+
+/// Blocking Respiration Rate Sensor methods.
+pub trait RespirationSensor: Sensor<Respiration, Sample = RespirationSample> {
+    /// Returns a respiration rate sample in breaths per minute, along with its confidence.
+    fn respiration_rate(&mut self) -> Result<RespirationSample, Self::Error>;
+}
+
+impl<T: RespirationSensor + ?Sized> RespirationSensor for &mut T {
+    #[inline]
+    fn respiration_rate(&mut self) -> Result<RespirationSample, Self::Error> {
+        T::respiration_rate(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    Respiration,
+    RespirationSensor,
+    respiration_rate,
+    RespirationSample
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_SAMPLE: RespirationSample = RespirationSample {
+        rate: 14.0,
+        confidence: 85.0,
+    };
+    const TEST_MIN_CONFIDENCE: Percentage = 50.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockRespirationRadar {
+        sample: RespirationSample,
+    }
+
+    impl crate::sensor::ErrorType for MockRespirationRadar {
+        type Error = MockError;
+    }
+
+    impl RespirationSensor for MockRespirationRadar {
+        fn respiration_rate(&mut self) -> Result<RespirationSample, Self::Error> {
+            Ok(self.sample)
+        }
+    }
+
+    #[test]
+    fn test_respiration_sensor_trait() {
+        let mut sensor = MockRespirationRadar {
+            sample: TEST_SAMPLE,
+        };
+        let result = sensor.respiration_rate();
+        assert!(result.is_ok());
+        assert_approx_eq!(
+            result
+                .unwrap()
+                .rate_with_confidence(TEST_MIN_CONFIDENCE)
+                .unwrap(),
+            TEST_SAMPLE.rate
+        );
+    }
+
+    #[test]
+    fn test_respiration_sensor_trait_mut_ref() {
+        let mut sensor = MockRespirationRadar {
+            sample: RespirationSample {
+                rate: 22.0,
+                confidence: 30.0,
+            },
+        };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.respiration_rate();
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap().rate_with_confidence(TEST_MIN_CONFIDENCE),
+            None
+        );
+    }
+
+    #[test]
+    fn test_respiration_ranges() {
+        assert!(RESPIRATION_RATE_RANGE.validate(TEST_SAMPLE.rate).is_ok());
+        assert!(CONFIDENCE_RANGE.validate(TEST_SAMPLE.confidence).is_ok());
+        assert_eq!(
+            RESPIRATION_RATE_RANGE.validate(-1.0),
+            Err(ErrorKind::OutOfRange)
+        );
+        assert_eq!(RespirationSample::default().rate_with_confidence(0.0), None);
+    }
+}
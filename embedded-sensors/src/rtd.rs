@@ -0,0 +1,239 @@
+//! Blocking RTD Sensor API
+//!
+//! This API provides generic methods for interfacing with resistance temperature detectors
+//! (RTDs, e.g. PT100 or PT1000 probes) through MAX31865-class converters specifically.
+//!
+//! Besides the temperature, each [`RtdSample`] carries the raw resistance of the RTD in ohms,
+//! for applications applying their own linearization, and the [`RtdFaults`] latched by the
+//! converter.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the RtdSensor trait for an RTD converter.
+//!
+//! ```
+//! use embedded_sensors_hal::rtd::{RtdFaults, RtdSample, RtdSensor};
+//! use embedded_sensors_hal::sensor;
+//!
+//! // A struct representing an RTD-to-digital converter.
+//! pub struct MyRtdConverter {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyRtdConverter {
+//!     type Error = Error;
+//! }
+//!
+//! impl RtdSensor for MyRtdConverter {
+//!     fn rtd(&mut self) -> Result<RtdSample, Self::Error> {
+//!         // Read RTD and fault status registers of converter, then convert the ratio to the
+//!         // reference resistor into resistance and temperature...
+//!         Ok(RtdSample {
+//!             temperature: 21.3,
+//!             resistance: 108.3,
+//!             faults: RtdFaults::NONE,
+//!         })
+//!     }
+//!
+//!     fn clear_faults(&mut self) -> Result<(), Self::Error> {
+//!         // Write fault status clear bit of configuration register...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+pub use crate::resistance::Ohms;
+use crate::sensor::Sensor;
+use crate::temperature::DegreesCelsius;
+
+/// Nominal resistance of a PT100 RTD at 0 °C.
+pub const PT100_R0: Ohms = 100.0;
+
+/// Nominal resistance of a PT1000 RTD at 0 °C.
+pub const PT1000_R0: Ohms = 1000.0;
+
+/// Set of faults latched by an RTD converter.
+///
+/// Fault detection differs between converters, so drivers should map their fault bits onto
+/// the closest of these flags.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RtdFaults(pub u8);
+
+impl RtdFaults {
+    /// No fault.
+    pub const NONE: Self = Self(0);
+    /// The RTD resistance is above the high fault threshold of the converter.
+    pub const HIGH_THRESHOLD: Self = Self(1 << 0);
+    /// The RTD resistance is below the low fault threshold of the converter.
+    pub const LOW_THRESHOLD: Self = Self(1 << 1);
+    /// The RTD or its leads are open.
+    pub const OPEN_CIRCUIT: Self = Self(1 << 2);
+    /// The RTD or its leads are shorted.
+    pub const SHORT_CIRCUIT: Self = Self(1 << 3);
+    /// The reference input voltage is out of range.
+    pub const REFERENCE: Self = Self(1 << 4);
+    /// An input is over or under the supply voltage.
+    pub const OVER_UNDER_VOLTAGE: Self = Self(1 << 5);
+
+    /// Returns `true` if no fault is set.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if every fault of `other` is set.
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the union of both sets of faults.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl core::ops::BitOr for RtdFaults {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+/// An RTD sample.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RtdSample {
+    /// Temperature in degrees Celsius, as linearized by the driver.
+    pub temperature: DegreesCelsius,
+    /// Raw resistance of the RTD in ohms.
+    pub resistance: Ohms,
+    /// Faults latched by the converter.
+    ///
+    /// The temperature and resistance should not be trusted unless this is empty.
+    pub faults: RtdFaults,
+}
+
+impl RtdSample {
+    /// Returns the temperature if no fault is set.
+    pub fn temperature(&self) -> Option<DegreesCelsius> {
+        self.faults.is_empty().then_some(self.temperature)
+    }
+}
+
+/// Marker type of the RTD temperature quantity, of which every [`RtdSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RtdTemperature;
+
+/// Blocking RTD Sensor methods.
+pub trait RtdSensor: Sensor<RtdTemperature, Sample = RtdSample> {
+    /// Returns an RTD sample with temperature in degrees Celsius, resistance in ohms and the
+    /// faults latched by the converter.
+    fn rtd(&mut self) -> Result<RtdSample, Self::Error>;
+
+    /// Clear the faults latched by the converter.
+    fn clear_faults(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: RtdSensor + ?Sized> RtdSensor for &mut T {
+    #[inline]
+    fn rtd(&mut self) -> Result<RtdSample, Self::Error> {
+        T::rtd(self)
+    }
+
+    #[inline]
+    fn clear_faults(&mut self) -> Result<(), Self::Error> {
+        T::clear_faults(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, RtdTemperature, RtdSensor, rtd, RtdSample);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_SAMPLE: RtdSample = RtdSample {
+        temperature: 21.3,
+        resistance: 108.3,
+        faults: RtdFaults::NONE,
+    };
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockRtdConverter {
+        sample: RtdSample,
+    }
+
+    impl crate::sensor::ErrorType for MockRtdConverter {
+        type Error = MockError;
+    }
+
+    impl RtdSensor for MockRtdConverter {
+        fn rtd(&mut self) -> Result<RtdSample, Self::Error> {
+            Ok(self.sample)
+        }
+
+        fn clear_faults(&mut self) -> Result<(), Self::Error> {
+            self.sample.faults = RtdFaults::NONE;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_rtd_sensor_trait() {
+        let mut sensor = MockRtdConverter {
+            sample: TEST_SAMPLE,
+        };
+        let result = sensor.rtd();
+        assert!(result.is_ok());
+        let sample = result.unwrap();
+        assert_approx_eq!(sample.resistance, 108.3);
+        assert_approx_eq!(sample.temperature().unwrap(), 21.3);
+    }
+
+    #[test]
+    fn test_rtd_sensor_trait_mut_ref() {
+        let mut sensor = MockRtdConverter {
+            sample: RtdSample {
+                faults: RtdFaults::OPEN_CIRCUIT | RtdFaults::HIGH_THRESHOLD,
+                ..TEST_SAMPLE
+            },
+        };
+        let mut_ref = &mut sensor;
+        let sample = mut_ref.rtd().unwrap();
+        assert!(sample.faults.contains(RtdFaults::OPEN_CIRCUIT));
+        assert!(!sample.faults.contains(RtdFaults::SHORT_CIRCUIT));
+        assert_eq!(sample.temperature(), None);
+
+        assert!(mut_ref.clear_faults().is_ok());
+        assert!(mut_ref.rtd().unwrap().faults.is_empty());
+    }
+}
@@ -0,0 +1,401 @@
+//! Blocking Sample Sanitization API
+//!
+//! This module provides a [`Sanitized`] wrapper which catches the NaN and infinite samples a
+//! driver may produce and handles them according to an [`InvalidSamplePolicy`], so that
+//! downstream components (filters, thresholds, telemetry) only ever see finite samples unless
+//! told otherwise. Samples may also be checked against the range of plausible values of their
+//! quantity (e.g. [`TEMPERATURE_RANGE`]), catching finite but corrupt samples such as those of a
+//! misread register.
+//!
+//! The policy is chosen per wrapper, so that each adapter chain can handle invalid samples as
+//! suits its consumer: a safety monitor would rather reject them, whereas a display may prefer
+//! the last good sample.
+//!
+//! [`Sanitized`] only implements the altitude, dew point, relative humidity, sound pressure level,
+//! temperature and wind speed sensor traits, the quantities with a range of plausible values.
+//! Samples of other sensors can be passed through a [`SampleSanitizer`] directly.
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal::sanitize::{InvalidSamplePolicy, Sanitized};
+//! use embedded_sensors_hal::temperature::TemperatureSensor;
+//!
+//! fn display_temperature<S: TemperatureSensor>(sensor: S) -> impl TemperatureSensor {
+//!     Sanitized::new(sensor, InvalidSamplePolicy::LastGood)
+//! }
+//! ```
+
+use crate::altitude::{AltitudeSensor, Meters, Pascals, ALTITUDE_RANGE};
+use crate::dew_point::DewPointSensor;
+use crate::humidity::{Percentage, RelativeHumiditySensor, RELATIVE_HUMIDITY_RANGE};
+use crate::sensor::{checked_sample, Error, ErrorKind, ErrorType, ValidRange};
+use crate::sound::{Decibels, SoundPressureLevelSensor, SOUND_PRESSURE_LEVEL_RANGE};
+use crate::temperature::{DegreesCelsius, TemperatureSensor, TEMPERATURE_RANGE};
+use crate::wind::{MetersPerSecond, WindSpeedSensor, WIND_SPEED_RANGE};
+
+/// Handling of invalid samples: non-finite (NaN or infinite) samples and, where range checking
+/// is enabled, samples outside the range of plausible values.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum InvalidSamplePolicy {
+    /// Fail with [`ErrorKind::InvalidInput`], or [`ErrorKind::OutOfRange`] for samples outside
+    /// the range.
+    #[default]
+    Reject,
+    /// Substitute the last valid sample, failing as [`Reject`](Self::Reject) does if there is
+    /// none yet.
+    LastGood,
+    /// Return the sample unchanged.
+    Propagate,
+}
+
+/// Applies an [`InvalidSamplePolicy`] to successive samples of a quantity.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SampleSanitizer {
+    policy: InvalidSamplePolicy,
+    last_good: Option<f32>,
+    invalid_samples: u32,
+}
+
+impl SampleSanitizer {
+    /// Creates a new sanitizer applying `policy`.
+    pub const fn new(policy: InvalidSamplePolicy) -> Self {
+        Self {
+            policy,
+            last_good: None,
+            invalid_samples: 0,
+        }
+    }
+
+    /// Returns the policy applied to invalid samples.
+    pub fn policy(&self) -> InvalidSamplePolicy {
+        self.policy
+    }
+
+    /// Returns the number of invalid samples seen so far, whatever the policy.
+    pub fn invalid_samples(&self) -> u32 {
+        self.invalid_samples
+    }
+
+    /// Returns the sample to report in place of `sample`, according to the policy.
+    pub fn apply(&mut self, sample: f32) -> Result<f32, ErrorKind> {
+        self.sanitize(sample, checked_sample(sample))
+    }
+
+    /// Returns the sample to report in place of `sample`, according to the policy, treating
+    /// samples outside `range` as invalid too.
+    pub fn apply_in_range(
+        &mut self,
+        sample: f32,
+        range: ValidRange<f32>,
+    ) -> Result<f32, ErrorKind> {
+        let checked = checked_sample(sample).and_then(|sample| range.validate(sample));
+        self.sanitize(sample, checked)
+    }
+
+    fn sanitize(&mut self, sample: f32, checked: Result<f32, ErrorKind>) -> Result<f32, ErrorKind> {
+        match checked {
+            Ok(sample) => {
+                self.last_good = Some(sample);
+                Ok(sample)
+            }
+            Err(kind) => {
+                self.invalid_samples = self.invalid_samples.saturating_add(1);
+                match self.policy {
+                    InvalidSamplePolicy::Reject => Err(kind),
+                    InvalidSamplePolicy::LastGood => self.last_good.ok_or(kind),
+                    InvalidSamplePolicy::Propagate => Ok(sample),
+                }
+            }
+        }
+    }
+}
+
+/// Error returned by a [`Sanitized`] sensor.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SanitizeError<E> {
+    /// The wrapped sensor failed.
+    Sensor(E),
+    /// The wrapped sensor produced an invalid sample, which the policy did not replace.
+    InvalidSample,
+    /// The wrapped sensor produced a sample outside the range of plausible values, which the
+    /// policy did not replace.
+    OutOfRange,
+}
+
+impl<E: Error> Error for SanitizeError<E> {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Sensor(e) => e.kind(),
+            Self::InvalidSample => ErrorKind::InvalidInput,
+            Self::OutOfRange => ErrorKind::OutOfRange,
+        }
+    }
+}
+
+/// Wraps a sensor, handling the non-finite samples it produces according to an
+/// [`InvalidSamplePolicy`].
+///
+/// Only the sensor traits listed in the module documentation are implemented. Each of them has a
+/// sanitizer of its own, so that the samples of a sensor measuring several quantities are never
+/// substituted for one another.
+pub struct Sanitized<S> {
+    sensor: S,
+    sanitizers: [SampleSanitizer; SANITIZED_QUANTITIES],
+    check_range: bool,
+}
+
+impl<S> Sanitized<S> {
+    /// Wraps `sensor`, applying `policy` to its invalid samples.
+    pub fn new(sensor: S, policy: InvalidSamplePolicy) -> Self {
+        Self {
+            sensor,
+            sanitizers: [SampleSanitizer::new(policy); SANITIZED_QUANTITIES],
+            check_range: false,
+        }
+    }
+
+    /// Also treats samples outside the range of plausible values of their quantity as invalid.
+    pub fn with_range_check(mut self) -> Self {
+        self.check_range = true;
+        self
+    }
+
+    /// Returns a mutable reference to the wrapped sensor.
+    pub fn inner_mut(&mut self) -> &mut S {
+        &mut self.sensor
+    }
+
+    /// Consumes the wrapper, returning the wrapped sensor.
+    pub fn into_inner(self) -> S {
+        self.sensor
+    }
+}
+
+impl<S: ErrorType> ErrorType for Sanitized<S> {
+    type Error = SanitizeError<S::Error>;
+}
+
+// Number of sensor traits implemented by `Sanitized`, each with a sanitizer of its own.
+const SANITIZED_QUANTITIES: usize = 6;
+
+// The first method of each trait returns the samples to sanitize, which are checked against the
+// range of their quantity if enabled by the sanitizer at the given index; any others are
+// forwarded to the wrapped sensor unchanged.
+macro_rules! impl_sanitized {
+    ($SensorTrait:ident[$index:literal] within $RANGE:ident {
+        fn $sample:ident() -> $Sample:ty;
+        $(fn $method:ident($($arg:ident: $ArgType:ty),*) -> $Ret:ty;)*
+    }) => {
+        paste::paste! {
+            impl<S> Sanitized<S> {
+                #[doc = concat!(" Returns the sanitizer applied to the `", stringify!($sample), "` samples of the wrapped sensor.")]
+                pub fn [<$sample _sanitizer>](&self) -> &SampleSanitizer {
+                    &self.sanitizers[$index]
+                }
+            }
+        }
+
+        impl<S: $SensorTrait> $SensorTrait for Sanitized<S> {
+            fn $sample(&mut self) -> Result<$Sample, Self::Error> {
+                let sample = self.sensor.$sample().map_err(SanitizeError::Sensor)?;
+                let sanitizer = &mut self.sanitizers[$index];
+                let sample = if self.check_range {
+                    sanitizer.apply_in_range(sample, $RANGE)
+                } else {
+                    sanitizer.apply(sample)
+                };
+                sample.map_err(|kind| match kind {
+                    ErrorKind::OutOfRange => SanitizeError::OutOfRange,
+                    _ => SanitizeError::InvalidSample,
+                })
+            }
+
+            $(
+                fn $method(&mut self, $($arg: $ArgType),*) -> Result<$Ret, Self::Error> {
+                    self.sensor.$method($($arg),*).map_err(SanitizeError::Sensor)
+                }
+            )*
+        }
+    };
+}
+
+impl_sanitized!(AltitudeSensor[0] within ALTITUDE_RANGE {
+    fn altitude() -> Meters;
+    fn set_sea_level_pressure(pressure: Pascals) -> ();
+});
+impl_sanitized!(DewPointSensor[1] within TEMPERATURE_RANGE {
+    fn dew_point() -> DegreesCelsius;
+});
+impl_sanitized!(RelativeHumiditySensor[2] within RELATIVE_HUMIDITY_RANGE {
+    fn relative_humidity() -> Percentage;
+});
+impl_sanitized!(SoundPressureLevelSensor[3] within SOUND_PRESSURE_LEVEL_RANGE {
+    fn sound_pressure_level() -> Decibels;
+});
+impl_sanitized!(TemperatureSensor[4] within TEMPERATURE_RANGE {
+    fn temperature() -> DegreesCelsius;
+});
+impl_sanitized!(WindSpeedSensor[5] within WIND_SPEED_RANGE {
+    fn wind_speed() -> MetersPerSecond;
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_TEMPS: [DegreesCelsius; 4] = [f32::NAN, 24.5, f32::INFINITY, 25.0];
+    const TEST_CORRUPT_TEMPS: [DegreesCelsius; 3] = [24.5, -300.0, 25.0];
+
+    #[derive(Debug, PartialEq)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Peripheral
+        }
+    }
+
+    // Returns each of the test values in turn.
+    struct MockTempSensor {
+        samples: &'static [DegreesCelsius],
+        reads: usize,
+    }
+
+    impl crate::sensor::ErrorType for MockTempSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockTempSensor {
+        fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            let sample = self.samples.get(self.reads).copied().ok_or(MockError);
+            self.reads += 1;
+            sample
+        }
+    }
+
+    #[test]
+    fn test_sanitized_reject() {
+        let mut sensor = Sanitized::new(
+            MockTempSensor {
+                samples: &TEST_TEMPS,
+                reads: 0,
+            },
+            InvalidSamplePolicy::Reject,
+        );
+        assert_eq!(sensor.temperature(), Err(SanitizeError::InvalidSample));
+        assert_approx_eq!(sensor.temperature().unwrap(), 24.5);
+        assert_eq!(
+            sensor.temperature().map_err(|e| e.kind()),
+            Err(ErrorKind::InvalidInput)
+        );
+        assert_approx_eq!(sensor.temperature().unwrap(), 25.0);
+        assert_eq!(sensor.temperature(), Err(SanitizeError::Sensor(MockError)));
+        assert_eq!(sensor.temperature_sanitizer().invalid_samples(), 2);
+    }
+
+    #[test]
+    fn test_sanitized_last_good() {
+        let mut sensor = Sanitized::new(
+            MockTempSensor {
+                samples: &TEST_TEMPS,
+                reads: 0,
+            },
+            InvalidSamplePolicy::LastGood,
+        );
+
+        // There is no good sample to substitute yet.
+        assert_eq!(sensor.temperature(), Err(SanitizeError::InvalidSample));
+        assert_approx_eq!(sensor.temperature().unwrap(), 24.5);
+        assert_approx_eq!(sensor.temperature().unwrap(), 24.5);
+        assert_approx_eq!(sensor.temperature().unwrap(), 25.0);
+    }
+
+    #[test]
+    fn test_sanitized_propagate() {
+        let mut sensor = Sanitized::new(
+            MockTempSensor {
+                samples: &TEST_TEMPS,
+                reads: 0,
+            },
+            InvalidSamplePolicy::Propagate,
+        );
+        assert!(sensor.temperature().unwrap().is_nan());
+        assert_approx_eq!(sensor.temperature().unwrap(), 24.5);
+        assert!(sensor.temperature().unwrap().is_infinite());
+        assert_eq!(sensor.temperature_sanitizer().invalid_samples(), 2);
+        assert_eq!(sensor.into_inner().reads, 3);
+    }
+
+    #[test]
+    fn test_sanitized_range_check() {
+        let sensor = MockTempSensor {
+            samples: &TEST_CORRUPT_TEMPS,
+            reads: 0,
+        };
+        let mut sensor = Sanitized::new(sensor, InvalidSamplePolicy::Reject).with_range_check();
+        assert_approx_eq!(sensor.temperature().unwrap(), 24.5);
+        assert_eq!(sensor.temperature(), Err(SanitizeError::OutOfRange));
+        assert_approx_eq!(sensor.temperature().unwrap(), 25.0);
+        assert_eq!(sensor.temperature_sanitizer().invalid_samples(), 1);
+
+        // Without range checking, finite samples are never invalid.
+        let sensor = MockTempSensor {
+            samples: &TEST_CORRUPT_TEMPS,
+            reads: 0,
+        };
+        let mut sensor = Sanitized::new(sensor, InvalidSamplePolicy::Reject);
+        assert_approx_eq!(sensor.temperature().unwrap(), 24.5);
+        assert_approx_eq!(sensor.temperature().unwrap(), -300.0);
+    }
+
+    #[test]
+    fn test_sanitized_range_check_last_good() {
+        let sensor = MockTempSensor {
+            samples: &TEST_CORRUPT_TEMPS,
+            reads: 0,
+        };
+        let mut sensor = Sanitized::new(sensor, InvalidSamplePolicy::LastGood).with_range_check();
+        assert_approx_eq!(sensor.temperature().unwrap(), 24.5);
+        assert_approx_eq!(sensor.temperature().unwrap(), 24.5);
+        assert_approx_eq!(sensor.temperature().unwrap(), 25.0);
+    }
+
+    // Measures a valid temperature alongside an invalid relative humidity.
+    struct MockTempHumiditySensor;
+
+    impl crate::sensor::ErrorType for MockTempHumiditySensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockTempHumiditySensor {
+        fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(24.5)
+        }
+    }
+
+    impl RelativeHumiditySensor for MockTempHumiditySensor {
+        fn relative_humidity(&mut self) -> Result<Percentage, Self::Error> {
+            Ok(f32::NAN)
+        }
+    }
+
+    #[test]
+    fn test_sanitized_multi_quantity() {
+        let mut sensor = Sanitized::new(MockTempHumiditySensor, InvalidSamplePolicy::LastGood);
+        assert_approx_eq!(sensor.temperature().unwrap(), 24.5);
+        // The last good temperature is never substituted for a humidity.
+        assert_eq!(
+            sensor.relative_humidity(),
+            Err(SanitizeError::InvalidSample)
+        );
+        assert_eq!(sensor.temperature_sanitizer().invalid_samples(), 0);
+        assert_eq!(sensor.relative_humidity_sanitizer().invalid_samples(), 1);
+    }
+}
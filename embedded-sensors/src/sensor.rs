@@ -5,6 +5,8 @@
 //! Please see specific sensor-type modules for additional example usage
 //! (e.g. see temperature.rs for TemperatureSensor examples).
 
+use crate::frequency::Hertz;
+
 /// Sensor error.
 pub trait Error: core::fmt::Debug {
     /// Convert error to a generic Sensor error kind.
@@ -43,6 +45,15 @@ pub enum ErrorKind {
     InvalidInput,
     /// A different error occurred. The original error may contain more information.
     Other,
+    /// The checksum (e.g. CRC) of data received from the sensor did not match the data, which was
+    /// likely corrupted in transfer.
+    ChecksumMismatch,
+    /// The sensor did not respond or complete an operation in time.
+    Timeout,
+    /// The sensor must be calibrated before it can be sampled, or its calibration failed.
+    NotCalibrated,
+    /// The sample lies outside of the measurement range of the sensor.
+    OutOfRange,
 }
 
 impl Error for ErrorKind {
@@ -67,6 +78,13 @@ impl core::fmt::Display for ErrorKind {
                 f,
                 "A different error occurred. The original error may contain more information"
             ),
+            Self::ChecksumMismatch => write!(
+                f,
+                "Checksum of data received from sensor did not match thus data may be corrupted"
+            ),
+            Self::Timeout => write!(f, "Sensor did not respond in time"),
+            Self::NotCalibrated => write!(f, "Sensor is not calibrated or its calibration failed"),
+            Self::OutOfRange => write!(f, "Sample is outside of the measurement range of sensor"),
         }
     }
 }
@@ -83,91 +101,1546 @@ impl<T: ErrorType + ?Sized> ErrorType for &mut T {
     type Error = T::Error;
 }
 
-/// Generates threshold traits for the specified sensor type.
+/// Set of optional features supported by a sensor, as reported by [`SensorCapabilities`].
 ///
-/// This macro supports both blocking and async sensors.
-/// When used with `blocking` mode, it generates `ThresholdSet` and `Hysteresis` traits.
-/// When used with `async` mode, it additionally generates `ThresholdWait` trait.
-#[macro_export]
-macro_rules! decl_threshold_traits {
-    // The `kw_async`, `op_await` and 'doc_suffix' parameters are automatically resolved
-    // based on the mode (blocking or async), allowing the trait and function definitions to
-    // exist in one place while supporting both synchronous and asynchronous variants.
-    (blocking, $SensorName:ident, $SensorTrait:ident, $SampleType:ty, $unit:expr) => {
-        decl_threshold_traits!(
-            @generate,
-            [kw_async = ],
-            [op_await = ],
-            [doc_suffix = "synchronously"],
-            $SensorName,
-            $SensorTrait,
-            $SampleType,
-            $unit
-        );
-    };
+/// ```
+/// use embedded_sensors_hal::sensor::Capabilities;
+///
+/// let capabilities = Capabilities::THRESHOLDS | Capabilities::HEATER;
+/// assert!(capabilities.contains(Capabilities::HEATER));
+/// assert!(!capabilities.contains(Capabilities::THRESHOLDS | Capabilities::FIFO));
+/// ```
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Capabilities(pub u32);
 
-    (async, $SensorName:ident, $SensorTrait:ident, $SampleType:ty, $unit:expr) => {
-        decl_threshold_traits!(
-            @generate,
-            [kw_async = async],
-            [op_await = .await],
-            [doc_suffix = " asynchronously"],
-            $SensorName,
-            $SensorTrait,
-            $SampleType,
-            $unit
-        );
+impl Capabilities {
+    /// No optional feature.
+    pub const NONE: Self = Self(0);
+    /// Lower and upper thresholds can be set (`*ThresholdSet` traits).
+    pub const THRESHOLDS: Self = Self(1 << 0);
+    /// Threshold hysteresis can be set (`*Hysteresis` traits).
+    pub const HYSTERESIS: Self = Self(1 << 1);
+    /// Threshold crossings can be waited for (`*ThresholdWait` traits, async only).
+    pub const THRESHOLD_WAIT: Self = Self(1 << 2);
+    /// Samples are buffered in a hardware FIFO.
+    pub const FIFO: Self = Self(1 << 3);
+    /// An integrated heater can be controlled (`heater::HeaterControl`).
+    pub const HEATER: Self = Self(1 << 4);
+    /// A built-in self-test can be run.
+    pub const SELF_TEST: Self = Self(1 << 5);
 
-        // Declare Async-specific trait (only generated for async mode).
-        paste::paste! {
-            #[doc = concat!(" Asynchronously wait for ", stringify!($SensorName), " measurements to exceed specified thresholds.")]
-            pub trait [<$SensorName ThresholdWait>]: [<$SensorName ThresholdSet>] {
-                #[doc = concat!(" Wait for ", stringify!($SensorName), " to be measured above or below the previously set high and low thresholds.")]
-                #[doc = concat!(" Returns the measured ", stringify!($SensorName), " at time threshold is exceeded (in ", $unit, ").")]
-                async fn [<wait_for_ $SensorName:snake _threshold>](&mut self) -> Result<$SampleType, Self::Error>;
-            }
+    /// Returns `true` if no capability is set.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
 
-            impl<T: [<$SensorName ThresholdWait>] + ?Sized> [<$SensorName ThresholdWait>] for &mut T {
-                async fn [<wait_for_ $SensorName:snake _threshold>](&mut self) -> Result<$SampleType, Self::Error> {
-                    T::[<wait_for_ $SensorName:snake _threshold>](self).await
-                }
-            }
-        }
-    };
+    /// Returns `true` if every capability of `other` is set.
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
 
-    // Common trait and functions definitions for both blocking and async modes.
-    (@generate, [kw_async = $($kw:ident)?], [op_await = $($op:tt)*], [doc_suffix = $doc_suffix:expr], $SensorName:ident, $SensorTrait:ident, $SampleType:ty, $unit:expr) => {
-        paste::paste! {
-            #[doc = concat!(" Set ", stringify!($SensorName), " thresholds ", $doc_suffix, ".")]
-            pub trait [<$SensorName ThresholdSet>]: $SensorTrait {
-                #[doc = concat!(" Set lower ", stringify!($SensorName), " threshold (in ", $unit, ").")]
-                $($kw)? fn [<set_ $SensorName:snake _threshold_low>](&mut self, threshold: $SampleType) -> Result<(), Self::Error>;
+    /// Returns the union of both sets of capabilities.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
 
-                #[doc = concat!(" Set upper ", stringify!($SensorName), " threshold (in ", $unit, ").")]
-                $($kw)? fn [<set_ $SensorName:snake _threshold_high>](&mut self, threshold: $SampleType) -> Result<(), Self::Error>;
-            }
+impl core::ops::BitOr for Capabilities {
+    type Output = Self;
 
-            #[doc = concat!(" Set ", stringify!($SensorName), " threshold hysteresis ", $doc_suffix, ".")]
-            pub trait [<$SensorName Hysteresis>]: [<$SensorName ThresholdSet>] {
-                #[doc = concat!(" Set ", stringify!($SensorName), " threshold hysteresis (in ", $unit, ").")]
-                $($kw)? fn [<set_ $SensorName:snake _threshold_hysteresis>](&mut self, hysteresis: $SampleType) -> Result<(), Self::Error>;
-            }
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
 
-            impl<T: [<$SensorName ThresholdSet>] + ?Sized> [<$SensorName ThresholdSet>] for &mut T {
-                $($kw)? fn [<set_ $SensorName:snake _threshold_low>](&mut self, threshold: $SampleType) -> Result<(), Self::Error> {
-                    T::[<set_ $SensorName:snake _threshold_low>](self, threshold) $($op)*
-                }
+/// Runtime discovery of the optional features supported by a sensor.
+///
+/// This lets dynamic systems (e.g. a sensor manager handling trait objects or sensors discovered
+/// at runtime) adapt their behavior without compile-time knowledge of the concrete driver.
+/// Drivers implementing this trait should report exactly the optional traits they implement,
+/// along with any hardware features which are not expressed as traits.
+pub trait SensorCapabilities {
+    /// Returns the optional features supported by the sensor.
+    fn capabilities(&self) -> Capabilities;
+}
 
-                $($kw)? fn [<set_ $SensorName:snake _threshold_high>](&mut self, threshold: $SampleType) -> Result<(), Self::Error> {
-                    T::[<set_ $SensorName:snake _threshold_high>](self, threshold) $($op)*
-                }
-            }
+impl<T: SensorCapabilities + ?Sized> SensorCapabilities for &mut T {
+    #[inline]
+    fn capabilities(&self) -> Capabilities {
+        T::capabilities(self)
+    }
+}
 
-            impl<T: [<$SensorName Hysteresis>] + ?Sized> [<$SensorName Hysteresis>] for &mut T {
-                $($kw)? fn [<set_ $SensorName:snake _threshold_hysteresis>](&mut self, hysteresis: $SampleType) -> Result<(), Self::Error> {
-                    T::[<set_ $SensorName:snake _threshold_hysteresis>](self, hysteresis) $($op)*
-                }
-            }
+/// Inclusive range of physically plausible sample values for a measured quantity.
+///
+/// Each sensor-type module provides a range constant for its quantity (e.g.
+/// `temperature::TEMPERATURE_RANGE`) which adapters, mocks and applications can use to
+/// catch invalid driver output at the HAL boundary.
+///
+/// ```
+/// use embedded_sensors_hal::humidity::RELATIVE_HUMIDITY_RANGE;
+/// use embedded_sensors_hal::sensor::ErrorKind;
+///
+/// assert_eq!(RELATIVE_HUMIDITY_RANGE.validate(42.0), Ok(42.0));
+/// assert_eq!(RELATIVE_HUMIDITY_RANGE.validate(120.0), Err(ErrorKind::OutOfRange));
+/// assert_eq!(RELATIVE_HUMIDITY_RANGE.validate(f32::NAN), Err(ErrorKind::OutOfRange));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ValidRange<T> {
+    /// Minimum plausible sample value (inclusive).
+    pub min: T,
+    /// Maximum plausible sample value (inclusive).
+    pub max: T,
+}
+
+impl<T: PartialOrd + Copy> ValidRange<T> {
+    /// Creates a new range spanning `min` to `max` (inclusive).
+    pub const fn new(min: T, max: T) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns true if the sample lies within the range.
+    ///
+    /// Samples which cannot be compared to the range bounds (e.g. NaN) are never contained.
+    #[inline]
+    pub fn contains(&self, sample: T) -> bool {
+        self.min <= sample && sample <= self.max
+    }
+
+    /// Returns the sample limited to the range.
+    ///
+    /// Samples which cannot be compared to the range bounds (e.g. NaN) are returned unchanged.
+    #[inline]
+    pub fn clamp(&self, sample: T) -> T {
+        if sample < self.min {
+            self.min
+        } else if sample > self.max {
+            self.max
+        } else {
+            sample
         }
-    };
+    }
+
+    /// Returns the sample unchanged if it lies within the range, or [`ErrorKind::OutOfRange`]
+    /// otherwise.
+    #[inline]
+    pub fn validate(&self, sample: T) -> Result<T, ErrorKind> {
+        if self.contains(sample) {
+            Ok(sample)
+        } else {
+            Err(ErrorKind::OutOfRange)
+        }
+    }
+}
+
+/// Measurement characteristics of a sensor, as reported by [`SensorInfo`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MeasurementInfo<T> {
+    /// Range of values the sensor can measure in its current configuration.
+    pub range: ValidRange<T>,
+    /// Smallest change in value the sensor can resolve.
+    pub resolution: T,
+    /// Typical accuracy of samples, as the absolute deviation from the true value.
+    pub accuracy: T,
+}
+
+impl<T> MeasurementInfo<T> {
+    /// Creates a new description of measurement characteristics.
+    pub const fn new(range: ValidRange<T>, resolution: T, accuracy: T) -> Self {
+        Self {
+            range,
+            resolution,
+            accuracy,
+        }
+    }
+}
+
+/// Report the measurement characteristics of a sensor.
+///
+/// This allows generic code (e.g. user interfaces or plausibility checks) to clamp and validate
+/// samples without hard-coding datasheet values of each part. Unlike the range constants of
+/// sensor-type modules, which hold every physically plausible value, the range reported here is
+/// that of the specific sensor, and may change along with its configuration (e.g. its full-scale
+/// range).
+pub trait SensorInfo<T> {
+    /// Returns the measurement characteristics of the sensor in its current configuration.
+    fn measurement_info(&self) -> MeasurementInfo<T>;
+}
+
+impl<T, S: SensorInfo<T> + ?Sized> SensorInfo<T> for &mut S {
+    #[inline]
+    fn measurement_info(&self) -> MeasurementInfo<T> {
+        S::measurement_info(self)
+    }
+}
+
+/// Returns the sample unchanged if it is finite, or [`ErrorKind::InvalidInput`] if it is NaN or
+/// infinite.
+///
+/// Drivers deriving samples through floating-point arithmetic (e.g. a division by a zero
+/// reference reading) should pass them through this check before returning them, rather than
+/// leak a non-finite value through the HAL.
+///
+/// ```
+/// use embedded_sensors_hal::sensor::{checked_sample, ErrorKind};
+///
+/// assert_eq!(checked_sample(21.5), Ok(21.5));
+/// assert_eq!(checked_sample(f32::NAN), Err(ErrorKind::InvalidInput));
+/// assert_eq!(checked_sample(f32::NEG_INFINITY), Err(ErrorKind::InvalidInput));
+/// ```
+#[inline]
+pub fn checked_sample(sample: f32) -> Result<f32, ErrorKind> {
+    if sample.is_finite() {
+        Ok(sample)
+    } else {
+        Err(ErrorKind::InvalidInput)
+    }
+}
+
+/// Identifies which of a sensor's thresholds was crossed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ThresholdKind {
+    /// The sample fell below the lower threshold.
+    Low,
+    /// The sample rose above the upper threshold.
+    High,
+}
+
+/// Severity of a threshold crossing, allowing consumers to route events accordingly
+/// (e.g. logging versus emergency shutdown).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Severity {
+    /// Informational event requiring no action.
+    Info,
+    /// Event which should be acted upon but is not immediately harmful.
+    Warning,
+    /// Event requiring immediate action (e.g. shutdown).
+    Critical,
+}
+
+/// Operating mode of a sensor, as set through [`ConversionMode`] or a sensor-type configuration
+/// struct (e.g. `temperature::TemperatureConfig`).
+///
+/// Powering a sensor down is handled separately by `power::PowerMode`.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum OperatingMode {
+    /// The sensor converts samples continuously at its configured conversion rate.
+    #[default]
+    Continuous,
+    /// The sensor converts a single sample when triggered, then returns to standby.
+    OneShot,
+}
+
+/// Conversion mode control, generic to all sensor types.
+///
+/// Battery-powered applications can put sensors in one-shot mode and trigger a conversion only
+/// when a sample is needed, letting the sensor idle in standby between samples.
+pub trait ConversionMode: ErrorType {
+    /// Set whether the sensor converts samples continuously or one at a time when triggered.
+    fn set_conversion_mode(&mut self, mode: OperatingMode) -> Result<(), Self::Error>;
+
+    /// Trigger a single conversion while in one-shot mode.
+    ///
+    /// The sample can be read through the sensor-type trait once the conversion time of the
+    /// sensor has elapsed (see [`ConversionTime`] or its datasheet). Sensors return an error of
+    /// kind `NotReady` if they are in continuous mode.
+    fn trigger_one_shot(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: ConversionMode + ?Sized> ConversionMode for &mut T {
+    #[inline]
+    fn set_conversion_mode(&mut self, mode: OperatingMode) -> Result<(), Self::Error> {
+        T::set_conversion_mode(self, mode)
+    }
+
+    #[inline]
+    fn trigger_one_shot(&mut self) -> Result<(), Self::Error> {
+        T::trigger_one_shot(self)
+    }
+}
+
+/// Conversion time query, generic to all sensor types.
+///
+/// The conversion time of most sensors depends on their configuration (e.g. resolution or
+/// oversampling), so schedulers can use this trait to know how long to wait after
+/// [triggering a one-shot conversion](ConversionMode::trigger_one_shot) before reading the sample.
+pub trait ConversionTime {
+    /// Returns the time a single conversion takes in the active configuration, in microseconds.
+    ///
+    /// Drivers should report the maximum conversion time given in the datasheet, rather than the
+    /// typical one, so that a sample is always ready once it has elapsed.
+    fn conversion_time_us(&self) -> u32;
+}
+
+impl<T: ConversionTime + ?Sized> ConversionTime for &mut T {
+    #[inline]
+    fn conversion_time_us(&self) -> u32 {
+        T::conversion_time_us(self)
+    }
+}
+
+/// Output data rate (sampling rate) configuration, generic to all sensor types.
+///
+/// Sensors only support a discrete set of output data rates, so drivers map requested rates to
+/// the nearest supported rate and report the rate actually selected. This lets generic code
+/// (e.g. a data logger) negotiate rates without knowledge of vendor-specific rate settings.
+pub trait SampleRateConfig: ErrorType {
+    /// Set the output data rate of the sensor to the nearest supported rate, in hertz.
+    ///
+    /// Returns the rate actually selected, in hertz. Sensors return an error of kind
+    /// `InvalidInput` for rates which are not positive and finite.
+    fn set_sample_rate_hz(&mut self, rate: Hertz) -> Result<Hertz, Self::Error>;
+
+    /// Returns the current output data rate of the sensor, in hertz.
+    fn sample_rate_hz(&mut self) -> Result<Hertz, Self::Error>;
+}
+
+impl<T: SampleRateConfig + ?Sized> SampleRateConfig for &mut T {
+    #[inline]
+    fn set_sample_rate_hz(&mut self, rate: Hertz) -> Result<Hertz, Self::Error> {
+        T::set_sample_rate_hz(self, rate)
+    }
+
+    #[inline]
+    fn sample_rate_hz(&mut self) -> Result<Hertz, Self::Error> {
+        T::sample_rate_hz(self)
+    }
+}
+
+/// Measurement resolution setting of a sensor, as used by [`ResolutionConfig`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Resolution {
+    /// Resolution of each conversion, in bits (e.g. 9 to 12 bits for a DS18B20).
+    Bits(u8),
+    /// Number of conversions averaged into each sample (e.g. 16x for a BME280).
+    Oversampling(u16),
+}
+
+/// Measurement resolution or oversampling configuration, generic to all sensor types.
+///
+/// Higher resolutions or oversampling factors lower the noise of samples at the cost of a longer
+/// conversion time (and usually a higher power consumption), see the datasheet of the sensor.
+pub trait ResolutionConfig: ErrorType {
+    /// Set the resolution of the sensor to the nearest supported setting.
+    ///
+    /// Returns the effective resolution actually selected. Sensors return an error of kind
+    /// `InvalidInput` if they do not support the requested kind of setting (e.g. oversampling on
+    /// a sensor with a configurable bit depth).
+    fn set_resolution(&mut self, resolution: Resolution) -> Result<Resolution, Self::Error>;
+
+    /// Returns the current resolution of the sensor.
+    fn resolution(&mut self) -> Result<Resolution, Self::Error>;
+}
+
+impl<T: ResolutionConfig + ?Sized> ResolutionConfig for &mut T {
+    #[inline]
+    fn set_resolution(&mut self, resolution: Resolution) -> Result<Resolution, Self::Error> {
+        T::set_resolution(self, resolution)
+    }
+
+    #[inline]
+    fn resolution(&mut self) -> Result<Resolution, Self::Error> {
+        T::resolution(self)
+    }
+}
+
+/// Measurement range (full-scale) configuration, generic to all sensor types.
+///
+/// The type parameter is the unit samples are measured in (e.g. `shock::GForce` for an
+/// accelerometer), so that sensors measuring several quantities can implement the trait once
+/// for each of them.
+pub trait RangeConfig<T>: ErrorType {
+    /// Set the full-scale range of the sensor to the smallest supported range covering `min` to
+    /// `max`.
+    ///
+    /// Returns the range actually granted. Sensors return an error of kind `InvalidInput` if no
+    /// supported range covers the requested one.
+    fn set_range(&mut self, min: T, max: T) -> Result<ValidRange<T>, Self::Error>;
+
+    /// Returns the current full-scale range of the sensor.
+    fn full_scale(&mut self) -> Result<ValidRange<T>, Self::Error>;
+}
+
+impl<T, R: RangeConfig<T> + ?Sized> RangeConfig<T> for &mut R {
+    #[inline]
+    fn set_range(&mut self, min: T, max: T) -> Result<ValidRange<T>, Self::Error> {
+        R::set_range(self, min, max)
+    }
+
+    #[inline]
+    fn full_scale(&mut self) -> Result<ValidRange<T>, Self::Error> {
+        R::full_scale(self)
+    }
+}
+
+/// Data-ready query, generic to all sensor types.
+///
+/// This lets polling loops check for a new sample before reading it, rather than reading
+/// and handling an error of kind `NotReady`.
+pub trait DataReady: ErrorType {
+    /// Returns `true` if a new sample is available to be read.
+    fn is_ready(&mut self) -> Result<bool, Self::Error>;
+}
+
+impl<T: DataReady + ?Sized> DataReady for &mut T {
+    #[inline]
+    fn is_ready(&mut self) -> Result<bool, Self::Error> {
+        T::is_ready(self)
+    }
+}
+
+/// Identification of a sensor part, as read from its identification registers.
+///
+/// The meaning of the identifiers is specific to the manufacturer, see the datasheet of the
+/// sensor.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SensorIdentity {
+    /// Manufacturer identifier (e.g. a JEDEC manufacturer ID).
+    pub manufacturer_id: u16,
+    /// Device (part) identifier.
+    pub device_id: u16,
+    /// Silicon revision of the device.
+    pub revision: u8,
+}
+
+/// Device identification, generic to all sensor types.
+///
+/// This lets system bring-up and inventory code verify that the expected part is present on the
+/// bus without knowledge of the concrete driver.
+pub trait SensorIdentify: ErrorType {
+    /// Reads the identification of the sensor.
+    fn identify(&mut self) -> Result<SensorIdentity, Self::Error>;
+}
+
+impl<T: SensorIdentify + ?Sized> SensorIdentify for &mut T {
+    #[inline]
+    fn identify(&mut self) -> Result<SensorIdentity, Self::Error> {
+        T::identify(self)
+    }
+}
+
+/// Calibration data persistence, generic to all sensor types.
+///
+/// Sensors which learn calibration on-chip (e.g. the baselines of SGP30 or SGP40 gas sensors)
+/// lose it on power cycles. This lets applications read it back as an opaque blob, store it
+/// (e.g. in flash) and restore it later. The format of the blob is specific to the driver.
+pub trait CalibrationData: ErrorType {
+    /// Reads the calibration data of the sensor into `buf`, returning the number of bytes read.
+    ///
+    /// Sensors return an error of kind `InvalidInput` if `buf` is too small to hold the data,
+    /// or of kind `NotReady` if no calibration is available yet.
+    fn calibration_data(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error>;
+
+    /// Restores calibration data previously read by
+    /// [`calibration_data`](Self::calibration_data).
+    ///
+    /// Sensors return an error of kind `InvalidInput` if the data is malformed.
+    fn restore_calibration(&mut self, data: &[u8]) -> Result<(), Self::Error>;
+}
+
+impl<T: CalibrationData + ?Sized> CalibrationData for &mut T {
+    #[inline]
+    fn calibration_data(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        T::calibration_data(self, buf)
+    }
+
+    #[inline]
+    fn restore_calibration(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+        T::restore_calibration(self, data)
+    }
+}
+
+/// Alert (interrupt) output configuration, generic to all sensor types.
+pub trait AlertConfig: ErrorType {
+    /// Set the configuration of the alert output of the sensor.
+    ///
+    /// Sensors round the fault queue depth to the nearest depth they support, and return an
+    /// error of kind `InvalidInput` for settings they do not support.
+    fn set_alert_config(&mut self, config: AlertPinConfig) -> Result<(), Self::Error>;
+}
+
+impl<T: AlertConfig + ?Sized> AlertConfig for &mut T {
+    #[inline]
+    fn set_alert_config(&mut self, config: AlertPinConfig) -> Result<(), Self::Error> {
+        T::set_alert_config(self, config)
+    }
+}
+
+/// Sample measurement, generic to all sensor types.
+///
+/// Generic infrastructure (e.g. loggers, schedulers or filters) can be written once over this
+/// trait rather than once per sensor type. Every sensor-type trait whose samples are read without
+/// arguments (e.g. `temperature::TemperatureSensor` or `pressure::PressureSensor`) refines
+/// `Sensor` for the marker type of its quantity (e.g. `temperature::Temperature`), which a blanket
+/// implementation provides, so drivers only ever implement the sensor-type trait. Traits reading
+/// samples into a buffer or for a given channel, gas or segment do not, nor do those whose own
+/// sample method is named `measure`, which would be ambiguous. Sensors measuring other quantities
+/// implement `Sensor` directly, for a marker type of their own implementing [`CustomQuantity`].
+///
+/// The quantity parameter `Q` lets sensors measuring several quantities (e.g. temperature and
+/// relative humidity) be a `Sensor` of each, in which case callers name the quantity to measure
+/// (e.g. `Sensor::<Temperature>::measure(&mut sensor)`).
+pub trait Sensor<Q>: ErrorType {
+    /// Sample type measured by the sensor.
+    type Sample;
+
+    /// Returns a new sample.
+    fn measure(&mut self) -> Result<Self::Sample, Self::Error>;
+}
+
+impl<Q: CustomQuantity, T: Sensor<Q> + ?Sized> Sensor<Q> for &mut T {
+    type Sample = T::Sample;
+
+    #[inline]
+    fn measure(&mut self) -> Result<Self::Sample, Self::Error> {
+        T::measure(self)
+    }
+}
+
+/// Marker trait of the quantities which no sensor-type trait refines [`Sensor`] for, such as
+/// those defined by applications.
+///
+/// Implementing it for the marker type of such a quantity makes mutable references to its sensors
+/// `Sensor`s too. References to sensors of the quantities refined by a sensor-type trait already
+/// are, through that trait.
+pub trait CustomQuantity {}
+
+/// Timestamped sample read, generic to all sensor types.
+///
+/// Fusion and logging pipelines need to know when a sample was taken, not just its value.
+/// Sensors with a timestamp counter of their own can implement this trait directly, while the
+/// `source::ClockTimestamped` adapter timestamps the samples of any sensor with a clock.
+pub trait TimestampedRead: ErrorType {
+    /// Sample type produced by the sensor.
+    type Sample;
+
+    /// Returns a new sample along with the time at which it was taken.
+    fn sample_with_timestamp(&mut self) -> Result<TimestampedSample<Self::Sample>, Self::Error>;
+}
+
+impl<T: TimestampedRead + ?Sized> TimestampedRead for &mut T {
+    type Sample = T::Sample;
+
+    #[inline]
+    fn sample_with_timestamp(&mut self) -> Result<TimestampedSample<Self::Sample>, Self::Error> {
+        T::sample_with_timestamp(self)
+    }
+}
+
+/// Active level of the alert (interrupt) output of a sensor.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlertPolarity {
+    /// The alert output is driven low while asserted.
+    #[default]
+    ActiveLow,
+    /// The alert output is driven high while asserted.
+    ActiveHigh,
+}
+
+/// Output driver of the alert (interrupt) output of a sensor.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlertOutput {
+    /// The alert output is open-drain and requires a pull-up (or pull-down) resistor.
+    #[default]
+    OpenDrain,
+    /// The alert output actively drives both levels.
+    PushPull,
+}
+
+/// Behavior of the alert (interrupt) output of a sensor once a threshold is crossed.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlertMode {
+    /// The alert output is asserted while the sample is beyond a threshold, and deasserted once
+    /// it is back within the thresholds (minus hysteresis).
+    #[default]
+    Comparator,
+    /// The alert output is asserted when a threshold is crossed, and latched until the alert is
+    /// cleared (e.g. through a `*ThresholdControl` trait).
+    Interrupt,
+}
+
+/// Configuration of the alert (interrupt) output of a sensor, as set through [`AlertConfig`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AlertPinConfig {
+    /// Active level of the alert output.
+    pub polarity: AlertPolarity,
+    /// Output driver of the alert output.
+    pub output: AlertOutput,
+    /// Whether the alert output follows the sample or latches.
+    pub mode: AlertMode,
+    /// Number of consecutive conversions beyond a threshold required to assert the alert output.
+    pub fault_queue: u8,
+}
+
+impl AlertPinConfig {
+    /// Creates a new alert output configuration.
+    pub const fn new(
+        polarity: AlertPolarity,
+        output: AlertOutput,
+        mode: AlertMode,
+        fault_queue: u8,
+    ) -> Self {
+        Self {
+            polarity,
+            output,
+            mode,
+            fault_queue,
+        }
+    }
+}
+
+impl Default for AlertPinConfig {
+    fn default() -> Self {
+        Self::new(
+            AlertPolarity::ActiveLow,
+            AlertOutput::OpenDrain,
+            AlertMode::Comparator,
+            1,
+        )
+    }
+}
+
+/// A threshold crossing reported by a sensor.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ThresholdEvent<T> {
+    /// Sample measured at the time the threshold was crossed.
+    pub value: T,
+    /// Which threshold was crossed.
+    pub kind: ThresholdKind,
+    /// Time at which the crossing was observed, in microseconds, as read from a
+    /// [`Clock`](crate::clock::Clock).
+    ///
+    /// `None` unless the producer of the event was given a clock. Drivers which latch the time of
+    /// the crossing itself should report it here in preference to the time it was observed.
+    pub timestamp_us: Option<u64>,
+}
+
+impl<T> ThresholdEvent<T> {
+    /// Creates a new event without a timestamp.
+    pub const fn new(value: T, kind: ThresholdKind) -> Self {
+        Self {
+            value,
+            kind,
+            timestamp_us: None,
+        }
+    }
+
+    /// Sets the time at which the crossing was observed, in microseconds.
+    pub fn with_timestamp(mut self, timestamp_us: u64) -> Self {
+        self.timestamp_us = Some(timestamp_us);
+        self
+    }
+}
+
+/// Index of a channel of a multi-channel sensor, numbered from 0.
+pub type Channel = u8;
+
+/// Quality flags of a [`Sample`], as reported by the sensor which took it.
+///
+/// ```
+/// use embedded_sensors_hal::sensor::SampleQuality;
+///
+/// let quality = SampleQuality::SATURATED | SampleQuality::OUT_OF_RANGE;
+/// assert!(quality.contains(SampleQuality::SATURATED));
+/// assert!(!quality.contains(SampleQuality::STALE));
+/// ```
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SampleQuality(pub u8);
+
+impl SampleQuality {
+    /// No quality issue was reported.
+    pub const NONE: Self = Self(0);
+    /// The sensor was saturated while taking the sample, which may be clipped.
+    pub const SATURATED: Self = Self(1 << 0);
+    /// No new conversion completed since the sample was last read, so it is repeated.
+    pub const STALE: Self = Self(1 << 1);
+    /// The sample was estimated (e.g. interpolated or extrapolated) rather than measured.
+    pub const ESTIMATED: Self = Self(1 << 2);
+    /// The sample lies outside of the specified measurement range of the sensor.
+    pub const OUT_OF_RANGE: Self = Self(1 << 3);
+
+    /// Returns `true` if no quality issue is set.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if every flag of `other` is set.
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the union of both sets of flags.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl core::ops::BitOr for SampleQuality {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+/// A sample along with its quality flags, as returned by `*SampleRead` traits.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Sample<T> {
+    /// The sample.
+    pub value: T,
+    /// Quality issues reported by the sensor for this sample.
+    pub quality: SampleQuality,
+}
+
+impl<T> Sample<T> {
+    /// Creates a new sample without quality issues.
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            quality: SampleQuality::NONE,
+        }
+    }
+
+    /// Sets the quality flags of the sample.
+    pub fn with_quality(mut self, quality: SampleQuality) -> Self {
+        self.quality = quality;
+        self
+    }
+}
+
+/// A sample along with the time at which it was taken, as returned by [`TimestampedRead`].
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TimestampedSample<T> {
+    /// The sample.
+    pub value: T,
+    /// Time at which the sample was taken, in microseconds, relative to the epoch of the time
+    /// source (e.g. the timestamp counter of the sensor or a [`Clock`](crate::clock::Clock)).
+    pub timestamp_us: u64,
+}
+
+impl<T> TimestampedSample<T> {
+    /// Creates a new timestamped sample.
+    pub const fn new(value: T, timestamp_us: u64) -> Self {
+        Self {
+            value,
+            timestamp_us,
+        }
+    }
+}
+
+/// Implements [`Sensor`] for every implementor of the specified sensor-type trait.
+///
+/// This macro supports both blocking and async sensors. It generates a blanket implementation of
+/// `Sensor<$Quantity>` measuring samples through the given method of the sensor-type trait, so
+/// that the trait can refine `Sensor` for the marker type of its quantity. `Sensor` and
+/// `ErrorType` must be in scope where the macro is used.
+#[macro_export]
+macro_rules! impl_sensor_refinement {
+    (blocking, $Quantity:ty, $SensorTrait:ident, $method:ident, $SampleType:ty) => {
+        impl<T: $SensorTrait + ?Sized> Sensor<$Quantity> for T {
+            type Sample = $SampleType;
+
+            #[inline]
+            fn measure(&mut self) -> Result<Self::Sample, Self::Error> {
+                $SensorTrait::$method(self)
+            }
+        }
+    };
+
+    // Bounding `&mut T` rather than `T` keeps rustc from resolving the `measure` future of `T`
+    // through the sensor-type trait, which it rejects for async methods.
+    (async, $Quantity:ty, $SensorTrait:ident, $method:ident, $SampleType:ty) => {
+        impl<T: ErrorType + ?Sized> Sensor<$Quantity> for T
+        where
+            for<'a> &'a mut T: $SensorTrait<Error = T::Error>,
+        {
+            type Sample = $SampleType;
+
+            #[inline]
+            async fn measure(&mut self) -> Result<Self::Sample, Self::Error> {
+                $SensorTrait::$method(&mut &mut *self).await
+            }
+        }
+    };
+}
+
+/// Generates threshold traits for the specified sensor type.
+///
+/// This macro supports both blocking and async sensors.
+/// When used with `blocking` mode, it generates `ThresholdSet`, `Hysteresis` and `ThresholdControl` traits.
+/// When used with `async` mode, it additionally generates `ThresholdWait` trait.
+#[macro_export]
+macro_rules! decl_threshold_traits {
+    // The `kw_async`, `op_await` and 'doc_suffix' parameters are automatically resolved
+    // based on the mode (blocking or async), allowing the trait and function definitions to
+    // exist in one place while supporting both synchronous and asynchronous variants.
+    (blocking, $SensorName:ident, $SensorTrait:ident, $SampleType:ty, $unit:expr) => {
+        decl_threshold_traits!(
+            @generate,
+            [kw_async = ],
+            [op_await = ],
+            [doc_suffix = "synchronously"],
+            $SensorName,
+            $SensorTrait,
+            $SampleType,
+            $unit
+        );
+    };
+
+    (async, $SensorName:ident, $SensorTrait:ident, $SampleType:ty, $unit:expr) => {
+        decl_threshold_traits!(
+            @generate,
+            [kw_async = async],
+            [op_await = .await],
+            [doc_suffix = " asynchronously"],
+            $SensorName,
+            $SensorTrait,
+            $SampleType,
+            $unit
+        );
+
+        // Declare Async-specific trait (only generated for async mode).
+        paste::paste! {
+            #[doc = concat!(" Asynchronously wait for ", stringify!($SensorName), " measurements to exceed specified thresholds.")]
+            pub trait [<$SensorName ThresholdWait>]: [<$SensorName ThresholdSet>] {
+                #[doc = concat!(" Wait for ", stringify!($SensorName), " to be measured above or below the previously set high and low thresholds.")]
+                #[doc = concat!(" Returns the measured ", stringify!($SensorName), " at time threshold is exceeded (in ", $unit, "), along with which threshold was crossed.")]
+                async fn [<wait_for_ $SensorName:snake _threshold>](&mut self) -> Result<$crate::sensor::ThresholdEvent<$SampleType>, Self::Error>;
+            }
+
+            impl<T: [<$SensorName ThresholdWait>] + ?Sized> [<$SensorName ThresholdWait>] for &mut T {
+                async fn [<wait_for_ $SensorName:snake _threshold>](&mut self) -> Result<$crate::sensor::ThresholdEvent<$SampleType>, Self::Error> {
+                    T::[<wait_for_ $SensorName:snake _threshold>](self).await
+                }
+            }
+        }
+    };
+
+    // Common trait and functions definitions for both blocking and async modes.
+    (@generate, [kw_async = $($kw:ident)?], [op_await = $($op:tt)*], [doc_suffix = $doc_suffix:expr], $SensorName:ident, $SensorTrait:ident, $SampleType:ty, $unit:expr) => {
+        paste::paste! {
+            #[doc = concat!(" Set ", stringify!($SensorName), " thresholds ", $doc_suffix, ".")]
+            pub trait [<$SensorName ThresholdSet>]: $SensorTrait {
+                #[doc = concat!(" Set lower ", stringify!($SensorName), " threshold (in ", $unit, ").")]
+                $($kw)? fn [<set_ $SensorName:snake _threshold_low>](&mut self, threshold: $SampleType) -> Result<(), Self::Error>;
+
+                #[doc = concat!(" Set upper ", stringify!($SensorName), " threshold (in ", $unit, ").")]
+                $($kw)? fn [<set_ $SensorName:snake _threshold_high>](&mut self, threshold: $SampleType) -> Result<(), Self::Error>;
+            }
+
+            #[doc = concat!(" Set ", stringify!($SensorName), " threshold hysteresis ", $doc_suffix, ".")]
+            pub trait [<$SensorName Hysteresis>]: [<$SensorName ThresholdSet>] {
+                #[doc = concat!(" Set ", stringify!($SensorName), " threshold hysteresis (in ", $unit, ").")]
+                $($kw)? fn [<set_ $SensorName:snake _threshold_hysteresis>](&mut self, hysteresis: $SampleType) -> Result<(), Self::Error>;
+            }
+
+            impl<T: [<$SensorName ThresholdSet>] + ?Sized> [<$SensorName ThresholdSet>] for &mut T {
+                $($kw)? fn [<set_ $SensorName:snake _threshold_low>](&mut self, threshold: $SampleType) -> Result<(), Self::Error> {
+                    T::[<set_ $SensorName:snake _threshold_low>](self, threshold) $($op)*
+                }
+
+                $($kw)? fn [<set_ $SensorName:snake _threshold_high>](&mut self, threshold: $SampleType) -> Result<(), Self::Error> {
+                    T::[<set_ $SensorName:snake _threshold_high>](self, threshold) $($op)*
+                }
+            }
+
+            impl<T: [<$SensorName Hysteresis>] + ?Sized> [<$SensorName Hysteresis>] for &mut T {
+                $($kw)? fn [<set_ $SensorName:snake _threshold_hysteresis>](&mut self, hysteresis: $SampleType) -> Result<(), Self::Error> {
+                    T::[<set_ $SensorName:snake _threshold_hysteresis>](self, hysteresis) $($op)*
+                }
+            }
+
+            #[doc = concat!(" Disable ", stringify!($SensorName), " thresholds and clear latched alerts ", $doc_suffix, ".")]
+            pub trait [<$SensorName ThresholdControl>]: [<$SensorName ThresholdSet>] {
+                #[doc = concat!(" Disable the lower and upper ", stringify!($SensorName), " thresholds.")]
+                ///
+                /// Setting either threshold again re-enables threshold monitoring.
+                $($kw)? fn [<disable_ $SensorName:snake _thresholds>](&mut self) -> Result<(), Self::Error>;
+
+                #[doc = concat!(" Clear (acknowledge) a latched ", stringify!($SensorName), " alert, deasserting the alert output.")]
+                $($kw)? fn [<clear_ $SensorName:snake _alert>](&mut self) -> Result<(), Self::Error>;
+            }
+
+            impl<T: [<$SensorName ThresholdControl>] + ?Sized> [<$SensorName ThresholdControl>] for &mut T {
+                $($kw)? fn [<disable_ $SensorName:snake _thresholds>](&mut self) -> Result<(), Self::Error> {
+                    T::[<disable_ $SensorName:snake _thresholds>](self) $($op)*
+                }
+
+                $($kw)? fn [<clear_ $SensorName:snake _alert>](&mut self) -> Result<(), Self::Error> {
+                    T::[<clear_ $SensorName:snake _alert>](self) $($op)*
+                }
+            }
+        }
+    };
+}
+
+/// Generates a configuration trait for the specified sensor type.
+///
+/// This macro supports both blocking and async sensors. It generates a `Configure` trait taking
+/// the configuration struct of the sensor type (e.g. `temperature::TemperatureConfig`).
+#[macro_export]
+macro_rules! decl_configuration_trait {
+    (blocking, $SensorName:ident, $SensorTrait:ident, $ConfigType:ty) => {
+        decl_configuration_trait!(
+            @generate,
+            [kw_async = ],
+            [op_await = ],
+            [doc_suffix = "synchronously"],
+            $SensorName,
+            $SensorTrait,
+            $ConfigType
+        );
+    };
+
+    (async, $SensorName:ident, $SensorTrait:ident, $ConfigType:ty) => {
+        decl_configuration_trait!(
+            @generate,
+            [kw_async = async],
+            [op_await = .await],
+            [doc_suffix = "asynchronously"],
+            $SensorName,
+            $SensorTrait,
+            $ConfigType
+        );
+    };
+
+    (@generate, [kw_async = $($kw:ident)?], [op_await = $($op:tt)*], [doc_suffix = $doc_suffix:expr], $SensorName:ident, $SensorTrait:ident, $ConfigType:ty) => {
+        paste::paste! {
+            #[doc = concat!(" Configure ", stringify!($SensorName), " sensors ", $doc_suffix, ".")]
+            pub trait [<$SensorName Configure>]: $SensorTrait {
+                #[doc = concat!(" Set the configuration of the ", stringify!($SensorName), " sensor.")]
+                ///
+                /// Sensors round the conversion rate to the nearest rate they support, and return
+                /// an error of kind `InvalidInput` for settings they do not support.
+                $($kw)? fn [<set_ $SensorName:snake _config>](&mut self, config: $ConfigType) -> Result<(), Self::Error>;
+            }
+
+            impl<T: [<$SensorName Configure>] + ?Sized> [<$SensorName Configure>] for &mut T {
+                $($kw)? fn [<set_ $SensorName:snake _config>](&mut self, config: $ConfigType) -> Result<(), Self::Error> {
+                    T::[<set_ $SensorName:snake _config>](self, config) $($op)*
+                }
+            }
+        }
+    };
+}
+
+/// Generates a calibration trait for the specified sensor type.
+///
+/// This macro supports both blocking and async sensors. It generates a `Calibrate` trait setting
+/// the offset (in the sample unit of the sensor type) and gain applied to samples.
+#[macro_export]
+macro_rules! decl_calibration_trait {
+    (blocking, $SensorName:ident, $SensorTrait:ident, $SampleType:ty, $unit:expr) => {
+        decl_calibration_trait!(
+            @generate,
+            [kw_async = ],
+            [op_await = ],
+            [doc_suffix = "synchronously"],
+            $SensorName,
+            $SensorTrait,
+            $SampleType,
+            $unit
+        );
+    };
+
+    (async, $SensorName:ident, $SensorTrait:ident, $SampleType:ty, $unit:expr) => {
+        decl_calibration_trait!(
+            @generate,
+            [kw_async = async],
+            [op_await = .await],
+            [doc_suffix = "asynchronously"],
+            $SensorName,
+            $SensorTrait,
+            $SampleType,
+            $unit
+        );
+    };
+
+    (@generate, [kw_async = $($kw:ident)?], [op_await = $($op:tt)*], [doc_suffix = $doc_suffix:expr], $SensorName:ident, $SensorTrait:ident, $SampleType:ty, $unit:expr) => {
+        paste::paste! {
+            #[doc = concat!(" Calibrate ", stringify!($SensorName), " sensors ", $doc_suffix, ".")]
+            ///
+            /// Once calibrated, the sensor reports `raw * gain + offset`, where `raw` is the
+            /// uncalibrated sample.
+            pub trait [<$SensorName Calibrate>]: $SensorTrait {
+                #[doc = concat!(" Set the offset added to ", stringify!($SensorName), " samples (in ", $unit, ").")]
+                $($kw)? fn [<set_ $SensorName:snake _offset>](&mut self, offset: $SampleType) -> Result<(), Self::Error>;
+
+                #[doc = concat!(" Set the gain ", stringify!($SensorName), " samples are multiplied by (1.0 when uncalibrated).")]
+                $($kw)? fn [<set_ $SensorName:snake _gain>](&mut self, gain: f32) -> Result<(), Self::Error>;
+            }
+
+            impl<T: [<$SensorName Calibrate>] + ?Sized> [<$SensorName Calibrate>] for &mut T {
+                $($kw)? fn [<set_ $SensorName:snake _offset>](&mut self, offset: $SampleType) -> Result<(), Self::Error> {
+                    T::[<set_ $SensorName:snake _offset>](self, offset) $($op)*
+                }
+
+                $($kw)? fn [<set_ $SensorName:snake _gain>](&mut self, gain: f32) -> Result<(), Self::Error> {
+                    T::[<set_ $SensorName:snake _gain>](self, gain) $($op)*
+                }
+            }
+        }
+    };
+}
+
+/// Generates a sample read trait for the specified sensor type.
+///
+/// This macro supports both blocking and async sensors. It generates a `SampleRead` trait
+/// returning samples along with their quality flags. Its method defaults to the sample read
+/// method of the sensor trait, which must be named after the sensor type (e.g. `temperature`),
+/// reporting no quality issue.
+#[macro_export]
+macro_rules! decl_sample_read_trait {
+    (blocking, $SensorName:ident, $SensorTrait:ident, $SampleType:ty) => {
+        decl_sample_read_trait!(
+            @generate,
+            [kw_async = ],
+            [op_await = ],
+            [doc_suffix = "synchronously"],
+            $SensorName,
+            $SensorTrait,
+            $SampleType
+        );
+    };
+
+    (async, $SensorName:ident, $SensorTrait:ident, $SampleType:ty) => {
+        decl_sample_read_trait!(
+            @generate,
+            [kw_async = async],
+            [op_await = .await],
+            [doc_suffix = "asynchronously"],
+            $SensorName,
+            $SensorTrait,
+            $SampleType
+        );
+    };
+
+    (@generate, [kw_async = $($kw:ident)?], [op_await = $($op:tt)*], [doc_suffix = $doc_suffix:expr], $SensorName:ident, $SensorTrait:ident, $SampleType:ty) => {
+        paste::paste! {
+            #[doc = concat!(" Read ", stringify!($SensorName), " samples along with their quality flags ", $doc_suffix, ".")]
+            pub trait [<$SensorName SampleRead>]: $SensorTrait {
+                #[doc = concat!(" Returns a ", stringify!($SensorName), " sample along with the quality issues reported by the sensor.")]
+                $($kw)? fn [<$SensorName:snake _sample>](&mut self) -> Result<$crate::sensor::Sample<$SampleType>, Self::Error> {
+                    Ok($crate::sensor::Sample::new(self.[<$SensorName:snake>]() $($op)* ?))
+                }
+            }
+
+            impl<T: [<$SensorName SampleRead>] + ?Sized> [<$SensorName SampleRead>] for &mut T {
+                $($kw)? fn [<$SensorName:snake _sample>](&mut self) -> Result<$crate::sensor::Sample<$SampleType>, Self::Error> {
+                    T::[<$SensorName:snake _sample>](self) $($op)*
+                }
+            }
+        }
+    };
+}
+
+/// Generates multi-channel read and threshold traits for the specified sensor type.
+///
+/// This macro supports both blocking and async sensors. It generates a `MultiChannel*Sensor`
+/// trait reading samples of a single [`Channel`] and a `MultiChannel*ThresholdSet` trait setting
+/// thresholds per channel, so that every multi-channel sensor shares the same channel-addressing
+/// convention.
+#[macro_export]
+macro_rules! decl_channel_traits {
+    (blocking, $SensorName:ident, $SampleType:ty, $unit:expr) => {
+        decl_channel_traits!(
+            @generate,
+            [kw_async = ],
+            [op_await = ],
+            [doc_suffix = "synchronously"],
+            $SensorName,
+            $SampleType,
+            $unit
+        );
+    };
+
+    (async, $SensorName:ident, $SampleType:ty, $unit:expr) => {
+        decl_channel_traits!(
+            @generate,
+            [kw_async = async],
+            [op_await = .await],
+            [doc_suffix = "asynchronously"],
+            $SensorName,
+            $SampleType,
+            $unit
+        );
+    };
+
+    (@generate, [kw_async = $($kw:ident)?], [op_await = $($op:tt)*], [doc_suffix = $doc_suffix:expr], $SensorName:ident, $SampleType:ty, $unit:expr) => {
+        paste::paste! {
+            #[doc = concat!(" Read ", stringify!($SensorName), " samples of multi-channel sensors ", $doc_suffix, ".")]
+            ///
+            /// Channels are numbered from 0 up to the channel count of the sensor.
+            pub trait [<MultiChannel $SensorName Sensor>]: $crate::sensor::ErrorType {
+                /// Returns the number of channels of the sensor.
+                fn channel_count(&self) -> $crate::sensor::Channel;
+
+                #[doc = concat!(" Returns a ", stringify!($SensorName), " sample of the channel (in ", $unit, ").")]
+                ///
+                /// Implementations should return an error of kind
+                /// [`InvalidInput`]($crate::sensor::ErrorKind::InvalidInput) if the channel does not exist.
+                $($kw)? fn [<$SensorName:snake _on>](&mut self, channel: $crate::sensor::Channel) -> Result<$SampleType, Self::Error>;
+            }
+
+            #[doc = concat!(" Set ", stringify!($SensorName), " thresholds of multi-channel sensors per channel ", $doc_suffix, ".")]
+            pub trait [<MultiChannel $SensorName ThresholdSet>]: [<MultiChannel $SensorName Sensor>] {
+                #[doc = concat!(" Set lower ", stringify!($SensorName), " threshold of the channel (in ", $unit, ").")]
+                $($kw)? fn [<set_ $SensorName:snake _threshold_low_on>](&mut self, channel: $crate::sensor::Channel, threshold: $SampleType) -> Result<(), Self::Error>;
+
+                #[doc = concat!(" Set upper ", stringify!($SensorName), " threshold of the channel (in ", $unit, ").")]
+                $($kw)? fn [<set_ $SensorName:snake _threshold_high_on>](&mut self, channel: $crate::sensor::Channel, threshold: $SampleType) -> Result<(), Self::Error>;
+            }
+
+            impl<T: [<MultiChannel $SensorName Sensor>] + ?Sized> [<MultiChannel $SensorName Sensor>] for &mut T {
+                fn channel_count(&self) -> $crate::sensor::Channel {
+                    T::channel_count(self)
+                }
+
+                $($kw)? fn [<$SensorName:snake _on>](&mut self, channel: $crate::sensor::Channel) -> Result<$SampleType, Self::Error> {
+                    T::[<$SensorName:snake _on>](self, channel) $($op)*
+                }
+            }
+
+            impl<T: [<MultiChannel $SensorName ThresholdSet>] + ?Sized> [<MultiChannel $SensorName ThresholdSet>] for &mut T {
+                $($kw)? fn [<set_ $SensorName:snake _threshold_low_on>](&mut self, channel: $crate::sensor::Channel, threshold: $SampleType) -> Result<(), Self::Error> {
+                    T::[<set_ $SensorName:snake _threshold_low_on>](self, channel, threshold) $($op)*
+                }
+
+                $($kw)? fn [<set_ $SensorName:snake _threshold_high_on>](&mut self, channel: $crate::sensor::Channel, threshold: $SampleType) -> Result<(), Self::Error> {
+                    T::[<set_ $SensorName:snake _threshold_high_on>](self, channel, threshold) $($op)*
+                }
+            }
+        }
+    };
+}
+
+/// Generates a fixed-point read trait for the specified sensor type.
+///
+/// This macro supports both blocking and async sensors. It generates a `FixedPoint*Sensor` trait
+/// returning samples as integer milli-units, which drivers for targets without a floating-point
+/// unit can implement instead of (or alongside) the `f32` sensor trait.
+#[macro_export]
+macro_rules! decl_fixed_point_trait {
+    (blocking, $SensorName:ident, $MilliType:ty, $unit:expr) => {
+        decl_fixed_point_trait!(
+            @generate,
+            [kw_async = ],
+            [op_await = ],
+            [doc_suffix = "synchronously"],
+            $SensorName,
+            $MilliType,
+            $unit
+        );
+    };
+
+    (async, $SensorName:ident, $MilliType:ty, $unit:expr) => {
+        decl_fixed_point_trait!(
+            @generate,
+            [kw_async = async],
+            [op_await = .await],
+            [doc_suffix = "asynchronously"],
+            $SensorName,
+            $MilliType,
+            $unit
+        );
+    };
+
+    (@generate, [kw_async = $($kw:ident)?], [op_await = $($op:tt)*], [doc_suffix = $doc_suffix:expr], $SensorName:ident, $MilliType:ty, $unit:expr) => {
+        paste::paste! {
+            #[doc = concat!(" Read ", stringify!($SensorName), " samples as fixed-point integers ", $doc_suffix, ".")]
+            pub trait [<FixedPoint $SensorName Sensor>]: $crate::sensor::ErrorType {
+                #[doc = concat!(" Returns a ", stringify!($SensorName), " sample in ", $unit, ".")]
+                $($kw)? fn [<$SensorName:snake _milli>](&mut self) -> Result<$MilliType, Self::Error>;
+            }
+
+            impl<T: [<FixedPoint $SensorName Sensor>] + ?Sized> [<FixedPoint $SensorName Sensor>] for &mut T {
+                $($kw)? fn [<$SensorName:snake _milli>](&mut self) -> Result<$MilliType, Self::Error> {
+                    T::[<$SensorName:snake _milli>](self) $($op)*
+                }
+            }
+        }
+    };
+}
+
+/// Generates fixed-point threshold traits for the specified sensor type.
+///
+/// This macro supports both blocking and async sensors. It generates the `ThresholdSet` and
+/// `Hysteresis` traits (along with the `ThresholdWait` trait in async mode) of
+/// [`decl_threshold_traits`], taking and returning integer milli-units instead of `f32`. The
+/// `FixedPoint*Sensor` trait of the sensor type must be in scope.
+#[macro_export]
+macro_rules! decl_fixed_point_threshold_traits {
+    (blocking, $SensorName:ident, $MilliType:ty, $unit:expr) => {
+        decl_fixed_point_threshold_traits!(
+            @generate,
+            [kw_async = ],
+            [op_await = ],
+            [doc_suffix = "synchronously"],
+            $SensorName,
+            $MilliType,
+            $unit
+        );
+    };
+
+    (async, $SensorName:ident, $MilliType:ty, $unit:expr) => {
+        decl_fixed_point_threshold_traits!(
+            @generate,
+            [kw_async = async],
+            [op_await = .await],
+            [doc_suffix = "asynchronously"],
+            $SensorName,
+            $MilliType,
+            $unit
+        );
+
+        // Declare Async-specific trait (only generated for async mode).
+        paste::paste! {
+            #[doc = concat!(" Asynchronously wait for fixed-point ", stringify!($SensorName), " measurements to exceed specified thresholds.")]
+            pub trait [<FixedPoint $SensorName ThresholdWait>]: [<FixedPoint $SensorName ThresholdSet>] {
+                #[doc = concat!(" Wait for ", stringify!($SensorName), " to be measured above or below the previously set high and low thresholds.")]
+                #[doc = concat!(" Returns the measured ", stringify!($SensorName), " at time threshold is exceeded (in ", $unit, "), along with which threshold was crossed.")]
+                async fn [<wait_for_ $SensorName:snake _threshold_milli>](&mut self) -> Result<$crate::sensor::ThresholdEvent<$MilliType>, Self::Error>;
+            }
+
+            impl<T: [<FixedPoint $SensorName ThresholdWait>] + ?Sized> [<FixedPoint $SensorName ThresholdWait>] for &mut T {
+                async fn [<wait_for_ $SensorName:snake _threshold_milli>](&mut self) -> Result<$crate::sensor::ThresholdEvent<$MilliType>, Self::Error> {
+                    T::[<wait_for_ $SensorName:snake _threshold_milli>](self).await
+                }
+            }
+        }
+    };
+
+    (@generate, [kw_async = $($kw:ident)?], [op_await = $($op:tt)*], [doc_suffix = $doc_suffix:expr], $SensorName:ident, $MilliType:ty, $unit:expr) => {
+        paste::paste! {
+            #[doc = concat!(" Set fixed-point ", stringify!($SensorName), " thresholds ", $doc_suffix, ".")]
+            pub trait [<FixedPoint $SensorName ThresholdSet>]: [<FixedPoint $SensorName Sensor>] {
+                #[doc = concat!(" Set lower ", stringify!($SensorName), " threshold (in ", $unit, ").")]
+                $($kw)? fn [<set_ $SensorName:snake _threshold_low_milli>](&mut self, threshold: $MilliType) -> Result<(), Self::Error>;
+
+                #[doc = concat!(" Set upper ", stringify!($SensorName), " threshold (in ", $unit, ").")]
+                $($kw)? fn [<set_ $SensorName:snake _threshold_high_milli>](&mut self, threshold: $MilliType) -> Result<(), Self::Error>;
+            }
+
+            #[doc = concat!(" Set fixed-point ", stringify!($SensorName), " threshold hysteresis ", $doc_suffix, ".")]
+            pub trait [<FixedPoint $SensorName Hysteresis>]: [<FixedPoint $SensorName ThresholdSet>] {
+                #[doc = concat!(" Set ", stringify!($SensorName), " threshold hysteresis (in ", $unit, ").")]
+                $($kw)? fn [<set_ $SensorName:snake _threshold_hysteresis_milli>](&mut self, hysteresis: $MilliType) -> Result<(), Self::Error>;
+            }
+
+            impl<T: [<FixedPoint $SensorName ThresholdSet>] + ?Sized> [<FixedPoint $SensorName ThresholdSet>] for &mut T {
+                $($kw)? fn [<set_ $SensorName:snake _threshold_low_milli>](&mut self, threshold: $MilliType) -> Result<(), Self::Error> {
+                    T::[<set_ $SensorName:snake _threshold_low_milli>](self, threshold) $($op)*
+                }
+
+                $($kw)? fn [<set_ $SensorName:snake _threshold_high_milli>](&mut self, threshold: $MilliType) -> Result<(), Self::Error> {
+                    T::[<set_ $SensorName:snake _threshold_high_milli>](self, threshold) $($op)*
+                }
+            }
+
+            impl<T: [<FixedPoint $SensorName Hysteresis>] + ?Sized> [<FixedPoint $SensorName Hysteresis>] for &mut T {
+                $($kw)? fn [<set_ $SensorName:snake _threshold_hysteresis_milli>](&mut self, hysteresis: $MilliType) -> Result<(), Self::Error> {
+                    T::[<set_ $SensorName:snake _threshold_hysteresis_milli>](self, hysteresis) $($op)*
+                }
+            }
+        }
+    };
+}
+
+/// Generates a fixed-point calibration trait for the specified sensor type.
+///
+/// This macro supports both blocking and async sensors. It generates the `Calibrate` trait of
+/// [`decl_calibration_trait`], taking the offset in integer milli-units and the gain in
+/// thousandths instead of `f32`. The `FixedPoint*Sensor` trait of the sensor type must be in
+/// scope.
+#[macro_export]
+macro_rules! decl_fixed_point_calibration_trait {
+    (blocking, $SensorName:ident, $MilliType:ty, $unit:expr) => {
+        decl_fixed_point_calibration_trait!(
+            @generate,
+            [kw_async = ],
+            [op_await = ],
+            [doc_suffix = "synchronously"],
+            $SensorName,
+            $MilliType,
+            $unit
+        );
+    };
+
+    (async, $SensorName:ident, $MilliType:ty, $unit:expr) => {
+        decl_fixed_point_calibration_trait!(
+            @generate,
+            [kw_async = async],
+            [op_await = .await],
+            [doc_suffix = "asynchronously"],
+            $SensorName,
+            $MilliType,
+            $unit
+        );
+    };
+
+    (@generate, [kw_async = $($kw:ident)?], [op_await = $($op:tt)*], [doc_suffix = $doc_suffix:expr], $SensorName:ident, $MilliType:ty, $unit:expr) => {
+        paste::paste! {
+            #[doc = concat!(" Calibrate fixed-point ", stringify!($SensorName), " sensors ", $doc_suffix, ".")]
+            ///
+            /// Once calibrated, the sensor reports `raw * gain / 1000 + offset`, where `raw` is the
+            /// uncalibrated sample.
+            pub trait [<FixedPoint $SensorName Calibrate>]: [<FixedPoint $SensorName Sensor>] {
+                #[doc = concat!(" Set the offset added to ", stringify!($SensorName), " samples (in ", $unit, ").")]
+                $($kw)? fn [<set_ $SensorName:snake _offset_milli>](&mut self, offset: $MilliType) -> Result<(), Self::Error>;
+
+                #[doc = concat!(" Set the gain ", stringify!($SensorName), " samples are multiplied by, in thousandths (1000 when uncalibrated).")]
+                $($kw)? fn [<set_ $SensorName:snake _gain_milli>](&mut self, gain: u32) -> Result<(), Self::Error>;
+            }
+
+            impl<T: [<FixedPoint $SensorName Calibrate>] + ?Sized> [<FixedPoint $SensorName Calibrate>] for &mut T {
+                $($kw)? fn [<set_ $SensorName:snake _offset_milli>](&mut self, offset: $MilliType) -> Result<(), Self::Error> {
+                    T::[<set_ $SensorName:snake _offset_milli>](self, offset) $($op)*
+                }
+
+                $($kw)? fn [<set_ $SensorName:snake _gain_milli>](&mut self, gain: u32) -> Result<(), Self::Error> {
+                    T::[<set_ $SensorName:snake _gain_milli>](self, gain) $($op)*
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Mock test values
+    const TEST_FULL_SCALES: [f32; 4] = [2.0, 4.0, 8.0, 16.0];
+    const TEST_IDENTITY: SensorIdentity = SensorIdentity {
+        manufacturer_id: 0x0054,
+        device_id: 0x0400,
+        revision: 2,
+    };
+    const TEST_SAMPLE_VALUE: u16 = 1_234;
+
+    struct MockAccelerometer {
+        full_scale: f32,
+    }
+
+    impl ErrorType for MockAccelerometer {
+        type Error = ErrorKind;
+    }
+
+    impl RangeConfig<f32> for MockAccelerometer {
+        fn set_range(&mut self, min: f32, max: f32) -> Result<ValidRange<f32>, Self::Error> {
+            self.full_scale = *TEST_FULL_SCALES
+                .iter()
+                .find(|&&fs| -fs <= min && max <= fs)
+                .ok_or(ErrorKind::InvalidInput)?;
+            self.full_scale()
+        }
+
+        fn full_scale(&mut self) -> Result<ValidRange<f32>, Self::Error> {
+            Ok(ValidRange::new(-self.full_scale, self.full_scale))
+        }
+    }
+
+    impl SensorIdentify for MockAccelerometer {
+        fn identify(&mut self) -> Result<SensorIdentity, Self::Error> {
+            Ok(TEST_IDENTITY)
+        }
+    }
+
+    impl SensorInfo<f32> for MockAccelerometer {
+        fn measurement_info(&self) -> MeasurementInfo<f32> {
+            // 16-bit samples, with a typical accuracy of 20 mg
+            let range = ValidRange::new(-self.full_scale, self.full_scale);
+            MeasurementInfo::new(range, 2.0 * self.full_scale / 65_536.0, 0.02)
+        }
+    }
+
+    #[test]
+    fn test_range_config_trait_mut_ref() {
+        let mut sensor = MockAccelerometer { full_scale: 2.0 };
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.set_range(-3.0, 3.0), Ok(ValidRange::new(-4.0, 4.0)));
+        assert_eq!(mut_ref.full_scale(), Ok(ValidRange::new(-4.0, 4.0)));
+        assert_eq!(mut_ref.set_range(0.0, 20.0), Err(ErrorKind::InvalidInput));
+        assert_eq!(sensor.full_scale, 4.0);
+    }
+
+    #[test]
+    fn test_sensor_info_trait_mut_ref() {
+        let mut sensor = MockAccelerometer { full_scale: 2.0 };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_range(-8.0, 8.0).is_ok());
+        let info = mut_ref.measurement_info();
+        assert_eq!(info.range, ValidRange::new(-8.0, 8.0));
+        assert_eq!(info.resolution, 16.0 / 65_536.0);
+        assert_eq!(info.range.clamp(-12.5), -8.0);
+        assert_eq!(info.range.clamp(3.0), 3.0);
+        assert!(info.range.clamp(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_sensor_identify_trait_mut_ref() {
+        let mut sensor = MockAccelerometer { full_scale: 2.0 };
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.identify(), Ok(TEST_IDENTITY));
+    }
+
+    struct MockGasSensor {
+        baseline: [u8; 4],
+    }
+
+    impl ErrorType for MockGasSensor {
+        type Error = ErrorKind;
+    }
+
+    impl CalibrationData for MockGasSensor {
+        fn calibration_data(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            let len = self.baseline.len();
+            buf.get_mut(..len)
+                .ok_or(ErrorKind::InvalidInput)?
+                .copy_from_slice(&self.baseline);
+            Ok(len)
+        }
+
+        fn restore_calibration(&mut self, data: &[u8]) -> Result<(), Self::Error> {
+            self.baseline = data.try_into().map_err(|_| ErrorKind::InvalidInput)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_calibration_data_trait_mut_ref() {
+        let mut sensor = MockGasSensor {
+            baseline: [0x8a, 0x2f, 0x91, 0x4c],
+        };
+        let mut blob = [0; 8];
+        let len = sensor.calibration_data(&mut blob).unwrap();
+        assert_eq!(len, 4);
+        assert_eq!(
+            sensor.calibration_data(&mut [0; 2]),
+            Err(ErrorKind::InvalidInput)
+        );
+
+        let mut restored = MockGasSensor { baseline: [0; 4] };
+        let mut_ref = &mut restored;
+        assert!(mut_ref.restore_calibration(&blob[..len]).is_ok());
+        assert_eq!(
+            mut_ref.restore_calibration(&blob),
+            Err(ErrorKind::InvalidInput)
+        );
+        assert_eq!(restored.baseline, sensor.baseline);
+    }
+
+    struct MockThermalSensor {
+        alert: AlertPinConfig,
+    }
+
+    impl ErrorType for MockThermalSensor {
+        type Error = ErrorKind;
+    }
+
+    impl AlertConfig for MockThermalSensor {
+        fn set_alert_config(&mut self, config: AlertPinConfig) -> Result<(), Self::Error> {
+            if config.fault_queue > 6 {
+                return Err(ErrorKind::InvalidInput);
+            }
+            self.alert = config;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_alert_config_trait_mut_ref() {
+        let mut sensor = MockThermalSensor {
+            alert: AlertPinConfig::default(),
+        };
+        assert_eq!(sensor.alert.fault_queue, 1);
+
+        let config = AlertPinConfig::new(
+            AlertPolarity::ActiveHigh,
+            AlertOutput::PushPull,
+            AlertMode::Interrupt,
+            4,
+        );
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_alert_config(config).is_ok());
+        assert_eq!(
+            mut_ref.set_alert_config(AlertPinConfig {
+                fault_queue: 8,
+                ..config
+            }),
+            Err(ErrorKind::InvalidInput)
+        );
+        assert_eq!(sensor.alert, config);
+    }
+
+    struct MockPressureSensor {
+        oversampling: u16,
+    }
+
+    impl ErrorType for MockPressureSensor {
+        type Error = ErrorKind;
+    }
+
+    impl ResolutionConfig for MockPressureSensor {
+        fn set_resolution(&mut self, resolution: Resolution) -> Result<Resolution, Self::Error> {
+            match resolution {
+                Resolution::Oversampling(ratio) => {
+                    self.oversampling = ratio.clamp(1, 16).next_power_of_two();
+                    self.resolution()
+                }
+                Resolution::Bits(_) => Err(ErrorKind::InvalidInput),
+            }
+        }
+
+        fn resolution(&mut self) -> Result<Resolution, Self::Error> {
+            Ok(Resolution::Oversampling(self.oversampling))
+        }
+    }
+
+    impl ConversionTime for MockPressureSensor {
+        fn conversion_time_us(&self) -> u32 {
+            1_000 + 2_000 * self.oversampling as u32
+        }
+    }
+
+    #[test]
+    fn test_conversion_time_trait_mut_ref() {
+        let mut sensor = MockPressureSensor { oversampling: 1 };
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.conversion_time_us(), 3_000);
+        assert_eq!(
+            mut_ref.set_resolution(Resolution::Oversampling(3)),
+            Ok(Resolution::Oversampling(4))
+        );
+        assert_eq!(mut_ref.conversion_time_us(), 9_000);
+    }
+
+    #[test]
+    fn test_sample_quality() {
+        let sample = Sample::new(TEST_SAMPLE_VALUE);
+        assert!(sample.quality.is_empty());
+
+        let sample = sample.with_quality(SampleQuality::STALE | SampleQuality::ESTIMATED);
+        assert_eq!(sample.value, TEST_SAMPLE_VALUE);
+        assert!(sample.quality.contains(SampleQuality::STALE));
+        assert!(!sample.quality.contains(SampleQuality::SATURATED));
+    }
 }
@@ -0,0 +1,138 @@
+//! Blocking Shock Sensor API
+//!
+//! This API provides generic methods for configuring the shock (impact) detection engines of
+//! accelerometers, as used by shipment monitoring devices.
+//!
+//! A shock is detected when the magnitude of acceleration exceeds the threshold of the configured
+//! [`ShockEnvelope`] for at least its minimum duration. Waiting for shocks is only provided by the
+//! async API, as detection is reported by interrupt.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the ShockDetection trait for an accelerometer.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::shock::{ShockDetection, ShockEnvelope};
+//!
+//! // A struct representing an accelerometer with a shock detection engine.
+//! pub struct MyAccelerometer {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyAccelerometer {
+//!     type Error = Error;
+//! }
+//!
+//! impl ShockDetection for MyAccelerometer {
+//!     fn set_shock_envelope(&mut self, envelope: ShockEnvelope) -> Result<(), Self::Error> {
+//!         // Write threshold and duration registers of the shock detection engine...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+
+/// Associates the units shock accelerations are measured in (multiples of the standard
+/// acceleration of gravity, g) with the underlying data type.
+pub type GForce = f32;
+
+/// Detection envelope of a shock detection engine.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ShockEnvelope {
+    /// Magnitude of acceleration above which a shock is detected.
+    pub threshold: GForce,
+    /// Minimum time the acceleration must stay above the threshold, in microseconds.
+    pub min_duration_us: u32,
+}
+
+impl ShockEnvelope {
+    /// Creates a new detection envelope.
+    pub const fn new(threshold: GForce, min_duration_us: u32) -> Self {
+        Self {
+            threshold,
+            min_duration_us,
+        }
+    }
+}
+
+/// A shock detected by a shock detection engine.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ShockEvent {
+    /// Peak magnitude of acceleration during the shock.
+    pub peak: GForce,
+    /// Time the acceleration stayed above the threshold, in microseconds.
+    pub duration_us: u32,
+}
+
+/// Blocking Shock Detection methods.
+pub trait ShockDetection: ErrorType {
+    /// Set the envelope within which accelerations are detected as shocks.
+    ///
+    /// Sensors round the envelope to the nearest threshold and duration they support.
+    fn set_shock_envelope(&mut self, envelope: ShockEnvelope) -> Result<(), Self::Error>;
+}
+
+impl<T: ShockDetection + ?Sized> ShockDetection for &mut T {
+    #[inline]
+    fn set_shock_envelope(&mut self, envelope: ShockEnvelope) -> Result<(), Self::Error> {
+        T::set_shock_envelope(self, envelope)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_ENVELOPE: ShockEnvelope = ShockEnvelope::new(8.0, 500);
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAccelerometer {
+        envelope: Option<ShockEnvelope>,
+    }
+
+    impl crate::sensor::ErrorType for MockAccelerometer {
+        type Error = MockError;
+    }
+
+    impl ShockDetection for MockAccelerometer {
+        fn set_shock_envelope(&mut self, envelope: ShockEnvelope) -> Result<(), Self::Error> {
+            self.envelope = Some(envelope);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_shock_detection_trait_mut_ref() {
+        let mut sensor = MockAccelerometer { envelope: None };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_shock_envelope(TEST_ENVELOPE).is_ok());
+        assert_eq!(sensor.envelope, Some(TEST_ENVELOPE));
+    }
+}
@@ -0,0 +1,213 @@
+//! Blocking Smoke Sensor API
+//!
+//! This API provides generic methods for interfacing with photoelectric smoke sensing front-ends
+//! specifically, as used in smoke alarms and other safety devices.
+//!
+//! The smoke level is reported as obscuration, i.e. the percentage of light attenuated by smoke per
+//! meter. Alarm thresholds are configured with the obscuration threshold traits, which in the async
+//! API also allow awaiting the alarm.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the SmokeSensor trait for a photoelectric smoke
+//! sensing front-end.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::smoke::{PercentPerMeter, SmokeSensor};
+//!
+//! // A struct representing a photoelectric smoke sensing front-end.
+//! pub struct MySmokeFrontEnd {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MySmokeFrontEnd {
+//!     type Error = Error;
+//! }
+//!
+//! impl SmokeSensor for MySmokeFrontEnd {
+//!     fn obscuration(&mut self) -> Result<PercentPerMeter, Self::Error> {
+//!         // Read photodiode signal and subtract clean air baseline...
+//!         Ok(0.8)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{Sensor, ValidRange};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units obscuration samples are measured in with the underlying data type.
+pub type PercentPerMeter = f32;
+
+/// Physically plausible range of obscuration samples.
+pub const OBSCURATION_RANGE: ValidRange<PercentPerMeter> = ValidRange::new(0.0, 100.0);
+
+/// Marker type of the smoke obscuration quantity, of which every [`SmokeSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Smoke;
+
+/// Blocking Smoke Sensor methods.
+pub trait SmokeSensor: Sensor<Smoke, Sample = PercentPerMeter> {
+    /// Returns an obscuration sample in percent per meter.
+    fn obscuration(&mut self) -> Result<PercentPerMeter, Self::Error>;
+}
+
+impl<T: SmokeSensor + ?Sized> SmokeSensor for &mut T {
+    #[inline]
+    fn obscuration(&mut self) -> Result<PercentPerMeter, Self::Error> {
+        T::obscuration(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Smoke, SmokeSensor, obscuration, PercentPerMeter);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait ObscurationThresholdSet: SmokeSensor {
+//     fn set_obscuration_threshold_low(&mut self, threshold: PercentPerMeter) -> Result<(), Self::Error>;
+//     fn set_obscuration_threshold_high(&mut self, threshold: PercentPerMeter) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ObscurationHysteresis: ObscurationThresholdSet {
+//     fn set_obscuration_threshold_hysteresis(&mut self, hysteresis: PercentPerMeter) -> Result<(), Self::Error>;
+// }
+//
+// pub trait ObscurationThresholdControl: ObscurationThresholdSet {
+//     fn disable_obscuration_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_obscuration_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(
+    blocking,
+    Obscuration,
+    SmokeSensor,
+    PercentPerMeter,
+    "percent per meter"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_VALUE: PercentPerMeter = 0.8;
+    const TEST_THRESHOLD_LOW: PercentPerMeter = 0.0;
+    const TEST_THRESHOLD_HIGH: PercentPerMeter = 4.0;
+    const TEST_HYSTERESIS: PercentPerMeter = 0.5;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockSmokeFrontEnd {
+        value: PercentPerMeter,
+        threshold_low: Option<PercentPerMeter>,
+        threshold_high: Option<PercentPerMeter>,
+        hysteresis: Option<PercentPerMeter>,
+    }
+
+    impl MockSmokeFrontEnd {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockSmokeFrontEnd {
+        type Error = MockError;
+    }
+
+    impl SmokeSensor for MockSmokeFrontEnd {
+        fn obscuration(&mut self) -> Result<PercentPerMeter, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl ObscurationThresholdSet for MockSmokeFrontEnd {
+        fn set_obscuration_threshold_low(
+            &mut self,
+            threshold: PercentPerMeter,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_obscuration_threshold_high(
+            &mut self,
+            threshold: PercentPerMeter,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl ObscurationHysteresis for MockSmokeFrontEnd {
+        fn set_obscuration_threshold_hysteresis(
+            &mut self,
+            hysteresis: PercentPerMeter,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_smoke_sensor_trait() {
+        let mut sensor = MockSmokeFrontEnd::new();
+        let result = sensor.obscuration();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_smoke_sensor_trait_mut_ref() {
+        let mut sensor = MockSmokeFrontEnd::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.obscuration();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_smoke_threshold_set_mut_ref() {
+        let mut sensor = MockSmokeFrontEnd::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref
+            .set_obscuration_threshold_low(TEST_THRESHOLD_LOW)
+            .is_ok());
+        assert!(mut_ref
+            .set_obscuration_threshold_high(TEST_THRESHOLD_HIGH)
+            .is_ok());
+        assert!(mut_ref
+            .set_obscuration_threshold_hysteresis(TEST_HYSTERESIS)
+            .is_ok());
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_THRESHOLD_LOW);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_THRESHOLD_HIGH);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), TEST_HYSTERESIS);
+    }
+}
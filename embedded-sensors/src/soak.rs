@@ -0,0 +1,191 @@
+//! Soak Test Harness
+//!
+//! This module provides a harness which exercises a sensor implementation continuously for a
+//! number of iterations, collecting latency and error statistics and verifying that every
+//! sample honors the documented range contract of the quantity being measured. It is intended
+//! for driver qualification on hardware-in-the-loop rigs and requires the `std` feature.
+//!
+//! # For driver authors
+//!
+//! ```
+//! use embedded_sensors_hal::soak::SoakTest;
+//! use embedded_sensors_hal::temperature::{TemperatureSensor, TEMPERATURE_RANGE};
+//!
+//! fn qualify<S: TemperatureSensor>(sensor: &mut S) -> bool {
+//!     let report = SoakTest::new(10_000)
+//!         .with_range(TEMPERATURE_RANGE)
+//!         .run(|| sensor.temperature());
+//!
+//!     println!("{report:?}");
+//!     report.passed()
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use crate::sensor::{Error, ErrorKind, ValidRange};
+
+/// A soak test, configured with the number of iterations to run and the contract to verify.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SoakTest<T> {
+    iterations: u32,
+    range: Option<ValidRange<T>>,
+}
+
+impl<T: PartialOrd + Copy> SoakTest<T> {
+    /// Creates a new soak test running for `iterations` samples.
+    pub fn new(iterations: u32) -> Self {
+        Self {
+            iterations,
+            range: None,
+        }
+    }
+
+    /// Verifies every successful sample lies within `range`.
+    pub fn with_range(mut self, range: ValidRange<T>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Runs the soak test, calling `sample` once per iteration.
+    ///
+    /// The latency of each call is measured, including calls which fail.
+    pub fn run<E: Error>(&self, mut sample: impl FnMut() -> Result<T, E>) -> SoakReport {
+        let mut report = SoakReport::default();
+
+        for _ in 0..self.iterations {
+            let start = Instant::now();
+            let result = sample();
+            report.record_latency(start.elapsed());
+
+            match result {
+                Ok(value) => {
+                    report.successes += 1;
+                    if self.range.is_some_and(|range| !range.contains(value)) {
+                        report.range_violations += 1;
+                    }
+                }
+                Err(e) => *report.errors.entry(e.kind()).or_insert(0) += 1,
+            }
+        }
+
+        report
+    }
+}
+
+/// Statistics collected by a [`SoakTest`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SoakReport {
+    /// Number of iterations run.
+    pub iterations: u32,
+    /// Number of samples returned successfully.
+    pub successes: u32,
+    /// Number of failed samples, by error kind.
+    pub errors: BTreeMap<ErrorKind, u32>,
+    /// Number of successful samples which fell outside of the expected range.
+    pub range_violations: u32,
+    /// Shortest observed sample latency.
+    pub min_latency: Duration,
+    /// Longest observed sample latency.
+    pub max_latency: Duration,
+    /// Sum of all observed sample latencies.
+    pub total_latency: Duration,
+}
+
+impl SoakReport {
+    fn record_latency(&mut self, latency: Duration) {
+        if self.iterations == 0 || latency < self.min_latency {
+            self.min_latency = latency;
+        }
+        self.max_latency = self.max_latency.max(latency);
+        self.total_latency += latency;
+        self.iterations += 1;
+    }
+
+    /// Returns the total number of failed samples.
+    pub fn failures(&self) -> u32 {
+        self.errors.values().sum()
+    }
+
+    /// Returns the mean sample latency, or zero if no iterations were run.
+    pub fn mean_latency(&self) -> Duration {
+        self.total_latency
+            .checked_div(self.iterations)
+            .unwrap_or_default()
+    }
+
+    /// Returns true if every sample succeeded and honored the range contract.
+    pub fn passed(&self) -> bool {
+        self.failures() == 0 && self.range_violations == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Mock test values
+    const TEST_ITERATIONS: u32 = 100;
+    const TEST_RANGE: ValidRange<f32> = ValidRange::new(0.0, 100.0);
+
+    #[derive(Debug)]
+    struct MockError(ErrorKind);
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_soak_passed() {
+        let report = SoakTest::new(TEST_ITERATIONS)
+            .with_range(TEST_RANGE)
+            .run(|| Ok::<_, MockError>(25.0));
+
+        assert!(report.passed());
+        assert_eq!(report.iterations, TEST_ITERATIONS);
+        assert_eq!(report.successes, TEST_ITERATIONS);
+        assert!(report.min_latency <= report.mean_latency());
+        assert!(report.mean_latency() <= report.max_latency);
+    }
+
+    #[test]
+    fn test_soak_error_kinds() {
+        let mut count = 0;
+        let report = SoakTest::new(TEST_ITERATIONS).run(|| {
+            count += 1;
+            match count % 4 {
+                0 => Err(MockError(ErrorKind::Peripheral)),
+                1 => Err(MockError(ErrorKind::Other)),
+                _ => Ok(50.0),
+            }
+        });
+
+        assert!(!report.passed());
+        assert_eq!(report.successes, TEST_ITERATIONS / 2);
+        assert_eq!(report.failures(), TEST_ITERATIONS / 2);
+        assert_eq!(report.errors[&ErrorKind::Peripheral], TEST_ITERATIONS / 4);
+        assert_eq!(report.errors[&ErrorKind::Other], TEST_ITERATIONS / 4);
+    }
+
+    #[test]
+    fn test_soak_range_violations() {
+        let mut values = [50.0, -1.0, f32::NAN, 101.0].into_iter().cycle();
+        let report = SoakTest::new(TEST_ITERATIONS)
+            .with_range(TEST_RANGE)
+            .run(|| Ok::<_, MockError>(values.next().unwrap()));
+
+        assert!(!report.passed());
+        assert_eq!(report.failures(), 0);
+        assert_eq!(report.range_violations, TEST_ITERATIONS * 3 / 4);
+    }
+
+    #[test]
+    fn test_soak_no_iterations() {
+        let report = SoakTest::<f32>::new(0).run(|| Ok::<_, MockError>(0.0));
+        assert!(report.passed());
+        assert_eq!(report.mean_latency(), Duration::ZERO);
+    }
+}
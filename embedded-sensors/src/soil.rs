@@ -0,0 +1,197 @@
+//! Blocking Soil Probe API
+//!
+//! This API provides generic methods for interfacing with multi-depth soil probes specifically.
+//!
+//! A soil probe has one or more sensing segments at different depths, identified by their index.
+//! The [`SoilProbe`] trait describes the depth of each segment, while the [`SoilTemperatureProbe`]
+//! and [`SoilMoistureProbe`] traits sample a segment.
+//!
+//! Sampling a segment index not less than the number of segments fails with an error of kind
+//! [`ErrorKind::InvalidInput`](crate::sensor::ErrorKind::InvalidInput).
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the soil probe traits for a multi-depth probe.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::soil::{
+//!     Centimeters, SoilMoistureProbe, SoilProbe, SoilTemperatureProbe, VolumetricWaterContent,
+//! };
+//! use embedded_sensors_hal::temperature::DegreesCelsius;
+//!
+//! // A struct representing a soil probe.
+//! pub struct MySoilProbe {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     InvalidSegment,
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             Error::InvalidSegment => sensor::ErrorKind::InvalidInput,
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MySoilProbe {
+//!     type Error = Error;
+//! }
+//!
+//! impl SoilProbe for MySoilProbe {
+//!     fn depths(&self) -> &[Centimeters] {
+//!         &[10.0, 20.0, 40.0]
+//!     }
+//! }
+//!
+//! impl SoilTemperatureProbe for MySoilProbe {
+//!     fn soil_temperature(&mut self, segment: usize) -> Result<DegreesCelsius, Self::Error> {
+//!         if segment >= self.depths().len() {
+//!             return Err(Error::InvalidSegment);
+//!         }
+//!         // Query segment temperature from probe...
+//!         Ok(14.5)
+//!     }
+//! }
+//!
+//! impl SoilMoistureProbe for MySoilProbe {
+//!     fn soil_moisture(&mut self, segment: usize) -> Result<VolumetricWaterContent, Self::Error> {
+//!         if segment >= self.depths().len() {
+//!             return Err(Error::InvalidSegment);
+//!         }
+//!         // Query segment moisture from probe...
+//!         Ok(27.0)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{ErrorType, ValidRange};
+use crate::temperature::DegreesCelsius;
+
+/// Associates the units segment depths are measured in with the underlying data type.
+pub type Centimeters = f32;
+
+/// Associates the units soil moisture samples (volumetric water content, in percent) are
+/// measured in with the underlying data type.
+pub type VolumetricWaterContent = f32;
+
+/// Physically plausible range of soil moisture samples.
+pub const SOIL_MOISTURE_RANGE: ValidRange<VolumetricWaterContent> = ValidRange::new(0.0, 100.0);
+
+/// Blocking Soil Probe methods.
+pub trait SoilProbe: ErrorType {
+    /// Returns the depth below the surface of each segment of the probe, indexed by segment.
+    fn depths(&self) -> &[Centimeters];
+}
+
+impl<T: SoilProbe + ?Sized> SoilProbe for &mut T {
+    #[inline]
+    fn depths(&self) -> &[Centimeters] {
+        T::depths(self)
+    }
+}
+
+/// Blocking Soil Temperature methods for probes able to measure it.
+pub trait SoilTemperatureProbe: SoilProbe {
+    /// Returns a temperature sample of the given segment in degrees celsius.
+    fn soil_temperature(&mut self, segment: usize) -> Result<DegreesCelsius, Self::Error>;
+}
+
+impl<T: SoilTemperatureProbe + ?Sized> SoilTemperatureProbe for &mut T {
+    #[inline]
+    fn soil_temperature(&mut self, segment: usize) -> Result<DegreesCelsius, Self::Error> {
+        T::soil_temperature(self, segment)
+    }
+}
+
+/// Blocking Soil Moisture methods for probes able to measure it.
+pub trait SoilMoistureProbe: SoilProbe {
+    /// Returns a moisture sample of the given segment as volumetric water content in percent.
+    fn soil_moisture(&mut self, segment: usize) -> Result<VolumetricWaterContent, Self::Error>;
+}
+
+impl<T: SoilMoistureProbe + ?Sized> SoilMoistureProbe for &mut T {
+    #[inline]
+    fn soil_moisture(&mut self, segment: usize) -> Result<VolumetricWaterContent, Self::Error> {
+        T::soil_moisture(self, segment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_DEPTHS: [Centimeters; 2] = [10.0, 30.0];
+    const TEST_TEMPERATURES: [DegreesCelsius; 2] = [18.0, 15.5];
+    const TEST_MOISTURES: [VolumetricWaterContent; 2] = [22.0, 31.0];
+
+    #[derive(Debug, PartialEq)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::InvalidInput
+        }
+    }
+
+    struct MockSoilProbe {}
+
+    impl crate::sensor::ErrorType for MockSoilProbe {
+        type Error = MockError;
+    }
+
+    impl SoilProbe for MockSoilProbe {
+        fn depths(&self) -> &[Centimeters] {
+            &TEST_DEPTHS
+        }
+    }
+
+    impl SoilTemperatureProbe for MockSoilProbe {
+        fn soil_temperature(&mut self, segment: usize) -> Result<DegreesCelsius, Self::Error> {
+            TEST_TEMPERATURES.get(segment).copied().ok_or(MockError)
+        }
+    }
+
+    impl SoilMoistureProbe for MockSoilProbe {
+        fn soil_moisture(&mut self, segment: usize) -> Result<VolumetricWaterContent, Self::Error> {
+            TEST_MOISTURES.get(segment).copied().ok_or(MockError)
+        }
+    }
+
+    #[test]
+    fn test_soil_probe_trait() {
+        let mut probe = MockSoilProbe {};
+        assert_eq!(probe.depths(), TEST_DEPTHS);
+
+        let result = probe.soil_temperature(1);
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_TEMPERATURES[1]);
+        assert_eq!(probe.soil_temperature(2), Err(MockError));
+    }
+
+    #[test]
+    fn test_soil_probe_trait_mut_ref() {
+        let mut probe = MockSoilProbe {};
+        let mut_ref = &mut probe;
+        assert_eq!(mut_ref.depths().len(), 2);
+
+        let result = mut_ref.soil_moisture(0);
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_MOISTURES[0]);
+    }
+
+    #[test]
+    fn test_soil_moisture_range() {
+        assert!(SOIL_MOISTURE_RANGE.contains(45.0));
+        assert!(!SOIL_MOISTURE_RANGE.contains(101.0));
+    }
+}
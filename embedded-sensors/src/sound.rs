@@ -0,0 +1,246 @@
+//! Blocking Sound Pressure Level Sensor API
+//!
+//! This API provides generic methods for interfacing with sound pressure level (SPL) sensors specifically.
+//!
+//! Samples are A-weighted sound pressure levels, as reported by digital microphones with
+//! level-detection engines and dedicated SPL meters.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the SoundPressureLevelSensor trait for a sound pressure level sensor.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::sound::{Decibels, SoundPressureLevelSensor};
+//!
+//! // A struct representing a sound pressure level sensor.
+//! pub struct MySoundSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MySoundSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl SoundPressureLevelSensor for MySoundSensor {
+//!     fn sound_pressure_level(&mut self) -> Result<Decibels, Self::Error> {
+//!         // ...
+//!         Ok(42.0)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{Sensor, ValidRange};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units A-weighted sound pressure level samples are measured in with the underlying data type.
+pub type Decibels = f32;
+
+/// Physically plausible range of sound pressure level samples (194 dB SPL being the loudest undistorted sound in air).
+pub const SOUND_PRESSURE_LEVEL_RANGE: ValidRange<Decibels> = ValidRange::new(0.0, 194.0);
+
+/// Acoustic event recognized by the event engine of a microphone or SPL front-end.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum AcousticEvent {
+    /// The sound of breaking glass was detected.
+    GlassBreak,
+    /// A clap (or similar short impulsive sound) was detected.
+    Clap,
+    /// The sound pressure level exceeded the configured threshold.
+    ThresholdExceeded,
+    /// A vendor-specific event, identified by a driver-defined code.
+    Other(u8),
+}
+
+/// Marker type of the sound pressure level quantity, of which every [`SoundPressureLevelSensor`]
+/// implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SoundPressureLevel;
+
+/// Blocking Sound Pressure Level Sensor methods.
+pub trait SoundPressureLevelSensor: Sensor<SoundPressureLevel, Sample = Decibels> {
+    /// Returns an A-weighted sound pressure level sample in decibels (dB SPL).
+    fn sound_pressure_level(&mut self) -> Result<Decibels, Self::Error>;
+}
+
+impl<T: SoundPressureLevelSensor + ?Sized> SoundPressureLevelSensor for &mut T {
+    #[inline]
+    fn sound_pressure_level(&mut self) -> Result<Decibels, Self::Error> {
+        T::sound_pressure_level(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    SoundPressureLevel,
+    SoundPressureLevelSensor,
+    sound_pressure_level,
+    Decibels
+);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait SoundPressureLevelThresholdSet: SoundPressureLevelSensor {
+//     fn set_sound_pressure_level_threshold_low(&mut self, threshold: Decibels) -> Result<(), Self::Error>;
+//     fn set_sound_pressure_level_threshold_high(&mut self, threshold: Decibels) -> Result<(), Self::Error>;
+// }
+//
+// pub trait SoundPressureLevelHysteresis: SoundPressureLevelThresholdSet {
+//     fn set_sound_pressure_level_threshold_hysteresis(&mut self, hysteresis: Decibels) -> Result<(), Self::Error>;
+// }
+//
+// pub trait SoundPressureLevelThresholdControl: SoundPressureLevelThresholdSet {
+//     fn disable_sound_pressure_level_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_sound_pressure_level_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(
+    blocking,
+    SoundPressureLevel,
+    SoundPressureLevelSensor,
+    Decibels,
+    "A-weighted decibels"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test value
+    const TEST_VALUE: Decibels = 55.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockSoundSensor {
+        value: Decibels,
+        threshold_low: Option<Decibels>,
+        threshold_high: Option<Decibels>,
+        hysteresis: Option<Decibels>,
+    }
+
+    impl MockSoundSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockSoundSensor {
+        type Error = MockError;
+    }
+
+    impl SoundPressureLevelSensor for MockSoundSensor {
+        fn sound_pressure_level(&mut self) -> Result<Decibels, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl SoundPressureLevelThresholdSet for MockSoundSensor {
+        fn set_sound_pressure_level_threshold_low(
+            &mut self,
+            threshold: Decibels,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_sound_pressure_level_threshold_high(
+            &mut self,
+            threshold: Decibels,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl SoundPressureLevelHysteresis for MockSoundSensor {
+        fn set_sound_pressure_level_threshold_hysteresis(
+            &mut self,
+            hysteresis: Decibels,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_sound_sensor_trait() {
+        let mut sensor = MockSoundSensor::new();
+        let result = sensor.sound_pressure_level();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_sound_sensor_trait_mut_ref() {
+        let mut sensor = MockSoundSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.sound_pressure_level();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_sound_threshold_set_mut_ref() {
+        let mut sensor = MockSoundSensor::new();
+        let mut_ref = &mut sensor;
+        let low_threshold = 40.0;
+        let high_threshold = 85.0;
+
+        let result_low = mut_ref.set_sound_pressure_level_threshold_low(low_threshold);
+        assert!(result_low.is_ok());
+
+        let result_high = mut_ref.set_sound_pressure_level_threshold_high(high_threshold);
+        assert!(result_high.is_ok());
+
+        assert_approx_eq!(sensor.threshold_low.unwrap(), low_threshold);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), high_threshold);
+    }
+
+    #[test]
+    fn test_sound_hysteresis_mut_ref() {
+        let mut sensor = MockSoundSensor::new();
+        let mut_ref = &mut sensor;
+        let hyst = 3.0;
+        let result = mut_ref.set_sound_pressure_level_threshold_hysteresis(hyst);
+        assert!(result.is_ok());
+        assert_approx_eq!(sensor.hysteresis.unwrap(), hyst);
+    }
+
+    #[test]
+    fn test_sound_range() {
+        assert!(SOUND_PRESSURE_LEVEL_RANGE.validate(TEST_VALUE).is_ok());
+        assert_eq!(
+            SOUND_PRESSURE_LEVEL_RANGE.validate(-10.0),
+            Err(ErrorKind::OutOfRange)
+        );
+    }
+}
@@ -0,0 +1,230 @@
+//! Blocking Spectral Light Sensor API
+//!
+//! This API provides generic methods for interfacing with multi-band spectral light sensors
+//! specifically.
+//!
+//! Spectral sensors sample irradiance through several optical filters. Each filter is described
+//! by a [`SpectralChannel`], allowing generic color-science pipelines to interpret readings
+//! without knowledge of the part.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the SpectralSensor trait for a spectral sensor.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::spectral::{Irradiance, SpectralChannel, SpectralSensor};
+//!
+//! // Filters of the sensor, from its datasheet.
+//! const CHANNELS: [SpectralChannel; 3] = [
+//!     SpectralChannel::new(445.0, 30.0),
+//!     SpectralChannel::new(555.0, 40.0),
+//!     SpectralChannel::new(680.0, 52.0),
+//! ];
+//!
+//! // A struct representing a spectral sensor.
+//! pub struct MySpectralSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MySpectralSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl SpectralSensor for MySpectralSensor {
+//!     fn channels(&self) -> &[SpectralChannel] {
+//!         &CHANNELS
+//!     }
+//!
+//!     fn irradiance(&mut self, readings: &mut [Irradiance]) -> Result<usize, Self::Error> {
+//!         // Read channel data registers of sensor and apply responsivity calibration...
+//!         let count = readings.len().min(CHANNELS.len());
+//!         readings[..count].fill(12.5);
+//!         Ok(count)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{ErrorType, ValidRange};
+
+/// Associates the units wavelengths are measured in with the underlying data type.
+pub type Nanometers = f32;
+
+/// Associates the units irradiance samples are measured in with the underlying data type.
+pub type Irradiance = f32;
+
+/// Physically plausible range of irradiance samples, in microwatts per square centimeter.
+pub const IRRADIANCE_RANGE: ValidRange<Irradiance> = ValidRange::new(0.0, Irradiance::MAX);
+
+/// Describes the optical filter of a spectral sensor channel.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SpectralChannel {
+    /// Center (peak) wavelength of the filter.
+    pub center_wavelength: Nanometers,
+    /// Full width at half maximum of the filter.
+    pub bandwidth: Nanometers,
+}
+
+impl SpectralChannel {
+    /// Creates a new channel description.
+    pub const fn new(center_wavelength: Nanometers, bandwidth: Nanometers) -> Self {
+        Self {
+            center_wavelength,
+            bandwidth,
+        }
+    }
+
+    /// Returns `true` if the wavelength lies within the full width at half maximum of the filter.
+    pub fn covers(&self, wavelength: Nanometers) -> bool {
+        self.offset_from(wavelength) <= self.bandwidth / 2.0
+    }
+
+    /// Returns the distance between the wavelength and the center wavelength of the filter.
+    pub fn offset_from(&self, wavelength: Nanometers) -> Nanometers {
+        if wavelength > self.center_wavelength {
+            wavelength - self.center_wavelength
+        } else {
+            self.center_wavelength - wavelength
+        }
+    }
+}
+
+/// Blocking Spectral Sensor methods.
+pub trait SpectralSensor: ErrorType {
+    /// Returns a description of each channel of the sensor, in the order readings are reported.
+    fn channels(&self) -> &[SpectralChannel];
+
+    /// Returns the index of the channel covering the wavelength, or `None` if no channel does.
+    ///
+    /// If the passbands of several channels cover the wavelength, the channel whose center
+    /// wavelength is nearest is returned.
+    fn channel_at(&self, wavelength: Nanometers) -> Option<usize> {
+        self.channels()
+            .iter()
+            .enumerate()
+            .filter(|(_, channel)| channel.covers(wavelength))
+            .min_by(|(_, a), (_, b)| {
+                a.offset_from(wavelength)
+                    .total_cmp(&b.offset_from(wavelength))
+            })
+            .map(|(index, _)| index)
+    }
+
+    /// Fills `readings` with an irradiance sample of each channel, in microwatts per square
+    /// centimeter.
+    ///
+    /// Returns the number of readings written, which is less than `readings.len()` if the
+    /// sensor has fewer channels.
+    fn irradiance(&mut self, readings: &mut [Irradiance]) -> Result<usize, Self::Error>;
+}
+
+impl<T: SpectralSensor + ?Sized> SpectralSensor for &mut T {
+    #[inline]
+    fn channels(&self) -> &[SpectralChannel] {
+        T::channels(self)
+    }
+
+    #[inline]
+    fn channel_at(&self, wavelength: Nanometers) -> Option<usize> {
+        T::channel_at(self, wavelength)
+    }
+
+    #[inline]
+    fn irradiance(&mut self, readings: &mut [Irradiance]) -> Result<usize, Self::Error> {
+        T::irradiance(self, readings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_CHANNELS: [SpectralChannel; 2] = [
+        SpectralChannel::new(480.0, 36.0),
+        SpectralChannel::new(630.0, 40.0),
+    ];
+    const TEST_READINGS: [Irradiance; 2] = [8.5, 21.0];
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockSpectralSensor {}
+
+    impl crate::sensor::ErrorType for MockSpectralSensor {
+        type Error = MockError;
+    }
+
+    impl SpectralSensor for MockSpectralSensor {
+        fn channels(&self) -> &[SpectralChannel] {
+            &TEST_CHANNELS
+        }
+
+        fn irradiance(&mut self, readings: &mut [Irradiance]) -> Result<usize, Self::Error> {
+            let count = readings.len().min(TEST_READINGS.len());
+            readings[..count].copy_from_slice(&TEST_READINGS[..count]);
+            Ok(count)
+        }
+    }
+
+    #[test]
+    fn test_spectral_sensor_trait() {
+        let mut sensor = MockSpectralSensor {};
+        let mut readings = [0.0; 4];
+        let result = sensor.irradiance(&mut readings);
+        assert_eq!(result.unwrap(), 2);
+        assert_approx_eq!(readings[0], TEST_READINGS[0]);
+        assert_approx_eq!(readings[1], TEST_READINGS[1]);
+    }
+
+    #[test]
+    fn test_spectral_sensor_trait_mut_ref() {
+        let mut sensor = MockSpectralSensor {};
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.channels(), TEST_CHANNELS);
+
+        let mut readings = [0.0; 1];
+        assert_eq!(mut_ref.irradiance(&mut readings).unwrap(), 1);
+        assert_approx_eq!(readings[0], TEST_READINGS[0]);
+    }
+
+    #[test]
+    fn test_irradiance_range() {
+        assert!(IRRADIANCE_RANGE.contains(0.0));
+        assert!(!IRRADIANCE_RANGE.contains(-1.0));
+    }
+
+    #[test]
+    fn test_spectral_channel_at() {
+        let channel = SpectralChannel::new(500.0, 60.0);
+        assert!(channel.covers(470.0));
+        assert!(!channel.covers(531.0));
+        assert_approx_eq!(channel.offset_from(470.0), 30.0);
+
+        let sensor = MockSpectralSensor {};
+        assert_eq!(sensor.channel_at(640.0), Some(1));
+        assert_eq!(sensor.channel_at(550.0), None);
+    }
+}
@@ -0,0 +1,167 @@
+//! Blocking Pulse Oximetry Sensor API
+//!
+//! This API provides generic methods for interfacing with pulse oximetry (SpO2) sensors
+//! specifically.
+//!
+//! Pulse oximeters can only estimate oxygen saturation while they have a good optical signal
+//! (e.g. skin contact and little motion), so each sample carries a validity flag. Devices which
+//! also measure heart rate expose it independently of this API.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the Spo2Sensor trait for a pulse oximeter.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::spo2::{Spo2Sample, Spo2Sensor};
+//!
+//! // A struct representing a pulse oximeter.
+//! pub struct MyPulseOximeter {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyPulseOximeter {
+//!     type Error = Error;
+//! }
+//!
+//! impl Spo2Sensor for MyPulseOximeter {
+//!     fn spo2(&mut self) -> Result<Spo2Sample, Self::Error> {
+//!         // Read saturation and signal quality registers of sensor...
+//!         Ok(Spo2Sample {
+//!             saturation: 97.0,
+//!             valid: true,
+//!         })
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+use crate::sensor::{Sensor, ValidRange};
+
+/// Associates the units oxygen saturation samples are measured in with the underlying data type.
+pub type Percentage = f32;
+
+/// Physically plausible range of oxygen saturation samples.
+pub const SPO2_RANGE: ValidRange<Percentage> = ValidRange::new(0.0, 100.0);
+
+/// An oxygen saturation sample.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Spo2Sample {
+    /// Peripheral blood oxygen saturation as a percentage.
+    pub saturation: Percentage,
+    /// Whether the signal was good enough for the saturation to be meaningful.
+    ///
+    /// Invalid samples should be discarded rather than displayed or alarmed on.
+    pub valid: bool,
+}
+
+impl Spo2Sample {
+    /// Returns the saturation if the sample is valid.
+    pub fn saturation(&self) -> Option<Percentage> {
+        self.valid.then_some(self.saturation)
+    }
+}
+
+/// Marker type of the blood oxygen saturation quantity, of which every [`Spo2Sensor`] implementor
+/// is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OxygenSaturation;
+
+/// Blocking Pulse Oximetry Sensor methods.
+pub trait Spo2Sensor: Sensor<OxygenSaturation, Sample = Spo2Sample> {
+    /// Returns an oxygen saturation sample as a percentage, along with its validity.
+    fn spo2(&mut self) -> Result<Spo2Sample, Self::Error>;
+}
+
+impl<T: Spo2Sensor + ?Sized> Spo2Sensor for &mut T {
+    #[inline]
+    fn spo2(&mut self) -> Result<Spo2Sample, Self::Error> {
+        T::spo2(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, OxygenSaturation, Spo2Sensor, spo2, Spo2Sample);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_SAMPLE: Spo2Sample = Spo2Sample {
+        saturation: 96.5,
+        valid: true,
+    };
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockPulseOximeter {
+        sample: Spo2Sample,
+    }
+
+    impl crate::sensor::ErrorType for MockPulseOximeter {
+        type Error = MockError;
+    }
+
+    impl Spo2Sensor for MockPulseOximeter {
+        fn spo2(&mut self) -> Result<Spo2Sample, Self::Error> {
+            Ok(self.sample)
+        }
+    }
+
+    #[test]
+    fn test_spo2_sensor_trait() {
+        let mut sensor = MockPulseOximeter {
+            sample: TEST_SAMPLE,
+        };
+        let result = sensor.spo2();
+        assert!(result.is_ok());
+        assert_approx_eq!(
+            result.unwrap().saturation().unwrap(),
+            TEST_SAMPLE.saturation
+        );
+    }
+
+    #[test]
+    fn test_spo2_sensor_trait_mut_ref() {
+        let mut sensor = MockPulseOximeter {
+            sample: Spo2Sample {
+                saturation: 0.0,
+                valid: false,
+            },
+        };
+        let mut_ref = &mut sensor;
+        let result = mut_ref.spo2();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().saturation(), None);
+    }
+
+    #[test]
+    fn test_spo2_range() {
+        assert!(SPO2_RANGE.validate(TEST_SAMPLE.saturation).is_ok());
+        assert_eq!(SPO2_RANGE.validate(101.0), Err(ErrorKind::OutOfRange));
+    }
+}
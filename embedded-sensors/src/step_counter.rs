@@ -0,0 +1,139 @@
+//! Blocking Step Counter API
+//!
+//! This API provides generic methods for interfacing with step counters (pedometers)
+//! specifically, such as the pedometer engines embedded in accelerometers like the BMA456 or
+//! LSM6DSO.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the StepCounter trait for an accelerometer.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::step_counter::{StepCount, StepCounter};
+//!
+//! // A struct representing an accelerometer with an embedded pedometer.
+//! pub struct MyAccelerometer {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyAccelerometer {
+//!     type Error = Error;
+//! }
+//!
+//! impl StepCounter for MyAccelerometer {
+//!     fn step_count(&mut self) -> Result<StepCount, Self::Error> {
+//!         // Read step counter registers of sensor...
+//!         Ok(4_812)
+//!     }
+//!
+//!     fn reset_step_count(&mut self) -> Result<(), Self::Error> {
+//!         // Write step counter reset bit of sensor...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::impl_sensor_refinement;
+use crate::sensor::Sensor;
+
+/// Associates the number of steps counted with the underlying data type.
+pub type StepCount = u32;
+
+/// Marker type of the step count quantity, of which every [`StepCounter`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Steps;
+
+/// Blocking Step Counter methods.
+pub trait StepCounter: Sensor<Steps, Sample = StepCount> {
+    /// Returns the number of steps counted since the counter was last reset.
+    ///
+    /// The width of hardware step counters varies, so the count may wrap around once it exceeds
+    /// the width of the counter of the sensor.
+    fn step_count(&mut self) -> Result<StepCount, Self::Error>;
+
+    /// Resets the step count to zero.
+    fn reset_step_count(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<T: StepCounter + ?Sized> StepCounter for &mut T {
+    #[inline]
+    fn step_count(&mut self) -> Result<StepCount, Self::Error> {
+        T::step_count(self)
+    }
+
+    #[inline]
+    fn reset_step_count(&mut self) -> Result<(), Self::Error> {
+        T::reset_step_count(self)
+    }
+}
+
+impl_sensor_refinement!(blocking, Steps, StepCounter, step_count, StepCount);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_STEPS: StepCount = 4_812;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockPedometer {
+        steps: StepCount,
+    }
+
+    impl crate::sensor::ErrorType for MockPedometer {
+        type Error = MockError;
+    }
+
+    impl StepCounter for MockPedometer {
+        fn step_count(&mut self) -> Result<StepCount, Self::Error> {
+            Ok(self.steps)
+        }
+
+        fn reset_step_count(&mut self) -> Result<(), Self::Error> {
+            self.steps = 0;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_step_counter_trait() {
+        let mut sensor = MockPedometer { steps: TEST_STEPS };
+        let result = sensor.step_count();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_STEPS);
+    }
+
+    #[test]
+    fn test_step_counter_trait_mut_ref() {
+        let mut sensor = MockPedometer { steps: TEST_STEPS };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.reset_step_count().is_ok());
+        assert_eq!(mut_ref.step_count().unwrap(), 0);
+    }
+}
@@ -0,0 +1,140 @@
+//! Blocking Tap Detection API
+//!
+//! This API provides generic methods for interfacing with the tap detection engines of
+//! accelerometers specifically.
+//!
+//! A tap is detected when the acceleration exceeds the threshold of the configured [`TapConfig`]
+//! and falls back below it within the maximum tap duration. A second tap within the double tap
+//! window is reported as a [`TapKind::Double`] tap.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the TapDetection trait for an accelerometer.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::tap::{TapConfig, TapDetection};
+//!
+//! // A struct representing an accelerometer with a tap detection engine.
+//! pub struct MyAccelerometer {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyAccelerometer {
+//!     type Error = Error;
+//! }
+//!
+//! impl TapDetection for MyAccelerometer {
+//!     fn set_tap_config(&mut self, config: TapConfig) -> Result<(), Self::Error> {
+//!         // Write threshold, shock, quiet and latency registers of the tap detection engine...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::ErrorType;
+pub use crate::shock::GForce;
+
+/// Configuration of a tap detection engine.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TapConfig {
+    /// Magnitude of acceleration above which a tap is detected.
+    pub threshold: GForce,
+    /// Maximum time the acceleration may stay above the threshold for a tap, in microseconds.
+    pub max_duration_us: u32,
+    /// Time after a first tap within which a second tap is detected as a double tap, in
+    /// microseconds, or 0 to only detect single taps.
+    pub double_tap_window_us: u32,
+}
+
+impl TapConfig {
+    /// Creates a new tap detection configuration.
+    pub const fn new(threshold: GForce, max_duration_us: u32, double_tap_window_us: u32) -> Self {
+        Self {
+            threshold,
+            max_duration_us,
+            double_tap_window_us,
+        }
+    }
+}
+
+/// Kind of a tap detected by a tap detection engine.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TapKind {
+    /// A single tap.
+    #[default]
+    Single,
+    /// Two taps within the double tap window.
+    Double,
+}
+
+/// Blocking Tap Detection methods.
+pub trait TapDetection: ErrorType {
+    /// Set the configuration of the tap detection engine.
+    ///
+    /// Sensors round the threshold and timings to the nearest values they support.
+    fn set_tap_config(&mut self, config: TapConfig) -> Result<(), Self::Error>;
+}
+
+impl<T: TapDetection + ?Sized> TapDetection for &mut T {
+    #[inline]
+    fn set_tap_config(&mut self, config: TapConfig) -> Result<(), Self::Error> {
+        T::set_tap_config(self, config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+
+    // Mock test values
+    const TEST_CONFIG: TapConfig = TapConfig::new(2.5, 60_000, 300_000);
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockAccelerometer {
+        config: Option<TapConfig>,
+    }
+
+    impl crate::sensor::ErrorType for MockAccelerometer {
+        type Error = MockError;
+    }
+
+    impl TapDetection for MockAccelerometer {
+        fn set_tap_config(&mut self, config: TapConfig) -> Result<(), Self::Error> {
+            self.config = Some(config);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_tap_detection_trait_mut_ref() {
+        let mut sensor = MockAccelerometer { config: None };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_tap_config(TEST_CONFIG).is_ok());
+        assert_eq!(sensor.config, Some(TEST_CONFIG));
+    }
+}
@@ -2,6 +2,19 @@
 //!
 //! This API provides generic methods for interfacing with temperature sensors specifically.
 //!
+//! Sensors measuring several temperatures, such as remote diode monitors with a local channel
+//! (e.g. TMP451, EMC1413 or MAX6697), can implement the [`MultiChannelTemperatureSensor`] trait.
+//! By convention, channel 0 is the local (on-die) channel of sensors which have one, followed by
+//! the remote channels.
+//!
+//! Samples are always reported in degrees Celsius. Application code presenting them in another
+//! unit can convert them through the [`Celsius`], [`Fahrenheit`] and [`Kelvin`] wrappers, which
+//! keep the unit of a temperature explicit in its type.
+//!
+//! Drivers for targets without a floating-point unit can implement the
+//! [`FixedPointTemperatureSensor`] trait, reporting samples in integer millidegrees Celsius, along
+//! with the fixed-point threshold and calibration traits built upon it.
+//!
 //! # For HAL authors
 //!
 //! Here is an example for the implementation of the TemperatureSensor trait for a temperature sensor.
@@ -40,14 +53,131 @@
 //! }
 //! ```
 
-use crate::decl_threshold_traits;
-use crate::sensor::ErrorType;
+use crate::frequency::Hertz;
+use crate::sensor::{AlertPolarity, OperatingMode, Sensor, ValidRange};
+use crate::units;
+use crate::{
+    decl_calibration_trait, decl_channel_traits, decl_configuration_trait,
+    decl_fixed_point_calibration_trait, decl_fixed_point_threshold_traits, decl_fixed_point_trait,
+    decl_sample_read_trait, decl_threshold_traits, impl_sensor_refinement,
+};
 
 /// Associates the units temperature samples are measured in with the underlying data type.
 pub type DegreesCelsius = f32;
 
+/// Associates the units fixed-point temperature samples are measured in with the underlying data type.
+pub type MilliDegreesCelsius = i32;
+
+/// Physically plausible range of temperature samples (at or above absolute zero).
+pub const TEMPERATURE_RANGE: ValidRange<DegreesCelsius> =
+    ValidRange::new(-273.15, DegreesCelsius::MAX);
+
+/// A temperature in degrees Celsius.
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Celsius(pub f32);
+
+/// A temperature in degrees Fahrenheit.
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Fahrenheit(pub f32);
+
+/// A temperature in kelvin.
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Kelvin(pub f32);
+
+impl From<DegreesCelsius> for Celsius {
+    #[inline]
+    fn from(celsius: DegreesCelsius) -> Self {
+        Self(celsius)
+    }
+}
+
+impl From<Celsius> for DegreesCelsius {
+    #[inline]
+    fn from(celsius: Celsius) -> Self {
+        celsius.0
+    }
+}
+
+impl From<Celsius> for Fahrenheit {
+    #[inline]
+    fn from(celsius: Celsius) -> Self {
+        Self(units::celsius_to_fahrenheit(celsius.0))
+    }
+}
+
+impl From<Celsius> for Kelvin {
+    #[inline]
+    fn from(celsius: Celsius) -> Self {
+        Self(units::celsius_to_kelvin(celsius.0))
+    }
+}
+
+impl From<Fahrenheit> for Celsius {
+    #[inline]
+    fn from(fahrenheit: Fahrenheit) -> Self {
+        Self(units::fahrenheit_to_celsius(fahrenheit.0))
+    }
+}
+
+impl From<Fahrenheit> for Kelvin {
+    #[inline]
+    fn from(fahrenheit: Fahrenheit) -> Self {
+        Self(units::fahrenheit_to_kelvin(fahrenheit.0))
+    }
+}
+
+impl From<Kelvin> for Celsius {
+    #[inline]
+    fn from(kelvin: Kelvin) -> Self {
+        Self(units::kelvin_to_celsius(kelvin.0))
+    }
+}
+
+impl From<Kelvin> for Fahrenheit {
+    #[inline]
+    fn from(kelvin: Kelvin) -> Self {
+        Self(units::kelvin_to_fahrenheit(kelvin.0))
+    }
+}
+
+/// Configuration of a temperature sensor.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TemperatureConfig {
+    /// Rate at which samples are converted in continuous mode, in hertz.
+    pub conversion_rate: Hertz,
+    /// Whether samples are converted continuously or on demand.
+    pub mode: OperatingMode,
+    /// Active level of the alert output.
+    pub alert_polarity: AlertPolarity,
+}
+
+impl TemperatureConfig {
+    /// Creates a new temperature sensor configuration.
+    pub const fn new(
+        conversion_rate: Hertz,
+        mode: OperatingMode,
+        alert_polarity: AlertPolarity,
+    ) -> Self {
+        Self {
+            conversion_rate,
+            mode,
+            alert_polarity,
+        }
+    }
+}
+
+/// Marker type of the temperature quantity, of which every [`TemperatureSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Temperature;
+
 /// Blocking Temperature Sensor methods.
-pub trait TemperatureSensor: ErrorType {
+pub trait TemperatureSensor: Sensor<Temperature, Sample = DegreesCelsius> {
     /// Returns a temperature sample in degrees Celsius.
     fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error>;
 }
@@ -59,6 +189,14 @@ impl<T: TemperatureSensor + ?Sized> TemperatureSensor for &mut T {
     }
 }
 
+impl_sensor_refinement!(
+    blocking,
+    Temperature,
+    TemperatureSensor,
+    temperature,
+    DegreesCelsius
+);
+
 // This macro generates the following blocking threshold traits:
 //
 // pub trait TemperatureThresholdSet: TemperatureSensor {
@@ -69,6 +207,11 @@ impl<T: TemperatureSensor + ?Sized> TemperatureSensor for &mut T {
 // pub trait TemperatureHysteresis: TemperatureThresholdSet {
 //     fn set_temperature_threshold_hysteresis(&mut self, hysteresis: DegreesCelsius) -> Result<(), Self::Error>;
 // }
+//
+// pub trait TemperatureThresholdControl: TemperatureThresholdSet {
+//     fn disable_temperature_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_temperature_alert(&mut self) -> Result<(), Self::Error>;
+// }
 decl_threshold_traits!(
     blocking,
     Temperature,
@@ -77,6 +220,89 @@ decl_threshold_traits!(
     "degrees Celsius"
 );
 
+// This macro generates the following blocking configuration trait:
+//
+// pub trait TemperatureConfigure: TemperatureSensor {
+//     fn set_temperature_config(&mut self, config: TemperatureConfig) -> Result<(), Self::Error>;
+// }
+decl_configuration_trait!(blocking, Temperature, TemperatureSensor, TemperatureConfig);
+
+// This macro generates the following blocking calibration trait:
+//
+// pub trait TemperatureCalibrate: TemperatureSensor {
+//     fn set_temperature_offset(&mut self, offset: DegreesCelsius) -> Result<(), Self::Error>;
+//     fn set_temperature_gain(&mut self, gain: f32) -> Result<(), Self::Error>;
+// }
+decl_calibration_trait!(
+    blocking,
+    Temperature,
+    TemperatureSensor,
+    DegreesCelsius,
+    "degrees Celsius"
+);
+
+// This macro generates the following blocking sample read trait:
+//
+// pub trait TemperatureSampleRead: TemperatureSensor {
+//     fn temperature_sample(&mut self) -> Result<Sample<DegreesCelsius>, Self::Error>;
+// }
+decl_sample_read_trait!(blocking, Temperature, TemperatureSensor, DegreesCelsius);
+
+// This macro generates the following blocking fixed-point trait:
+//
+// pub trait FixedPointTemperatureSensor: ErrorType {
+//     fn temperature_milli(&mut self) -> Result<MilliDegreesCelsius, Self::Error>;
+// }
+decl_fixed_point_trait!(
+    blocking,
+    Temperature,
+    MilliDegreesCelsius,
+    "millidegrees Celsius"
+);
+
+// This macro generates the following blocking fixed-point threshold traits:
+//
+// pub trait FixedPointTemperatureThresholdSet: FixedPointTemperatureSensor {
+//     fn set_temperature_threshold_low_milli(&mut self, threshold: MilliDegreesCelsius) -> Result<(), Self::Error>;
+//     fn set_temperature_threshold_high_milli(&mut self, threshold: MilliDegreesCelsius) -> Result<(), Self::Error>;
+// }
+//
+// pub trait FixedPointTemperatureHysteresis: FixedPointTemperatureThresholdSet {
+//     fn set_temperature_threshold_hysteresis_milli(&mut self, hysteresis: MilliDegreesCelsius) -> Result<(), Self::Error>;
+// }
+decl_fixed_point_threshold_traits!(
+    blocking,
+    Temperature,
+    MilliDegreesCelsius,
+    "millidegrees Celsius"
+);
+
+// This macro generates the following blocking fixed-point calibration trait:
+//
+// pub trait FixedPointTemperatureCalibrate: FixedPointTemperatureSensor {
+//     fn set_temperature_offset_milli(&mut self, offset: MilliDegreesCelsius) -> Result<(), Self::Error>;
+//     fn set_temperature_gain_milli(&mut self, gain: u32) -> Result<(), Self::Error>;
+// }
+decl_fixed_point_calibration_trait!(
+    blocking,
+    Temperature,
+    MilliDegreesCelsius,
+    "millidegrees Celsius"
+);
+
+// This macro generates the following blocking multi-channel traits:
+//
+// pub trait MultiChannelTemperatureSensor: ErrorType {
+//     fn channel_count(&self) -> Channel;
+//     fn temperature_on(&mut self, channel: Channel) -> Result<DegreesCelsius, Self::Error>;
+// }
+//
+// pub trait MultiChannelTemperatureThresholdSet: MultiChannelTemperatureSensor {
+//     fn set_temperature_threshold_low_on(&mut self, channel: Channel, threshold: DegreesCelsius) -> Result<(), Self::Error>;
+//     fn set_temperature_threshold_high_on(&mut self, channel: Channel, threshold: DegreesCelsius) -> Result<(), Self::Error>;
+// }
+decl_channel_traits!(blocking, Temperature, DegreesCelsius, "degrees Celsius");
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,6 +338,8 @@ mod tests {
         }
     }
 
+    impl TemperatureSampleRead for MockTempSensor {}
+
     impl TemperatureThresholdSet for MockTempSensor {
         fn set_temperature_threshold_low(
             &mut self,
@@ -130,6 +358,18 @@ mod tests {
         }
     }
 
+    impl TemperatureThresholdControl for MockTempSensor {
+        fn disable_temperature_thresholds(&mut self) -> Result<(), Self::Error> {
+            self.threshold_low = None;
+            self.threshold_high = None;
+            Ok(())
+        }
+
+        fn clear_temperature_alert(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
     impl TemperatureHysteresis for MockTempSensor {
         fn set_temperature_threshold_hysteresis(
             &mut self,
@@ -168,6 +408,20 @@ mod tests {
         assert_approx_eq!(value, TEST_TEMP);
     }
 
+    #[test]
+    fn test_temperature_sample_read_mut_ref() {
+        let mut sensor = MockTempSensor {
+            value: TEST_TEMP,
+            threshold_low: None,
+            threshold_high: None,
+            hysteresis: None,
+        };
+        let mut_ref = &mut sensor;
+        let sample = mut_ref.temperature_sample().unwrap();
+        assert_approx_eq!(sample.value, TEST_TEMP);
+        assert!(sample.quality.is_empty());
+    }
+
     #[test]
     fn test_temperature_threshold_set_low() {
         let mut sensor = MockTempSensor {
@@ -218,6 +472,21 @@ mod tests {
         assert_approx_eq!(sensor.threshold_high.unwrap(), high_threshold);
     }
 
+    #[test]
+    fn test_temperature_threshold_control_mut_ref() {
+        let mut sensor = MockTempSensor {
+            value: TEST_TEMP,
+            threshold_low: Some(15.0),
+            threshold_high: Some(35.0),
+            hysteresis: None,
+        };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.clear_temperature_alert().is_ok());
+        assert!(mut_ref.disable_temperature_thresholds().is_ok());
+        assert!(sensor.threshold_low.is_none());
+        assert!(sensor.threshold_high.is_none());
+    }
+
     #[test]
     fn test_temperature_hysteresis() {
         let mut sensor = MockTempSensor {
@@ -246,4 +515,193 @@ mod tests {
         assert!(result.is_ok());
         assert_approx_eq!(sensor.hysteresis.unwrap(), hyst);
     }
+
+    #[test]
+    fn test_temperature_range() {
+        assert!(TEMPERATURE_RANGE.validate(TEST_TEMP).is_ok());
+        assert!(TEMPERATURE_RANGE.validate(-273.15).is_ok());
+        assert_eq!(
+            TEMPERATURE_RANGE.validate(-300.0),
+            Err(ErrorKind::OutOfRange)
+        );
+        assert_eq!(
+            TEMPERATURE_RANGE.validate(DegreesCelsius::INFINITY),
+            Err(ErrorKind::OutOfRange)
+        );
+        assert_eq!(
+            TEMPERATURE_RANGE.validate(DegreesCelsius::NAN),
+            Err(ErrorKind::OutOfRange)
+        );
+    }
+
+    struct MockConfigurableTempSensor {
+        config: TemperatureConfig,
+        offset: DegreesCelsius,
+        gain: f32,
+    }
+
+    impl crate::sensor::ErrorType for MockConfigurableTempSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockConfigurableTempSensor {
+        fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            Ok(TEST_TEMP * self.gain + self.offset)
+        }
+    }
+
+    impl TemperatureCalibrate for MockConfigurableTempSensor {
+        fn set_temperature_offset(&mut self, offset: DegreesCelsius) -> Result<(), Self::Error> {
+            self.offset = offset;
+            Ok(())
+        }
+
+        fn set_temperature_gain(&mut self, gain: f32) -> Result<(), Self::Error> {
+            self.gain = gain;
+            Ok(())
+        }
+    }
+
+    impl TemperatureConfigure for MockConfigurableTempSensor {
+        fn set_temperature_config(&mut self, config: TemperatureConfig) -> Result<(), Self::Error> {
+            self.config = config;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_temperature_configure_mut_ref() {
+        let mut sensor = MockConfigurableTempSensor {
+            config: TemperatureConfig::default(),
+            offset: 0.0,
+            gain: 1.0,
+        };
+        assert_eq!(sensor.config.mode, OperatingMode::Continuous);
+        let config = TemperatureConfig::new(8.0, OperatingMode::OneShot, AlertPolarity::ActiveHigh);
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_temperature_config(config).is_ok());
+        assert_eq!(sensor.config, config);
+    }
+
+    #[test]
+    fn test_temperature_calibrate_mut_ref() {
+        let mut sensor = MockConfigurableTempSensor {
+            config: TemperatureConfig::default(),
+            offset: 0.0,
+            gain: 1.0,
+        };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_temperature_gain(2.0).is_ok());
+        assert!(mut_ref.set_temperature_offset(-1.5).is_ok());
+        assert_approx_eq!(mut_ref.temperature().unwrap(), TEST_TEMP * 2.0 - 1.5);
+    }
+
+    struct MockRemoteDiodeSensor {
+        temperatures: [DegreesCelsius; 3],
+        thresholds: [(DegreesCelsius, DegreesCelsius); 3],
+    }
+
+    impl crate::sensor::ErrorType for MockRemoteDiodeSensor {
+        type Error = MockError;
+    }
+
+    impl MultiChannelTemperatureSensor for MockRemoteDiodeSensor {
+        fn channel_count(&self) -> u8 {
+            self.temperatures.len() as u8
+        }
+
+        fn temperature_on(&mut self, channel: u8) -> Result<DegreesCelsius, Self::Error> {
+            self.temperatures
+                .get(channel as usize)
+                .copied()
+                .ok_or(MockError)
+        }
+    }
+
+    impl MultiChannelTemperatureThresholdSet for MockRemoteDiodeSensor {
+        fn set_temperature_threshold_low_on(
+            &mut self,
+            channel: u8,
+            threshold: DegreesCelsius,
+        ) -> Result<(), Self::Error> {
+            self.thresholds
+                .get_mut(channel as usize)
+                .ok_or(MockError)?
+                .0 = threshold;
+            Ok(())
+        }
+
+        fn set_temperature_threshold_high_on(
+            &mut self,
+            channel: u8,
+            threshold: DegreesCelsius,
+        ) -> Result<(), Self::Error> {
+            self.thresholds
+                .get_mut(channel as usize)
+                .ok_or(MockError)?
+                .1 = threshold;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_multi_channel_temperature_sensor_mut_ref() {
+        let mut sensor = MockRemoteDiodeSensor {
+            temperatures: [TEST_TEMP, 61.5, 48.25],
+            thresholds: [(0.0, 0.0); 3],
+        };
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.channel_count(), 3);
+        assert_approx_eq!(mut_ref.temperature_on(0).unwrap(), TEST_TEMP);
+        assert_approx_eq!(mut_ref.temperature_on(2).unwrap(), 48.25);
+        assert!(mut_ref.temperature_on(3).is_err());
+    }
+
+    #[test]
+    fn test_multi_channel_temperature_threshold_set_mut_ref() {
+        let mut sensor = MockRemoteDiodeSensor {
+            temperatures: [TEST_TEMP; 3],
+            thresholds: [(0.0, 0.0); 3],
+        };
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.set_temperature_threshold_low_on(1, 5.0).is_ok());
+        assert!(mut_ref.set_temperature_threshold_high_on(1, 85.0).is_ok());
+        assert!(mut_ref.set_temperature_threshold_high_on(3, 85.0).is_err());
+        assert_eq!(sensor.thresholds, [(0.0, 0.0), (5.0, 85.0), (0.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_temperature_unit_conversions() {
+        let boiling = Celsius(100.0);
+        assert_approx_eq!(Fahrenheit::from(boiling).0, 212.0);
+        assert_approx_eq!(Kelvin::from(boiling).0, 373.15);
+        assert_approx_eq!(Celsius::from(Fahrenheit(-40.0)).0, -40.0);
+        assert_approx_eq!(Fahrenheit::from(Kelvin(0.0)).0, -459.67, 1e-3);
+        assert_approx_eq!(Kelvin::from(Fahrenheit(32.0)).0, 273.15);
+
+        let sample: DegreesCelsius = Celsius::from(Kelvin(300.0)).into();
+        assert_approx_eq!(sample, 26.85, 1e-4);
+        assert!(Celsius::from(TEST_TEMP) > Celsius(0.0));
+    }
+
+    struct MockFixedPointTempSensor;
+
+    impl crate::sensor::ErrorType for MockFixedPointTempSensor {
+        type Error = MockError;
+    }
+
+    impl FixedPointTemperatureSensor for MockFixedPointTempSensor {
+        fn temperature_milli(&mut self) -> Result<MilliDegreesCelsius, Self::Error> {
+            // 16-bit sample in 1/128 °C, converted without floating-point arithmetic
+            let raw: i16 = 0x0D80;
+            Ok(raw as i32 * 1_000 / 128)
+        }
+    }
+
+    #[test]
+    fn test_fixed_point_temperature_sensor_mut_ref() {
+        let mut sensor = MockFixedPointTempSensor;
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.temperature_milli().unwrap(), 27_000);
+    }
 }
@@ -0,0 +1,227 @@
+//! Blocking Capacitive Touch Sensor API
+//!
+//! This API provides generic methods for interfacing with capacitive touch and proximity
+//! controllers (e.g. for keypads and sliders) specifically.
+//!
+//! A controller has one or more electrodes, identified by their index. The raw capacitance count
+//! of each electrode can be sampled, while the touch status of all electrodes is reported as a
+//! [`TouchMask`] in which bit `i` is set if electrode `i` is touched.
+//!
+//! Controllers able to sample and set touch thresholds of a single electrode can implement the
+//! [`MultiChannelCapacitanceSensor`] and [`MultiChannelCapacitanceThresholdSet`] traits, each
+//! electrode being a channel.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the TouchSensor trait for a touch controller.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::touch::{CapacitanceCounts, TouchMask, TouchSensor};
+//!
+//! // A struct representing an 8-electrode capacitive touch controller.
+//! pub struct MyTouchController {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyTouchController {
+//!     type Error = Error;
+//! }
+//!
+//! impl TouchSensor for MyTouchController {
+//!     fn electrode_count(&self) -> usize {
+//!         8
+//!     }
+//!
+//!     fn counts(&mut self, counts: &mut [CapacitanceCounts]) -> Result<usize, Self::Error> {
+//!         // Read delta count registers of controller...
+//!         let count = counts.len().min(8);
+//!         counts[..count].fill(0);
+//!         Ok(count)
+//!     }
+//!
+//!     fn touched(&mut self) -> Result<TouchMask, Self::Error> {
+//!         // Read touch status register of controller...
+//!         Ok(0b0000_0100)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::Sensor;
+use crate::{decl_channel_traits, impl_sensor_refinement};
+
+/// Associates the units raw electrode capacitance samples are measured in (device-specific
+/// counts) with the underlying data type.
+pub type CapacitanceCounts = i32;
+
+/// Touch status of the electrodes of a controller, bit `i` being set if electrode `i` is touched.
+pub type TouchMask = u32;
+
+/// Maximum number of electrodes whose touch status can be reported in a [`TouchMask`].
+pub const MAX_ELECTRODES: usize = TouchMask::BITS as usize;
+
+/// Marker type of the touch quantity, of which every [`TouchSensor`] implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Touch;
+
+/// Blocking Capacitive Touch Sensor methods.
+pub trait TouchSensor: Sensor<Touch, Sample = TouchMask> {
+    /// Returns the number of electrodes of the controller, at most [`MAX_ELECTRODES`].
+    fn electrode_count(&self) -> usize;
+
+    /// Fills `counts` with a capacitance sample of each electrode, in device-specific counts.
+    ///
+    /// Returns the number of samples written, which is less than `counts.len()` if the
+    /// controller has fewer electrodes.
+    fn counts(&mut self, counts: &mut [CapacitanceCounts]) -> Result<usize, Self::Error>;
+
+    /// Returns the touch status of every electrode.
+    fn touched(&mut self) -> Result<TouchMask, Self::Error>;
+
+    /// Returns true if the given electrode is touched.
+    ///
+    /// Electrodes the controller does not have are never touched.
+    fn is_touched(&mut self, electrode: usize) -> Result<bool, Self::Error> {
+        let mask = self.touched()?;
+        Ok(electrode < MAX_ELECTRODES && mask & (1 << electrode) != 0)
+    }
+}
+
+impl<T: TouchSensor + ?Sized> TouchSensor for &mut T {
+    #[inline]
+    fn electrode_count(&self) -> usize {
+        T::electrode_count(self)
+    }
+
+    #[inline]
+    fn counts(&mut self, counts: &mut [CapacitanceCounts]) -> Result<usize, Self::Error> {
+        T::counts(self, counts)
+    }
+
+    #[inline]
+    fn touched(&mut self) -> Result<TouchMask, Self::Error> {
+        T::touched(self)
+    }
+
+    #[inline]
+    fn is_touched(&mut self, electrode: usize) -> Result<bool, Self::Error> {
+        T::is_touched(self, electrode)
+    }
+}
+
+impl_sensor_refinement!(blocking, Touch, TouchSensor, touched, TouchMask);
+
+// This macro generates the following blocking multi-channel traits:
+//
+// pub trait MultiChannelCapacitanceSensor: ErrorType {
+//     fn channel_count(&self) -> Channel;
+//     fn capacitance_on(&mut self, channel: Channel) -> Result<CapacitanceCounts, Self::Error>;
+// }
+//
+// pub trait MultiChannelCapacitanceThresholdSet: MultiChannelCapacitanceSensor {
+//     fn set_capacitance_threshold_low_on(&mut self, channel: Channel, threshold: CapacitanceCounts) -> Result<(), Self::Error>;
+//     fn set_capacitance_threshold_high_on(&mut self, channel: Channel, threshold: CapacitanceCounts) -> Result<(), Self::Error>;
+// }
+decl_channel_traits!(
+    blocking,
+    Capacitance,
+    CapacitanceCounts,
+    "device-specific counts"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Channel, Error, ErrorKind};
+
+    // Mock test values
+    const TEST_COUNTS: [CapacitanceCounts; 3] = [12, -3, 87];
+    const TEST_TOUCHED: TouchMask = 0b100;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockTouchController {}
+
+    impl crate::sensor::ErrorType for MockTouchController {
+        type Error = MockError;
+    }
+
+    impl TouchSensor for MockTouchController {
+        fn electrode_count(&self) -> usize {
+            TEST_COUNTS.len()
+        }
+
+        fn counts(&mut self, counts: &mut [CapacitanceCounts]) -> Result<usize, Self::Error> {
+            let count = counts.len().min(TEST_COUNTS.len());
+            counts[..count].copy_from_slice(&TEST_COUNTS[..count]);
+            Ok(count)
+        }
+
+        fn touched(&mut self) -> Result<TouchMask, Self::Error> {
+            Ok(TEST_TOUCHED)
+        }
+    }
+
+    impl MultiChannelCapacitanceSensor for MockTouchController {
+        fn channel_count(&self) -> Channel {
+            TEST_COUNTS.len() as Channel
+        }
+
+        fn capacitance_on(&mut self, channel: Channel) -> Result<CapacitanceCounts, Self::Error> {
+            TEST_COUNTS.get(channel as usize).copied().ok_or(MockError)
+        }
+    }
+
+    #[test]
+    fn test_touch_sensor_trait() {
+        let mut sensor = MockTouchController {};
+        let mut counts = [0; 4];
+        assert_eq!(sensor.counts(&mut counts).unwrap(), 3);
+        assert_eq!(counts[..3], TEST_COUNTS);
+
+        assert!(sensor.is_touched(2).unwrap());
+        assert!(!sensor.is_touched(0).unwrap());
+        assert!(!sensor.is_touched(MAX_ELECTRODES).unwrap());
+    }
+
+    #[test]
+    fn test_touch_sensor_trait_mut_ref() {
+        let mut sensor = MockTouchController {};
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.electrode_count(), 3);
+
+        let result = mut_ref.touched();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_TOUCHED);
+    }
+
+    #[test]
+    fn test_multi_channel_capacitance_sensor_mut_ref() {
+        let mut sensor = MockTouchController {};
+        let mut_ref = &mut sensor;
+        assert_eq!(mut_ref.channel_count(), 3);
+        assert_eq!(mut_ref.capacitance_on(1).unwrap(), TEST_COUNTS[1]);
+        assert!(mut_ref.capacitance_on(3).is_err());
+    }
+}
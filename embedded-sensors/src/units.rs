@@ -0,0 +1,179 @@
+//! Unit Conversion Helpers
+//!
+//! This module provides standalone conversion functions between the units samples are
+//! reported in and other commonly displayed units.
+//!
+//! Sensor traits always report samples in a single canonical unit (e.g. degrees Celsius),
+//! so these helpers are intended for display and logging layers which need to present
+//! samples in another unit without embedding ad-hoc conversion constants.
+//!
+//! ```
+//! use embedded_sensors_hal::units;
+//!
+//! let fahrenheit = units::celsius_to_fahrenheit(100.0);
+//! assert_eq!(fahrenheit, 212.0);
+//! ```
+
+/// Offset between the Celsius and Kelvin scales.
+pub const KELVIN_OFFSET: f32 = 273.15;
+
+/// Number of pascals in one hectopascal.
+pub const PASCALS_PER_HECTOPASCAL: f32 = 100.0;
+
+/// Number of pascals in one inch of mercury (at 0 °C).
+pub const PASCALS_PER_INCH_OF_MERCURY: f32 = 3386.389;
+
+/// Number of pascals in one millimeter of mercury (at 0 °C).
+pub const PASCALS_PER_MILLIMETER_OF_MERCURY: f32 = 133.322_39;
+
+/// Standard acceleration of gravity in meters per second squared.
+pub const STANDARD_GRAVITY: f32 = 9.806_65;
+
+/// Number of lux in one foot-candle.
+pub const LUX_PER_FOOT_CANDLE: f32 = 10.763_91;
+
+/// Converts degrees Celsius to degrees Fahrenheit.
+#[inline]
+pub fn celsius_to_fahrenheit(celsius: f32) -> f32 {
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+/// Converts degrees Fahrenheit to degrees Celsius.
+#[inline]
+pub fn fahrenheit_to_celsius(fahrenheit: f32) -> f32 {
+    (fahrenheit - 32.0) * 5.0 / 9.0
+}
+
+/// Converts degrees Celsius to kelvin.
+#[inline]
+pub fn celsius_to_kelvin(celsius: f32) -> f32 {
+    celsius + KELVIN_OFFSET
+}
+
+/// Converts kelvin to degrees Celsius.
+#[inline]
+pub fn kelvin_to_celsius(kelvin: f32) -> f32 {
+    kelvin - KELVIN_OFFSET
+}
+
+/// Converts degrees Fahrenheit to kelvin.
+#[inline]
+pub fn fahrenheit_to_kelvin(fahrenheit: f32) -> f32 {
+    celsius_to_kelvin(fahrenheit_to_celsius(fahrenheit))
+}
+
+/// Converts kelvin to degrees Fahrenheit.
+#[inline]
+pub fn kelvin_to_fahrenheit(kelvin: f32) -> f32 {
+    celsius_to_fahrenheit(kelvin_to_celsius(kelvin))
+}
+
+/// Converts pascals to hectopascals.
+#[inline]
+pub fn pascals_to_hectopascals(pascals: f32) -> f32 {
+    pascals / PASCALS_PER_HECTOPASCAL
+}
+
+/// Converts hectopascals to pascals.
+#[inline]
+pub fn hectopascals_to_pascals(hectopascals: f32) -> f32 {
+    hectopascals * PASCALS_PER_HECTOPASCAL
+}
+
+/// Converts pascals to inches of mercury.
+#[inline]
+pub fn pascals_to_inches_of_mercury(pascals: f32) -> f32 {
+    pascals / PASCALS_PER_INCH_OF_MERCURY
+}
+
+/// Converts inches of mercury to pascals.
+#[inline]
+pub fn inches_of_mercury_to_pascals(inches: f32) -> f32 {
+    inches * PASCALS_PER_INCH_OF_MERCURY
+}
+
+/// Converts pascals to millimeters of mercury.
+#[inline]
+pub fn pascals_to_millimeters_of_mercury(pascals: f32) -> f32 {
+    pascals / PASCALS_PER_MILLIMETER_OF_MERCURY
+}
+
+/// Converts millimeters of mercury to pascals.
+#[inline]
+pub fn millimeters_of_mercury_to_pascals(millimeters: f32) -> f32 {
+    millimeters * PASCALS_PER_MILLIMETER_OF_MERCURY
+}
+
+/// Converts an acceleration in meters per second squared to multiples of standard gravity (g).
+#[inline]
+pub fn meters_per_second_squared_to_g(acceleration: f32) -> f32 {
+    acceleration / STANDARD_GRAVITY
+}
+
+/// Converts an acceleration in multiples of standard gravity (g) to meters per second squared.
+#[inline]
+pub fn g_to_meters_per_second_squared(g: f32) -> f32 {
+    g * STANDARD_GRAVITY
+}
+
+/// Converts lux to foot-candles.
+#[inline]
+pub fn lux_to_foot_candles(lux: f32) -> f32 {
+    lux / LUX_PER_FOOT_CANDLE
+}
+
+/// Converts foot-candles to lux.
+#[inline]
+pub fn foot_candles_to_lux(foot_candles: f32) -> f32 {
+    foot_candles * LUX_PER_FOOT_CANDLE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    #[test]
+    fn test_celsius_fahrenheit() {
+        assert_approx_eq!(celsius_to_fahrenheit(0.0), 32.0);
+        assert_approx_eq!(celsius_to_fahrenheit(100.0), 212.0);
+        assert_approx_eq!(celsius_to_fahrenheit(-40.0), -40.0);
+        assert_approx_eq!(fahrenheit_to_celsius(212.0), 100.0);
+        assert_approx_eq!(fahrenheit_to_celsius(98.6), 37.0, 1e-4);
+    }
+
+    #[test]
+    fn test_celsius_kelvin() {
+        assert_approx_eq!(celsius_to_kelvin(0.0), 273.15);
+        assert_approx_eq!(celsius_to_kelvin(-273.15), 0.0);
+        assert_approx_eq!(kelvin_to_celsius(300.0), 26.85, 1e-4);
+    }
+
+    #[test]
+    fn test_fahrenheit_kelvin() {
+        assert_approx_eq!(fahrenheit_to_kelvin(32.0), 273.15);
+        assert_approx_eq!(kelvin_to_fahrenheit(373.15), 212.0, 1e-4);
+    }
+
+    #[test]
+    fn test_pressure() {
+        assert_approx_eq!(pascals_to_hectopascals(101_325.0), 1013.25);
+        assert_approx_eq!(hectopascals_to_pascals(1013.25), 101_325.0);
+        assert_approx_eq!(pascals_to_inches_of_mercury(101_325.0), 29.921, 1e-3);
+        assert_approx_eq!(inches_of_mercury_to_pascals(29.921), 101_325.0, 1.0);
+        assert_approx_eq!(pascals_to_millimeters_of_mercury(101_325.0), 760.0, 1e-2);
+        assert_approx_eq!(millimeters_of_mercury_to_pascals(760.0), 101_325.0, 1.0);
+    }
+
+    #[test]
+    fn test_acceleration() {
+        assert_approx_eq!(meters_per_second_squared_to_g(STANDARD_GRAVITY), 1.0);
+        assert_approx_eq!(g_to_meters_per_second_squared(2.0), 19.6133, 1e-4);
+    }
+
+    #[test]
+    fn test_illuminance() {
+        assert_approx_eq!(lux_to_foot_candles(LUX_PER_FOOT_CANDLE), 1.0);
+        assert_approx_eq!(foot_candles_to_lux(10.0), 107.6391, 1e-3);
+    }
+}
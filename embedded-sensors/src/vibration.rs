@@ -0,0 +1,607 @@
+//! Blocking Vibration Sensor API
+//!
+//! This API provides generic methods for interfacing with vibration sensors specifically.
+//!
+//! The [`VibrationSpectrum`] trait exposes the frequency-domain view of vibration used by
+//! predictive-maintenance applications: magnitude bins of the acceleration spectrum and its
+//! dominant frequency. It is implemented by sensors with a built-in spectral engine, or by the
+//! [`FftSpectrum`] adapter (enabled by the `fft` feature) over buffered acceleration samples.
+//!
+//! Condition-monitoring sensors instead report the overall RMS vibration velocity or
+//! acceleration through the [`VibrationVelocitySensor`] and [`VibrationAccelerationSensor`]
+//! traits. Their threshold traits allow alarms to be raised on the boundaries of the ISO 10816
+//! [`VibrationZone`]s.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the VibrationSpectrum trait for a vibration
+//! sensor with a built-in spectral engine.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::vibration::{
+//!     Hertz, MetersPerSecondSquared, SpectralPeak, VibrationSpectrum,
+//! };
+//!
+//! // A struct representing a vibration sensor.
+//! pub struct MyVibrationSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyVibrationSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl VibrationSpectrum for MyVibrationSensor {
+//!     fn bin_width(&self) -> Hertz {
+//!         // Output data rate divided by FFT length...
+//!         3.125
+//!     }
+//!
+//!     fn spectrum(&mut self, bins: &mut [MetersPerSecondSquared]) -> Result<usize, Self::Error> {
+//!         // Read spectrum bins from sensor...
+//!         bins.fill(0.0);
+//!         Ok(bins.len())
+//!     }
+//!
+//!     fn dominant_frequency(&mut self) -> Result<SpectralPeak, Self::Error> {
+//!         // Read peak registers of sensor...
+//!         Ok(SpectralPeak {
+//!             frequency: 50.0,
+//!             magnitude: 0.8,
+//!         })
+//!     }
+//! }
+//! ```
+
+pub use crate::frequency::Hertz;
+use crate::sensor::{ErrorType, Sensor};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units acceleration magnitudes are measured in with the underlying data type.
+pub type MetersPerSecondSquared = f32;
+
+/// Associates the units vibration velocities are measured in with the underlying data type.
+pub type MillimetersPerSecond = f32;
+
+/// A peak of a vibration spectrum.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SpectralPeak {
+    /// Frequency of the peak.
+    pub frequency: Hertz,
+    /// Acceleration amplitude at the peak.
+    pub magnitude: MetersPerSecondSquared,
+}
+
+/// Blocking Vibration Spectrum methods.
+pub trait VibrationSpectrum: ErrorType {
+    /// Returns the width of each spectrum bin in hertz.
+    ///
+    /// Bin `i` is centered on the frequency `i * bin_width()`, bin 0 being the DC component.
+    fn bin_width(&self) -> Hertz;
+
+    /// Fills `bins` with the acceleration amplitude of consecutive spectrum bins, starting with
+    /// the DC component.
+    ///
+    /// Returns the number of bins written, which is less than `bins.len()` if the spectrum has
+    /// fewer bins.
+    fn spectrum(&mut self, bins: &mut [MetersPerSecondSquared]) -> Result<usize, Self::Error>;
+
+    /// Returns the peak with the largest amplitude, excluding the DC component.
+    fn dominant_frequency(&mut self) -> Result<SpectralPeak, Self::Error>;
+}
+
+impl<T: VibrationSpectrum + ?Sized> VibrationSpectrum for &mut T {
+    #[inline]
+    fn bin_width(&self) -> Hertz {
+        T::bin_width(self)
+    }
+
+    #[inline]
+    fn spectrum(&mut self, bins: &mut [MetersPerSecondSquared]) -> Result<usize, Self::Error> {
+        T::spectrum(self, bins)
+    }
+
+    #[inline]
+    fn dominant_frequency(&mut self) -> Result<SpectralPeak, Self::Error> {
+        T::dominant_frequency(self)
+    }
+}
+
+/// Vibration severity zone of a machine, as defined by ISO 10816.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum VibrationZone {
+    /// Vibration typical of newly commissioned machines.
+    A,
+    /// Acceptable for unrestricted long-term operation.
+    B,
+    /// Unsatisfactory for long-term operation, remedial action should be scheduled.
+    C,
+    /// Severe enough to cause damage to the machine.
+    D,
+}
+
+/// Boundaries between the [`VibrationZone`]s of a machine, in RMS vibration velocity.
+///
+/// Boundaries depend on the class and mounting of the machine, see ISO 10816. They are typically
+/// programmed as thresholds of a [`VibrationVelocityThresholdSet`] implementor, e.g. raising an
+/// alarm when entering zone C and tripping the machine when entering zone D.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct VibrationZoneBoundaries {
+    /// Boundary between zones A and B.
+    pub ab: MillimetersPerSecond,
+    /// Boundary between zones B and C.
+    pub bc: MillimetersPerSecond,
+    /// Boundary between zones C and D.
+    pub cd: MillimetersPerSecond,
+}
+
+impl VibrationZoneBoundaries {
+    /// Creates new zone boundaries, in increasing order.
+    pub const fn new(
+        ab: MillimetersPerSecond,
+        bc: MillimetersPerSecond,
+        cd: MillimetersPerSecond,
+    ) -> Self {
+        Self { ab, bc, cd }
+    }
+
+    /// Returns the zone an RMS vibration velocity sample falls in.
+    ///
+    /// A sample equal to a boundary falls in the upper zone.
+    pub fn zone(&self, velocity: MillimetersPerSecond) -> VibrationZone {
+        if velocity >= self.cd {
+            VibrationZone::D
+        } else if velocity >= self.bc {
+            VibrationZone::C
+        } else if velocity >= self.ab {
+            VibrationZone::B
+        } else {
+            VibrationZone::A
+        }
+    }
+}
+
+/// Marker type of the vibration velocity quantity, of which every [`VibrationVelocitySensor`]
+/// implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct VibrationVelocity;
+
+/// Blocking Vibration Velocity Sensor methods.
+pub trait VibrationVelocitySensor:
+    Sensor<VibrationVelocity, Sample = MillimetersPerSecond>
+{
+    /// Returns an RMS vibration velocity sample in millimeters per second.
+    fn rms_velocity(&mut self) -> Result<MillimetersPerSecond, Self::Error>;
+}
+
+impl<T: VibrationVelocitySensor + ?Sized> VibrationVelocitySensor for &mut T {
+    #[inline]
+    fn rms_velocity(&mut self) -> Result<MillimetersPerSecond, Self::Error> {
+        T::rms_velocity(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    VibrationVelocity,
+    VibrationVelocitySensor,
+    rms_velocity,
+    MillimetersPerSecond
+);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait VibrationVelocityThresholdSet: VibrationVelocitySensor {
+//     fn set_vibration_velocity_threshold_low(&mut self, threshold: MillimetersPerSecond) -> Result<(), Self::Error>;
+//     fn set_vibration_velocity_threshold_high(&mut self, threshold: MillimetersPerSecond) -> Result<(), Self::Error>;
+// }
+//
+// pub trait VibrationVelocityHysteresis: VibrationVelocityThresholdSet {
+//     fn set_vibration_velocity_threshold_hysteresis(&mut self, hysteresis: MillimetersPerSecond) -> Result<(), Self::Error>;
+// }
+//
+// pub trait VibrationVelocityThresholdControl: VibrationVelocityThresholdSet {
+//     fn disable_vibration_velocity_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_vibration_velocity_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(
+    blocking,
+    VibrationVelocity,
+    VibrationVelocitySensor,
+    MillimetersPerSecond,
+    "millimeters per second"
+);
+
+/// Marker type of the vibration acceleration quantity, of which every
+/// [`VibrationAccelerationSensor`] implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct VibrationAcceleration;
+
+/// Blocking Vibration Acceleration Sensor methods.
+pub trait VibrationAccelerationSensor:
+    Sensor<VibrationAcceleration, Sample = MetersPerSecondSquared>
+{
+    /// Returns an RMS vibration acceleration sample in meters per second squared.
+    fn rms_acceleration(&mut self) -> Result<MetersPerSecondSquared, Self::Error>;
+}
+
+impl<T: VibrationAccelerationSensor + ?Sized> VibrationAccelerationSensor for &mut T {
+    #[inline]
+    fn rms_acceleration(&mut self) -> Result<MetersPerSecondSquared, Self::Error> {
+        T::rms_acceleration(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    VibrationAcceleration,
+    VibrationAccelerationSensor,
+    rms_acceleration,
+    MetersPerSecondSquared
+);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait VibrationAccelerationThresholdSet: VibrationAccelerationSensor {
+//     fn set_vibration_acceleration_threshold_low(&mut self, threshold: MetersPerSecondSquared) -> Result<(), Self::Error>;
+//     fn set_vibration_acceleration_threshold_high(&mut self, threshold: MetersPerSecondSquared) -> Result<(), Self::Error>;
+// }
+//
+// pub trait VibrationAccelerationHysteresis: VibrationAccelerationThresholdSet {
+//     fn set_vibration_acceleration_threshold_hysteresis(&mut self, hysteresis: MetersPerSecondSquared) -> Result<(), Self::Error>;
+// }
+//
+// pub trait VibrationAccelerationThresholdControl: VibrationAccelerationThresholdSet {
+//     fn disable_vibration_acceleration_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_vibration_acceleration_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(
+    blocking,
+    VibrationAcceleration,
+    VibrationAccelerationSensor,
+    MetersPerSecondSquared,
+    "meters per second squared"
+);
+
+#[cfg(feature = "fft")]
+pub use fft::FftSpectrum;
+
+#[cfg(feature = "fft")]
+mod fft {
+    use super::{Hertz, MetersPerSecondSquared, SpectralPeak, VibrationSpectrum};
+    use crate::sensor::{ErrorKind, ErrorType};
+    use core::f32::consts::PI;
+
+    /// Computes the vibration spectrum of the `N` most recent acceleration samples.
+    ///
+    /// Samples taken at a fixed rate are pushed into the adapter, which removes their mean
+    /// (reported as the DC component), applies a Hann window and a radix-2 FFT to produce
+    /// `N / 2 + 1` spectrum bins. `N` must be a power of two.
+    ///
+    /// Until `N` samples have been pushed, spectrum methods return [`ErrorKind::NotReady`].
+    #[derive(Debug, Clone)]
+    pub struct FftSpectrum<const N: usize> {
+        samples: [MetersPerSecondSquared; N],
+        len: usize,
+        next: usize,
+        sample_rate: Hertz,
+    }
+
+    impl<const N: usize> FftSpectrum<N> {
+        /// Creates a new adapter for samples taken at `sample_rate`.
+        pub fn new(sample_rate: Hertz) -> Self {
+            const { assert!(N >= 2 && N.is_power_of_two(), "N must be a power of two") };
+
+            Self {
+                samples: [0.0; N],
+                len: 0,
+                next: 0,
+                sample_rate,
+            }
+        }
+
+        /// Pushes an acceleration sample, evicting the oldest one if the buffer is full.
+        pub fn push(&mut self, sample: MetersPerSecondSquared) {
+            self.samples[self.next] = sample;
+            self.next = (self.next + 1) % N;
+            self.len = (self.len + 1).min(N);
+        }
+
+        /// Returns true once `N` samples have been pushed.
+        pub fn is_ready(&self) -> bool {
+            self.len == N
+        }
+
+        /// Discards all buffered samples.
+        pub fn clear(&mut self) {
+            self.len = 0;
+            self.next = 0;
+        }
+
+        // Returns the mean of the samples, and the real and imaginary parts of the spectrum of the
+        // windowed samples with their mean removed.
+        fn transform(&self) -> Result<(f32, [f32; N], [f32; N]), ErrorKind> {
+            if !self.is_ready() {
+                return Err(ErrorKind::NotReady);
+            }
+
+            // Removing the mean prevents it from leaking into the lowest bins through the window.
+            let mean = self.samples.iter().sum::<f32>() / N as f32;
+
+            // Window the samples in chronological order, storing them in bit-reversed order.
+            let bits = N.trailing_zeros();
+            let mut re = [0.0; N];
+            let mut im = [0.0; N];
+            for i in 0..N {
+                let window = 0.5 - 0.5 * libm::cosf(2.0 * PI * i as f32 / N as f32);
+                let j = i.reverse_bits() >> (usize::BITS - bits);
+                re[j] = window * (self.samples[(self.next + i) % N] - mean);
+            }
+
+            let mut size = 2;
+            while size <= N {
+                let angle = -2.0 * PI / size as f32;
+                for start in (0..N).step_by(size) {
+                    for k in 0..size / 2 {
+                        let (w_im, w_re) = libm::sincosf(angle * k as f32);
+                        let (a, b) = (start + k, start + k + size / 2);
+                        let t_re = w_re * re[b] - w_im * im[b];
+                        let t_im = w_re * im[b] + w_im * re[b];
+                        re[b] = re[a] - t_re;
+                        im[b] = im[a] - t_im;
+                        re[a] += t_re;
+                        im[a] += t_im;
+                    }
+                }
+                size *= 2;
+            }
+
+            Ok((mean, re, im))
+        }
+
+        // Amplitude of bin `k > 0`, corrected for the coherent gain of the Hann window.
+        fn amplitude(re: &[f32; N], im: &[f32; N], k: usize) -> MetersPerSecondSquared {
+            let scale = if k == N / 2 { 2.0 } else { 4.0 };
+            scale * libm::hypotf(re[k], im[k]) / N as f32
+        }
+    }
+
+    impl<const N: usize> ErrorType for FftSpectrum<N> {
+        type Error = ErrorKind;
+    }
+
+    impl<const N: usize> VibrationSpectrum for FftSpectrum<N> {
+        fn bin_width(&self) -> Hertz {
+            self.sample_rate / N as f32
+        }
+
+        fn spectrum(&mut self, bins: &mut [MetersPerSecondSquared]) -> Result<usize, Self::Error> {
+            let (mean, re, im) = self.transform()?;
+            let count = bins.len().min(N / 2 + 1);
+            for (k, bin) in bins[..count].iter_mut().enumerate() {
+                *bin = match k {
+                    0 => libm::fabsf(mean),
+                    _ => Self::amplitude(&re, &im, k),
+                };
+            }
+
+            Ok(count)
+        }
+
+        fn dominant_frequency(&mut self) -> Result<SpectralPeak, Self::Error> {
+            let (_, re, im) = self.transform()?;
+            let mut peak = SpectralPeak::default();
+            for k in 1..=N / 2 {
+                let magnitude = Self::amplitude(&re, &im, k);
+                if magnitude > peak.magnitude {
+                    peak = SpectralPeak {
+                        frequency: k as f32 * self.bin_width(),
+                        magnitude,
+                    };
+                }
+            }
+
+            Ok(peak)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_PEAK: SpectralPeak = SpectralPeak {
+        frequency: 120.0,
+        magnitude: 1.5,
+    };
+    const TEST_VELOCITY: MillimetersPerSecond = 3.2;
+    // ISO 10816-3, group 2 machines on rigid foundations.
+    const TEST_BOUNDARIES: VibrationZoneBoundaries = VibrationZoneBoundaries::new(1.4, 2.8, 4.5);
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockVibrationSensor {}
+
+    struct MockVelocitySensor {
+        value: MillimetersPerSecond,
+        threshold_low: Option<MillimetersPerSecond>,
+        threshold_high: Option<MillimetersPerSecond>,
+        hysteresis: Option<MillimetersPerSecond>,
+    }
+
+    impl MockVelocitySensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VELOCITY,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockVelocitySensor {
+        type Error = MockError;
+    }
+
+    impl VibrationVelocitySensor for MockVelocitySensor {
+        fn rms_velocity(&mut self) -> Result<MillimetersPerSecond, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl VibrationVelocityThresholdSet for MockVelocitySensor {
+        fn set_vibration_velocity_threshold_low(
+            &mut self,
+            threshold: MillimetersPerSecond,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_vibration_velocity_threshold_high(
+            &mut self,
+            threshold: MillimetersPerSecond,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl VibrationVelocityHysteresis for MockVelocitySensor {
+        fn set_vibration_velocity_threshold_hysteresis(
+            &mut self,
+            hysteresis: MillimetersPerSecond,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockVibrationSensor {
+        type Error = MockError;
+    }
+
+    impl VibrationSpectrum for MockVibrationSensor {
+        fn bin_width(&self) -> Hertz {
+            10.0
+        }
+
+        fn spectrum(&mut self, bins: &mut [MetersPerSecondSquared]) -> Result<usize, Self::Error> {
+            bins.fill(0.0);
+            Ok(bins.len())
+        }
+
+        fn dominant_frequency(&mut self) -> Result<SpectralPeak, Self::Error> {
+            Ok(TEST_PEAK)
+        }
+    }
+
+    #[test]
+    fn test_vibration_spectrum_trait_mut_ref() {
+        let mut sensor = MockVibrationSensor {};
+        let mut_ref = &mut sensor;
+        assert_approx_eq!(mut_ref.bin_width(), 10.0);
+
+        let mut bins = [1.0; 4];
+        assert_eq!(mut_ref.spectrum(&mut bins).unwrap(), 4);
+
+        let result = mut_ref.dominant_frequency();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), TEST_PEAK);
+    }
+
+    #[test]
+    fn test_vibration_velocity_sensor_trait() {
+        let mut sensor = MockVelocitySensor::new();
+        let result = sensor.rms_velocity();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VELOCITY);
+    }
+
+    #[test]
+    fn test_vibration_velocity_threshold_set_mut_ref() {
+        let mut sensor = MockVelocitySensor::new();
+        let mut_ref = &mut sensor;
+
+        let result_low = mut_ref.set_vibration_velocity_threshold_low(TEST_BOUNDARIES.ab);
+        assert!(result_low.is_ok());
+
+        let result_high = mut_ref.set_vibration_velocity_threshold_high(TEST_BOUNDARIES.bc);
+        assert!(result_high.is_ok());
+
+        let result_hyst = mut_ref.set_vibration_velocity_threshold_hysteresis(0.2);
+        assert!(result_hyst.is_ok());
+
+        assert_approx_eq!(sensor.threshold_low.unwrap(), TEST_BOUNDARIES.ab);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), TEST_BOUNDARIES.bc);
+        assert_approx_eq!(sensor.hysteresis.unwrap(), 0.2);
+    }
+
+    #[test]
+    fn test_vibration_zone() {
+        assert_eq!(TEST_BOUNDARIES.zone(0.5), VibrationZone::A);
+        assert_eq!(TEST_BOUNDARIES.zone(1.4), VibrationZone::B);
+        assert_eq!(TEST_BOUNDARIES.zone(TEST_VELOCITY), VibrationZone::C);
+        assert_eq!(TEST_BOUNDARIES.zone(7.1), VibrationZone::D);
+        assert!(VibrationZone::C > VibrationZone::B);
+    }
+
+    #[cfg(feature = "fft")]
+    #[test]
+    fn test_fft_spectrum() {
+        const SAMPLE_RATE: Hertz = 1_000.0;
+        let mut adapter = FftSpectrum::<64>::new(SAMPLE_RATE);
+        assert_eq!(adapter.dominant_frequency(), Err(ErrorKind::NotReady));
+
+        // 2 m/s^2 offset with a 0.5 m/s^2 tone centered on bin 8 (125 Hz).
+        for i in 0..64 {
+            let t = i as f32 / SAMPLE_RATE;
+            adapter.push(2.0 + 0.5 * libm::sinf(2.0 * core::f32::consts::PI * 125.0 * t));
+        }
+        assert!(adapter.is_ready());
+        assert_approx_eq!(adapter.bin_width(), 15.625);
+
+        let peak = adapter.dominant_frequency().unwrap();
+        assert_approx_eq!(peak.frequency, 125.0);
+        assert_approx_eq!(peak.magnitude, 0.5, 1e-3);
+
+        let mut bins = [0.0; 64];
+        assert_eq!(adapter.spectrum(&mut bins).unwrap(), 33);
+        assert_approx_eq!(bins[0], 2.0, 1e-3);
+        assert_approx_eq!(bins[8], 0.5, 1e-3);
+        assert_approx_eq!(bins[20], 0.0, 1e-3);
+
+        adapter.clear();
+        assert!(!adapter.is_ready());
+    }
+}
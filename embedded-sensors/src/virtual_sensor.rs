@@ -0,0 +1,228 @@
+//! Blocking Virtual Sensor API
+//!
+//! This module provides adapters which estimate a quantity at a location that is not directly
+//! measured, from one or more sensors which are.
+//!
+//! A [`VirtualTemperatureSensor`] combines the readings of several temperature sensors through a
+//! [`LinearModel`] (weights and offset, typically identified offline) and exposes the result
+//! through the [`TemperatureSensor`] trait. This is the usual way of estimating e.g. the skin
+//! temperature of a laptop from internal sensors. Estimates which are not finite (e.g. from a
+//! NaN sample) are reported as [`VirtualSensorError::InvalidSample`].
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal::temperature::TemperatureSensor;
+//! use embedded_sensors_hal::virtual_sensor::{
+//!     LinearModel, VirtualSensorError, VirtualTemperatureSensor,
+//! };
+//!
+//! fn skin_temperature<S: TemperatureSensor>(
+//!     cpu: S,
+//!     battery: S,
+//! ) -> Result<f32, VirtualSensorError<S::Error>> {
+//!     // skin = 0.6 * cpu + 0.3 * battery + 2.5
+//!     let model = LinearModel::new([0.6, 0.3], 2.5);
+//!     let mut skin = VirtualTemperatureSensor::new([cpu, battery], model);
+//!     skin.temperature()
+//! }
+//! ```
+
+use crate::sensor::{checked_sample, Error, ErrorKind, ErrorType};
+use crate::temperature::{DegreesCelsius, TemperatureSensor};
+
+/// A linear model mapping `N` measured samples to an estimated sample.
+///
+/// The estimate is computed as `offset + sum(weights[i] * samples[i])`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LinearModel<const N: usize> {
+    /// Weight applied to each measured sample.
+    pub weights: [f32; N],
+    /// Offset added to the weighted sum.
+    pub offset: f32,
+}
+
+impl<const N: usize> LinearModel<N> {
+    /// Creates a new linear model.
+    pub const fn new(weights: [f32; N], offset: f32) -> Self {
+        Self { weights, offset }
+    }
+
+    /// Returns the estimate for the given measured samples.
+    pub fn estimate(&self, samples: &[f32; N]) -> f32 {
+        self.weights
+            .iter()
+            .zip(samples)
+            .fold(self.offset, |acc, (weight, sample)| acc + weight * sample)
+    }
+}
+
+/// Estimates an unmeasured temperature from `N` temperature sensors through a [`LinearModel`].
+///
+/// All sensors must be of the same type. Sensors of different types can be combined by using
+/// trait objects (e.g. `&mut dyn TemperatureSensor<Error = E>`).
+pub struct VirtualTemperatureSensor<S, const N: usize> {
+    sensors: [S; N],
+    model: LinearModel<N>,
+}
+
+impl<S, const N: usize> VirtualTemperatureSensor<S, N> {
+    /// Creates a new virtual temperature sensor.
+    pub fn new(sensors: [S; N], model: LinearModel<N>) -> Self {
+        Self { sensors, model }
+    }
+
+    /// Returns the model used to compute estimates.
+    pub fn model(&self) -> &LinearModel<N> {
+        &self.model
+    }
+
+    /// Consumes the virtual sensor, returning the underlying sensors.
+    pub fn into_inner(self) -> [S; N] {
+        self.sensors
+    }
+}
+
+/// Error returned by a [`VirtualTemperatureSensor`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum VirtualSensorError<E> {
+    /// One of the underlying sensors failed.
+    Sensor(E),
+    /// The estimate is not finite (e.g. an underlying sensor reported NaN).
+    InvalidSample,
+}
+
+impl<E: Error> Error for VirtualSensorError<E> {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Sensor(e) => e.kind(),
+            Self::InvalidSample => ErrorKind::InvalidInput,
+        }
+    }
+}
+
+impl<S: ErrorType, const N: usize> ErrorType for VirtualTemperatureSensor<S, N> {
+    type Error = VirtualSensorError<S::Error>;
+}
+
+impl<S: TemperatureSensor, const N: usize> TemperatureSensor for VirtualTemperatureSensor<S, N> {
+    fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+        let mut samples = [0.0; N];
+        for (sample, sensor) in samples.iter_mut().zip(self.sensors.iter_mut()) {
+            *sample = sensor.temperature().map_err(VirtualSensorError::Sensor)?;
+        }
+
+        checked_sample(self.model.estimate(&samples)).map_err(|_| VirtualSensorError::InvalidSample)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test values
+    const TEST_CPU_TEMP: DegreesCelsius = 60.0;
+    const TEST_BATTERY_TEMP: DegreesCelsius = 35.0;
+    const TEST_MODEL: LinearModel<2> = LinearModel::new([0.5, 0.25], 3.0);
+
+    #[derive(Debug, PartialEq)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Peripheral
+        }
+    }
+
+    struct MockTempSensor {
+        value: Option<DegreesCelsius>,
+    }
+
+    impl crate::sensor::ErrorType for MockTempSensor {
+        type Error = MockError;
+    }
+
+    impl TemperatureSensor for MockTempSensor {
+        fn temperature(&mut self) -> Result<DegreesCelsius, Self::Error> {
+            self.value.ok_or(MockError)
+        }
+    }
+
+    #[test]
+    fn test_linear_model_estimate() {
+        let estimate = TEST_MODEL.estimate(&[TEST_CPU_TEMP, TEST_BATTERY_TEMP]);
+        assert_approx_eq!(
+            estimate,
+            3.0 + 0.5 * TEST_CPU_TEMP + 0.25 * TEST_BATTERY_TEMP
+        );
+    }
+
+    #[test]
+    fn test_virtual_temperature_sensor() {
+        let sensors = [
+            MockTempSensor {
+                value: Some(TEST_CPU_TEMP),
+            },
+            MockTempSensor {
+                value: Some(TEST_BATTERY_TEMP),
+            },
+        ];
+        let mut sensor = VirtualTemperatureSensor::new(sensors, TEST_MODEL);
+        let result = sensor.temperature();
+        assert!(result.is_ok());
+        assert_approx_eq!(
+            result.unwrap(),
+            TEST_MODEL.estimate(&[TEST_CPU_TEMP, TEST_BATTERY_TEMP])
+        );
+    }
+
+    #[test]
+    fn test_virtual_temperature_sensor_error() {
+        let sensors = [
+            MockTempSensor {
+                value: Some(TEST_CPU_TEMP),
+            },
+            MockTempSensor { value: None },
+        ];
+        let mut sensor = VirtualTemperatureSensor::new(sensors, TEST_MODEL);
+        assert_eq!(
+            sensor.temperature(),
+            Err(VirtualSensorError::Sensor(MockError))
+        );
+    }
+
+    #[test]
+    fn test_virtual_temperature_sensor_nan_input() {
+        let sensors = [
+            MockTempSensor {
+                value: Some(TEST_CPU_TEMP),
+            },
+            MockTempSensor {
+                value: Some(f32::NAN),
+            },
+        ];
+        let mut sensor = VirtualTemperatureSensor::new(sensors, TEST_MODEL);
+        let result = sensor.temperature();
+        assert_eq!(result, Err(VirtualSensorError::InvalidSample));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_virtual_temperature_sensor_dyn() {
+        let mut cpu = MockTempSensor {
+            value: Some(TEST_CPU_TEMP),
+        };
+        let mut battery = MockTempSensor {
+            value: Some(TEST_BATTERY_TEMP),
+        };
+        let sensors: [&mut dyn TemperatureSensor<Error = MockError>; 2] = [&mut cpu, &mut battery];
+        let mut sensor = VirtualTemperatureSensor::new(sensors, TEST_MODEL);
+        let result = sensor.temperature();
+        assert!(result.is_ok());
+    }
+}
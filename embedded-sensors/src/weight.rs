@@ -0,0 +1,252 @@
+//! Blocking Weight Sensor API
+//!
+//! This API provides generic methods for interfacing with weight scales specifically.
+//!
+//! Scales are typically load cells read through a bridge amplifier and ADC front-end. The
+//! tare and scale factor operations are part of the API so that applications can calibrate
+//! a scale against known masses: tare the empty scale, place a known mass on it, then set
+//! the scale factor so that the reported weight matches the known mass.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the WeightSensor trait for a load cell scale.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::weight::{Grams, WeightSensor};
+//!
+//! // A struct representing a load cell scale.
+//! pub struct MyWeightSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyWeightSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl WeightSensor for MyWeightSensor {
+//!     fn weight(&mut self) -> Result<Grams, Self::Error> {
+//!         // Read raw ADC value, subtract zero offset and apply scale factor...
+//!         Ok(250.0)
+//!     }
+//!
+//!     fn tare(&mut self) -> Result<(), Self::Error> {
+//!         // Average a few raw samples and store them as the zero offset...
+//!         Ok(())
+//!     }
+//!
+//!     fn set_scale_factor(&mut self, factor: f32) -> Result<(), Self::Error> {
+//!         // Store scale factor used by weight conversion...
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::sensor::Sensor;
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units weight samples are measured in with the underlying data type.
+pub type Grams = f32;
+
+/// Marker type of the weight quantity, of which every [`WeightSensor`] implementor is a [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Weight;
+
+/// Blocking Weight Sensor methods.
+pub trait WeightSensor: Sensor<Weight, Sample = Grams> {
+    /// Returns a weight sample in grams, relative to the load present at the last tare.
+    fn weight(&mut self) -> Result<Grams, Self::Error>;
+
+    /// Tare the scale, so that the current load reads as zero weight.
+    fn tare(&mut self) -> Result<(), Self::Error>;
+
+    /// Set the factor converting raw readings (relative to the tare offset) to grams.
+    fn set_scale_factor(&mut self, factor: f32) -> Result<(), Self::Error>;
+}
+
+impl<T: WeightSensor + ?Sized> WeightSensor for &mut T {
+    #[inline]
+    fn weight(&mut self) -> Result<Grams, Self::Error> {
+        T::weight(self)
+    }
+
+    #[inline]
+    fn tare(&mut self) -> Result<(), Self::Error> {
+        T::tare(self)
+    }
+
+    #[inline]
+    fn set_scale_factor(&mut self, factor: f32) -> Result<(), Self::Error> {
+        T::set_scale_factor(self, factor)
+    }
+}
+
+impl_sensor_refinement!(blocking, Weight, WeightSensor, weight, Grams);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait WeightThresholdSet: WeightSensor {
+//     fn set_weight_threshold_low(&mut self, threshold: Grams) -> Result<(), Self::Error>;
+//     fn set_weight_threshold_high(&mut self, threshold: Grams) -> Result<(), Self::Error>;
+// }
+//
+// pub trait WeightHysteresis: WeightThresholdSet {
+//     fn set_weight_threshold_hysteresis(&mut self, hysteresis: Grams) -> Result<(), Self::Error>;
+// }
+//
+// pub trait WeightThresholdControl: WeightThresholdSet {
+//     fn disable_weight_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_weight_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(blocking, Weight, WeightSensor, Grams, "grams");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test value
+    const TEST_VALUE: Grams = 1_250.0;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockWeightSensor {
+        value: Grams,
+        threshold_low: Option<Grams>,
+        threshold_high: Option<Grams>,
+        hysteresis: Option<Grams>,
+        offset: Grams,
+        scale_factor: f32,
+    }
+
+    impl MockWeightSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+                offset: 0.0,
+                scale_factor: 1.0,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockWeightSensor {
+        type Error = MockError;
+    }
+
+    impl WeightSensor for MockWeightSensor {
+        fn weight(&mut self) -> Result<Grams, Self::Error> {
+            Ok(self.value)
+        }
+
+        fn tare(&mut self) -> Result<(), Self::Error> {
+            self.offset = self.value;
+            Ok(())
+        }
+
+        fn set_scale_factor(&mut self, factor: f32) -> Result<(), Self::Error> {
+            self.scale_factor = factor;
+            Ok(())
+        }
+    }
+
+    impl WeightThresholdSet for MockWeightSensor {
+        fn set_weight_threshold_low(&mut self, threshold: Grams) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_weight_threshold_high(&mut self, threshold: Grams) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl WeightHysteresis for MockWeightSensor {
+        fn set_weight_threshold_hysteresis(
+            &mut self,
+            hysteresis: Grams,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_weight_sensor_trait() {
+        let mut sensor = MockWeightSensor::new();
+        let result = sensor.weight();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_weight_sensor_trait_mut_ref() {
+        let mut sensor = MockWeightSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.weight();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_weight_threshold_set_mut_ref() {
+        let mut sensor = MockWeightSensor::new();
+        let mut_ref = &mut sensor;
+        let low_threshold = 0.0;
+        let high_threshold = 5_000.0;
+
+        let result_low = mut_ref.set_weight_threshold_low(low_threshold);
+        assert!(result_low.is_ok());
+
+        let result_high = mut_ref.set_weight_threshold_high(high_threshold);
+        assert!(result_high.is_ok());
+
+        assert_approx_eq!(sensor.threshold_low.unwrap(), low_threshold);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), high_threshold);
+    }
+
+    #[test]
+    fn test_weight_hysteresis_mut_ref() {
+        let mut sensor = MockWeightSensor::new();
+        let mut_ref = &mut sensor;
+        let hyst = 5.0;
+        let result = mut_ref.set_weight_threshold_hysteresis(hyst);
+        assert!(result.is_ok());
+        assert_approx_eq!(sensor.hysteresis.unwrap(), hyst);
+    }
+
+    #[test]
+    fn test_weight_calibration_mut_ref() {
+        let mut sensor = MockWeightSensor::new();
+        let mut_ref = &mut sensor;
+        assert!(mut_ref.tare().is_ok());
+        assert!(mut_ref.set_scale_factor(0.5).is_ok());
+        assert_approx_eq!(sensor.offset, TEST_VALUE);
+        assert_approx_eq!(sensor.scale_factor, 0.5);
+    }
+}
@@ -0,0 +1,228 @@
+//! Blocking Wind Speed Sensor API
+//!
+//! This API provides generic methods for interfacing with wind speed sensors (anemometers) specifically.
+//!
+//! The API is independent of the measurement principle, and is equally suited to
+//! pulse-counting cup anemometers and ultrasonic anemometers.
+//!
+//! # For HAL authors
+//!
+//! Here is an example for the implementation of the WindSpeedSensor trait for a cup anemometer.
+//!
+//! ```
+//! use embedded_sensors_hal::sensor;
+//! use embedded_sensors_hal::wind::{MetersPerSecond, WindSpeedSensor};
+//!
+//! // A struct representing a cup anemometer.
+//! pub struct MyWindSpeedSensor {
+//!     // ...
+//! }
+//!
+//! #[derive(Clone, Copy, Debug)]
+//! pub enum Error {
+//!     // ...
+//! }
+//!
+//! impl sensor::Error for Error {
+//!     fn kind(&self) -> sensor::ErrorKind {
+//!         match *self {
+//!             // ...
+//!         }
+//!     }
+//! }
+//!
+//! impl sensor::ErrorType for MyWindSpeedSensor {
+//!     type Error = Error;
+//! }
+//!
+//! impl WindSpeedSensor for MyWindSpeedSensor {
+//!     fn wind_speed(&mut self) -> Result<MetersPerSecond, Self::Error> {
+//!         // Convert pulse count over gate time to wind speed...
+//!         Ok(4.2)
+//!     }
+//! }
+//! ```
+
+use crate::sensor::{Sensor, ValidRange};
+use crate::{decl_threshold_traits, impl_sensor_refinement};
+
+/// Associates the units wind speed samples are measured in with the underlying data type.
+pub type MetersPerSecond = f32;
+
+/// Physically plausible range of wind speed samples (up to the strongest recorded gusts).
+pub const WIND_SPEED_RANGE: ValidRange<MetersPerSecond> = ValidRange::new(0.0, 120.0);
+
+/// Marker type of the wind speed quantity, of which every [`WindSpeedSensor`] implementor is a
+/// [`Sensor`].
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WindSpeed;
+
+/// Blocking Wind Speed Sensor methods.
+pub trait WindSpeedSensor: Sensor<WindSpeed, Sample = MetersPerSecond> {
+    /// Returns a wind speed sample in meters per second.
+    fn wind_speed(&mut self) -> Result<MetersPerSecond, Self::Error>;
+}
+
+impl<T: WindSpeedSensor + ?Sized> WindSpeedSensor for &mut T {
+    #[inline]
+    fn wind_speed(&mut self) -> Result<MetersPerSecond, Self::Error> {
+        T::wind_speed(self)
+    }
+}
+
+impl_sensor_refinement!(
+    blocking,
+    WindSpeed,
+    WindSpeedSensor,
+    wind_speed,
+    MetersPerSecond
+);
+
+// This macro generates the following blocking threshold traits:
+//
+// pub trait WindSpeedThresholdSet: WindSpeedSensor {
+//     fn set_wind_speed_threshold_low(&mut self, threshold: MetersPerSecond) -> Result<(), Self::Error>;
+//     fn set_wind_speed_threshold_high(&mut self, threshold: MetersPerSecond) -> Result<(), Self::Error>;
+// }
+//
+// pub trait WindSpeedHysteresis: WindSpeedThresholdSet {
+//     fn set_wind_speed_threshold_hysteresis(&mut self, hysteresis: MetersPerSecond) -> Result<(), Self::Error>;
+// }
+//
+// pub trait WindSpeedThresholdControl: WindSpeedThresholdSet {
+//     fn disable_wind_speed_thresholds(&mut self) -> Result<(), Self::Error>;
+//     fn clear_wind_speed_alert(&mut self) -> Result<(), Self::Error>;
+// }
+decl_threshold_traits!(
+    blocking,
+    WindSpeed,
+    WindSpeedSensor,
+    MetersPerSecond,
+    "meters per second"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensor::{Error, ErrorKind};
+    use assert_approx_eq::assert_approx_eq;
+
+    // Mock test value
+    const TEST_VALUE: MetersPerSecond = 7.5;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    struct MockWindSpeedSensor {
+        value: MetersPerSecond,
+        threshold_low: Option<MetersPerSecond>,
+        threshold_high: Option<MetersPerSecond>,
+        hysteresis: Option<MetersPerSecond>,
+    }
+
+    impl MockWindSpeedSensor {
+        fn new() -> Self {
+            Self {
+                value: TEST_VALUE,
+                threshold_low: None,
+                threshold_high: None,
+                hysteresis: None,
+            }
+        }
+    }
+
+    impl crate::sensor::ErrorType for MockWindSpeedSensor {
+        type Error = MockError;
+    }
+
+    impl WindSpeedSensor for MockWindSpeedSensor {
+        fn wind_speed(&mut self) -> Result<MetersPerSecond, Self::Error> {
+            Ok(self.value)
+        }
+    }
+
+    impl WindSpeedThresholdSet for MockWindSpeedSensor {
+        fn set_wind_speed_threshold_low(
+            &mut self,
+            threshold: MetersPerSecond,
+        ) -> Result<(), Self::Error> {
+            self.threshold_low = Some(threshold);
+            Ok(())
+        }
+
+        fn set_wind_speed_threshold_high(
+            &mut self,
+            threshold: MetersPerSecond,
+        ) -> Result<(), Self::Error> {
+            self.threshold_high = Some(threshold);
+            Ok(())
+        }
+    }
+
+    impl WindSpeedHysteresis for MockWindSpeedSensor {
+        fn set_wind_speed_threshold_hysteresis(
+            &mut self,
+            hysteresis: MetersPerSecond,
+        ) -> Result<(), Self::Error> {
+            self.hysteresis = Some(hysteresis);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_wind_sensor_trait() {
+        let mut sensor = MockWindSpeedSensor::new();
+        let result = sensor.wind_speed();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_wind_sensor_trait_mut_ref() {
+        let mut sensor = MockWindSpeedSensor::new();
+        let mut_ref = &mut sensor;
+        let result = mut_ref.wind_speed();
+        assert!(result.is_ok());
+        assert_approx_eq!(result.unwrap(), TEST_VALUE);
+    }
+
+    #[test]
+    fn test_wind_threshold_set_mut_ref() {
+        let mut sensor = MockWindSpeedSensor::new();
+        let mut_ref = &mut sensor;
+        let low_threshold = 1.0;
+        let high_threshold = 25.0;
+
+        let result_low = mut_ref.set_wind_speed_threshold_low(low_threshold);
+        assert!(result_low.is_ok());
+
+        let result_high = mut_ref.set_wind_speed_threshold_high(high_threshold);
+        assert!(result_high.is_ok());
+
+        assert_approx_eq!(sensor.threshold_low.unwrap(), low_threshold);
+        assert_approx_eq!(sensor.threshold_high.unwrap(), high_threshold);
+    }
+
+    #[test]
+    fn test_wind_hysteresis_mut_ref() {
+        let mut sensor = MockWindSpeedSensor::new();
+        let mut_ref = &mut sensor;
+        let hyst = 0.5;
+        let result = mut_ref.set_wind_speed_threshold_hysteresis(hyst);
+        assert!(result.is_ok());
+        assert_approx_eq!(sensor.hysteresis.unwrap(), hyst);
+    }
+
+    #[test]
+    fn test_wind_range() {
+        assert!(WIND_SPEED_RANGE.validate(TEST_VALUE).is_ok());
+        assert_eq!(WIND_SPEED_RANGE.validate(-1.0), Err(ErrorKind::OutOfRange));
+    }
+}
@@ -0,0 +1,279 @@
+//! Wire Format API
+//!
+//! This module provides `#[repr(C)]` structs with an explicit little-endian encoding for samples,
+//! thresholds and threshold events, so that sensor data can be shared between cores or over IPC
+//! mailboxes regardless of the endianness of either side.
+//!
+//! Every field is stored as a byte array, so the structs have an alignment of one, no padding
+//! and a stable size. They can be converted to and from byte arrays, or from the corresponding
+//! sensor types.
+//!
+//! # For application authors
+//!
+//! ```
+//! use embedded_sensors_hal::sensor::{ThresholdEvent, ThresholdKind};
+//! use embedded_sensors_hal::wire::WireEvent;
+//!
+//! // Producer core.
+//! let event = ThresholdEvent::new(72.5, ThresholdKind::High).with_timestamp(1_000);
+//! let bytes = WireEvent::from(event).to_bytes();
+//!
+//! // Consumer core.
+//! let received = ThresholdEvent::try_from(WireEvent::from_bytes(bytes));
+//! assert_eq!(received, Ok(event));
+//! ```
+
+use crate::sensor::{ErrorKind, ThresholdEvent, ThresholdKind};
+
+/// A sample, encoded as a little-endian IEEE 754 single-precision float.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WireSample {
+    value: [u8; 4],
+}
+
+impl WireSample {
+    /// Size of the encoded sample in bytes.
+    pub const SIZE: usize = 4;
+
+    /// Encodes a sample.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value: value.to_le_bytes(),
+        }
+    }
+
+    /// Returns the decoded sample.
+    pub fn value(&self) -> f32 {
+        f32::from_le_bytes(self.value)
+    }
+
+    /// Returns the encoded sample as bytes.
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.value
+    }
+
+    /// Creates an encoded sample from bytes.
+    pub fn from_bytes(bytes: [u8; Self::SIZE]) -> Self {
+        Self { value: bytes }
+    }
+}
+
+impl From<f32> for WireSample {
+    #[inline]
+    fn from(value: f32) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<WireSample> for f32 {
+    #[inline]
+    fn from(sample: WireSample) -> Self {
+        sample.value()
+    }
+}
+
+/// Threshold configuration of a sensor, encoded as little-endian single-precision floats.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WireThresholds {
+    low: [u8; 4],
+    high: [u8; 4],
+    hysteresis: [u8; 4],
+}
+
+impl WireThresholds {
+    /// Size of the encoded thresholds in bytes.
+    pub const SIZE: usize = 12;
+
+    /// Encodes a threshold configuration.
+    pub fn new(low: f32, high: f32, hysteresis: f32) -> Self {
+        Self {
+            low: low.to_le_bytes(),
+            high: high.to_le_bytes(),
+            hysteresis: hysteresis.to_le_bytes(),
+        }
+    }
+
+    /// Returns the decoded lower threshold.
+    pub fn low(&self) -> f32 {
+        f32::from_le_bytes(self.low)
+    }
+
+    /// Returns the decoded upper threshold.
+    pub fn high(&self) -> f32 {
+        f32::from_le_bytes(self.high)
+    }
+
+    /// Returns the decoded hysteresis.
+    pub fn hysteresis(&self) -> f32 {
+        f32::from_le_bytes(self.hysteresis)
+    }
+
+    /// Returns the encoded thresholds as bytes.
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        bytes[0..4].copy_from_slice(&self.low);
+        bytes[4..8].copy_from_slice(&self.high);
+        bytes[8..12].copy_from_slice(&self.hysteresis);
+        bytes
+    }
+
+    /// Creates encoded thresholds from bytes.
+    pub fn from_bytes(bytes: [u8; Self::SIZE]) -> Self {
+        Self {
+            low: [bytes[0], bytes[1], bytes[2], bytes[3]],
+            high: [bytes[4], bytes[5], bytes[6], bytes[7]],
+            hysteresis: [bytes[8], bytes[9], bytes[10], bytes[11]],
+        }
+    }
+}
+
+/// A threshold event, encoded as its little-endian sample, the crossed threshold and an optional
+/// little-endian timestamp.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WireEvent {
+    value: [u8; 4],
+    kind: u8,
+    flags: u8,
+    reserved: [u8; 2],
+    timestamp_us: [u8; 8],
+}
+
+impl WireEvent {
+    /// Size of the encoded event in bytes.
+    pub const SIZE: usize = 16;
+
+    const KIND_LOW: u8 = 0;
+    const KIND_HIGH: u8 = 1;
+    const FLAG_TIMESTAMP: u8 = 1 << 0;
+
+    /// Returns the encoded event as bytes.
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        bytes[0..4].copy_from_slice(&self.value);
+        bytes[4] = self.kind;
+        bytes[5] = self.flags;
+        bytes[6..8].copy_from_slice(&self.reserved);
+        bytes[8..16].copy_from_slice(&self.timestamp_us);
+        bytes
+    }
+
+    /// Creates an encoded event from bytes.
+    ///
+    /// The bytes are only validated when the event is decoded.
+    pub fn from_bytes(bytes: [u8; Self::SIZE]) -> Self {
+        let mut timestamp_us = [0; 8];
+        timestamp_us.copy_from_slice(&bytes[8..16]);
+        Self {
+            value: [bytes[0], bytes[1], bytes[2], bytes[3]],
+            kind: bytes[4],
+            flags: bytes[5],
+            reserved: [bytes[6], bytes[7]],
+            timestamp_us,
+        }
+    }
+}
+
+impl From<ThresholdEvent<f32>> for WireEvent {
+    fn from(event: ThresholdEvent<f32>) -> Self {
+        Self {
+            value: event.value.to_le_bytes(),
+            kind: match event.kind {
+                ThresholdKind::Low => Self::KIND_LOW,
+                ThresholdKind::High => Self::KIND_HIGH,
+            },
+            flags: if event.timestamp_us.is_some() {
+                Self::FLAG_TIMESTAMP
+            } else {
+                0
+            },
+            reserved: [0; 2],
+            timestamp_us: event.timestamp_us.unwrap_or(0).to_le_bytes(),
+        }
+    }
+}
+
+impl TryFrom<WireEvent> for ThresholdEvent<f32> {
+    type Error = ErrorKind;
+
+    /// Decodes an event, failing with [`ErrorKind::InvalidInput`] if the crossed threshold is not
+    /// a known one.
+    fn try_from(event: WireEvent) -> Result<Self, Self::Error> {
+        let kind = match event.kind {
+            WireEvent::KIND_LOW => ThresholdKind::Low,
+            WireEvent::KIND_HIGH => ThresholdKind::High,
+            _ => return Err(ErrorKind::InvalidInput),
+        };
+
+        let decoded = ThresholdEvent::new(f32::from_le_bytes(event.value), kind);
+        Ok(if event.flags & WireEvent::FLAG_TIMESTAMP != 0 {
+            decoded.with_timestamp(u64::from_le_bytes(event.timestamp_us))
+        } else {
+            decoded
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn test_wire_layout() {
+        assert_eq!(size_of::<WireSample>(), WireSample::SIZE);
+        assert_eq!(size_of::<WireThresholds>(), WireThresholds::SIZE);
+        assert_eq!(size_of::<WireEvent>(), WireEvent::SIZE);
+        assert_eq!(align_of::<WireEvent>(), 1);
+    }
+
+    #[test]
+    fn test_wire_sample() {
+        let sample = WireSample::new(1.0);
+        assert_eq!(sample.to_bytes(), [0x00, 0x00, 0x80, 0x3f]);
+        assert_approx_eq!(f32::from(WireSample::from_bytes(sample.to_bytes())), 1.0);
+    }
+
+    #[test]
+    fn test_wire_thresholds() {
+        let thresholds = WireThresholds::from_bytes(WireThresholds::new(5.0, 70.0, 2.0).to_bytes());
+        assert_approx_eq!(thresholds.low(), 5.0);
+        assert_approx_eq!(thresholds.high(), 70.0);
+        assert_approx_eq!(thresholds.hysteresis(), 2.0);
+    }
+
+    #[test]
+    fn test_wire_event() {
+        let event = ThresholdEvent::new(-12.5, ThresholdKind::Low);
+        let bytes = WireEvent::from(event).to_bytes();
+        assert_eq!(bytes[4..8], [0, 0, 0, 0]);
+        assert_eq!(
+            ThresholdEvent::try_from(WireEvent::from_bytes(bytes)),
+            Ok(event)
+        );
+
+        let event = event.with_timestamp(0x0102_0304_0506_0708);
+        let bytes = WireEvent::from(event).to_bytes();
+        assert_eq!(bytes[8..16], [8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(
+            ThresholdEvent::try_from(WireEvent::from_bytes(bytes)),
+            Ok(event)
+        );
+    }
+
+    #[test]
+    fn test_wire_event_invalid_kind() {
+        let mut bytes = WireEvent::from(ThresholdEvent::new(1.0, ThresholdKind::High)).to_bytes();
+        bytes[4] = 7;
+        assert_eq!(
+            ThresholdEvent::try_from(WireEvent::from_bytes(bytes)),
+            Err(ErrorKind::InvalidInput)
+        );
+    }
+}